@@ -20,6 +20,20 @@ fn edit_data(
     edit_data_at(data_dir(), provide_data)
 }
 
+fn edit_client_profile(name: &str) -> Result<()> {
+    edit_client_profile_at(data_dir(), name, |default| {
+        build_client_profile(name, &default)
+    })
+}
+
+/// Asks for client profiles to add besides the default client until skipped.
+fn add_client_profiles() -> Result<()> {
+    while let Some(name) = ask_for_client_profile_name()? {
+        edit_client_profile(&name)?;
+    }
+    Ok(())
+}
+
 fn edit_email_data(
     provide_data: impl FnOnce(EncryptedEmailSettings) -> Result<EncryptedEmailSettings>,
 ) -> Result<()> {
@@ -73,25 +87,109 @@ fn record_expenses(period: &PeriodAnno, expenses: &[Item]) -> Result<()> {
     record_expenses_with_base_path(period, expenses, data_dir())
 }
 
+fn list_expenses_with_base_path(
+    period: &PeriodAnno,
+    base_path: impl AsRef<Path>,
+) -> Result<Vec<Item>> {
+    let expenses = read_expenses_with_base_path(period, base_path)?;
+    for (index, expense) in expenses.iter().enumerate() {
+        info!("{}. {}", index + 1, expense.to_expense_string());
+    }
+    Ok(expenses)
+}
+
+/// Edits the expense addressed by `input`, or else picked using `select`,
+/// replacing it with the replacement of `input`, if any, or else with the
+/// expense returned by `build`. The discount of the expense, which cannot be
+/// given in the replacement, is kept.
+fn edit_expense_of_input_with_base_path(
+    input: &EditExpenseInput,
+    select: impl FnOnce(&[Item]) -> Result<ExpenseSelector>,
+    build: impl FnOnce(&Item) -> Result<Item>,
+    base_path: impl AsRef<Path>,
+) -> Result<Item> {
+    let base_path = base_path.as_ref();
+    let selector = match input.expense() {
+        Some(selector) => selector.clone(),
+        None => select(&read_expenses_with_base_path(input.period(), base_path)?)?,
+    };
+    edit_expense_with_base_path(
+        input.period(),
+        &selector,
+        |current| match input.replacement() {
+            Some(replacement) => Ok(replacement.clone().with_discount(*current.discount())),
+            None => build(current),
+        },
+        base_path,
+    )
+}
+
+fn run_expenses_command_with_base_path(
+    command: &ExpensesInputCommand,
+    base_path: impl AsRef<Path>,
+) -> Result<()> {
+    let base_path = base_path.as_ref();
+    match command {
+        ExpensesInputCommand::List(input) => {
+            list_expenses_with_base_path(input.period(), base_path).map_to_void()
+        }
+        ExpensesInputCommand::Remove(input) => {
+            remove_expense_with_base_path(input.period(), input.expense(), base_path).map_to_void()
+        }
+        ExpensesInputCommand::Edit(input) => loop {
+            edit_expense_of_input_with_base_path(
+                input,
+                |expenses| select_expense(input.period(), expenses),
+                build_expense,
+                base_path,
+            )?;
+            if input.expense().is_some() || !ask_to_edit_another_expense(input.period())? {
+                return Ok(());
+            }
+        },
+        ExpensesInputCommand::Move(input) => {
+            move_expense_with_base_path(input.period(), input.expense(), input.to(), base_path)
+                .map_to_void()
+        }
+    }
+}
+
 fn record_period_off(period: &PeriodAnno) -> Result<()> {
     record_period_off_with_base_path(period, data_dir())
 }
 
+fn record_date_off(date_off: &DateOff) -> Result<()> {
+    record_date_off_with_base_path::<PeriodAnno>(date_off, data_dir())
+}
+
 pub fn run_data_command(command: &DataAdminInputCommand) -> Result<()> {
     match command {
-        DataAdminInputCommand::Init => init_data(curry2(ask_for_data, None)),
+        DataAdminInputCommand::Init => {
+            init_data(curry2(ask_for_data, None))?;
+            add_client_profiles()
+        }
         DataAdminInputCommand::Dump => dump_data(),
         DataAdminInputCommand::Validate => validate_data(),
-        DataAdminInputCommand::Edit(input) => edit_data(curry2(
-            ask_for_data,
-            Some(DataSelector::from(*input.selector())),
-        )),
+        DataAdminInputCommand::Edit(input) => match (input.selector(), input.name()) {
+            (EditDataInputSelector::ClientProfile, Some(name)) => edit_client_profile(name),
+            (selector, _) => edit_data(curry2(ask_for_data, Some(DataSelector::from(*selector)))),
+        },
         DataAdminInputCommand::PeriodOff(period_off_input) => {
             record_period_off(period_off_input.period())
         }
-        DataAdminInputCommand::Expenses(expenses_input) => {
-            record_expenses(expenses_input.period(), expenses_input.expenses())
-        }
+        DataAdminInputCommand::TimeOff(date_off_input) => match date_off_input.command() {
+            DateOffInputCommand::Add(input) => record_date_off(&input.date_off()?),
+        },
+        DataAdminInputCommand::Expenses(expenses_input) => match expenses_input.command() {
+            Some(command) => run_expenses_command_with_base_path(command, data_dir()),
+            None => record_expenses(
+                expenses_input
+                    .period()
+                    .as_ref()
+                    .expect("Period is required unless a subcommand is given"),
+                expenses_input.expenses(),
+            ),
+        },
     }
 }
 
@@ -120,17 +218,57 @@ pub fn render_sample_with_nonce(use_nonce: bool) -> Result<NamedPdf> {
             .maybe_output_path(path)
             .period(YearMonthAndFortnight::last())
             .build(),
+        None,
         render,
     )
 }
 
+/// The client profile named `client` in the data directory at `data_path`,
+/// if any.
+fn client_profile_with_base_path(
+    client: &Option<String>,
+    data_path: impl AsRef<Path>,
+) -> Result<Option<ClientProfile<PeriodAnno>>> {
+    client
+        .as_ref()
+        .map(|name| read_client_profile_with_base_path(data_path, name))
+        .transpose()
+}
+
+/// The language of the client profile, if any and set, else English.
+fn default_language(profile: &Option<ClientProfile<PeriodAnno>>) -> Language {
+    profile
+        .as_ref()
+        .and_then(|profile| *profile.language())
+        .unwrap_or_default()
+}
+
+/// The email settings with the recipients replaced by those of the client
+/// profile, if it has any.
+fn email_settings_for_profile(
+    email_settings: Option<DecryptedEmailSettings>,
+    profile: &Option<ClientProfile<PeriodAnno>>,
+) -> Option<DecryptedEmailSettings> {
+    match profile {
+        Some(profile) if !profile.recipients().is_empty() => email_settings
+            .map(|email_settings| email_settings.with_recipients(profile.recipients().clone())),
+        _ => email_settings,
+    }
+}
+
 fn run_invoice_command_with_base_path(
     input: InvoiceInput,
     data_path: impl AsRef<Path>,
 ) -> Result<NamedPdf> {
-    let input = input.parsed()?;
+    let data_path = data_path.as_ref();
+    let profile = client_profile_with_base_path(input.client(), data_path)?;
+    let service_fees = match &profile {
+        Some(profile) => profile.service_fees().clone(),
+        None => service_fees(data_path)?,
+    };
+    let input = input.parsed(&service_fees, default_language(&profile))?;
     info!("🔮 Starting PDF creation, input: {:?}...", input);
-    let email_settings = input.email().clone();
+    let email_settings = email_settings_for_profile(input.email().clone(), &profile);
     let named_pdf = create_pdf_with_data_base_path(data_path, input, render)?;
     save_pdf_location_to_tmp_file(named_pdf.saved_at().clone())?;
     if let Some(email_settings) = email_settings {
@@ -139,6 +277,43 @@ fn run_invoice_command_with_base_path(
     Ok(named_pdf)
 }
 
+fn run_reminder_command_with_base_path(
+    input: ReminderInput,
+    data_path: impl AsRef<Path>,
+) -> Result<NamedPdf> {
+    let data_path = data_path.as_ref();
+    let profile = client_profile_with_base_path(input.client(), data_path)?;
+    let input = input.parsed(default_language(&profile))?;
+    info!("🔮 Starting reminder PDF creation, input: {}...", input);
+    let email_settings = email_settings_for_profile(input.invoice().email().clone(), &profile);
+    let named_pdf = create_reminder_pdf_with_data_base_path(data_path, input, render)?;
+    save_pdf_location_to_tmp_file(named_pdf.saved_at().clone())?;
+    if let Some(email_settings) = email_settings {
+        send_email_with_settings_for_pdf(&named_pdf, &email_settings)?
+    }
+    Ok(named_pdf)
+}
+
+fn run_credit_note_command_with_base_path(
+    input: CreditNoteInput,
+    data_path: impl AsRef<Path>,
+) -> Result<NamedPdf> {
+    let data_path = data_path.as_ref();
+    let profile = client_profile_with_base_path(input.client(), data_path)?;
+    let input = input.parsed(
+        &read_ledger_with_base_path(data_path)?,
+        default_language(&profile),
+    )?;
+    info!("🔮 Starting credit note PDF creation, input: {}...", input);
+    let email_settings = email_settings_for_profile(input.invoice().email().clone(), &profile);
+    let named_pdf = create_credit_note_pdf_with_data_base_path(data_path, input, render)?;
+    save_pdf_location_to_tmp_file(named_pdf.saved_at().clone())?;
+    if let Some(email_settings) = email_settings {
+        send_email_with_settings_for_pdf(&named_pdf, &email_settings)?
+    }
+    Ok(named_pdf)
+}
+
 fn validate_email_data_with(
     get_email_password: impl FnOnce() -> Result<SecretString>,
 ) -> Result<DecryptedEmailSettings> {
@@ -175,10 +350,140 @@ pub fn run_email_command(
     }
 }
 
+fn list_ledger_with_base_path(base_path: impl AsRef<Path>) -> Result<Ledger> {
+    let ledger = read_ledger_with_base_path(base_path)?;
+    if ledger.entries().is_empty() {
+        info!("No invoices have been issued yet");
+    }
+    for entry in ledger.entries() {
+        info!("{}", entry);
+    }
+    for (before, after) in ledger.gaps() {
+        warn!("⚠️ Gap in invoice numbers between {} and {}", before, after);
+    }
+    Ok(ledger)
+}
+
+fn show_ledger_entry_with_base_path(
+    number: &FormattedInvoiceNumber,
+    base_path: impl AsRef<Path>,
+) -> Result<Vec<LedgerEntry>> {
+    let ledger = read_ledger_with_base_path(base_path)?;
+    let entries = ledger
+        .entries_with_number(number)
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    if entries.is_empty() {
+        return Err(Error::NoLedgerEntryWithNumber {
+            number: number.to_string(),
+        });
+    }
+    for entry in &entries {
+        let ron_str = ron::ser::to_string_pretty(entry, ron::ser::PrettyConfig::default())
+            .map_err(|e| Error::FailedToRonSerializeData {
+                type_name: type_name::<LedgerEntry>().to_owned(),
+                underlying: format!("{:?}", e),
+            })?;
+        info!("{ron_str}");
+    }
+    Ok(entries)
+}
+
+pub fn run_ledger_command(command: &LedgerInputCommand) -> Result<()> {
+    match command {
+        LedgerInputCommand::List => list_ledger_with_base_path(data_dir()).map_to_void(),
+        LedgerInputCommand::Show(input) => {
+            show_ledger_entry_with_base_path(input.number(), data_dir()).map_to_void()
+        }
+    }
+}
+
+/// The company name of the client of the client profile named `client`, or
+/// of the default client if none, which the retainers are keyed by.
+fn retainer_client_with_base_path(
+    client: &Option<String>,
+    base_path: impl AsRef<Path>,
+) -> Result<String> {
+    let company = match client {
+        Some(name) => read_client_profile_with_base_path(base_path, name)?
+            .client()
+            .clone(),
+        None => read_data_from_disk_with_base_path(base_path)?
+            .client()
+            .clone(),
+    };
+    Ok(company.company_name().clone())
+}
+
+fn show_retainer_with_base_path(
+    client: &Option<String>,
+    base_path: impl AsRef<Path>,
+) -> Result<Retainer> {
+    let base_path = base_path.as_ref();
+    let client = retainer_client_with_base_path(client, base_path)?;
+    let retainer = read_retainer_with_base_path(base_path, &client)?.unwrap_or_default();
+    for transaction in retainer.transactions() {
+        match transaction {
+            RetainerTransaction::TopUp { date, amount } => info!("{}: topped up {}", date, amount),
+            RetainerTransaction::Drawdown {
+                date,
+                invoice_number,
+                amount,
+            } => info!("{}: drew {} for invoice {}", date, amount, invoice_number),
+            RetainerTransaction::Return {
+                date,
+                credit_note_number,
+                invoice_number,
+                amount,
+            } => info!(
+                "{}: returned {} for invoice {} by credit note {}",
+                date, amount, invoice_number, credit_note_number
+            ),
+        }
+    }
+    info!("💰 Retainer balance of {}: {}", client, retainer.balance());
+    Ok(retainer)
+}
+
+fn top_up_retainer_of_client_with_base_path(
+    input: &TopUpRetainerInput,
+    base_path: impl AsRef<Path>,
+) -> Result<Retainer> {
+    let base_path = base_path.as_ref();
+    let client = retainer_client_with_base_path(input.client(), base_path)?;
+    let date = input
+        .date()
+        .unwrap_or_else(|| Date::from(chrono::Local::now().date_naive()));
+    let currency = *read_data_from_disk_with_base_path(base_path)?
+        .payment_info()
+        .currency();
+    top_up_retainer_with_base_path(base_path, &client, date, *input.amount(), currency)
+}
+
+pub fn run_retainer_command(command: &RetainerInputCommand) -> Result<()> {
+    match command {
+        RetainerInputCommand::Show(input) => {
+            show_retainer_with_base_path(input.client(), data_dir()).map_to_void()
+        }
+        RetainerInputCommand::TopUp(input) => {
+            top_up_retainer_of_client_with_base_path(input, data_dir()).map_to_void()
+        }
+    }
+}
+
 pub fn run_invoice_command(input: InvoiceInput) -> Result<NamedPdf> {
     run_invoice_command_with_base_path(input, data_dir())
 }
 
+pub fn run_reminder_command(input: ReminderInput) -> Result<NamedPdf> {
+    run_reminder_command_with_base_path(input, data_dir())
+}
+
+pub fn run_credit_note_command(input: CreditNoteInput) -> Result<NamedPdf> {
+    run_credit_note_command_with_base_path(input, data_dir())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,4 +503,253 @@ mod tests {
         let result = run_invoice_command_with_base_path(input, tempdir.path());
         assert!(result.is_ok(), "Expected run to succeed, got: {:?}", result);
     }
+
+    #[test]
+    fn test_run_reminder_command() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let tempfile = tempdir.path().join("out.pdf");
+        let data = Data::<YearAndMonth>::sample();
+        save_data_with_base_path(data.clone(), tempdir.path()).unwrap();
+        let period = YearAndMonth::last();
+        let number = calculate_invoice_number(
+            data.information().offset(),
+            &period,
+            false,
+            data.information().record_of_periods_off(),
+            data.information().number_reset(),
+        )
+        .unwrap();
+        let number = data.information().format_invoice_number(&number, &period);
+        let input = ReminderInput::parse_from([
+            "reminder",
+            "--number",
+            &number.to_string(),
+            "--period",
+            &period.to_string(),
+            "--paid-on",
+            "2099-12-31",
+            "--out",
+            &format!("{}", tempfile.as_path().display()),
+        ]);
+        let named_pdf = run_reminder_command_with_base_path(input, tempdir.path()).unwrap();
+        assert!(named_pdf.prepared_data().reminder().is_some());
+    }
+
+    #[test]
+    fn test_run_credit_note_command() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::<YearAndMonth>::sample(), tempdir.path()).unwrap();
+        let invoice = InvoiceInput::parse_from([
+            "invoice",
+            "--out",
+            &format!("{}", tempdir.path().join("invoice.pdf").display()),
+        ]);
+        let invoice = run_invoice_command_with_base_path(invoice, tempdir.path()).unwrap();
+        let number = invoice.prepared_data().information().number().to_string();
+        let input = CreditNoteInput::parse_from([
+            "credit-note",
+            "--invoice",
+            &number,
+            "--out",
+            &format!("{}", tempdir.path().join("credit_note.pdf").display()),
+        ]);
+        let named_pdf = run_credit_note_command_with_base_path(input, tempdir.path()).unwrap();
+        let credit_note = named_pdf.prepared_data().credit_note().clone().unwrap();
+        assert_eq!(credit_note.invoice_number().to_string(), number);
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger.entries().len(), 2);
+    }
+
+    #[test]
+    fn test_run_milestone_invoice_and_credit_note() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let data = Data::<YearAndMonth>::sample()
+            .with_milestone_contract(Some(MilestoneContract::sample()));
+        save_data_with_base_path(data, tempdir.path()).unwrap();
+        let out = |name: &str| format!("{}", tempdir.path().join(name).display());
+        let invoice = |name: &str| {
+            let input =
+                InvoiceInput::parse_from(["invoice", "--out", &out(name), "milestone", "Kickoff"]);
+            run_invoice_command_with_base_path(input, tempdir.path())
+        };
+        let kickoff = invoice("kickoff.pdf").unwrap();
+        let result = invoice("kickoff_again.pdf");
+        assert!(matches!(result, Err(Error::MilestoneAlreadyBilled { .. })));
+
+        let number = kickoff.prepared_data().information().number().to_string();
+        let input = CreditNoteInput::parse_from([
+            "credit-note",
+            "--invoice",
+            &number,
+            "--out",
+            &out("credit_note.pdf"),
+        ]);
+        let credit_note = run_credit_note_command_with_base_path(input, tempdir.path()).unwrap();
+        assert_eq!(
+            credit_note
+                .prepared_data()
+                .information()
+                .milestone()
+                .as_deref(),
+            Some("Kickoff")
+        );
+        assert!(invoice("kickoff_rebilled.pdf").is_ok());
+    }
+
+    #[test]
+    fn test_run_commands_for_client_profile() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::<YearAndMonth>::sample(), tempdir.path()).unwrap();
+        let profile =
+            ClientProfile::<YearAndMonth>::sample().with_client(CompanyInformation::sample_other());
+        save_client_profile_with_base_path(&profile, "acme", tempdir.path()).unwrap();
+        let invoice = InvoiceInput::parse_from([
+            "invoice",
+            "--client",
+            "acme",
+            "--out",
+            &format!("{}", tempdir.path().join("invoice.pdf").display()),
+        ]);
+        let invoice = run_invoice_command_with_base_path(invoice, tempdir.path()).unwrap();
+        assert_eq!(invoice.prepared_data().client(), profile.client());
+        let number = invoice.prepared_data().information().number().to_string();
+        let input = CreditNoteInput::parse_from([
+            "credit-note",
+            "--invoice",
+            &number,
+            "--client",
+            "acme",
+            "--out",
+            &format!("{}", tempdir.path().join("credit_note.pdf").display()),
+        ]);
+        let named_pdf = run_credit_note_command_with_base_path(input, tempdir.path()).unwrap();
+        assert_eq!(named_pdf.prepared_data().client(), profile.client());
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger.entries().len(), 2);
+    }
+
+    #[test]
+    fn test_run_invoice_command_for_unknown_client_profile_is_err() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::<YearAndMonth>::sample(), tempdir.path()).unwrap();
+        let input = InvoiceInput::parse_from(["invoice", "--client", "acme"]);
+        let result = run_invoice_command_with_base_path(input, tempdir.path());
+        assert!(matches!(result, Err(Error::NoClientProfileNamed { .. })));
+    }
+
+    #[test]
+    fn test_list_and_show_ledger() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let ledger = list_ledger_with_base_path(tempdir.path()).unwrap();
+        assert!(ledger.entries().is_empty());
+
+        let entry = LedgerEntry::sample();
+        append_to_ledger_with_base_path(&entry, tempdir.path()).unwrap();
+        let ledger = list_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger.entries(), &vec![entry.clone()]);
+
+        let shown = show_ledger_entry_with_base_path(entry.number(), tempdir.path()).unwrap();
+        assert_eq!(shown, vec![entry]);
+
+        let result =
+            show_ledger_entry_with_base_path(&FormattedInvoiceNumber::from("1"), tempdir.path());
+        assert!(matches!(result, Err(Error::NoLedgerEntryWithNumber { .. })));
+    }
+
+    #[test]
+    fn test_top_up_and_show_retainer_of_client_profile() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::<YearAndMonth>::sample(), tempdir.path()).unwrap();
+        let profile =
+            ClientProfile::<YearAndMonth>::sample().with_client(CompanyInformation::sample_other());
+        save_client_profile_with_base_path(&profile, "acme", tempdir.path()).unwrap();
+        let top_up = |args: &[&str]| {
+            let input = CliArgs::parse_from([&[BINARY_NAME, "retainer", "top-up"], args].concat());
+            let input = input.command.unwrap_retainer();
+            let RetainerInputCommand::TopUp(top_up) = input.command() else {
+                panic!("Expected top-up command")
+            };
+            top_up_retainer_of_client_with_base_path(top_up, tempdir.path())
+        };
+        top_up(&["1000"]).unwrap();
+        top_up(&["250", "--client", "acme"]).unwrap();
+        top_up(&["250", "--client", "acme"]).unwrap();
+        let result = top_up(&["0", "--client", "acme"]);
+        assert!(matches!(result, Err(Error::InvalidRetainerTopUp { .. })));
+
+        let default = show_retainer_with_base_path(&None, tempdir.path()).unwrap();
+        assert_eq!(default.balance(), Cost::from_str("1000").unwrap());
+        let acme = show_retainer_with_base_path(&Some("acme".to_owned()), tempdir.path()).unwrap();
+        assert_eq!(acme.balance(), Cost::from_str("500").unwrap());
+        let result = show_retainer_with_base_path(&Some("unknown".to_owned()), tempdir.path());
+        assert!(matches!(result, Err(Error::NoClientProfileNamed { .. })));
+    }
+
+    #[test]
+    fn test_list_remove_edit_and_move_expenses() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::<YearAndMonth>::sample(), tempdir.path()).unwrap();
+        let period = PeriodAnno::from(YearAndMonth::march(2025));
+        record_expenses_with_base_path(
+            &period,
+            &[
+                Item::from_str("Coffe, 2.5, EUR, 3, 2025-05-31").unwrap(),
+                Item::from_str("Lunch, 11, GBP, 2, 2025-05-31, 12%").unwrap(),
+                Item::from_str("Taxi, 30, EUR, 1, 2025-05-31").unwrap(),
+            ],
+            tempdir.path(),
+        )
+        .unwrap();
+        let expenses_command = |args: &[&str]| {
+            let input = CliArgs::parse_from([&[BINARY_NAME, "data", "expenses"], args].concat());
+            let input = input.command.unwrap_data();
+            let DataAdminInputCommand::Expenses(expenses) = input.command() else {
+                panic!("Expected expenses command")
+            };
+            expenses.command().clone().unwrap()
+        };
+
+        let edit = expenses_command(&[
+            "edit",
+            "-p",
+            "2025-03",
+            "coffe",
+            "-e",
+            "Coffee, 2.5, EUR, 3, 2025-05-31",
+        ]);
+        run_expenses_command_with_base_path(&edit, tempdir.path()).unwrap();
+        let edit = expenses_command(&["edit", "-p", "2025-03"]).unwrap_edit();
+        let edited = edit_expense_of_input_with_base_path(
+            &edit,
+            |expenses| {
+                assert_eq!(expenses.len(), 3);
+                Ok(ExpenseSelector::Index(2))
+            },
+            |current| {
+                assert_eq!(current.name(), "Lunch");
+                Item::from_str("Lunch, 11, GBP, 1, 2025-05-30, 12%")
+            },
+            tempdir.path(),
+        )
+        .unwrap();
+        assert_eq!(edited.transaction_date().to_string(), "2025-05-30");
+
+        let remove = expenses_command(&["remove", "-p", "2025-03", "Taxi"]);
+        run_expenses_command_with_base_path(&remove, tempdir.path()).unwrap();
+        let move_lunch = expenses_command(&["move", "-p", "2025-03", "2", "--to", "2025-04"]);
+        run_expenses_command_with_base_path(&move_lunch, tempdir.path()).unwrap();
+
+        let march = list_expenses_with_base_path(&period, tempdir.path()).unwrap();
+        assert_eq!(
+            march,
+            vec![Item::from_str("Coffee, 2.5, EUR, 3, 2025-05-31").unwrap()]
+        );
+        let april = PeriodAnno::from(YearAndMonth::april(2025));
+        let april = list_expenses_with_base_path(&april, tempdir.path()).unwrap();
+        assert_eq!(april, vec![edited]);
+
+        let remove = expenses_command(&["remove", "-p", "2025-03", "Taxi"]);
+        let result = run_expenses_command_with_base_path(&remove, tempdir.path());
+        assert!(matches!(result, Err(Error::NoExpenseMatching { .. })));
+    }
 }
@@ -0,0 +1,218 @@
+use crate::prelude::*;
+
+/// The CLI arguments for generating a credit note PDF reversing a previously
+/// issued invoice, in full or in part.
+#[derive(Debug, Clone, Builder, Getters, Parser)]
+#[command(name = "credit-note")]
+#[command(about = "Generate a credit note PDF reversing an issued invoice", long_about = None)]
+pub struct CreditNoteInput {
+    /// The number of the invoice to credit as printed on it, e.g. `237` or
+    /// `LUP-2025-0042`.
+    #[arg(long, short = 'i')]
+    #[getset(get = "pub")]
+    invoice: FormattedInvoiceNumber,
+
+    /// The period of the invoice to credit, e.g. `2025-05`, if not specified
+    /// it is looked up in the ledger of issued invoices.
+    #[arg(long, short = 'p')]
+    #[getset(get = "pub")]
+    period: Option<PeriodAnno>,
+
+    /// The share of the invoice to credit, e.g. `50` for half of every line
+    /// item, defaults to the full invoice.
+    #[arg(long, default_value_t = Percentage::HUNDRED)]
+    #[builder(default = Percentage::HUNDRED)]
+    #[getset(get = "pub")]
+    percentage: Percentage,
+
+    /// The date of the credit note, defaults to today.
+    #[arg(long, short = 'd')]
+    #[getset(get = "pub")]
+    date: Option<Date>,
+
+    /// The name of the client profile of the invoice, e.g. `acme`, if it
+    /// was issued to a client profile instead of the default client.
+    #[arg(long, short = 'c')]
+    #[getset(get = "pub")]
+    client: Option<String>,
+
+    /// The language of the credit note, defaults to the language of the client
+    /// profile, if any, else English.
+    #[arg(long, short = 'l')]
+    #[getset(get = "pub")]
+    language: Option<Language>,
+
+    /// The layout of the credit note to use
+    #[arg(long, short = 't', default_value_t)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    layout: Layout,
+
+    /// The items of the invoice to credit, e.g. services with the same time
+    /// off as the invoice, if not specified the kind of the invoice in the
+    /// ledger is used.
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    items: Option<TargetItems>,
+
+    /// An optional override of where to save the output PDF file.
+    #[arg(long, short = 'o')]
+    out: Option<PathBuf>,
+
+    /// Whether to send the credit note via email after generating it - if
+    /// the email settings are configured.
+    #[arg(long, short = 'e')]
+    #[builder(default = false)]
+    email: bool,
+}
+
+impl CreditNoteInput {
+    /// Returns a `ValidCreditNoteInput` from the parsed command line arguments,
+    /// looking up the period and kind of the invoice in `ledger` unless
+    /// specified. The language is `default_language` unless specified, e.g.
+    /// the language of the client profile.
+    ///
+    /// # Errors
+    /// Returns an error if the period is not specified and the invoice is not
+    /// in the ledger, if the output path does not exist, if the items are not
+    /// specified correctly or if email is requested but not configured.
+    pub fn parsed(
+        self,
+        ledger: &Ledger,
+        default_language: Language,
+    ) -> Result<ValidCreditNoteInput> {
+        let today = Date::from(chrono::Local::now().date_naive());
+        self.parsed_with_today(ledger, default_language, today)
+    }
+
+    fn parsed_with_today(
+        self,
+        ledger: &Ledger,
+        default_language: Language,
+        today: Date,
+    ) -> Result<ValidCreditNoteInput> {
+        if let Some(path) = &self.out {
+            let parent = path.parent().ok_or_else(|| Error::InvalidOutputPath {
+                path: path.display().to_string(),
+            })?;
+            if !parent.exists() {
+                Err(Error::SpecifiedOutputPathDoesNotExist {
+                    path: path.display().to_string(),
+                })?;
+            }
+        }
+        let issued = ledger.invoice_with_number(&self.invoice);
+        let period = match (self.period, issued) {
+            (Some(period), _) => period,
+            (None, Some(issued)) => issued.period().clone(),
+            (None, None) => {
+                return Err(Error::NoLedgerEntryWithNumber {
+                    number: self.invoice.to_string(),
+                });
+            }
+        };
+        let items = self.items.unwrap_or_else(|| match issued {
+            Some(issued) => match (issued.kind(), issued.milestone()) {
+                (InvoiceKind::Expenses, _) => TargetItems::Expenses,
+                (InvoiceKind::ServicesAndExpenses, _) => TargetItems::All,
+                (InvoiceKind::Milestone, Some(milestone)) => {
+                    TargetItems::Milestone(MilestoneInput::builder().name(milestone).build())
+                }
+                _ => TargetItems::Services,
+            },
+            None => TargetItems::Services,
+        });
+        let email_config = if self.email {
+            validate_email_data().map(Some)
+        } else {
+            Ok(None)
+        }?;
+        let invoice = ValidInput::builder()
+            .period(period)
+            .layout(self.layout)
+            .items(InvoicedItems::try_from(items)?)
+            .language(self.language.unwrap_or(default_language))
+            .maybe_client(self.client)
+            .maybe_maybe_output_path(self.out)
+            .maybe_email(email_config)
+            .build();
+        Ok(ValidCreditNoteInput::builder()
+            .invoice(invoice)
+            .number(self.invoice)
+            .date(self.date.unwrap_or(today))
+            .percentage(self.percentage)
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_input_parsing_credit_note() {
+        let input = CliArgs::parse_from([
+            BINARY_NAME,
+            "credit-note",
+            "--invoice",
+            "237",
+            "--period",
+            "2025-05",
+            "--percentage",
+            "50",
+            "--date",
+            "2025-07-20",
+            "expenses",
+        ]);
+        let input = input.command.unwrap_credit_note();
+        assert_eq!(*input.invoice(), FormattedInvoiceNumber::from("237"));
+        assert_eq!(*input.period(), Some(YearAndMonth::may(2025).into()));
+        assert_eq!(*input.percentage(), Percentage::from_str("50").unwrap());
+        assert_eq!(*input.items(), Some(TargetItems::Expenses));
+        let input = input.parsed(&Ledger::default(), Language::EN).unwrap();
+        assert_eq!(input.date().to_string(), "2025-07-20");
+        assert_eq!(*input.invoice().items(), InvoicedItems::Expenses);
+    }
+
+    #[test]
+    fn test_input_parsing_credit_note_requires_invoice() {
+        let result = CliArgs::try_parse_from([BINARY_NAME, "credit-note", "--period", "2025-05"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parsed_looks_up_period_and_kind_in_ledger() {
+        let today = Date::from_str("2025-08-01").unwrap();
+        let entry = LedgerEntry::sample_other();
+        let input = CreditNoteInput::builder()
+            .invoice(entry.number().clone())
+            .build()
+            .parsed_with_today(&Ledger::from(vec![entry.clone()]), Language::EN, today)
+            .unwrap();
+        assert_eq!(*input.date(), today);
+        assert_eq!(*input.percentage(), Percentage::HUNDRED);
+        assert_eq!(input.invoice().period(), entry.period());
+        assert_eq!(*input.invoice().items(), InvoicedItems::Expenses);
+    }
+
+    #[test]
+    fn test_parsed_without_period_of_unknown_invoice_is_err() {
+        let result = CreditNoteInput::builder()
+            .invoice(FormattedInvoiceNumber::sample())
+            .build()
+            .parsed(&Ledger::default(), Language::EN);
+        assert!(matches!(result, Err(Error::NoLedgerEntryWithNumber { .. })));
+    }
+
+    #[test]
+    fn test_parsed_output_path_without_parent_is_err() {
+        let result = CreditNoteInput::builder()
+            .invoice(FormattedInvoiceNumber::sample())
+            .period(YearAndMonth::may(2025).into())
+            .out(PathBuf::from("/"))
+            .build()
+            .parsed(&Ledger::default(), Language::EN);
+        assert!(matches!(result, Err(Error::InvalidOutputPath { .. })));
+    }
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod credit_note_input;
+
+pub use credit_note_input::*;
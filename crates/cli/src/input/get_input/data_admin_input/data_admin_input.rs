@@ -33,7 +33,11 @@ pub enum DataAdminInputCommand {
     Edit(EditDataInput),
     /// Records a period off for the specified period, which is used to calculate the invoice.
     PeriodOff(PeriodOffInput),
+    /// Records concrete dates off, e.g. a vacation, a half day or some hours,
+    /// which are subtracted from the quantity when invoicing per day or hour.
+    TimeOff(DateOffInput),
     /// Records expenses for the specified period, used to create expenses invoices
-    /// and affects invoice number calculation.
+    /// and affects invoice number calculation, or lists, removes, edits or moves
+    /// recorded expenses.
     Expenses(ExpensesInput),
 }
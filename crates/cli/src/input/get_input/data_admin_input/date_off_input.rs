@@ -0,0 +1,100 @@
+use clap::Args;
+use derive_more::Unwrap;
+
+use crate::prelude::*;
+
+/// Manage concrete dates off, which are subtracted from the quantity when
+/// invoicing per day or hour.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct DateOffInput {
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: DateOffInputCommand,
+}
+
+#[derive(Debug, Subcommand, Unwrap, PartialEq)]
+pub enum DateOffInputCommand {
+    /// Records a date or a range of dates off, e.g. `2025-07-01..2025-07-25`,
+    /// or a half day or some hours off on a date.
+    Add(AddDateOffInput),
+}
+
+/// Record a date, a range of dates, a half day or a number of hours off.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct AddDateOffInput {
+    /// The date or range of dates off, both inclusive.
+    #[arg(help = "The date or range of dates off, e.g. `2025-07-01` or `2025-07-01..2025-07-25`.")]
+    #[getset(get = "pub")]
+    dates: DateOff,
+
+    /// Record only half of the working hours of the date as off.
+    #[arg(long, conflicts_with = "hours", help = "Record a half day off.")]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    half_day: bool,
+
+    /// Record only this many hours of the date as off.
+    #[arg(long, help = "Record a number of hours off, e.g. `2.5`.")]
+    #[getset(get = "pub")]
+    hours: Option<Quantity>,
+}
+
+impl AddDateOffInput {
+    /// The date off to record, taking `--half-day` and `--hours` into account.
+    ///
+    /// # Errors
+    /// Returns an error if `--half-day` or `--hours` is used with anything but
+    /// a single date, or if the hours are invalid.
+    pub fn date_off(&self) -> Result<DateOff> {
+        match (&self.dates, self.half_day, self.hours) {
+            (dates, false, None) => Ok(dates.clone()),
+            (DateOff::Day(date), true, None) => Ok(DateOff::HalfDay(*date)),
+            (DateOff::Day(date), false, Some(hours)) => DateOff::hours(*date, hours),
+            (dates, _, _) => Err(Error::InvalidDateOff {
+                invalid_string: dates.to_string(),
+                reason: "A half day or hours off requires a single date".to_owned(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = AddDateOffInput;
+
+    #[test]
+    fn date_off_of_half_day() {
+        let sut = Sut::builder()
+            .dates(DateOff::from_str("2025-07-28").unwrap())
+            .half_day(true)
+            .build();
+        assert_eq!(
+            sut.date_off().unwrap(),
+            DateOff::from_str("2025-07-28 half").unwrap()
+        );
+    }
+
+    #[test]
+    fn date_off_of_hours() {
+        let sut = Sut::builder()
+            .dates(DateOff::from_str("2025-07-29").unwrap())
+            .hours(Quantity::from_str("2.5").unwrap())
+            .build();
+        assert_eq!(
+            sut.date_off().unwrap(),
+            DateOff::from_str("2025-07-29 2.5h").unwrap()
+        );
+    }
+
+    #[test]
+    fn date_off_of_range_with_hours_is_err() {
+        let sut = Sut::builder()
+            .dates(DateOff::sample())
+            .hours(Quantity::from_str("2.5").unwrap())
+            .build();
+        assert!(matches!(sut.date_off(), Err(Error::InvalidDateOff { .. })));
+    }
+}
@@ -8,6 +8,12 @@ pub struct EditDataInput {
     #[arg(value_enum)]
     #[getset(get = "pub")]
     selector: EditDataInputSelector,
+
+    /// The name of the client profile to add or edit, e.g. `acme`, required
+    /// when editing a client profile.
+    #[arg(long, short = 'n', required_if_eq("selector", "client-profile"))]
+    #[getset(get = "pub")]
+    name: Option<String>,
 }
 
 #[derive(Clone, Copy, Debug, Subcommand, Unwrap, PartialEq, ValueEnum)]
@@ -19,6 +25,7 @@ pub enum EditDataInputSelector {
     Information,
     PaymentInfo,
     ServiceFees,
+    ClientProfile,
 }
 
 impl From<EditDataInputSelector> for DataSelector {
@@ -30,6 +37,7 @@ impl From<EditDataInputSelector> for DataSelector {
             EditDataInputSelector::Information => DataSelector::Information,
             EditDataInputSelector::PaymentInfo => DataSelector::PaymentInfo,
             EditDataInputSelector::ServiceFees => DataSelector::ServiceFees,
+            EditDataInputSelector::ClientProfile => DataSelector::ClientProfile,
         }
     }
 }
@@ -1,8 +1,10 @@
 #[allow(clippy::module_inception)]
 mod data_admin_input;
+mod date_off_input;
 mod edit_data;
 mod period_off_input;
 
 pub use data_admin_input::*;
+pub use date_off_input::*;
 pub use edit_data::*;
 pub use period_off_input::*;
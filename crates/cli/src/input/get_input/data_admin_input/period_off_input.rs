@@ -10,7 +10,7 @@ pub struct PeriodOffInput {
         long,
         short = 'p',
         default_value = None,
-        help = "The period for which you wanna record a period off, e.g. `2025-05`."
+        help = "The period for which you wanna record a period off, e.g. `2025-05`, `2025-06-first-half` or `2025-W27`."
     )]
     #[getset(get = "pub")]
     period: PeriodAnno,
@@ -1,27 +1,205 @@
 use clap::Args;
+use derive_more::Unwrap;
 
 use crate::prelude::*;
 
 /// Record expenses for the specified period, which will be used to create expenses invoices
-/// and affects invoice number calculation.
+/// and affects invoice number calculation, or list, remove, edit or move recorded expenses.
 #[derive(Debug, Args, Getters, PartialEq, Builder)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct ExpensesInput {
+    /// Lists, removes, edits or moves recorded expenses instead of recording
+    /// new ones.
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: Option<ExpensesInputCommand>,
+
     /// The period for which the expenses are recorded.
     #[arg(
         long,
         short = 'p',
-        default_value = None,
-        help = "The period for which you wanna record expenses, e.g. `2025-05`. or `2025-06-first-half`. Note that we might expense for period of May even thought we had an expense in beginning of June, so this is not a strict period, but rather a period in which we want to record the expenses."
+        required = true,
+        help = "The period for which you wanna record expenses, e.g. `2025-05`, `2025-06-first-half` or `2025-W27`. Note that we might expense for period of May even thought we had an expense in beginning of June, so this is not a strict period, but rather a period in which we want to record the expenses."
     )]
     #[getset(get = "pub")]
-    period: PeriodAnno,
+    period: Option<PeriodAnno>,
 
     /// The expenses to record for the period, which are specified as a list of items.
     /// Please note that the transaction date might be different from the month specified,
     /// so you can record expenses for a month even if the transaction date is in the next
     /// month, e.g. you can record expenses for May even if the transaction date is in June.
-    /// Format for each item is: `name,amount,currency,quantity,date`, e.g. `Coffee,2.5,EUR,3.0,2025-05-31`.
+    /// Format for each item is: `name,amount,currency,quantity,date`, e.g. `Coffee,2.5,EUR,3.0,2025-05-31`,
+    /// optionally followed by a tax rate, e.g. `Coffee,2.5,EUR,3.0,2025-05-31,12%`, and
+    /// the path to a receipt, e.g. `Taxi,30,EUR,1,2025-05-31,,/path/to/taxi.pdf` without tax rate.
     #[arg(long, short = 'e', help = "The expenses to record for the period.")]
+    #[builder(default)]
     #[getset(get = "pub")]
     expenses: Vec<Item>,
 }
+
+/// The commands managing the expenses already recorded for a period, which
+/// address an expense by its position as listed, starting at `1`, or by its
+/// name.
+#[derive(Clone, Debug, Subcommand, Unwrap, PartialEq)]
+pub enum ExpensesInputCommand {
+    /// Lists the expenses recorded for a period, numbered by position.
+    List(ListExpensesInput),
+    /// Removes an expense recorded for a period.
+    Remove(RemoveExpenseInput),
+    /// Replaces an expense recorded for a period, or fixes its fields
+    /// interactively if no replacement is given.
+    Edit(EditExpenseInput),
+    /// Moves an expense recorded for a period to another period.
+    Move(MoveExpenseInput),
+}
+
+#[derive(Clone, Debug, Args, Getters, PartialEq)]
+pub struct ListExpensesInput {
+    /// The period of the expenses, e.g. `2025-05`.
+    #[arg(long, short = 'p')]
+    #[getset(get = "pub")]
+    period: PeriodAnno,
+}
+
+#[derive(Clone, Debug, Args, Getters, PartialEq)]
+pub struct RemoveExpenseInput {
+    /// The period of the expense, e.g. `2025-05`.
+    #[arg(long, short = 'p')]
+    #[getset(get = "pub")]
+    period: PeriodAnno,
+
+    /// The position of the expense as listed, e.g. `2`, or its name, e.g.
+    /// `Coffee`.
+    #[getset(get = "pub")]
+    expense: ExpenseSelector,
+}
+
+#[derive(Clone, Debug, Args, Getters, PartialEq)]
+pub struct EditExpenseInput {
+    /// The period of the expense, e.g. `2025-05`.
+    #[arg(long, short = 'p')]
+    #[getset(get = "pub")]
+    period: PeriodAnno,
+
+    /// The position of the expense as listed, e.g. `2`, or its name, e.g.
+    /// `Coffee`, chosen from a list if omitted.
+    #[getset(get = "pub")]
+    expense: Option<ExpenseSelector>,
+
+    /// The replacing expense, in the same format as when recording expenses,
+    /// e.g. `Coffee,2.5,EUR,3.0,2025-05-31`. Without it, the fields of the
+    /// expense are edited interactively.
+    #[arg(long, short = 'e', requires = "expense")]
+    #[getset(get = "pub")]
+    replacement: Option<Item>,
+}
+
+#[derive(Clone, Debug, Args, Getters, PartialEq)]
+pub struct MoveExpenseInput {
+    /// The period of the expense, e.g. `2025-05`.
+    #[arg(long, short = 'p')]
+    #[getset(get = "pub")]
+    period: PeriodAnno,
+
+    /// The position of the expense as listed, e.g. `2`, or its name, e.g.
+    /// `Coffee`.
+    #[getset(get = "pub")]
+    expense: ExpenseSelector,
+
+    /// The period to move the expense to, e.g. `2025-06`.
+    #[arg(long, short = 't')]
+    #[getset(get = "pub")]
+    to: PeriodAnno,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn parse_expenses_command(args: &[&str]) -> ExpensesInputCommand {
+        let input = CliArgs::parse_from([&[BINARY_NAME, "data", "expenses"], args].concat());
+        let input = input.command.unwrap_data();
+        let DataAdminInputCommand::Expenses(expenses) = input.command() else {
+            panic!("Expected expenses command")
+        };
+        assert_eq!(*expenses.period(), None);
+        expenses
+            .command()
+            .clone()
+            .expect("Expected expenses subcommand")
+    }
+
+    #[test]
+    fn test_input_parsing_expenses_list() {
+        let list = parse_expenses_command(&["list", "--period", "2025-05"]).unwrap_list();
+        assert_eq!(list.period().to_string(), "2025-05");
+    }
+
+    #[test]
+    fn test_input_parsing_expenses_remove() {
+        let remove =
+            parse_expenses_command(&["remove", "--period", "2025-05", "2"]).unwrap_remove();
+        assert_eq!(*remove.expense(), ExpenseSelector::Index(2));
+    }
+
+    #[test]
+    fn test_input_parsing_expenses_edit() {
+        let edit = parse_expenses_command(&[
+            "edit",
+            "-p",
+            "2025-05",
+            "Coffee",
+            "-e",
+            "Coffee,2.5,EUR,3.0,2025-05-31",
+        ])
+        .unwrap_edit();
+        assert_eq!(
+            *edit.expense(),
+            Some(ExpenseSelector::Name("Coffee".to_owned()))
+        );
+        assert_eq!(
+            *edit.replacement(),
+            Some(Item::from_str("Coffee,2.5,EUR,3.0,2025-05-31").unwrap())
+        );
+
+        let interactive = parse_expenses_command(&["edit", "-p", "2025-05"]).unwrap_edit();
+        assert_eq!(*interactive.expense(), None);
+        assert_eq!(*interactive.replacement(), None);
+    }
+
+    #[test]
+    fn test_input_parsing_expenses_edit_replacement_requires_expense() {
+        let result = CliArgs::try_parse_from([
+            BINARY_NAME,
+            "data",
+            "expenses",
+            "edit",
+            "-p",
+            "2025-05",
+            "-e",
+            "Coffee,2.5,EUR,3.0,2025-05-31",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_input_parsing_expenses_move() {
+        let move_input = parse_expenses_command(&["move", "-p", "2025-05", "1", "--to", "2025-06"])
+            .unwrap_move();
+        assert_eq!(*move_input.expense(), ExpenseSelector::Index(1));
+        assert_eq!(move_input.to().to_string(), "2025-06");
+    }
+
+    #[test]
+    fn test_input_parsing_expenses_record_requires_period() {
+        let result = CliArgs::try_parse_from([
+            BINARY_NAME,
+            "data",
+            "expenses",
+            "-e",
+            "Coffee,2.5,EUR,3.0,2025-05-31",
+        ]);
+        assert!(result.is_err());
+    }
+}
@@ -24,8 +24,22 @@ pub enum Command {
     /// The CLI arguments for generating an invoice PDF.
     Invoice(InvoiceInput),
 
+    /// The CLI arguments for generating a payment reminder PDF of an overdue
+    /// invoice.
+    Reminder(ReminderInput),
+
+    /// The CLI arguments for generating a credit note PDF reversing an issued
+    /// invoice.
+    CreditNote(CreditNoteInput),
+
     /// CLI arguments for admin tasks related to data.
     Data(DataAdminInput),
+
+    /// Lists and shows the issued invoices recorded in the ledger.
+    Ledger(LedgerInput),
+
+    /// Shows or tops up the balance prepaid by a client paying a retainer.
+    Retainer(RetainerInput),
 }
 
 /// The CLI arguments for generating an invoice PDF.
@@ -39,11 +53,50 @@ pub struct InvoiceInput {
     #[getset(get = "pub")]
     period: TargetPeriod,
 
-    /// The language for which the invoice is generated.
-    #[arg(long, short = 'l', default_value_t)]
-    #[builder(default)]
+    /// The first day of a custom date range to invoice for instead of a
+    /// period, e.g. `2025-05-10`, the invoice number is that of the period
+    /// containing the last day of the range, whose regular invoice it
+    /// replaces.
+    #[arg(long, requires = "to", conflicts_with = "period")]
+    #[getset(get = "pub")]
+    from: Option<Date>,
+
+    /// The last day, inclusive, of a custom date range to invoice for, e.g.
+    /// `2025-06-15`.
+    #[arg(long, requires = "from", conflicts_with = "period")]
+    #[getset(get = "pub")]
+    to: Option<Date>,
+
+    /// The name of the client profile to invoice, e.g. `acme`, instead of the
+    /// default client, see `klirr data edit client-profile`.
+    #[arg(long, short = 'c')]
+    #[getset(get = "pub")]
+    client: Option<String>,
+
+    /// The invoice date, defaults to the last day of the period or date range.
+    #[arg(long, short = 'd')]
+    #[getset(get = "pub")]
+    date: Option<Date>,
+
+    /// The language for which the invoice is generated, defaults to the
+    /// language of the client profile, if any, else English.
+    #[arg(long, short = 'l')]
+    #[getset(get = "pub")]
+    language: Option<Language>,
+
+    /// A discount of the whole invoice, either a percentage, e.g. `10%`, or an
+    /// amount, e.g. `500`, replacing the standing discount of the invoice
+    /// information, if any.
+    #[arg(long)]
     #[getset(get = "pub")]
-    language: Language,
+    discount: Option<Discount>,
+
+    /// A discount of the service fees, either a percentage, e.g. `10%`, or an
+    /// amount, e.g. `500`, replacing the standing discount of the service
+    /// fees, if any.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    service_discount: Option<Discount>,
 
     /// The layout of the invoice to use
     #[arg(long, short = 't', default_value_t)]
@@ -71,26 +124,28 @@ pub struct InvoiceInput {
 impl InvoiceInput {
     /// Maps `Option<TargetItems>` to `InvoicedItems`.
     fn _invoiced_items(&self) -> Result<InvoicedItems> {
-        match self.items.clone().unwrap_or_default() {
-            TargetItems::ServicesOff(time_off) => {
-                let time_off = TimeOff::try_from(time_off)?;
-                Ok(InvoicedItems::Service {
-                    time_off: Some(time_off),
-                })
-            }
-            TargetItems::Services => Ok(InvoicedItems::Service { time_off: None }),
-            TargetItems::Expenses => Ok(InvoicedItems::Expenses),
-        }
+        InvoicedItems::try_from(self.items.clone().unwrap_or_default())
     }
 
     /// Returns a `ValidInput` from the parsed command line arguments.
     /// This function validates the input, e.g. checks if the output path exists,
     /// and returns a `ValidInput` that can be used to generate the invoice.
     ///
+    /// The cadence of the `service_fees` decides the kind of the target period,
+    /// i.e. a week for weekly cadence. If a date range is given the target
+    /// period is the one containing the last day of the range.
+    ///
+    /// The language is `default_language` unless specified, e.g. the language
+    /// of the client profile.
+    ///
     /// # Errors
     /// Returns an error if the input is invalid, e.g. if the output path does not
     /// exist or if the items are not specified correctly.
-    pub fn parsed(self) -> Result<ValidInput> {
+    pub fn parsed(
+        self,
+        service_fees: &ServiceFees,
+        default_language: Language,
+    ) -> Result<ValidInput> {
         if let Some(path) = &self.out {
             let parent = path
                 .parent()
@@ -107,12 +162,24 @@ impl InvoiceInput {
             Ok(None)
         }?;
         let items = self._invoiced_items()?;
-        let period = self.period.period();
+        let date_range = match (self.from, self.to) {
+            (Some(start), Some(end)) => Some(DateRange::builder().start(start).end(end).build()?),
+            _ => None,
+        };
+        let period = match &date_range {
+            Some(date_range) => service_fees.period_containing(date_range.end())?,
+            None => self.period.period(service_fees)?,
+        };
         let valid = ValidInput::builder()
             .period(period)
+            .maybe_date_range(date_range)
+            .maybe_invoice_date(self.date)
             .layout(*self.layout())
             .items(items)
-            .language(*self.language())
+            .language(self.language.unwrap_or(default_language))
+            .maybe_client(self.client)
+            .maybe_discount(self.discount)
+            .maybe_service_discount(self.service_discount)
             .maybe_maybe_output_path(self.out)
             .maybe_email(email_config)
             .build();
@@ -133,6 +200,30 @@ mod tests {
             assert!(matches!(input.command, Command::Data(_)));
         }
 
+        #[test]
+        fn test_data_admin_edit_client_profile() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "data",
+                "edit",
+                "client-profile",
+                "--name",
+                "acme",
+            ]);
+            let input = input.command.unwrap_data();
+            let DataAdminInputCommand::Edit(edit) = input.command() else {
+                panic!("Expected edit command");
+            };
+            assert_eq!(*edit.selector(), EditDataInputSelector::ClientProfile);
+            assert_eq!(edit.name().as_deref(), Some("acme"));
+        }
+
+        #[test]
+        fn test_data_admin_edit_client_profile_requires_name() {
+            let result = CliArgs::try_parse_from([BINARY_NAME, "data", "edit", "client-profile"]);
+            assert!(result.is_err());
+        }
+
         #[test]
         fn test_data_admin_validate() {
             let input = CliArgs::parse_from([BINARY_NAME, "data", "validate"]);
@@ -181,16 +272,107 @@ mod tests {
                 assert_eq!(input.command.unwrap_invoice().period, TargetPeriod::Last);
             }
 
+            #[test]
+            fn test_input_parsing_date_range() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "--from",
+                    "2025-05-10",
+                    "--to",
+                    "2025-06-15",
+                    "--date",
+                    "2025-06-16",
+                ]);
+                let input = input.command.unwrap_invoice();
+                assert_eq!(input.from, Some(Date::from_str("2025-05-10").unwrap()));
+                assert_eq!(input.to, Some(Date::from_str("2025-06-15").unwrap()));
+                assert_eq!(input.date, Some(Date::from_str("2025-06-16").unwrap()));
+            }
+
+            #[test]
+            fn test_input_parsing_date_range_requires_both_ends() {
+                let result =
+                    CliArgs::try_parse_from([BINARY_NAME, "invoice", "--from", "2025-05-10"]);
+                assert!(result.is_err());
+            }
+
+            #[test]
+            fn test_input_parsing_date_range_conflicts_with_period() {
+                let result = CliArgs::try_parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "--period",
+                    "last",
+                    "--from",
+                    "2025-05-10",
+                    "--to",
+                    "2025-06-15",
+                ]);
+                assert!(result.is_err());
+            }
+
             #[test]
             fn test_input_parsing_language_specified() {
                 let input = CliArgs::parse_from([BINARY_NAME, "invoice", "--language", "swedish"]);
-                assert_eq!(input.command.unwrap_invoice().language, Language::SV);
+                assert_eq!(input.command.unwrap_invoice().language, Some(Language::SV));
             }
 
             #[test]
             fn test_input_parsing_language_default() {
                 let input = CliArgs::parse_from([BINARY_NAME, "invoice"]);
-                assert_eq!(input.command.unwrap_invoice().language, Language::EN);
+                assert_eq!(input.command.unwrap_invoice().language, None);
+            }
+
+            #[test]
+            fn test_input_parsing_client_profile() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "--client", "acme"]);
+                let input = input
+                    .command
+                    .unwrap_invoice()
+                    .parsed(&ServiceFees::sample(), Language::SV)
+                    .unwrap();
+                assert_eq!(input.client().as_deref(), Some("acme"));
+                assert_eq!(*input.language(), Language::SV);
+            }
+
+            #[test]
+            fn test_input_parsing_discounts() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "--discount",
+                    "10%",
+                    "--service-discount",
+                    "500",
+                ]);
+                let input = input
+                    .command
+                    .unwrap_invoice()
+                    .parsed(&ServiceFees::sample(), Language::EN)
+                    .unwrap();
+                assert_eq!(*input.discount(), Some(Discount::from_str("10%").unwrap()));
+                assert_eq!(
+                    *input.service_discount(),
+                    Some(Discount::from_str("500").unwrap())
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_invalid_discount() {
+                let result = CliArgs::try_parse_from([BINARY_NAME, "invoice", "--discount", "ten"]);
+                assert!(result.is_err());
+            }
+
+            #[test]
+            fn test_input_parsing_language_overrides_default_language() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "--language", "english"]);
+                let input = input
+                    .command
+                    .unwrap_invoice()
+                    .parsed(&ServiceFees::sample(), Language::SV)
+                    .unwrap();
+                assert_eq!(*input.language(), Language::EN);
             }
 
             #[test]
@@ -215,6 +397,28 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn test_input_parsing_items_specified_timesheet() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "timesheet",
+                    "--file",
+                    "hours.csv",
+                    "--format",
+                    "toggl",
+                ]);
+                assert_eq!(
+                    input.command.unwrap_invoice().items,
+                    Some(TargetItems::Timesheet(
+                        TimesheetInput::builder()
+                            .file(PathBuf::from("hours.csv"))
+                            .format(TimesheetFormat::Toggl)
+                            .build()
+                    ))
+                );
+            }
+
             #[test]
             fn test_input_parsing_items_specified_services_not_off() {
                 let input = CliArgs::parse_from([BINARY_NAME, "invoice", "services"]);
@@ -233,6 +437,12 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn test_input_parsing_items_specified_all() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "all"]);
+                assert_eq!(input.command.unwrap_invoice().items, Some(TargetItems::All));
+            }
+
             #[test]
             fn test_input_parsing_items_default() {
                 let input = CliArgs::parse_from([BINARY_NAME, "invoice"]);
@@ -270,7 +480,7 @@ mod tests {
                             .build(),
                     ))
                     .build();
-                let input = input.parsed().unwrap();
+                let input = input.parsed(&ServiceFees::sample(), Language::EN).unwrap();
                 let expected_decimal = Decimal::try_from(25.0).unwrap();
                 let expected_quantity = Quantity::from(expected_decimal);
                 assert_eq!(
@@ -281,19 +491,104 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn test_input_parsing_items_timesheet() {
+                let tempdir = tempfile::tempdir().unwrap();
+                let file = tempdir.path().join("hours.csv");
+                std::fs::write(&file, "2025-05-02,7.5,Code review\n2025-05-05,8").unwrap();
+                let input = InvoiceInput::builder()
+                    .items(TargetItems::Timesheet(
+                        TimesheetInput::builder().file(file).build(),
+                    ))
+                    .build();
+                let input = input.parsed(&ServiceFees::sample(), Language::EN).unwrap();
+                assert_eq!(
+                    *input.items(),
+                    InvoicedItems::Timesheet {
+                        timesheet: Timesheet::sample()
+                    }
+                );
+            }
+
             #[test]
             fn test_input_parsing_items_expenses() {
                 let input = InvoiceInput::builder().items(TargetItems::Expenses).build();
-                let input = input.parsed().unwrap();
+                let input = input.parsed(&ServiceFees::sample(), Language::EN).unwrap();
                 assert_eq!(*input.items(), InvoicedItems::Expenses);
             }
 
+            #[test]
+            fn test_input_parsing_items_all() {
+                let input = InvoiceInput::builder().items(TargetItems::All).build();
+                let input = input.parsed(&ServiceFees::sample(), Language::EN).unwrap();
+                assert_eq!(
+                    *input.items(),
+                    InvoicedItems::ServiceAndExpenses { time_off: None }
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_items_milestone() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "milestone", "Kickoff"]);
+                let input = input
+                    .command
+                    .unwrap_invoice()
+                    .parsed(&ServiceFees::sample(), Language::EN)
+                    .unwrap();
+                assert_eq!(
+                    *input.items(),
+                    InvoicedItems::Milestone {
+                        name: "Kickoff".to_owned()
+                    }
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_period_weekly() {
+                let input = InvoiceInput::builder().build();
+                let service_fees = ServiceFees::builder()
+                    .name("Consulting")
+                    .rate(Rate::daily(UnitPrice::ONE))
+                    .cadence(Cadence::Weekly)
+                    .build()
+                    .unwrap();
+                let input = input.parsed(&service_fees, Language::EN).unwrap();
+                assert_eq!(*input.period(), PeriodAnno::from(YearAndWeek::last()));
+            }
+
+            #[test]
+            fn test_input_parsing_date_range_period_contains_end() {
+                let input = InvoiceInput::builder()
+                    .from(Date::from_str("2025-05-10").unwrap())
+                    .to(Date::from_str("2025-06-15").unwrap())
+                    .build();
+                let input = input.parsed(&ServiceFees::sample(), Language::EN).unwrap();
+                assert_eq!(*input.date_range(), Some(DateRange::sample()));
+                assert_eq!(
+                    *input.period(),
+                    PeriodAnno::from(YearMonthAndFortnight::year_and_month_with_half(
+                        YearAndMonth::june(2025),
+                        MonthHalf::First,
+                    ))
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_date_range_end_before_start_is_err() {
+                let input = InvoiceInput::builder()
+                    .from(Date::from_str("2025-06-15").unwrap())
+                    .to(Date::from_str("2025-05-10").unwrap())
+                    .build();
+                let result = input.parsed(&ServiceFees::sample(), Language::EN);
+                assert!(matches!(result, Err(Error::InvalidDateRange { .. })));
+            }
+
             #[test]
             fn test_input_parsing_out() {
                 let input = InvoiceInput::builder()
                     .out(PathBuf::from("/tmp/invoice.pdf"))
                     .build();
-                let input = input.parsed().unwrap();
+                let input = input.parsed(&ServiceFees::sample(), Language::EN).unwrap();
                 assert_eq!(
                     *input.maybe_output_path(),
                     Some(PathBuf::from("/tmp/invoice.pdf"))
@@ -304,7 +599,7 @@ mod tests {
             #[should_panic]
             fn test_input_parsing_out_at_root_crashes() {
                 let input = InvoiceInput::builder().out(PathBuf::from("/")).build();
-                let _ = input.parsed();
+                let _ = input.parsed(&ServiceFees::sample(), Language::EN);
             }
         }
     }
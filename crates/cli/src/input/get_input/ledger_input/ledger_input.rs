@@ -0,0 +1,54 @@
+use clap::Args;
+use derive_more::Unwrap;
+
+use crate::prelude::*;
+
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct LedgerInput {
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: LedgerInputCommand,
+}
+
+#[derive(Debug, Subcommand, Unwrap, PartialEq)]
+pub enum LedgerInputCommand {
+    /// Lists all issued invoices recorded in the ledger in the data
+    /// directory, and any gaps between their numbers.
+    List,
+    /// Shows the ledger entries of the issued invoice with the number.
+    Show(ShowLedgerEntryInput),
+}
+
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct ShowLedgerEntryInput {
+    /// The number of the invoice as printed on it, e.g. `237` or
+    /// `LUP-2025-0042`.
+    #[getset(get = "pub")]
+    number: FormattedInvoiceNumber,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_input_parsing_ledger_list() {
+        let input = CliArgs::parse_from([BINARY_NAME, "ledger", "list"]);
+        let input = input.command.unwrap_ledger();
+        assert_eq!(*input.command(), LedgerInputCommand::List);
+    }
+
+    #[test]
+    fn test_input_parsing_ledger_show() {
+        let input = CliArgs::parse_from([BINARY_NAME, "ledger", "show", "LUP-2025-0042"]);
+        let input = input.command.unwrap_ledger();
+        let LedgerInputCommand::Show(show) = input.command() else {
+            panic!("Expected show command")
+        };
+        assert_eq!(
+            show.number(),
+            &FormattedInvoiceNumber::from("LUP-2025-0042")
+        );
+    }
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod ledger_input;
+
+pub use ledger_input::*;
@@ -1,10 +1,18 @@
+mod credit_note_input;
 mod data_admin_input;
 mod email_input;
 mod expenses_input;
 #[allow(clippy::module_inception)]
 mod get_input;
+mod ledger_input;
+mod reminder_input;
+mod retainer_input;
 
+pub use credit_note_input::*;
 pub use data_admin_input::*;
 pub use email_input::*;
 pub use expenses_input::*;
 pub use get_input::*;
+pub use ledger_input::*;
+pub use reminder_input::*;
+pub use retainer_input::*;
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod reminder_input;
+
+pub use reminder_input::*;
@@ -0,0 +1,178 @@
+use crate::prelude::*;
+
+/// The CLI arguments for generating a payment reminder PDF of a previously
+/// issued invoice which is overdue, charging late payment interest and a
+/// reminder fee.
+#[derive(Debug, Clone, Builder, Getters, Parser)]
+#[command(name = "reminder")]
+#[command(about = "Generate a payment reminder PDF of an overdue invoice", long_about = None)]
+pub struct ReminderInput {
+    /// The number of the overdue invoice as printed on it, e.g. `237` or
+    /// `LUP-2025-0042`.
+    #[arg(long, short = 'n')]
+    #[getset(get = "pub")]
+    number: FormattedInvoiceNumber,
+
+    /// The period of the overdue invoice, e.g. `2025-05`, `2025-06-first-half`
+    /// or `2025-W27`.
+    #[arg(long, short = 'p')]
+    #[getset(get = "pub")]
+    period: PeriodAnno,
+
+    /// The date the invoice was paid, after the due date, charging late
+    /// payment interest until then. If not specified the invoice is unpaid and
+    /// interest is charged until today.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    paid_on: Option<Date>,
+
+    /// The name of the client profile of the invoice, e.g. `acme`, if it
+    /// was issued to a client profile instead of the default client.
+    #[arg(long, short = 'c')]
+    #[getset(get = "pub")]
+    client: Option<String>,
+
+    /// The language of the reminder, defaults to the language of the client
+    /// profile, if any, else English.
+    #[arg(long, short = 'l')]
+    #[getset(get = "pub")]
+    language: Option<Language>,
+
+    /// The layout of the reminder to use
+    #[arg(long, short = 't', default_value_t)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    layout: Layout,
+
+    /// The items of the overdue invoice, e.g. `expenses` for an expenses
+    /// invoice, or services with the same time off as the invoice.
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    items: Option<TargetItems>,
+
+    /// An optional override of where to save the output PDF file.
+    #[arg(long, short = 'o')]
+    out: Option<PathBuf>,
+
+    /// Whether to send the reminder via email after generating it - if
+    /// the email settings are configured.
+    #[arg(long, short = 'e')]
+    #[builder(default = false)]
+    email: bool,
+}
+
+impl ReminderInput {
+    /// Returns a `ValidReminderInput` from the parsed command line arguments,
+    /// charging interest until today unless the invoice was paid. The language
+    /// is `default_language` unless specified, e.g. the language of the client
+    /// profile.
+    ///
+    /// # Errors
+    /// Returns an error if the output path does not exist, if the items are not
+    /// specified correctly or if email is requested but not configured.
+    pub fn parsed(self, default_language: Language) -> Result<ValidReminderInput> {
+        let today = Date::from(chrono::Local::now().date_naive());
+        self.parsed_with_today(default_language, today)
+    }
+
+    fn parsed_with_today(
+        self,
+        default_language: Language,
+        today: Date,
+    ) -> Result<ValidReminderInput> {
+        if let Some(path) = &self.out {
+            let parent = path.parent().ok_or_else(|| Error::InvalidOutputPath {
+                path: path.display().to_string(),
+            })?;
+            if !parent.exists() {
+                Err(Error::SpecifiedOutputPathDoesNotExist {
+                    path: path.display().to_string(),
+                })?;
+            }
+        }
+        let email_config = if self.email {
+            validate_email_data().map(Some)
+        } else {
+            Ok(None)
+        }?;
+        let items = InvoicedItems::try_from(self.items.unwrap_or_default())?;
+        let invoice = ValidInput::builder()
+            .period(self.period)
+            .layout(self.layout)
+            .items(items)
+            .language(self.language.unwrap_or(default_language))
+            .maybe_client(self.client)
+            .maybe_maybe_output_path(self.out)
+            .maybe_email(email_config)
+            .build();
+        Ok(ValidReminderInput::builder()
+            .invoice(invoice)
+            .number(self.number)
+            .date(self.paid_on.unwrap_or(today))
+            .is_paid(self.paid_on.is_some())
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_input_parsing_reminder() {
+        let input = CliArgs::parse_from([
+            BINARY_NAME,
+            "reminder",
+            "--number",
+            "237",
+            "--period",
+            "2025-05",
+            "--paid-on",
+            "2025-07-20",
+            "expenses",
+        ]);
+        let input = input.command.unwrap_reminder();
+        assert_eq!(*input.number(), FormattedInvoiceNumber::from("237"));
+        assert_eq!(*input.period(), YearAndMonth::may(2025).into());
+        assert_eq!(*input.items(), Some(TargetItems::Expenses));
+        let input = input.parsed(Language::EN).unwrap();
+        assert_eq!(input.date().to_string(), "2025-07-20");
+        assert!(*input.is_paid());
+        assert_eq!(*input.invoice().items(), InvoicedItems::Expenses);
+    }
+
+    #[test]
+    fn test_input_parsing_reminder_requires_number() {
+        let result = CliArgs::try_parse_from([BINARY_NAME, "reminder", "--period", "2025-05"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parsed_unpaid_is_charged_until_today() {
+        let today = Date::from_str("2025-08-01").unwrap();
+        let input = ReminderInput::builder()
+            .number(FormattedInvoiceNumber::sample())
+            .period(YearAndMonth::may(2025).into())
+            .build()
+            .parsed_with_today(Language::EN, today)
+            .unwrap();
+        assert_eq!(*input.date(), today);
+        assert!(!*input.is_paid());
+        assert_eq!(
+            *input.invoice().items(),
+            InvoicedItems::Service { time_off: None }
+        );
+    }
+
+    #[test]
+    fn test_parsed_output_path_without_parent_is_err() {
+        let result = ReminderInput::builder()
+            .number(FormattedInvoiceNumber::sample())
+            .period(YearAndMonth::may(2025).into())
+            .out(PathBuf::from("/"))
+            .build()
+            .parsed_with_today(Language::EN, Date::from_str("2025-08-01").unwrap());
+        assert!(matches!(result, Err(Error::InvalidOutputPath { .. })));
+    }
+}
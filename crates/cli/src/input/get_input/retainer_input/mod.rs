@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod retainer_input;
+
+pub use retainer_input::*;
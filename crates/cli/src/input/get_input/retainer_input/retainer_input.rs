@@ -0,0 +1,82 @@
+use clap::Args;
+use derive_more::Unwrap;
+
+use crate::prelude::*;
+
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct RetainerInput {
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: RetainerInputCommand,
+}
+
+#[derive(Debug, Subcommand, Unwrap, PartialEq)]
+pub enum RetainerInputCommand {
+    /// Shows the balance prepaid by the client and its top-ups and drawdowns.
+    Show(ShowRetainerInput),
+    /// Adds an amount prepaid by the client to the balance.
+    TopUp(TopUpRetainerInput),
+}
+
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct ShowRetainerInput {
+    /// The name of the client profile paying the retainer, e.g. `acme`,
+    /// instead of the default client.
+    #[arg(long, short = 'c')]
+    #[getset(get = "pub")]
+    client: Option<String>,
+}
+
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct TopUpRetainerInput {
+    /// The amount prepaid, in the currency of the payment information, which
+    /// the retainer is kept in, e.g. `10000`.
+    #[getset(get = "pub")]
+    amount: Cost,
+
+    /// The name of the client profile paying the retainer, e.g. `acme`,
+    /// instead of the default client.
+    #[arg(long, short = 'c')]
+    #[getset(get = "pub")]
+    client: Option<String>,
+
+    /// The date of the prepayment, defaults to today.
+    #[arg(long, short = 'd')]
+    #[getset(get = "pub")]
+    date: Option<Date>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_input_parsing_retainer_show() {
+        let input = CliArgs::parse_from([BINARY_NAME, "retainer", "show", "--client", "acme"]);
+        let input = input.command.unwrap_retainer();
+        let RetainerInputCommand::Show(show) = input.command() else {
+            panic!("Expected show command")
+        };
+        assert_eq!(show.client().as_deref(), Some("acme"));
+    }
+
+    #[test]
+    fn test_input_parsing_retainer_top_up() {
+        let input = CliArgs::parse_from([
+            BINARY_NAME,
+            "retainer",
+            "top-up",
+            "10000",
+            "--date",
+            "2025-06-01",
+        ]);
+        let input = input.command.unwrap_retainer();
+        let RetainerInputCommand::TopUp(top_up) = input.command() else {
+            panic!("Expected top-up command")
+        };
+        assert_eq!(*top_up.amount(), Cost::from_str("10000").unwrap());
+        assert_eq!(top_up.date().unwrap().to_string(), "2025-06-01");
+        assert_eq!(*top_up.client(), None);
+    }
+}
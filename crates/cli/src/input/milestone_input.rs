@@ -0,0 +1,13 @@
+use clap::Args;
+
+use crate::prelude::*;
+
+/// CLI arguments for invoicing a milestone of the milestone contract.
+#[derive(Args, Debug, Clone, PartialEq, Builder, Getters)]
+pub struct MilestoneInput {
+    /// The name of the milestone to bill, e.g. `Kickoff`, as named in
+    /// `milestones.ron` in the data directory.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    name: String,
+}
@@ -1,13 +1,17 @@
 mod get_input;
+mod milestone_input;
 mod target_items;
 mod target_period;
 mod time_off_input;
 mod time_off_unit_input;
+mod timesheet_input;
 mod tui;
 
 pub use get_input::*;
+pub use milestone_input::*;
 pub use target_items::*;
 pub use target_period::*;
 pub use time_off_input::*;
 pub use time_off_unit_input::*;
+pub use timesheet_input::*;
 pub use tui::*;
@@ -9,9 +9,43 @@ pub enum TargetItems {
     Services,
     /// Services mode with time off specification
     ServicesOff(TimeOffInput),
+    /// Services mode with the quantity being the hours of a timesheet CSV file
+    /// for the target period.
+    Timesheet(TimesheetInput),
     /// Expenses mode, specify expenses in `input/data/expenses.json` for the
     /// target month.
     Expenses,
+    /// Services followed by the expenses of the target period in a single
+    /// invoice, numbered as the services invoice.
+    All,
+    /// A milestone of the milestone contract, numbered after the last invoice
+    /// and billed only once.
+    Milestone(MilestoneInput),
+}
+
+impl TryFrom<TargetItems> for InvoicedItems {
+    type Error = klirr_core::prelude::Error;
+
+    /// Maps `TargetItems` to `InvoicedItems`, reading the timesheet file if any.
+    fn try_from(items: TargetItems) -> Result<Self> {
+        match items {
+            TargetItems::ServicesOff(time_off) => {
+                let time_off = TimeOff::try_from(time_off)?;
+                Ok(InvoicedItems::Service {
+                    time_off: Some(time_off),
+                })
+            }
+            TargetItems::Timesheet(timesheet) => Ok(InvoicedItems::Timesheet {
+                timesheet: Timesheet::try_from(timesheet)?,
+            }),
+            TargetItems::Services => Ok(InvoicedItems::Service { time_off: None }),
+            TargetItems::Expenses => Ok(InvoicedItems::Expenses),
+            TargetItems::All => Ok(InvoicedItems::ServiceAndExpenses { time_off: None }),
+            TargetItems::Milestone(milestone) => Ok(InvoicedItems::Milestone {
+                name: milestone.name().clone(),
+            }),
+        }
+    }
 }
 
 impl HasSample for TargetItems {
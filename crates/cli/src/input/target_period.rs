@@ -4,22 +4,46 @@ use crate::prelude::*;
 #[command(name = "invoice")]
 #[command(about = "Generate an invoice PDF", long_about = None)]
 pub enum TargetPeriod {
-    /// Current period, e.g. current month or current fortnight
+    /// Current period, e.g. current month, current fortnight or current week
     Current,
     #[default]
-    /// Last period, e.g. last month of last fortnight
+    /// Last period, e.g. last month, last fortnight or last week
     Last,
 }
 
 impl TargetPeriod {
-    /// Note we return `YearMonthAndFortnight` since it has higher granularity than
-    /// `YearAndMonth`, so we can always turn a `YearMonthAndFortnight` into a
-    /// `YearAndMonth`, later in the flow if that matches the invoice cadence.
-    pub fn period(&self) -> YearMonthAndFortnight {
-        match self {
-            Self::Current => YearMonthAndFortnight::current(),
-            Self::Last => YearMonthAndFortnight::last(),
-        }
+    /// Note for monthly and bi-weekly cadence we return `YearMonthAndFortnight`
+    /// since it has higher granularity than `YearAndMonth`, so we can always
+    /// turn a `YearMonthAndFortnight` into a `YearAndMonth`, later in the flow
+    /// if that matches the invoice cadence. For weekly, quarterly and yearly
+    /// cadence we return a `YearAndWeek`, `YearAndQuarter` or `Year`, and for
+    /// rolling fortnights a `RollingFortnight` anchored on the service fees.
+    ///
+    /// # Errors
+    /// Returns an error if cadence is `RollingFortnight` and the service fees
+    /// lack an anchor date.
+    pub fn period(&self, service_fees: &ServiceFees) -> Result<PeriodAnno> {
+        let period = match (self, *service_fees.cadence()) {
+            (Self::Current, Cadence::Weekly) => YearAndWeek::current().into(),
+            (Self::Last, Cadence::Weekly) => YearAndWeek::last().into(),
+            (Self::Current, Cadence::Quarterly) => YearAndQuarter::current().into(),
+            (Self::Last, Cadence::Quarterly) => YearAndQuarter::last().into(),
+            (Self::Current, Cadence::Yearly) => Year::current().into(),
+            (Self::Last, Cadence::Yearly) => Year::last().into(),
+            (Self::Current, Cadence::Monthly | Cadence::BiWeekly) => {
+                YearMonthAndFortnight::current().into()
+            }
+            (Self::Last, Cadence::Monthly | Cadence::BiWeekly) => {
+                YearMonthAndFortnight::last().into()
+            }
+            (Self::Current, Cadence::RollingFortnight) => {
+                RollingFortnight::current(&service_fees.rolling_period_anchor_or_err()?).into()
+            }
+            (Self::Last, Cadence::RollingFortnight) => {
+                RollingFortnight::last(&service_fees.rolling_period_anchor_or_err()?).into()
+            }
+        };
+        Ok(period)
     }
 }
 
@@ -40,6 +64,16 @@ mod tests {
 
     type Sut = TargetPeriod;
 
+    fn service_fees(cadence: Cadence) -> ServiceFees {
+        ServiceFees::builder()
+            .name("Consulting")
+            .rate(Rate::daily(UnitPrice::ONE))
+            .cadence(cadence)
+            .maybe_rolling_period_anchor(Date::from_str("2025-06-30").ok())
+            .build()
+            .unwrap()
+    }
+
     #[test]
     fn equality() {
         assert_eq!(Sut::sample(), Sut::sample());
@@ -54,14 +88,53 @@ mod tests {
     #[test]
     fn target_month_current() {
         let target = Sut::Current;
-        let period = target.period();
-        assert_eq!(period, YearMonthAndFortnight::current());
+        let period = target.period(&ServiceFees::sample()).unwrap();
+        assert_eq!(period, YearMonthAndFortnight::current().into());
     }
 
     #[test]
     fn target_month_last() {
         let target = Sut::Last;
-        let period = target.period();
-        assert_eq!(period, YearMonthAndFortnight::current().one_half_earlier());
+        let period = target.period(&ServiceFees::sample_other()).unwrap();
+        assert_eq!(
+            period,
+            YearMonthAndFortnight::current().one_half_earlier().into()
+        );
+    }
+
+    #[test]
+    fn target_week_last() {
+        let target = Sut::Last;
+        let period = target.period(&service_fees(Cadence::Weekly)).unwrap();
+        assert_eq!(period, YearAndWeek::current().one_week_earlier().into());
+    }
+
+    #[test]
+    fn target_quarter_and_year_last() {
+        let target = Sut::Last;
+        assert_eq!(
+            target.period(&service_fees(Cadence::Quarterly)).unwrap(),
+            YearAndQuarter::current().one_quarter_earlier().into()
+        );
+        assert_eq!(
+            target.period(&service_fees(Cadence::Yearly)).unwrap(),
+            Year::current().one_year_earlier().into()
+        );
+    }
+
+    #[test]
+    fn target_rolling_fortnight() {
+        let anchor = Date::from_str("2025-06-30").unwrap();
+        let service_fees = service_fees(Cadence::RollingFortnight);
+        assert_eq!(
+            Sut::Current.period(&service_fees).unwrap(),
+            RollingFortnight::current(&anchor).into()
+        );
+        assert_eq!(
+            Sut::Last.period(&service_fees).unwrap(),
+            RollingFortnight::current(&anchor)
+                .one_fortnight_earlier()
+                .into()
+        );
     }
 }
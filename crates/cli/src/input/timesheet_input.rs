@@ -0,0 +1,27 @@
+use clap::Args;
+
+use crate::prelude::*;
+
+/// CLI arguments for invoicing the hours of a timesheet CSV file.
+#[derive(Args, Debug, Clone, PartialEq, Builder, Getters)]
+pub struct TimesheetInput {
+    /// Path to the timesheet CSV file, with rows of `date,hours,description`
+    /// or a Toggl or Clockify CSV export.
+    #[arg(long, short = 'f')]
+    #[getset(get = "pub")]
+    file: PathBuf,
+
+    /// The format of the timesheet, detected from the header if not specified,
+    /// one of `plain`, `toggl` or `clockify`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    format: Option<TimesheetFormat>,
+}
+
+impl TryFrom<TimesheetInput> for Timesheet {
+    type Error = klirr_core::Error;
+
+    fn try_from(input: TimesheetInput) -> Result<Self, Self::Error> {
+        Timesheet::read(input.file, input.format)
+    }
+}
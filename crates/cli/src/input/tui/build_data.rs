@@ -21,11 +21,18 @@ pub fn ask_for_data(
         build_service_fees,
     )?;
 
+    let service_lines = select_or_default(
+        data_selector,
+        DataSelector::ServiceFees,
+        default.service_lines(),
+        |d| build_service_lines(d),
+    )?;
+
     let invoice_info = select_or_default(
         data_selector,
         DataSelector::Information,
         default.information(),
-        curry2(build_invoice_info, *service_fees.cadence()),
+        curry2(build_invoice_info, &service_fees),
     )?;
 
     let payment_info = select_or_default(
@@ -40,6 +47,7 @@ pub fn ask_for_data(
         .vendor(vendor)
         .payment_info(payment_info)
         .service_fees(service_fees)
+        .service_lines(service_lines)
         .information(invoice_info)
         .expensed_periods(default.expensed_periods().clone())
         .build();
@@ -0,0 +1,101 @@
+use inquire::{Confirm, CustomType, Text, error::InquireResult};
+
+use crate::prelude::*;
+
+pub fn ask_for_client_profile_name() -> Result<Option<String>> {
+    Text::new("Add a client profile? Name (optional)")
+        .with_help_message(&format_help_skippable(format!(
+            "For clients besides your default one, invoiced with `{BINARY_NAME} invoice --client <name>`"
+        )))
+        .prompt_skippable()
+        .map(|name| name.filter(|name| !name.trim().is_empty()))
+        .map_err(|e| Error::InvalidClientProfile {
+            reason: format!("{:?}", e),
+        })
+}
+
+pub fn build_client_profile(
+    name: impl AsRef<str>,
+    default: &ClientProfile<PeriodAnno>,
+) -> Result<ClientProfile<PeriodAnno>> {
+    fn inner(name: &str, default: &ClientProfile<PeriodAnno>) -> Result<ClientProfile<PeriodAnno>> {
+        let client = build_company(format!("Client '{name}'"), default.client())?;
+        let service_fees = build_service_fees(default.service_fees())?;
+        let service_lines = build_service_lines(default.service_lines())?;
+        let prompts = |service_fees: &ServiceFees| -> InquireResult<_> {
+            let purchase_order =
+                CustomType::<PurchaseOrder>::new("Purchase order number (optional)")
+                    .with_optional_default(default.purchase_order())
+                    .with_help_message(&format_help_skippable(
+                        "If you have a purchase order number with this client, enter it here"
+                            .to_owned(),
+                    ))
+                    .prompt_skippable()?;
+
+            let payment_terms = CustomType::<PaymentTerms>::new("Payment terms (optional)")
+                .with_optional_default(default.payment_terms())
+                .with_help_message(&format_help_skippable(
+                    "Defaults to the terms of your payment information, e.g. 'Net 30'".to_owned(),
+                ))
+                .prompt_skippable()?;
+
+            let language = CustomType::<Language>::new("Language of invoices (optional)")
+                .with_optional_default(default.language())
+                .with_help_message(&format_help_skippable(
+                    "Used unless a language is specified, e.g. 'sv' for Swedish".to_owned(),
+                ))
+                .prompt_skippable()?;
+
+            let own_series = Confirm::new("Number invoices in a series of their own?")
+                .with_default(default.numbering().is_own())
+                .with_help_message(
+                    "If not, invoices continue the series of invoice numbers shared by all clients",
+                )
+                .prompt()?;
+            let (numbering, number_format) = if own_series {
+                let default_offset = match default.numbering() {
+                    ClientNumbering::Own(offset) => offset.clone(),
+                    ClientNumbering::Shared => TimestampedInvoiceNumber::builder()
+                        .offset(InvoiceNumber::default())
+                        .period(PeriodAnno::from(YearAndMonth::last()))
+                        .build(),
+                };
+                let offset = build_invoice_number_offset(&default_offset, service_fees)?;
+                // A series of its own needs a format of its own, e.g. a
+                // prefix, not to print the numbers of other clients
+                let number_format = build_invoice_number_format(
+                    &default.number_format().clone().unwrap_or_default(),
+                )?;
+                (ClientNumbering::Own(offset), Some(number_format))
+            } else {
+                (ClientNumbering::Shared, None)
+            };
+            Ok((
+                purchase_order,
+                payment_terms,
+                language,
+                numbering,
+                number_format,
+            ))
+        };
+        let (purchase_order, payment_terms, language, numbering, number_format) =
+            prompts(&service_fees).map_err(|e| Error::InvalidClientProfile {
+                reason: format!("{:?}", e),
+            })?;
+        let recipients =
+            ask_for_many_email_addresses(EmailAddressRole::Recipient, default.recipients())?;
+
+        Ok(ClientProfile::builder()
+            .client(client)
+            .service_fees(service_fees)
+            .service_lines(service_lines)
+            .maybe_purchase_order(purchase_order)
+            .maybe_payment_terms(payment_terms)
+            .maybe_language(language)
+            .recipients(recipients)
+            .numbering(numbering)
+            .maybe_number_format(number_format)
+            .build())
+    }
+    inner(name.as_ref(), default)
+}
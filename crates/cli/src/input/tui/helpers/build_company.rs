@@ -1,4 +1,4 @@
-use inquire::{Text, error::InquireResult};
+use inquire::{CustomType, Text, error::InquireResult};
 
 use crate::prelude::*;
 
@@ -33,13 +33,22 @@ pub fn build_company(
 
         let postal_address = build_postal_address(&owner, default.postal_address())?;
 
+        let holiday_calendar = CustomType::<HolidayCalendar>::new(&text("public holidays"))
+            .with_optional_default(default.holiday_calendar())
+            .with_help_message(&format_help_skippable(format!(
+                "Excluded from working days when invoicing per day or hour. {}",
+                HolidayCalendar::format_possible_values()
+            )))
+            .prompt_skippable()?;
+
         let company_info = default
             .clone()
             .with_company_name(name)
             .with_contact_person(contact_person)
             .with_organisation_number(org_no)
             .with_postal_address(postal_address)
-            .with_vat_number(vat);
+            .with_vat_number(vat)
+            .with_holiday_calendar(holiday_calendar);
 
         Ok(company_info)
     }
@@ -52,11 +52,8 @@ pub fn ask_for_many_email_addresses(
     defaults: &IndexSet<EmailAddress>,
 ) -> Result<IndexSet<EmailAddress>> {
     let mut emails = IndexSet::new();
-    loop {
-        let Some(email) = ask_for_email_address_skippable(role, defaults.get_index(emails.len()))?
-        else {
-            break;
-        };
+    while let Some(email) = ask_for_email_address_skippable(role, defaults.get_index(emails.len()))?
+    {
         if emails.contains(&email) {
             warn!("Email address already exists, skipping");
             continue;
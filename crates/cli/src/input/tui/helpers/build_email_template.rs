@@ -15,8 +15,18 @@ fn ask_for_proto_email_atom_template(part: &str, default: &TemplatePart) -> Resu
 pub fn ask_for_template(default: &Template) -> Result<Template> {
     let subject = ask_for_proto_email_atom_template("subject", default.subject_format())?;
     let body = ask_for_proto_email_atom_template("body", default.body_format())?;
+    let reminder_subject = ask_for_proto_email_atom_template(
+        "subject of payment reminders",
+        default.reminder_subject_format(),
+    )?;
+    let reminder_body = ask_for_proto_email_atom_template(
+        "body of payment reminders",
+        default.reminder_body_format(),
+    )?;
     Ok(Template::builder()
         .subject_format(subject)
         .body_format(body)
+        .reminder_subject_format(reminder_subject)
+        .reminder_body_format(reminder_body)
         .build())
 }
@@ -0,0 +1,104 @@
+use inquire::{Confirm, Text, error::InquireResult, validator::Validation};
+
+use crate::prelude::*;
+
+/// The prompt and help of each field of an expense, in the order of the
+/// comma-separated values parsed by `Item::from_str`.
+const EXPENSE_FIELDS: [(&str, &str); 7] = [
+    ("Name?", "The short name of the expense, e.g. 'Coffee'"),
+    ("Unit price?", "The cost per item, e.g. 2.5"),
+    (
+        "Currency?",
+        "The currency the expense was paid in, e.g. 'EUR'",
+    ),
+    ("Quantity?", "The number of items, e.g. 3"),
+    (
+        "Transaction date?",
+        "The date of the expense, e.g. 2025-05-31",
+    ),
+    (
+        "VAT or sales tax rate (optional)",
+        "Clear if untaxed, e.g. '12%'",
+    ),
+    (
+        "Receipt (optional)",
+        "Path to a receipt image or PDF to copy into the receipts folder, clear if none",
+    ),
+];
+
+fn failed_to_build_expense(default: &Item, error: impl std::fmt::Debug) -> Error {
+    Error::InvalidExpenseItem {
+        invalid_string: default.to_expense_string(),
+        reason: format!("{:?}", error),
+    }
+}
+
+/// Lets the user pick one of the `expenses` recorded for `period`, returning
+/// a selector addressing it by position.
+pub fn select_expense(period: &PeriodAnno, expenses: &[Item]) -> Result<ExpenseSelector> {
+    let options = expenses
+        .iter()
+        .enumerate()
+        .map(|(index, expense)| format!("{}. {}", index + 1, expense.to_expense_string()))
+        .collect::<Vec<_>>();
+    inquire::Select::new(&format!("Which expense of {period}?"), options)
+        .raw_prompt()
+        .map(|selected| ExpenseSelector::Index(selected.index + 1))
+        .map_err(|e| Error::InvalidExpenseSelector {
+            invalid_string: format!("{:?}", e),
+        })
+}
+
+/// Asks whether to edit another expense of `period`.
+pub fn ask_to_edit_another_expense(period: &PeriodAnno) -> Result<bool> {
+    Confirm::new(&format!("Edit another expense of {period}?"))
+        .with_default(false)
+        .prompt()
+        .map_err(|e| Error::InvalidExpenseSelector {
+            invalid_string: format!("{:?}", e),
+        })
+}
+
+/// Lets the user fix the fields of the expense `default` one by one, each
+/// validated by parsing the whole expense using `Item::from_str`, just like
+/// when recording expenses. The discount of the expense, if any, is kept.
+pub fn build_expense(default: &Item) -> Result<Item> {
+    fn inner(default: &Item) -> InquireResult<Vec<String>> {
+        let mut fields = vec![
+            default.name().clone(),
+            default.unit_price().to_string(),
+            default.currency().to_string(),
+            default.quantity().to_string(),
+            default.transaction_date().to_string(),
+            default
+                .tax_rate()
+                .map(|tax_rate| tax_rate.to_string())
+                .unwrap_or_default(),
+            default
+                .receipt()
+                .as_ref()
+                .map(|receipt| receipt.display().to_string())
+                .unwrap_or_default(),
+        ];
+        for (index, (message, help)) in EXPENSE_FIELDS.into_iter().enumerate() {
+            let others = fields.clone();
+            let validator = move |input: &str| {
+                let mut candidate = others.clone();
+                candidate[index] = input.to_owned();
+                Ok(match Item::from_str(&candidate.join(", ")) {
+                    Ok(_) => Validation::Valid,
+                    Err(e) => Validation::Invalid(e.to_string().into()),
+                })
+            };
+            fields[index] = Text::new(message)
+                .with_initial_value(&fields[index])
+                .with_help_message(help)
+                .with_validator(validator)
+                .prompt()?;
+        }
+        Ok(fields)
+    }
+
+    let fields = inner(default).map_err(|e| failed_to_build_expense(default, e))?;
+    Item::from_str(&fields.join(", ")).map(|item| item.with_discount(*default.discount()))
+}
@@ -1,37 +1,16 @@
-use inquire::{CustomType, error::InquireResult};
+use inquire::{Confirm, CustomType, Text, error::InquireResult};
 
 use crate::prelude::*;
 
 pub fn build_invoice_info(
     default: &ProtoInvoiceInfo<PeriodAnno>,
-    cadence: Cadence,
+    service_fees: &ServiceFees,
 ) -> Result<ProtoInvoiceInfo<PeriodAnno>> {
     fn inner(
         default: &ProtoInvoiceInfo<PeriodAnno>,
-        cadence: Cadence,
+        service_fees: &ServiceFees,
     ) -> InquireResult<ProtoInvoiceInfo<PeriodAnno>> {
-        let invoice_number_offset = CustomType::<InvoiceNumber>::new(
-            "What is the last invoice number you issued? We call this the 'offset'",
-        )
-        .with_help_message(&format_help_skippable(
-            "Used with the date of that invoice to calculate future invoice numbers.".to_owned(),
-        ))
-        .with_default(default.offset().offset().clone())
-        .prompt_skippable()?
-        .unwrap_or_default();
-
-        let invoice_number_offset_period = build_period(
-            "When was that invoice issued? (Used to calculate future invoice numbers)".to_owned(),
-            Some(default.offset().period().clone()),
-            cadence,
-        )?
-        // if we use `0` as offset and set month to last month, then the next invoice number will be `1` for this month, which is correct.
-        .unwrap_or(YearAndMonth::last().into());
-
-        let offset = TimestampedInvoiceNumber::<PeriodAnno>::builder()
-            .offset(invoice_number_offset)
-            .period(invoice_number_offset_period)
-            .build();
+        let offset = build_invoice_number_offset(default.offset(), service_fees)?;
 
         let purchase_order = CustomType::<PurchaseOrder>::new("Purchase order number (optional)")
             .with_optional_default(default.purchase_order())
@@ -55,17 +34,128 @@ pub fn build_invoice_info(
             ))
             .prompt_skippable()?;
 
+        let number_format = build_invoice_number_format(default.number_format())?;
+
+        let fiscal_year_start = CustomType::<Month>::new(
+            "Restart invoice numbers every fiscal year? Month it starts (optional)",
+        )
+        .with_optional_default(&match default.number_reset() {
+            InvoiceNumberReset::Never => None,
+            InvoiceNumberReset::Yearly { fiscal_year_start } => Some(*fiscal_year_start),
+        })
+        .with_help_message(&format_help_skippable(
+            "E.g. 1 for January, numbers restart at 1 for the first invoice of each fiscal year"
+                .to_owned(),
+        ))
+        .prompt_skippable()?;
+        let number_reset = fiscal_year_start
+            .map(|fiscal_year_start| InvoiceNumberReset::Yearly { fiscal_year_start })
+            .unwrap_or_default();
+
+        let discount = CustomType::<Discount>::new("Standing discount of invoices (optional)")
+            .with_optional_default(default.discount())
+            .with_help_message(&format_help_skippable(
+                "Deducted from the total of every invoice of services, e.g. '5%' or '500'"
+                    .to_owned(),
+            ))
+            .prompt_skippable()?;
+
         let info = ProtoInvoiceInfo::builder()
             .offset(offset)
             .maybe_purchase_order(purchase_order)
             .maybe_footer_text(footer_text)
             .maybe_emphasize_color_hex(emphasize_color_hex)
             .record_of_periods_off(default.record_of_periods_off().clone())
+            .record_of_dates_off(default.record_of_dates_off().clone())
+            .number_format(number_format)
+            .number_reset(number_reset)
+            .maybe_expenses_number_format(default.expenses_number_format().clone())
+            .credit_note_number_format(default.credit_note_number_format().clone())
+            .milestone_number_format(default.milestone_number_format().clone())
+            .maybe_discount(discount)
             .build();
 
         Ok(info)
     }
-    inner(default, cadence).map_err(|e| Error::InvalidInvoiceInfo {
+    inner(default, service_fees).map_err(|e| Error::InvalidInvoiceInfo {
         reason: format!("{:?}", e),
     })
 }
+
+pub fn build_invoice_number_offset(
+    default: &TimestampedInvoiceNumber<PeriodAnno>,
+    service_fees: &ServiceFees,
+) -> InquireResult<TimestampedInvoiceNumber<PeriodAnno>> {
+    let cadence = *service_fees.cadence();
+    let invoice_number_offset = CustomType::<InvoiceNumber>::new(
+        "What is the last invoice number you issued? We call this the 'offset'",
+    )
+    .with_help_message(&format_help_skippable(
+        "Used with the date of that invoice to calculate future invoice numbers.".to_owned(),
+    ))
+    .with_default(default.offset().clone())
+    .prompt_skippable()?
+    .unwrap_or_default();
+
+    let invoice_number_offset_period = build_period(
+        "When was that invoice issued? (Used to calculate future invoice numbers)".to_owned(),
+        Some(default.period().clone()),
+        cadence,
+    )?
+    // if we use `0` as offset and set month to last month, then the next invoice number will be `1` for this month, which is correct.
+    .unwrap_or(match cadence {
+        Cadence::Weekly => YearAndWeek::last().into(),
+        Cadence::Quarterly => YearAndQuarter::last().into(),
+        Cadence::Yearly => Year::last().into(),
+        Cadence::RollingFortnight => match service_fees.rolling_period_anchor() {
+            Some(anchor) => RollingFortnight::last(anchor).into(),
+            None => YearAndMonth::last().into(),
+        },
+        Cadence::Monthly | Cadence::BiWeekly => YearAndMonth::last().into(),
+    });
+
+    Ok(TimestampedInvoiceNumber::<PeriodAnno>::builder()
+        .offset(invoice_number_offset)
+        .period(invoice_number_offset_period)
+        .build())
+}
+
+pub fn build_invoice_number_format(
+    default: &InvoiceNumberFormat,
+) -> InquireResult<InvoiceNumberFormat> {
+    let prefix = Text::new("Invoice number prefix (optional)")
+        .with_default(default.prefix())
+        .with_help_message(&format_help_skippable(
+            "Printed before the invoice number, e.g. 'LUP-'".to_owned(),
+        ))
+        .prompt_skippable()?
+        .unwrap_or_default();
+
+    let include_year = Confirm::new("Include the year in the invoice number?")
+        .with_default(*default.include_year())
+        .with_help_message("E.g. 'LUP-2025-42' instead of 'LUP-42'")
+        .prompt()?;
+
+    let padding = CustomType::<u8>::new("Minimum number of digits of the invoice number?")
+        .with_default(*default.padding())
+        .with_help_message(&format_help_skippable(
+            "Padded with leading zeros, e.g. 4 for '0042'".to_owned(),
+        ))
+        .prompt_skippable()?
+        .unwrap_or_default();
+
+    let suffix = Text::new("Invoice number suffix (optional)")
+        .with_default(default.suffix())
+        .with_help_message(&format_help_skippable(
+            "Printed after the invoice number, e.g. '-B' for a separate series".to_owned(),
+        ))
+        .prompt_skippable()?
+        .unwrap_or_default();
+
+    Ok(InvoiceNumberFormat::builder()
+        .prefix(prefix)
+        .include_year(include_year)
+        .padding(padding)
+        .suffix(suffix)
+        .build())
+}
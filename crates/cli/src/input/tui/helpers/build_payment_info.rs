@@ -22,8 +22,39 @@ pub fn build_payment_info(default: &PaymentInformation) -> Result<PaymentInforma
             .prompt()?;
 
         let payment_terms = CustomType::<PaymentTerms>::new("Payment terms?")
-            .with_help_message("The payment terms for this invoice, e.g. 'Net 30'")
-            .with_default(PaymentTerms::net30())
+            .with_help_message(
+                "The payment terms for this invoice, e.g. 'Net 30', 'EOM + 15', 'Due on receipt', 'Due 2025-07-31' or '2/10 Net 30' for a 2% discount if paid within 10 days",
+            )
+            .with_default(default.terms().clone())
+            .prompt()?;
+
+        let business_day_convention = CustomType::<BusinessDayConvention>::new(
+            "Move due dates falling on weekends or holidays?",
+        )
+        .with_help_possible_values()
+        .with_default(*default.business_day_convention())
+        .prompt()?;
+
+        let late_payment = default.late_payment();
+        let reference_rate = CustomType::<Percentage>::new("Late payment reference rate?")
+            .with_help_message(
+                "The reference rate per year of the central bank used for late payment interest, e.g. '2%'",
+            )
+            .with_default(*late_payment.reference_rate())
+            .prompt()?;
+
+        let margin = CustomType::<Percentage>::new("Late payment margin?")
+            .with_help_message(
+                "The margin added to the reference rate for late payment interest, e.g. '8%'",
+            )
+            .with_default(*late_payment.margin())
+            .prompt()?;
+
+        let reminder_fee = CustomType::<Cost>::new("Reminder fee?")
+            .with_help_message(&format!(
+                "A fixed fee in {currency} charged for each payment reminder, e.g. '60'"
+            ))
+            .with_default(*late_payment.reminder_fee())
             .prompt()?;
 
         let payment_info = default
@@ -32,7 +63,15 @@ pub fn build_payment_info(default: &PaymentInformation) -> Result<PaymentInforma
             .with_iban(iban)
             .with_bic(bic)
             .with_currency(currency)
-            .with_terms(payment_terms);
+            .with_terms(payment_terms)
+            .with_business_day_convention(business_day_convention)
+            .with_late_payment(
+                late_payment
+                    .clone()
+                    .with_reference_rate(reference_rate)
+                    .with_margin(margin)
+                    .with_reminder_fee(reminder_fee),
+            );
 
         Ok(payment_info)
     }
@@ -9,6 +9,50 @@ pub fn build_period(
 ) -> InquireResult<Option<PeriodAnno>> {
     let help = help.into();
 
+    match cadence {
+        Cadence::Weekly => {
+            return CustomType::<YearAndWeek>::new("Year and week?")
+                .with_help_message(&format_help_skippable(
+                    help.unwrap_or("Year and ISO week, e.g. 2025-W27".to_owned()),
+                ))
+                .with_optional_default(&default.and_then(|d| d.try_unwrap_year_and_week().ok()))
+                .prompt_skippable()
+                .map(|week| week.map(PeriodAnno::from));
+        }
+        Cadence::Quarterly => {
+            return CustomType::<YearAndQuarter>::new("Year and quarter?")
+                .with_help_message(&format_help_skippable(
+                    help.unwrap_or("Year and quarter, e.g. 2025-Q3".to_owned()),
+                ))
+                .with_optional_default(&default.and_then(|d| d.try_unwrap_year_and_quarter().ok()))
+                .prompt_skippable()
+                .map(|quarter| quarter.map(PeriodAnno::from));
+        }
+        Cadence::Yearly => {
+            return CustomType::<Year>::new("Year?")
+                .with_help_message(&format_help_skippable(
+                    help.unwrap_or("Year, e.g. 2025".to_owned()),
+                ))
+                .with_optional_default(&default.map(|d| *d.year()))
+                .prompt_skippable()
+                .map(|year| year.map(PeriodAnno::from));
+        }
+        Cadence::RollingFortnight => {
+            return CustomType::<Date>::new("First day of the 14 days period?")
+                .with_help_message(&format_help_skippable(
+                    help.unwrap_or("First day of the period, e.g. 2025-06-30".to_owned()),
+                ))
+                .with_optional_default(&default.and_then(|d| {
+                    d.try_unwrap_rolling_fortnight()
+                        .ok()
+                        .map(|p| *p.first_day())
+                }))
+                .prompt_skippable()
+                .map(|first_day| first_day.map(|d| RollingFortnight::new(d).into()));
+        }
+        Cadence::Monthly | Cadence::BiWeekly => {}
+    }
+
     let Some(ym) = build_year_month_inner(
         help.clone(),
         default.as_ref().map(|d| d.year()),
@@ -18,7 +62,6 @@ pub fn build_period(
         return Ok(None);
     };
     match cadence {
-        Cadence::Monthly => Ok(Some(ym.into())),
         Cadence::BiWeekly => {
             let half = CustomType::<MonthHalf>::new("Half of month?")
                 .with_help_possible_values()
@@ -38,5 +81,6 @@ pub fn build_period(
                     .into(),
             ))
         }
+        _ => Ok(Some(ym.into())),
     }
 }
@@ -14,7 +14,7 @@ pub fn build_service_fees(default: &ServiceFees) -> Result<ServiceFees> {
             .with_default(*default.cadence())
             .prompt()?;
 
-        let granularity = CustomType::<Granularity>::new("Do you invoice per month, day or hour? Next question will be the rate which is per time unit you provide here")
+        let granularity = CustomType::<Granularity>::new("Do you invoice per year, quarter, month, day or hour? Next question will be the rate which is per time unit you provide here")
           .with_help_possible_values()
             .with_default(default.rate().granularity())
             .prompt()?;
@@ -30,13 +30,128 @@ pub fn build_service_fees(default: &ServiceFees) -> Result<ServiceFees> {
 
         let rate = Rate::from((unit_price, granularity));
 
+        // The rate history must be of the same granularity as the rate
+        let is_same_granularity = granularity == default.rate().granularity();
+        let mut rate_history = if is_same_granularity {
+            default.rate_history().clone()
+        } else {
+            Vec::new()
+        };
+        if is_same_granularity && unit_price != default.unit_price() {
+            let until = CustomType::<Date>::new("Last day of the previous rate (optional)")
+                .with_help_message(&format_help_skippable(
+                    "Earlier periods are invoiced at the previous rate, e.g. 2025-03-31".to_owned(),
+                ))
+                .prompt_skippable()?;
+            if let Some(until) = until {
+                rate_history.push(
+                    HistoricRate::builder()
+                        .rate(*default.rate())
+                        .until(until)
+                        .build(),
+                );
+            }
+        }
+
+        let work_schedule = match granularity {
+            Granularity::Day | Granularity::Hour => build_work_schedule(default.work_schedule())?,
+            Granularity::Fortnight
+            | Granularity::Month
+            | Granularity::Quarter
+            | Granularity::Year => default.work_schedule().clone(),
+        };
+
+        let rolling_period_anchor = match cadence {
+            Cadence::RollingFortnight => Some(
+                CustomType::<Date>::new("First day of any 14 days period?")
+                    .with_help_message(
+                        "Periods start every 14 days from this date, e.g. 2025-06-30",
+                    )
+                    .with_optional_default(default.rolling_period_anchor())
+                    .prompt()?,
+            ),
+            _ => *default.rolling_period_anchor(),
+        };
+
+        let contract_date = |part: &str, default: &Option<Date>| {
+            CustomType::<Date>::new(&format!("Contract {part} date?"))
+                .with_optional_default(default)
+                .with_help_message(&format_help_skippable(
+                    "Fees of periods partially outside the contract are pro-rated, e.g. 2025-05-14"
+                        .to_owned(),
+                ))
+                .prompt_skippable()
+        };
+        let contract_start = contract_date("start", default.contract_window().start())?;
+        let contract_end = contract_date("end", default.contract_window().end())?;
+        let contract_window = ContractWindow::builder()
+            .maybe_start(contract_start)
+            .maybe_end(contract_end)
+            .build()
+            .map_err(|e| inquire::InquireError::Custom(Box::new(e)))?;
+
+        let tax_rate = CustomType::<Percentage>::new("VAT or sales tax rate (optional)")
+            .with_optional_default(default.tax_rate())
+            .with_help_message(&format_help_skippable(
+                "Added to the invoice total, e.g. '25%', skip if not taxed".to_owned(),
+            ))
+            .prompt_skippable()?;
+
+        let discount = CustomType::<Discount>::new("Standing discount (optional)")
+            .with_optional_default(default.discount())
+            .with_help_message(&format_help_skippable(
+                "Deducted from the service fees of every invoice, e.g. '10%' or '500'".to_owned(),
+            ))
+            .prompt_skippable()?;
+
         Ok(ServiceFees::builder()
             .name(name)
             .cadence(cadence)
             .rate(rate)
+            .rate_history(rate_history)
+            .work_schedule(work_schedule)
+            .maybe_rolling_period_anchor(rolling_period_anchor)
+            .contract_window(contract_window)
+            .maybe_tax_rate(tax_rate)
+            .maybe_discount(discount)
             .build()
             .unwrap())
     }
+
+    fn build_work_schedule(default: &WorkSchedule) -> InquireResult<WorkSchedule> {
+        let hours = |weekday: Weekday| {
+            CustomType::<Quantity>::new(&format!("Working hours on {weekday}?"))
+                .with_help_message("Enter 0 if you do not work this weekday")
+                .with_default(default.hours_on(weekday))
+                .prompt()
+        };
+        let monday = hours(Weekday::Mon)?;
+        let tuesday = hours(Weekday::Tue)?;
+        let wednesday = hours(Weekday::Wed)?;
+        let thursday = hours(Weekday::Thu)?;
+        let friday = hours(Weekday::Fri)?;
+        let saturday = hours(Weekday::Sat)?;
+        let sunday = hours(Weekday::Sun)?;
+
+        let employment_percentage = CustomType::<Percentage>::new("Employment percentage?")
+            .with_optional_default(default.employment_percentage())
+            .with_help_message(&format_help_skippable(
+                "If you work part time, e.g. '80%', skip if full time".to_owned(),
+            ))
+            .prompt_skippable()?;
+
+        WorkSchedule::builder()
+            .monday(monday)
+            .tuesday(tuesday)
+            .wednesday(wednesday)
+            .thursday(thursday)
+            .friday(friday)
+            .saturday(saturday)
+            .sunday(sunday)
+            .maybe_employment_percentage(employment_percentage)
+            .build()
+            .map_err(|e| inquire::InquireError::Custom(Box::new(e)))
+    }
     inner(default).map_err(|e| Error::InvalidServiceFees {
         reason: format!("{:?}", e),
     })
@@ -0,0 +1,101 @@
+use inquire::{Confirm, CustomType, Text, error::InquireResult};
+
+use crate::prelude::*;
+
+pub fn build_service_lines(defaults: &[ServiceLine]) -> Result<Vec<ServiceLine>> {
+    fn build_service_line(default: Option<&ServiceLine>) -> InquireResult<Option<ServiceLine>> {
+        let help = format_help_skippable(
+            "Invoiced on a line of its own after the service fees, e.g. 'On-call'".to_owned(),
+        );
+        let mut name =
+            Text::new("Additional service line? Name (optional)").with_help_message(&help);
+        if let Some(default) = default {
+            name = name.with_default(default.name());
+        }
+        let Some(name) = name
+            .prompt_skippable()?
+            .filter(|name| !name.trim().is_empty())
+        else {
+            return Ok(None);
+        };
+
+        let granularity = CustomType::<Granularity>::new(
+            "Do you invoice this service per year, quarter, month, day or hour?",
+        )
+        .with_help_possible_values()
+        .with_default(default.map_or(Granularity::Hour, |d| d.rate().granularity()))
+        .prompt()?;
+
+        let unit_price = CustomType::<UnitPrice>::new("Unit price?")
+            .with_help_message(&format!(
+                "Price per {}, e.g. {}",
+                granularity,
+                granularity.example_rate()
+            ))
+            .with_optional_default(&default.map(|d| d.rate().unit_price()))
+            .prompt()?;
+
+        let fixed_quantity = CustomType::<Quantity>::new("Fixed quantity (optional)")
+            .with_optional_default(&default.and_then(|d| match d.quantity() {
+                ServiceLineQuantity::Fixed(quantity) => Some(*quantity),
+                ServiceLineQuantity::Worked => None,
+            }))
+            .with_help_message(&format_help_skippable(format!(
+                "Same quantity every period, e.g. 10, skip to invoice the {}s worked",
+                granularity
+            )))
+            .prompt_skippable()?;
+        let quantity =
+            fixed_quantity.map_or(ServiceLineQuantity::Worked, ServiceLineQuantity::Fixed);
+
+        let purchase_order = CustomType::<PurchaseOrder>::new("Purchase order number (optional)")
+            .with_optional_default(&default.and_then(|d| d.purchase_order().clone()))
+            .with_help_message(&format_help_skippable(
+                "If this service has a purchase order of its own, enter it here".to_owned(),
+            ))
+            .prompt_skippable()?;
+
+        let tax_rate = CustomType::<Percentage>::new("VAT or sales tax rate (optional)")
+            .with_optional_default(&default.and_then(|d| *d.tax_rate()))
+            .with_help_message(&format_help_skippable(
+                "Skip to use the tax rate of the service fees, e.g. '25%'".to_owned(),
+            ))
+            .prompt_skippable()?;
+
+        let discount = CustomType::<Discount>::new("Standing discount (optional)")
+            .with_optional_default(&default.and_then(|d| *d.discount()))
+            .with_help_message(&format_help_skippable(
+                "Deducted from this service on every invoice, e.g. '10%' or '500'".to_owned(),
+            ))
+            .prompt_skippable()?;
+
+        Ok(Some(
+            ServiceLine::builder()
+                .name(name)
+                .rate(Rate::from((unit_price, granularity)))
+                .quantity(quantity)
+                .maybe_purchase_order(purchase_order)
+                .maybe_tax_rate(tax_rate)
+                .maybe_discount(discount)
+                .build(),
+        ))
+    }
+
+    fn inner(defaults: &[ServiceLine]) -> InquireResult<Vec<ServiceLine>> {
+        let mut lines = Vec::new();
+        while let Some(line) = build_service_line(defaults.get(lines.len()))? {
+            lines.push(line);
+            let another = Confirm::new("Add another service line?")
+                .with_default(lines.len() < defaults.len())
+                .prompt()?;
+            if !another {
+                break;
+            }
+        }
+        Ok(lines)
+    }
+
+    inner(defaults).map_err(|e| Error::InvalidServiceFees {
+        reason: format!("{:?}", e),
+    })
+}
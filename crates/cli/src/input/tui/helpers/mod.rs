@@ -1,28 +1,34 @@
+mod build_client_profile;
 mod build_company;
 mod build_date;
 mod build_email_account;
 mod build_email_address;
 mod build_email_template;
+mod build_expense;
 mod build_invoice_info;
 mod build_password;
 mod build_payment_info;
 mod build_period;
 mod build_postal_address;
 mod build_service_fees;
+mod build_service_lines;
 mod build_smtp_server;
 mod build_year_and_month;
 mod select_or_default;
 
+pub use build_client_profile::*;
 pub use build_company::*;
 pub use build_email_account::*;
 pub use build_email_address::*;
 pub use build_email_template::*;
+pub use build_expense::*;
 pub use build_invoice_info::*;
 pub use build_password::*;
 pub use build_payment_info::*;
 pub use build_period::*;
 pub use build_postal_address::*;
 pub use build_service_fees::*;
+pub use build_service_lines::*;
 pub use build_smtp_server::*;
 pub use build_year_and_month::*;
 pub use select_or_default::*;
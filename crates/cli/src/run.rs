@@ -43,10 +43,34 @@ pub fn run(input: CliArgs) {
                     open_path(outcome.saved_at());
                 });
         }
+        Command::Reminder(reminder_input) => {
+            let _ = run_reminder_command(reminder_input)
+                .inspect_err(|e| error!("Error creating reminder PDF: {}", e))
+                .inspect(|outcome| {
+                    open_path(outcome.saved_at());
+                });
+        }
+        Command::CreditNote(credit_note_input) => {
+            let _ = run_credit_note_command(credit_note_input)
+                .inspect_err(|e| error!("Error creating credit note PDF: {}", e))
+                .inspect(|outcome| {
+                    open_path(outcome.saved_at());
+                });
+        }
         Command::Data(data_admin_input) => {
             let _ = run_data_command(data_admin_input.command()).inspect_err(|e| {
                 error!("Error running data admin command: {}", e);
             });
         }
+        Command::Ledger(ledger_input) => {
+            let _ = run_ledger_command(ledger_input.command()).inspect_err(|e| {
+                error!("Error running ledger command: {}", e);
+            });
+        }
+        Command::Retainer(retainer_input) => {
+            let _ = run_retainer_command(retainer_input.command()).inspect_err(|e| {
+                error!("Error running retainer command: {}", e);
+            });
+        }
     }
 }
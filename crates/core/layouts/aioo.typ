@@ -48,11 +48,14 @@
 // Function to format numbers to two decimals
 #let format_amount(amount, currency) = {
   let amt = amount * 1.0
+  // Format the magnitude and prefix the sign, e.g. for credit notes
+  let sign = if amt < 0 { "-" } else { "" }
+  amt = calc.abs(amt)
   let integer = calc.floor(amt)
   let frac = int(calc.round((amt - integer) * 100, digits: 0))
   let frac_str = str(frac)
   if frac < 10 { frac_str = "0" + frac_str }
-  let without_currency = str(integer) + "." + frac_str
+  let without_currency = sign + str(integer) + "." + frac_str
   without_currency + " " + currency
 }
 
@@ -92,6 +95,51 @@
   content
 }
 
+// Sums the total cost of the line items `items`.
+#let sum_total_cost(items) = {
+  let total = 0.0
+  for it in items { total = total + it.total_cost }
+  total
+}
+
+// The description of the line item `row`, prefixed by the discount label if
+// it deducts a discount.
+#let item_description(l18n, row) = {
+  if row.is_discount {
+    let label = l18n.line_items.discount.replace("{discount}", row.discount)
+    if row.name == "" { label } else { label + ": " + row.name }
+  } else {
+    row.name
+  }
+}
+
+// Renders a table of the line items `items`, with a header row.
+#let items_table(l18n, is_expenses, items) = {
+  table(
+    columns: (auto, auto, 1fr, auto, auto),
+    align: (left, left, center, center, right),
+    stroke: none,
+    table.header(
+      [#strong(l18n.line_items.description)],
+      [#strong(l18n.line_items.when)],
+      [#strong(l18n.line_items.unit_price)],
+      [#strong(l18n.line_items.quantity)],
+      [#strong(l18n.line_items.total_cost)],
+    ),
+    table.hline(stroke: 0.2pt),
+    ..for row in items {
+      (
+        item_description(l18n, row),
+        format_item_date(l18n, is_expenses, row.transaction_date),
+        format_amount(row.unit_price, row.currency),
+        str(row.quantity),
+        format_amount(row.total_cost, row.currency),
+        table.hline(stroke: (thickness: 0.2pt, dash: "dashed")),
+      )
+    },
+  )
+}
+
 // Wraps content in a rounded box with a stroke and fill.
 #let ovalbox(width, content) = {
   box(
@@ -117,6 +165,7 @@
 // formatting dates, amounts, and rendering lines and boxes.
 #let render_invoice(data, l18n) = {
   let is_expenses = data.line_items.is_expenses
+  let is_credit_note = "credit_note" in data
 
   // ** Invoice Data Variables **
   let emphasize_color = rgb(data.information.emphasize_color_hex)
@@ -183,19 +232,29 @@
     ]),
     block(fill: none, inset: 0pt, stroke: none, width: 100%, [
       // align the following block to the right margin
-      #ovalbox(100%, [#Large(strong[#l18n.invoice_info.invoice_identifier]) #text(fill: emphasize_color)[#strong(str(
+      #let identifier = if is_credit_note {
+        l18n.credit_note.credit_note_identifier
+      } else {
+        l18n.invoice_info.invoice_identifier
+      }
+      #ovalbox(100%, [#Large(strong[#identifier]) #text(fill: emphasize_color)[#strong(
             data.information.number,
-          ))]])
+          )]])
       // Conditionally display purchase order if it exists
       #if "purchase_order" in data.information {
         ovalbox(100%, [#strong[#l18n.invoice_info.purchase_order] #text(fill: emphasize_color)[#strong(
               data.information.purchase_order,
             )]])
       }
-      #block(fill: none, [
-        #ovalbox(49%, [#strong[#l18n.invoice_info.invoice_date] #data.information.invoice_date])
-        #ovalbox(49%, [#strong[#l18n.invoice_info.due_date] #data.information.due_date])
-      ])
+      // A credit note is not paid, so it has no due date
+      #if is_credit_note {
+        ovalbox(100%, [#strong[#l18n.credit_note.date] #data.information.invoice_date])
+      } else {
+        block(fill: none, [
+          #ovalbox(49%, [#strong[#l18n.invoice_info.invoice_date] #data.information.invoice_date])
+          #ovalbox(49%, [#strong[#l18n.invoice_info.due_date] #data.information.due_date])
+        ])
+      }
       #if (
         "contact_person" in data.client and data.client.contact_person != none and data.client.contact_person != ""
       ) {
@@ -206,54 +265,178 @@
         ]
       }
       #strong[#l18n.invoice_info.vendor_contact] #data.vendor.contact_person \
-      #strong[#l18n.invoice_info.terms] #data.payment_info.terms
+      #if not is_credit_note {
+        [#strong[#l18n.invoice_info.terms] #data.information.terms]
+      }
     ]),
   )
 
   v(1cm)
 
+  // Conditionally display the reminder section referencing the original invoice
+  if "reminder" in data {
+    let reminder = data.reminder
+    block[
+      #Large(strong(text(fill: emphasize_color, l18n.reminder.title))) \
+      #l18n.reminder.regarding
+        .replace("{number}", data.information.number)
+        .replace("{invoice_date}", data.information.invoice_date)
+        .replace("{due_date}", data.information.due_date)
+      #if reminder.is_paid {
+        l18n.reminder.paid_late.replace("{date}", reminder.date)
+      } else {
+        l18n.reminder.unpaid.replace("{date}", reminder.date)
+      }
+    ]
+    v(5mm)
+  }
+
+  // Conditionally display the credit note section referencing the credited invoice
+  if is_credit_note {
+    let credit_note = data.credit_note
+    block[
+      #Large(strong(text(fill: emphasize_color, l18n.credit_note.title))) \
+      #l18n.credit_note.credits_invoice
+        .replace("{number}", credit_note.invoice_number)
+        .replace("{invoice_date}", credit_note.invoice_date)
+      #if credit_note.percentage != 100 {
+        l18n.credit_note.partial
+          .replace("{percentage}", str(credit_note.percentage) + "%")
+          .replace("{invoice_amount}", format_amount(credit_note.invoice_amount, data.payment_info.currency))
+      }
+    ]
+    v(5mm)
+  }
+
   // ** Invoice Items Table **
   double-line()
-  // Calculate total in a scripting block
-  let grand_total
-  {
-    grand_total = 0.0
-    for it in data.line_items.items { grand_total = grand_total + it.total_cost }
-  }
+  // Expenses invoiced together with the services are listed in a section of
+  // their own, each section with a subtotal
+  let has_expenses_section = "expenses" in data.line_items
+  let grand_total = sum_total_cost(data.line_items.items)
   v(-10pt)
-  table(
-    columns: (auto, auto, 1fr, auto, auto),
-    align: (left, left, center, center, right),
-    stroke: none,
-    table.header(
-      [#strong(l18n.line_items.description)],
-      [#strong(l18n.line_items.when)],
-      [#strong(l18n.line_items.unit_price)],
-      [#strong(l18n.line_items.quantity)],
-      [#strong(l18n.line_items.total_cost)],
-    ),
-    table.hline(stroke: 0.2pt),
-    ..for row in data.line_items.items {
-      (
-        row.name,
-        format_item_date(l18n, is_expenses, row.transaction_date),
-        format_amount(row.unit_price, row.currency),
-        str(row.quantity),
-        format_amount(row.total_cost, row.currency),
-        table.hline(stroke: (thickness: 0.2pt, dash: "dashed")),
-      )
-    },
-  )
-  // Grand Total Row
-  align(right)[
-    #set text(weight: "bold")
-    #l18n.line_items.grand_total
-    #set text(fill: emphasize_color)
-    #format_amount(grand_total, data.payment_info.currency)
-  ]
+  items_table(l18n, is_expenses, data.line_items.items)
+  if has_expenses_section {
+    let expenses_subtotal = sum_total_cost(data.line_items.expenses)
+    align(right)[
+      #l18n.line_items.services_subtotal
+      #format_amount(grand_total, data.payment_info.currency)
+    ]
+    items_table(l18n, true, data.line_items.expenses)
+    align(right)[
+      #l18n.line_items.expenses_subtotal
+      #format_amount(expenses_subtotal, data.payment_info.currency)
+    ]
+    grand_total = grand_total + expenses_subtotal
+  }
+  // Discounts of the whole invoice are deducted after all items
+  if "discounts" in data.line_items {
+    align(right, table(
+      columns: (auto, auto),
+      align: (left, right),
+      stroke: none,
+      ..for row in data.line_items.discounts {
+        (item_description(l18n, row), format_amount(row.total_cost, row.currency))
+      },
+    ))
+    grand_total = grand_total + sum_total_cost(data.line_items.discounts)
+  }
+  // Grand Total Row, followed by the tax per rate and the total including tax
+  // if any line item is taxed
+  if "tax" in data {
+    let tax = data.tax
+    let currency = data.payment_info.currency
+    align(right, table(
+      columns: (auto, auto),
+      align: (left, right),
+      stroke: none,
+      l18n.tax.total_excluding_tax, format_amount(grand_total, currency),
+      ..for subtotal in tax.subtotals {
+        (
+          l18n.tax.tax
+            .replace("{rate}", str(subtotal.rate) + "%")
+            .replace("{taxable_amount}", format_amount(subtotal.taxable_amount, currency)),
+          format_amount(subtotal.tax, currency),
+        )
+      },
+      strong(if is_credit_note { l18n.credit_note.total_credited } else { l18n.tax.total_including_tax }),
+      strong(text(fill: emphasize_color, format_amount(tax.total_including_tax, currency))),
+    ))
+    grand_total = tax.total_including_tax
+  } else {
+    align(right)[
+      #set text(weight: "bold")
+      #if is_credit_note { l18n.credit_note.total_credited } else { l18n.line_items.grand_total }
+      #set text(fill: emphasize_color)
+      #format_amount(grand_total, data.payment_info.currency)
+    ]
+  }
+  // Conditionally deduct the amount drawn from the prepaid retainer, followed
+  // by the amount due and the remaining balance of the retainer
+  if "retainer" in data {
+    let retainer = data.retainer
+    let currency = data.payment_info.currency
+    align(right, table(
+      columns: (auto, auto),
+      align: (left, right),
+      stroke: none,
+      l18n.retainer.drawn, "-" + format_amount(retainer.drawn, currency),
+      strong(l18n.retainer.amount_due), strong(text(fill: emphasize_color, format_amount(retainer.amount_due, currency))),
+      l18n.retainer.remaining, format_amount(retainer.remaining, currency),
+    ))
+    grand_total = retainer.amount_due
+  }
+  // Conditionally display the late payment interest, fee and amount due
+  if "reminder" in data {
+    let reminder = data.reminder
+    let currency = data.payment_info.currency
+    align(right, table(
+      columns: (auto, auto),
+      align: (left, right),
+      stroke: none,
+      l18n.reminder.invoice_amount, format_amount(reminder.invoice_amount, currency),
+      ..if reminder.is_paid {
+        (
+          l18n.reminder.paid.replace("{date}", reminder.date),
+          "-" + format_amount(reminder.invoice_amount, currency),
+        )
+      } else { () },
+      l18n.reminder.late_interest
+        .replace("{rate}", str(reminder.annual_interest_rate) + "%")
+        .replace("{days}", str(reminder.days_overdue)),
+      format_amount(reminder.late_interest, currency),
+      l18n.reminder.reminder_fee, format_amount(reminder.reminder_fee, currency),
+      strong(l18n.reminder.amount_due), strong(text(fill: emphasize_color, format_amount(reminder.amount_due, currency))),
+    ))
+  }
+  // Conditionally display the cash discount for early payment, unless reminding
+  if "early_payment_discount" in data and not ("reminder" in data) {
+    let discount = data.early_payment_discount
+    align(right)[
+      #l18n.invoice_info.early_payment_discount_offer
+        .replace("{discounted_amount}", format_amount(discount.discounted_amount, data.payment_info.currency))
+        .replace("{discount_date}", discount.deadline)
+        .replace("{percentage}", str(discount.percentage) + "%")
+        .replace("{total}", format_amount(grand_total, data.payment_info.currency))
+        .replace("{due_date}", data.information.due_date)
+    ]
+  }
   v(-5pt)
   double-line()
 
+  // Conditionally display the legal wording of reverse charged VAT
+  if "tax" in data and data.tax.is_reverse_charge {
+    small[#l18n.tax.reverse_charge]
+  }
+
+  // Conditionally list the public holidays excluded from the quantity
+  if "excluded_holidays" in data.information {
+    small[
+      #strong(l18n.line_items.excluded_holidays)
+      #data.information.excluded_holidays.map(h => str(h.date) + " " + h.name).join(", ")
+    ]
+  }
+
   v(30pt)
 
   // Conditionally display the purchase order if it exists
@@ -9,6 +9,39 @@ impl Year {
         let year = **self;
         (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
     }
+
+    /// Returns the date of Easter Sunday (Western) for this year, using the
+    /// anonymous Gregorian algorithm (Meeus/Jones/Butcher).
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    ///
+    /// let easter = Year::from(2025).easter_sunday();
+    /// assert_eq!(easter.to_string(), "2025-04-20");
+    /// ```
+    pub fn easter_sunday(&self) -> Date {
+        let year = **self as u32;
+        let a = year % 19;
+        let b = year / 100;
+        let c = year % 100;
+        let d = b / 4;
+        let e = b % 4;
+        let f = (b + 8) / 25;
+        let g = (b - f + 1) / 3;
+        let h = (19 * a + b - d - g + 15) % 30;
+        let i = c / 4;
+        let k = c % 4;
+        let l = (32 + 2 * e + 2 * i - h - k) % 7;
+        let m = (a + 11 * h + 22 * l) / 451;
+        let month = (h + l - 7 * m + 114) / 31;
+        let day = (h + l - 7 * m + 114) % 31 + 1;
+        Date::builder()
+            .year(*self)
+            .month(Month::try_from(month).expect("Easter is in March or April"))
+            .day(Day::try_from(day).expect("LEQ 31 days"))
+            .build()
+    }
 }
 
 impl Month {
@@ -185,6 +218,36 @@ impl TryFromPeriodAnno for YearMonthAndFortnight {
     }
 }
 
+impl TryFromPeriodAnno for YearAndWeek {
+    fn try_from_period_anno(period: PeriodAnno) -> Result<Self> {
+        period
+            .try_unwrap_year_and_week()
+            .map_err(|_| Error::PeriodIsNotYearAndWeek)
+    }
+}
+
+impl TryFromPeriodAnno for YearAndQuarter {
+    fn try_from_period_anno(period: PeriodAnno) -> Result<Self> {
+        period
+            .try_unwrap_year_and_quarter()
+            .map_err(|_| Error::PeriodIsNotYearAndQuarter)
+    }
+}
+
+impl TryFromPeriodAnno for RollingFortnight {
+    fn try_from_period_anno(period: PeriodAnno) -> Result<Self> {
+        period
+            .try_unwrap_rolling_fortnight()
+            .map_err(|_| Error::PeriodIsNotRollingFortnight)
+    }
+}
+
+impl TryFromPeriodAnno for Year {
+    fn try_from_period_anno(period: PeriodAnno) -> Result<Self> {
+        period.try_unwrap_year().map_err(|_| Error::PeriodIsNotYear)
+    }
+}
+
 impl TryFromPeriodAnno for YearAndMonth {
     fn try_from_period_anno(period: PeriodAnno) -> Result<Self> {
         period
@@ -202,6 +265,14 @@ impl IsPeriod for YearAndMonth {
         self.elapsed_months_since(start)
     }
 
+    fn to_date_start_of_period(&self) -> Date {
+        Date::builder()
+            .year(*self.year())
+            .month(*self.month())
+            .day(Day::try_from(1).expect("LEQ 31 days"))
+            .build()
+    }
+
     fn to_date_end_of_period(&self) -> Date {
         self.to_date_end_of_month()
     }
@@ -213,16 +284,78 @@ impl IsPeriod for YearAndMonth {
     fn month(&self) -> &Month {
         self.month()
     }
+
+    fn period_of_same_kind_containing(&self, date: &Date) -> Self {
+        Self::from(*date)
+    }
+}
+
+impl Year {
+    /// The year of today's date.
+    pub fn current() -> Self {
+        Self::from(chrono::Local::now().date_naive().year())
+    }
+
+    /// The year before the current year.
+    pub fn last() -> Self {
+        Self::current().one_year_earlier()
+    }
+
+    /// Returns the year before this one, e.g. 2024 for 2025.
+    pub fn one_year_earlier(&self) -> Self {
+        Self::new(**self - 1)
+    }
+}
+
+impl IsPeriod for Year {
+    fn max_granularity(&self) -> Granularity {
+        Granularity::Year
+    }
+
+    fn elapsed_periods_since(&self, start: impl Borrow<Self>) -> Result<u16> {
+        let start = start.borrow();
+        if start > self {
+            return Err(Error::StartPeriodAfterEndPeriod {
+                start: start.to_string(),
+                end: self.to_string(),
+            });
+        }
+        Ok(**self - **start)
+    }
+
+    fn to_date_start_of_period(&self) -> Date {
+        YearAndMonth::january(**self).to_date_start_of_period()
+    }
+
+    fn to_date_end_of_period(&self) -> Date {
+        YearAndMonth::december(**self).to_date_end_of_period()
+    }
+
+    fn year(&self) -> &Year {
+        self
+    }
+
+    fn month(&self) -> &Month {
+        &Month::January
+    }
+
+    fn period_of_same_kind_containing(&self, date: &Date) -> Self {
+        *date.year()
+    }
 }
 
 /// Calculates the invoice number based on the offset, target month, whether
-/// the items are expenses, and the months off record.
+/// the items are expenses, the months off record and the reset policy.
 /// This function assumes that the `ProtoInvoiceInfo` has already been validated
 /// to ensure that the target month is not in the record of months off.
 /// It computes the invoice number by considering the elapsed months since
 /// the offset month, adjusting for any months that are off record, and
 /// adding an additional increment if the items are expenses.
 ///
+/// If numbers reset yearly and the offset month is in an earlier fiscal year
+/// than the target month, numbering instead starts at `1` for the first month
+/// of the fiscal year of the target month.
+///
 /// ```
 /// extern crate klirr_core;
 /// use klirr_core::prelude::*;
@@ -238,6 +371,7 @@ impl IsPeriod for YearAndMonth {
 ///     &target_month,
 ///     is_expenses,
 ///     &months_off_record,
+///     &InvoiceNumberReset::Never,
 /// ).unwrap();
 ///
 /// // The expected invoice number is calculated as follows:
@@ -248,30 +382,59 @@ impl IsPeriod for YearAndMonth {
 /// // - Therefore, the invoice number should be 100 + 7 - 2 + 1 = 106
 /// let expected = InvoiceNumber::from(106);
 /// assert_eq!(invoice_number, expected);
+///
+/// // With a fiscal year starting in July, August 2024 is the second month
+/// // of the fiscal year, so numbering has restarted.
+/// let invoice_number = calculate_invoice_number(
+///     &offset,
+///     &target_month,
+///     false,
+///     &months_off_record,
+///     &InvoiceNumberReset::Yearly { fiscal_year_start: Month::July },
+/// ).unwrap();
+/// assert_eq!(invoice_number, InvoiceNumber::from(2));
 /// ```
 pub fn calculate_invoice_number<Period: IsPeriod>(
     offset: &TimestampedInvoiceNumber<Period>,
     target_period: &Period,
     is_expenses: bool,
     record_of_periods_off: &RecordOfPeriodsOff<Period>,
+    number_reset: &InvoiceNumberReset,
 ) -> Result<InvoiceNumber> {
     if record_of_periods_off.contains(offset.period()) {
         return Err(Error::RecordsOffMustNotContainOffsetPeriod {
             offset_period: format!("{:?}", offset.period()),
         });
     }
-    let periods_elapsed_since_offset = target_period.elapsed_periods_since(offset.period())?;
+
+    // The first period of the fiscal year of the target period, if numbering
+    // has been reset since the offset period.
+    let reset_period = number_reset
+        .start_of_fiscal_year_containing(&target_period.to_date_end_of_period())
+        .filter(|start_of_fiscal_year| {
+            offset.period().to_date_end_of_period() < *start_of_fiscal_year
+        })
+        .map(|start_of_fiscal_year| {
+            target_period.period_of_same_kind_containing(&start_of_fiscal_year)
+        });
+
+    let (start_period, start_number) = match &reset_period {
+        Some(first_period_of_fiscal_year) => (first_period_of_fiscal_year, 1),
+        None => (offset.period(), **offset.offset()),
+    };
+    let periods_elapsed_since_start = target_period.elapsed_periods_since(start_period)?;
 
     let mut periods_off_to_subtract = 0;
     for period_off in record_of_periods_off.iter() {
-        if period_off > offset.period() && period_off <= target_period {
+        let is_after_start =
+            period_off > start_period || (reset_period.is_some() && period_off == start_period);
+        if is_after_start && period_off <= target_period {
             // If the period is recorded as off, we need to adjust the invoice number
             // by subtracting the number of periods off
             periods_off_to_subtract += 1;
         }
     }
-    let mut invoice_number =
-        **offset.offset() + periods_elapsed_since_offset - periods_off_to_subtract;
+    let mut invoice_number = start_number + periods_elapsed_since_start - periods_off_to_subtract;
     if is_expenses {
         // For expenses we add 1, ensuring that if we invoice for services and
         // expenses the same month, the expense invoice number is always higher.
@@ -280,24 +443,39 @@ pub fn calculate_invoice_number<Period: IsPeriod>(
     Ok(InvoiceNumber::from(invoice_number))
 }
 
-/// Calculates the number of working days in a given month, excluding weekends.
+/// Calculates the quantity of `granularity` in the target period, e.g. the
+/// number of working days or hours according to the `work_schedule`,
+/// excluding - if a `holiday_calendar` is provided - public holidays falling
+/// on working days. Days and hours are scaled by the employment percentage of
+/// the schedule, if any.
+///
+/// Only days within the `contract_window` are counted, and fees per year,
+/// quarter, month or fortnight of a period partially outside of the window
+/// are pro-rated by the share of working days inside it.
 ///
 /// # Errors
-/// Returns an error if the target month is in the record of months off.
+/// Returns an error if the target month is in the record of months off, or
+/// if the target period is entirely outside of the contract window.
 ///
 /// ```
 /// extern crate klirr_core;
 /// use klirr_core::prelude::*;
 ///
 /// let target_month = YearAndMonth::january(2024);
-/// let working_days = quantity_in_period(&target_month, Granularity::Day, Cadence::Monthly, &RecordOfPeriodsOff::default());
+/// let working_days = quantity_in_period(&target_month, Granularity::Day, Cadence::Monthly, &RecordOfPeriodsOff::default(), None, &WorkSchedule::default(), &ContractWindow::default());
 /// assert_eq!(*working_days.unwrap(), dec!(23)); // January 2024 has 23
+///
+/// let working_days = quantity_in_period(&target_month, Granularity::Day, Cadence::Monthly, &RecordOfPeriodsOff::default(), Some(HolidayCalendar::SE), &WorkSchedule::default(), &ContractWindow::default());
+/// assert_eq!(*working_days.unwrap(), dec!(22)); // New Year's Day excluded, Epiphany is a Saturday
 /// ```
 pub fn quantity_in_period<Period: IsPeriod>(
     target_period: &Period,
     granularity: Granularity,
     cadence: Cadence,
     record_of_periods_off: &RecordOfPeriodsOff<Period>,
+    holiday_calendar: Option<HolidayCalendar>,
+    work_schedule: &WorkSchedule,
+    contract_window: &ContractWindow,
 ) -> Result<Quantity> {
     if record_of_periods_off.contains(target_period) {
         return Err(Error::TargetPeriodMustNotBeInRecordOfPeriodsOff {
@@ -313,77 +491,474 @@ pub fn quantity_in_period<Period: IsPeriod>(
         });
     }
 
-    let daily = || working_days_in_period(target_period);
+    if !contract_window.overlaps(target_period) {
+        return Err(Error::TargetPeriodOutsideContractWindow {
+            target_period: format!("{:?}", target_period),
+            contract_window: contract_window.to_string(),
+        });
+    }
+
+    let all_working_days =
+        || working_days_in_period(target_period, holiday_calendar, work_schedule);
+    let working_days = || {
+        let days = all_working_days()?
+            .into_iter()
+            .filter(|day| contract_window.contains(&Date::from(*day)))
+            .collect::<Vec<_>>();
+        Result::Ok(days)
+    };
+    let employment_factor = work_schedule.employment_factor();
+    let daily = || {
+        let days = Quantity::from(working_days()?.len() as i32);
+        Result::Ok(days.mul(employment_factor))
+    };
     let hourly = || {
-        // TODO maybe 8h per day should be configurable
-        Result::Ok(Quantity::EIGHT.mul(*working_days_in_period(target_period)?))
+        let hours = working_days()?
+            .into_iter()
+            .map(|day| work_schedule.hours_on(day.weekday()))
+            .fold(Quantity::ZERO, |acc, hours| acc + hours);
+        Result::Ok(hours.mul(employment_factor))
+    };
+
+    let pro_rated = |quantity: Quantity| {
+        if contract_window.covers(target_period) {
+            return Ok(quantity);
+        }
+        let all = all_working_days()?.len();
+        if all == 0 {
+            return Ok(quantity);
+        }
+        let within = rust_decimal::Decimal::from(working_days()?.len());
+        let share = within / rust_decimal::Decimal::from(all);
+        Ok(Quantity::from((*quantity * share).round_dp(2)))
     };
 
+    let too_coarse = || {
+        Err(Error::CannotInvoiceForGranularityWithCadence {
+            granularity,
+            cadence,
+        })
+    };
     match granularity {
+        Granularity::Year => match cadence {
+            Cadence::Yearly => pro_rated(Quantity::ONE),
+            Cadence::Monthly
+            | Cadence::BiWeekly
+            | Cadence::Weekly
+            | Cadence::Quarterly
+            | Cadence::RollingFortnight => too_coarse(),
+        },
+        Granularity::Quarter => match cadence {
+            Cadence::Quarterly => pro_rated(Quantity::ONE),
+            Cadence::Yearly => pro_rated(Quantity::from(dec!(4))),
+            Cadence::Monthly | Cadence::BiWeekly | Cadence::Weekly | Cadence::RollingFortnight => {
+                too_coarse()
+            }
+        },
         Granularity::Month => match cadence {
-            Cadence::Monthly => Ok(Quantity::ONE),
+            Cadence::Monthly => pro_rated(Quantity::ONE),
             Cadence::BiWeekly => Err(Error::CannotInvoiceForMonthWhenCadenceIsBiWeekly),
+            Cadence::Weekly | Cadence::RollingFortnight => too_coarse(),
+            Cadence::Quarterly => pro_rated(Quantity::from(dec!(3))),
+            Cadence::Yearly => pro_rated(Quantity::from(dec!(12))),
         },
         Granularity::Fortnight => match cadence {
-            Cadence::Monthly => Ok(Quantity::TWO), // Two fortnights in a month
-            Cadence::BiWeekly => Ok(Quantity::ONE),
+            Cadence::Monthly => pro_rated(Quantity::TWO), // Two fortnights in a month
+            Cadence::BiWeekly | Cadence::RollingFortnight => pro_rated(Quantity::ONE),
+            Cadence::Weekly => too_coarse(),
+            Cadence::Quarterly => pro_rated(Quantity::from(dec!(6))),
+            Cadence::Yearly => pro_rated(Quantity::from(dec!(24))),
         },
         Granularity::Day => daily(),
         Granularity::Hour => hourly(),
     }
 }
 
-trait FromYmd: Sized {
-    fn ymd(year: impl Into<i32>, month: impl Into<u32>, day: impl Into<u32>) -> Result<Self>;
+/// Calculates the quantity of `granularity` in a custom `date_range`, e.g. the
+/// number of working days or hours from the first to the last day of the
+/// range, analogous to [`quantity_in_period`].
+///
+/// Fees per year, quarter, month or fortnight are pro-rated per month by the
+/// share of its working days within the range, e.g. a range of all working
+/// days of June and half of those of July is `1.5` months.
+///
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+///
+/// let date_range = DateRange::builder()
+///     .start(Date::from_str("2025-05-10").unwrap())
+///     .end(Date::from_str("2025-06-15").unwrap())
+///     .build()
+///     .unwrap();
+/// let working_days = quantity_in_date_range(&date_range, Granularity::Day, None, &WorkSchedule::default(), &ContractWindow::default());
+/// assert_eq!(*working_days.unwrap(), dec!(25)); // 15 in May and 10 in June
+/// ```
+///
+/// # Errors
+/// Returns an error if the date range is entirely outside of the contract
+/// window.
+pub fn quantity_in_date_range(
+    date_range: &DateRange,
+    granularity: Granularity,
+    holiday_calendar: Option<HolidayCalendar>,
+    work_schedule: &WorkSchedule,
+    contract_window: &ContractWindow,
+) -> Result<Quantity> {
+    if !contract_window.overlaps_date_range(date_range) {
+        return Err(Error::TargetPeriodOutsideContractWindow {
+            target_period: date_range.to_string(),
+            contract_window: contract_window.to_string(),
+        });
+    }
+
+    let working_days = working_days_between(
+        date_range.start(),
+        date_range.end(),
+        holiday_calendar,
+        work_schedule,
+    )?
+    .into_iter()
+    .filter(|day| contract_window.contains(&Date::from(*day)))
+    .collect::<Vec<_>>();
+
+    let employment_factor = work_schedule.employment_factor();
+    let months = || {
+        let mut working_days_per_month = IndexMap::<YearAndMonth, usize>::new();
+        for day in &working_days {
+            *working_days_per_month
+                .entry(YearAndMonth::from(Date::from(*day)))
+                .or_default() += 1;
+        }
+        let mut months = rust_decimal::Decimal::ZERO;
+        for (month, days) in working_days_per_month {
+            let all = working_days_in_period(&month, holiday_calendar, work_schedule)?.len();
+            months += rust_decimal::Decimal::from(days) / rust_decimal::Decimal::from(all);
+        }
+        Result::Ok(months)
+    };
+    let quantity = match granularity {
+        Granularity::Year => months()? / dec!(12),
+        Granularity::Quarter => months()? / dec!(3),
+        Granularity::Month => months()?,
+        Granularity::Fortnight => months()? * dec!(2),
+        Granularity::Day => rust_decimal::Decimal::from(working_days.len()) * employment_factor,
+        Granularity::Hour => {
+            let hours = working_days
+                .iter()
+                .map(|day| work_schedule.hours_on(day.weekday()))
+                .fold(Quantity::ZERO, |acc, hours| acc + hours);
+            *hours * employment_factor
+        }
+    };
+    Ok(Quantity::from(quantity.round_dp(2)))
 }
-impl FromYmd for NaiveDate {
-    fn ymd(year: impl Into<i32>, month: impl Into<u32>, day: impl Into<u32>) -> Result<Self> {
-        let year = year.into();
-        let month = month.into();
-        let day = day.into();
-        Self::from_ymd_opt(year, month, day)
-            .ok_or(Error::InvalidDate {
-                underlying: "Failed to create date from year and month".to_owned(),
-            })?
-            .pred_opt()
-            .ok_or(Error::InvalidDate {
-                underlying: "Failed to create date from year and month".to_owned(),
-            })
+
+/// Returns the quantity of hours, or days, worked according to the `timesheet`,
+/// used instead of [`quantity_in_period`] when invoicing actual hours. Days
+/// are the hours divided by the average hours per working day of the
+/// `work_schedule`, rounded to two decimals.
+///
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+///
+/// let timesheet = Timesheet::parse("2025-05-02,7.5\n2025-05-05,10", None).unwrap();
+/// let days = quantity_in_timesheet(
+///     &timesheet,
+///     &YearAndMonth::may(2025),
+///     Granularity::Day,
+///     &RecordOfPeriodsOff::default(),
+///     &RecordOfDatesOff::default(),
+///     &WorkSchedule::default(),
+/// ).unwrap();
+/// assert_eq!(days, Quantity::from(dec!(2.19)));
+/// ```
+///
+/// # Errors
+/// Returns an error if the target period is a period off, if the granularity
+/// is coarser than Day, if an entry is outside of the target period, or if an
+/// entry is on a recorded full day off.
+pub fn quantity_in_timesheet<Period: IsPeriod>(
+    timesheet: &Timesheet,
+    target_period: &Period,
+    granularity: Granularity,
+    record_of_periods_off: &RecordOfPeriodsOff<Period>,
+    record_of_dates_off: &RecordOfDatesOff,
+    work_schedule: &WorkSchedule,
+) -> Result<Quantity> {
+    if record_of_periods_off.contains(target_period) {
+        return Err(Error::TargetPeriodMustNotBeInRecordOfPeriodsOff {
+            target_period: format!("{:?}", target_period),
+        });
     }
+    quantity_in_timesheet_between(
+        timesheet,
+        &target_period.to_date_start_of_period(),
+        &target_period.to_date_end_of_period(),
+        granularity,
+        record_of_dates_off,
+        work_schedule,
+    )
 }
 
-/// Calculates the number of working days in a given month, excluding weekends.
+/// Returns the quantity of hours, or days, worked according to the `timesheet`
+/// within a custom `date_range`, analogous to [`quantity_in_timesheet`].
 ///
 /// # Errors
-/// Returns an error if the target month is in the record of months off.
-fn working_days_in_period<Period: IsPeriod>(target_period: &Period) -> Result<Quantity> {
-    let year = **target_period.year() as i32;
-    let month = **target_period.month() as u32;
-
-    // Start from the 1st of the month
-    let mut day = NaiveDate::ymd(year, month, 1u32)?;
-
-    // Get the last day of the month
-    let last_day = if month == 12 {
-        NaiveDate::ymd(year + 1, 1u32, 1u32)
-    } else {
-        NaiveDate::ymd(year, month + 1, 1u32)
-    }?;
-
-    let mut working_days = 0;
-    while day <= last_day {
-        match day.weekday() {
-            Weekday::Mon | Weekday::Tue | Weekday::Wed | Weekday::Thu | Weekday::Fri => {
-                working_days += 1;
+/// Returns an error if the granularity is coarser than Day, if an entry is
+/// outside of the date range, or if an entry is on a recorded full day off.
+pub fn quantity_in_timesheet_in_date_range(
+    timesheet: &Timesheet,
+    date_range: &DateRange,
+    granularity: Granularity,
+    record_of_dates_off: &RecordOfDatesOff,
+    work_schedule: &WorkSchedule,
+) -> Result<Quantity> {
+    quantity_in_timesheet_between(
+        timesheet,
+        date_range.start(),
+        date_range.end(),
+        granularity,
+        record_of_dates_off,
+        work_schedule,
+    )
+}
+
+fn quantity_in_timesheet_between(
+    timesheet: &Timesheet,
+    start: &Date,
+    end: &Date,
+    granularity: Granularity,
+    record_of_dates_off: &RecordOfDatesOff,
+    work_schedule: &WorkSchedule,
+) -> Result<Quantity> {
+    if !matches!(granularity, Granularity::Day | Granularity::Hour) {
+        return Err(Error::TimesheetRequiresDayOrHourGranularity { granularity });
+    }
+
+    for entry in timesheet.entries() {
+        let date = entry.date();
+        if date < start || date > end {
+            return Err(Error::TimesheetEntryOutsideTargetPeriod {
+                date: date.to_string(),
+                target_period: format!("{start}..{end}"),
+            });
+        }
+        if let Some(date_off) = record_of_dates_off
+            .iter()
+            .find(|date_off| date_off.is_full_day_off(date))
+        {
+            return Err(Error::TimesheetEntryOnDateOff {
+                date: date.to_string(),
+                date_off: date_off.to_string(),
+            });
+        }
+    }
+
+    let hours = timesheet.total_hours();
+    let quantity = match granularity {
+        Granularity::Hour => *hours,
+        _ => *hours / *work_schedule.hours_per_working_day(),
+    };
+    Ok(Quantity::from(quantity.round_dp(2)))
+}
+
+/// Returns the quantity of days or hours off in the target period according to
+/// the `record_of_dates_off`, counting only dates which are working days, i.e.
+/// not weekends or holidays, within the `contract_window`, since the quantity
+/// in the period is already pro-rated by it. Always zero unless invoicing per
+/// day or hour.
+///
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+///
+/// let record = RecordOfDatesOff::new([
+///     DateOff::from_str("2025-07-01..2025-07-06").unwrap(), // Tuesday to Sunday
+///     DateOff::from_str("2025-07-08 half").unwrap(),
+/// ]);
+/// let days_off = quantity_off_in_period(
+///     &YearAndMonth::july(2025),
+///     Granularity::Day,
+///     &record,
+///     None,
+///     &WorkSchedule::default(),
+///     &ContractWindow::default(),
+/// ).unwrap();
+/// assert_eq!(days_off, Quantity::from(dec!(4.5)));
+/// ```
+pub fn quantity_off_in_period<Period: IsPeriod>(
+    target_period: &Period,
+    granularity: Granularity,
+    record_of_dates_off: &RecordOfDatesOff,
+    holiday_calendar: Option<HolidayCalendar>,
+    work_schedule: &WorkSchedule,
+    contract_window: &ContractWindow,
+) -> Result<Quantity> {
+    quantity_off_between(
+        &target_period.to_date_start_of_period(),
+        &target_period.to_date_end_of_period(),
+        granularity,
+        record_of_dates_off,
+        holiday_calendar,
+        work_schedule,
+        contract_window,
+    )
+}
+
+/// Returns the quantity of days or hours off within a custom `date_range`,
+/// analogous to [`quantity_off_in_period`].
+pub fn quantity_off_in_date_range(
+    date_range: &DateRange,
+    granularity: Granularity,
+    record_of_dates_off: &RecordOfDatesOff,
+    holiday_calendar: Option<HolidayCalendar>,
+    work_schedule: &WorkSchedule,
+    contract_window: &ContractWindow,
+) -> Result<Quantity> {
+    quantity_off_between(
+        date_range.start(),
+        date_range.end(),
+        granularity,
+        record_of_dates_off,
+        holiday_calendar,
+        work_schedule,
+        contract_window,
+    )
+}
+
+fn quantity_off_between(
+    start: &Date,
+    end: &Date,
+    granularity: Granularity,
+    record_of_dates_off: &RecordOfDatesOff,
+    holiday_calendar: Option<HolidayCalendar>,
+    work_schedule: &WorkSchedule,
+    contract_window: &ContractWindow,
+) -> Result<Quantity> {
+    if !matches!(granularity, Granularity::Day | Granularity::Hour)
+        || record_of_dates_off.is_empty()
+    {
+        return Ok(Quantity::ZERO);
+    }
+    let quantity_off = working_days_between(start, end, holiday_calendar, work_schedule)?
+        .into_iter()
+        .filter(|day| contract_window.contains(&Date::from(*day)))
+        .map(|day| {
+            let hours_on_day = work_schedule.hours_on(day.weekday());
+            let hours_off = record_of_dates_off.hours_off_on(&Date::from(day), hours_on_day);
+            match granularity {
+                Granularity::Hour => hours_off,
+                _ => Quantity::from(*hours_off / *hours_on_day),
             }
-            _ => {}
+        })
+        .fold(Quantity::ZERO, |acc, quantity| acc + quantity);
+    Ok(quantity_off.mul(work_schedule.employment_factor()))
+}
+
+/// Returns the holidays of `holiday_calendar` falling on a working day of the
+/// `work_schedule` within the target period, i.e. the holidays which are
+/// excluded from the working days.
+///
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+///
+/// let holidays = holidays_on_working_days_in_period(&YearAndMonth::june(2025), HolidayCalendar::SE, &WorkSchedule::default());
+/// assert_eq!(
+///     holidays.iter().map(|h| h.to_string()).collect::<Vec<_>>(),
+///     ["2025-06-06 National Day of Sweden", "2025-06-20 Midsummer Eve"]
+/// );
+/// ```
+pub fn holidays_on_working_days_in_period<Period: IsPeriod>(
+    target_period: &Period,
+    holiday_calendar: HolidayCalendar,
+    work_schedule: &WorkSchedule,
+) -> Vec<Holiday> {
+    holidays_on_working_days_between(
+        &target_period.to_date_start_of_period(),
+        &target_period.to_date_end_of_period(),
+        holiday_calendar,
+        work_schedule,
+    )
+}
+
+/// Returns the holidays of `holiday_calendar` falling on a working day of the
+/// `work_schedule` within a custom `date_range`, analogous to
+/// [`holidays_on_working_days_in_period`].
+pub fn holidays_on_working_days_in_date_range(
+    date_range: &DateRange,
+    holiday_calendar: HolidayCalendar,
+    work_schedule: &WorkSchedule,
+) -> Vec<Holiday> {
+    holidays_on_working_days_between(
+        date_range.start(),
+        date_range.end(),
+        holiday_calendar,
+        work_schedule,
+    )
+}
+
+fn holidays_on_working_days_between(
+    start: &Date,
+    end: &Date,
+    holiday_calendar: HolidayCalendar,
+    work_schedule: &WorkSchedule,
+) -> Vec<Holiday> {
+    holiday_calendar
+        .holidays_between(start, end)
+        .into_iter()
+        .filter(|holiday| {
+            work_schedule.is_working_day(holiday.date().to_datetime().date().weekday())
+        })
+        .collect()
+}
+
+/// Returns the working days in a given period according to the
+/// `work_schedule`, excluding holidays of the `holiday_calendar`, if any.
+fn working_days_in_period<Period: IsPeriod>(
+    target_period: &Period,
+    holiday_calendar: Option<HolidayCalendar>,
+    work_schedule: &WorkSchedule,
+) -> Result<Vec<NaiveDate>> {
+    working_days_between(
+        &target_period.to_date_start_of_period(),
+        &target_period.to_date_end_of_period(),
+        holiday_calendar,
+        work_schedule,
+    )
+}
+
+/// Returns the working days from `start` to `end`, both inclusive, according
+/// to the `work_schedule`, excluding holidays of the `holiday_calendar`, if any.
+fn working_days_between(
+    start: &Date,
+    end: &Date,
+    holiday_calendar: Option<HolidayCalendar>,
+    work_schedule: &WorkSchedule,
+) -> Result<Vec<NaiveDate>> {
+    let holidays = holiday_calendar
+        .map(|calendar| holidays_on_working_days_between(start, end, calendar, work_schedule))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|holiday| *holiday.date())
+        .collect::<IndexSet<_>>();
+
+    let mut day = start.to_datetime().date();
+    let last_day = end.to_datetime().date();
+
+    let mut working_days = Vec::new();
+    while day <= last_day {
+        if work_schedule.is_working_day(day.weekday()) && !holidays.contains(&Date::from(day)) {
+            working_days.push(day);
         }
         day = day.succ_opt().ok_or(Error::InvalidDate {
             underlying: "Failed to get next day".to_owned(),
         })?
     }
 
-    Ok(Quantity::from(working_days))
+    Ok(working_days)
 }
 
 #[cfg(test)]
@@ -453,6 +1028,9 @@ mod tests {
             Granularity::Month,
             Cadence::Monthly,
             &record_of_periods_off,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
         );
 
         assert!(result.is_ok());
@@ -470,6 +1048,9 @@ mod tests {
             Granularity::Day,
             Cadence::Monthly,
             &record_of_periods_off,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
         );
 
         assert!(result.is_ok());
@@ -486,6 +1067,9 @@ mod tests {
             Granularity::Hour,
             Cadence::Monthly,
             &record_of_periods_off,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
         );
 
         assert!(result.is_ok());
@@ -507,6 +1091,9 @@ mod tests {
             Granularity::Month,
             Cadence::Monthly,
             &fortnight_record,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
         );
 
         assert!(result.is_err());
@@ -538,6 +1125,9 @@ mod tests {
             Granularity::Fortnight,
             Cadence::Monthly,
             &fortnight_record,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
         );
 
         assert!(result.is_ok());
@@ -558,6 +1148,9 @@ mod tests {
             Granularity::Hour,
             Cadence::Monthly,
             &fortnight_record,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
         );
 
         assert!(result.is_ok());
@@ -595,6 +1188,7 @@ mod tests {
             &YearAndMonth::from(target_period),
             is_expenses,
             information.record_of_periods_off(),
+            information.number_reset(),
         )
         .unwrap();
         assert_eq!(invoice_number, expected.into());
@@ -903,10 +1497,353 @@ mod tests {
             &YearAndMonth::december(2025),
             true,
             invoice_info.record_of_periods_off(),
+            invoice_info.number_reset(),
         );
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_calculate_invoice_number_weekly_across_year_boundary() {
+        let week = |s: &str| YearAndWeek::from_str(s).unwrap();
+        let offset = TimestampedInvoiceNumber::builder()
+            .period(week("2024-W51"))
+            .offset(100.into())
+            .build();
+        let record_of_periods_off = RecordOfPeriodsOff::new([week("2025-W01")]);
+
+        let invoice_number = calculate_invoice_number(
+            &offset,
+            &week("2025-W03"),
+            false,
+            &record_of_periods_off,
+            &InvoiceNumberReset::Never,
+        )
+        .unwrap();
+        // 2024-W52, 2025-W02 and 2025-W03, since 2025-W01 is a period off
+        assert_eq!(invoice_number, InvoiceNumber::from(103));
+    }
+
+    #[test]
+    fn test_calculate_invoice_number_quarterly_and_yearly() {
+        let quarter = |s: &str| YearAndQuarter::from_str(s).unwrap();
+        let offset = TimestampedInvoiceNumber::builder()
+            .period(quarter("2024-Q3"))
+            .offset(10.into())
+            .build();
+        let invoice_number = calculate_invoice_number(
+            &offset,
+            &quarter("2025-Q2"),
+            false,
+            &RecordOfPeriodsOff::new([quarter("2025-Q1")]),
+            &InvoiceNumberReset::Never,
+        )
+        .unwrap();
+        assert_eq!(invoice_number, InvoiceNumber::from(12));
+
+        let offset = TimestampedInvoiceNumber::builder()
+            .period(Year::from(2023))
+            .offset(1.into())
+            .build();
+        let invoice_number = calculate_invoice_number(
+            &offset,
+            &Year::from(2025),
+            true,
+            &RecordOfPeriodsOff::default(),
+            &InvoiceNumberReset::Never,
+        )
+        .unwrap();
+        assert_eq!(invoice_number, InvoiceNumber::from(4));
+    }
+
+    #[test]
+    fn quantity_in_period_quarter_and_year() {
+        let target_quarter = YearAndQuarter::from_str("2025-Q1").unwrap();
+        let quantity = |granularity, cadence| {
+            quantity_in_period(
+                &target_quarter,
+                granularity,
+                cadence,
+                &RecordOfPeriodsOff::default(),
+                None,
+                &WorkSchedule::default(),
+                &ContractWindow::default(),
+            )
+        };
+        assert_eq!(
+            quantity(Granularity::Quarter, Cadence::Quarterly).unwrap(),
+            Quantity::ONE
+        );
+        assert_eq!(
+            quantity(Granularity::Month, Cadence::Quarterly).unwrap(),
+            Quantity::from(dec!(3))
+        );
+        // January to March 2025 has 64 weekdays
+        assert_eq!(
+            quantity(Granularity::Day, Cadence::Quarterly).unwrap(),
+            Quantity::from(dec!(64))
+        );
+        assert_eq!(
+            quantity(Granularity::Quarter, Cadence::Monthly),
+            Err(Error::CannotInvoiceForGranularityWithCadence {
+                granularity: Granularity::Quarter,
+                cadence: Cadence::Monthly,
+            })
+        );
+
+        let year = quantity_in_period(
+            &Year::from(2025),
+            Granularity::Quarter,
+            Cadence::Yearly,
+            &RecordOfPeriodsOff::default(),
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
+        );
+        assert_eq!(year.unwrap(), Quantity::from(dec!(4)));
+    }
+
+    #[test]
+    fn quantity_in_period_pro_rated_by_contract_window() {
+        let contract_window = ContractWindow::sample();
+        let quantity = |granularity| {
+            quantity_in_period(
+                &YearAndMonth::may(2025),
+                granularity,
+                Cadence::Monthly,
+                &RecordOfPeriodsOff::default(),
+                None,
+                &WorkSchedule::default(),
+                &contract_window,
+            )
+        };
+        // 13 of the 22 weekdays of May 2025 are on or after the 14th
+        assert_eq!(
+            quantity(Granularity::Day).unwrap(),
+            Quantity::from(dec!(13))
+        );
+        assert_eq!(
+            quantity(Granularity::Month).unwrap(),
+            Quantity::from(dec!(0.59))
+        );
+        assert_eq!(
+            quantity(Granularity::Fortnight).unwrap(),
+            Quantity::from(dec!(1.18))
+        );
+    }
+
+    #[test]
+    fn quantity_in_period_not_pro_rated_when_covered_by_contract_window() {
+        let result = quantity_in_period(
+            &YearAndMonth::june(2025),
+            Granularity::Month,
+            Cadence::Monthly,
+            &RecordOfPeriodsOff::default(),
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::sample(),
+        );
+        assert_eq!(result.unwrap(), Quantity::ONE);
+    }
+
+    #[test]
+    fn quantity_in_period_throws_when_outside_contract_window() {
+        let result = quantity_in_period(
+            &YearAndMonth::july(2025),
+            Granularity::Month,
+            Cadence::Monthly,
+            &RecordOfPeriodsOff::default(),
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::sample_other(),
+        );
+        assert!(matches!(
+            result,
+            Err(Error::TargetPeriodOutsideContractWindow { .. })
+        ));
+    }
+
+    #[test]
+    fn quantity_in_date_range_pro_rated_per_month() {
+        let quantity = |granularity| {
+            quantity_in_date_range(
+                &DateRange::sample(),
+                granularity,
+                None,
+                &WorkSchedule::default(),
+                &ContractWindow::default(),
+            )
+            .unwrap()
+        };
+        // 15 of 22 weekdays of May 2025 and 10 of 21 weekdays of June 2025
+        assert_eq!(quantity(Granularity::Month), Quantity::from(dec!(1.16)));
+        assert_eq!(quantity(Granularity::Fortnight), Quantity::from(dec!(2.32)));
+        assert_eq!(quantity(Granularity::Hour), Quantity::from(dec!(200)));
+    }
+
+    #[test]
+    fn quantity_in_date_range_throws_when_outside_contract_window() {
+        let result = quantity_in_date_range(
+            &DateRange::sample_other(),
+            Granularity::Day,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::sample_other(),
+        );
+        assert!(matches!(
+            result,
+            Err(Error::TargetPeriodOutsideContractWindow { .. })
+        ));
+    }
+
+    #[test]
+    fn test_calculate_invoice_number_rolling_fortnight_across_year_boundary() {
+        let period = |s: &str| RollingFortnight::new(Date::from_str(s).unwrap());
+        let offset = TimestampedInvoiceNumber::builder()
+            .period(period("2024-12-02"))
+            .offset(7.into())
+            .build();
+        let invoice_number = calculate_invoice_number(
+            &offset,
+            &period("2025-01-13"),
+            false,
+            &RecordOfPeriodsOff::new([period("2024-12-30")]),
+            &InvoiceNumberReset::Never,
+        )
+        .unwrap();
+        // 2024-12-16 and 2025-01-13, since 2024-12-30 is a period off
+        assert_eq!(invoice_number, InvoiceNumber::from(9));
+    }
+
+    #[test]
+    fn test_calculate_invoice_number_yearly_reset() {
+        let offset = TimestampedInvoiceNumber::builder()
+            .period(YearAndMonth::may(2024))
+            .offset(237.into())
+            .build();
+        let reset = InvoiceNumberReset::Yearly {
+            fiscal_year_start: Month::January,
+        };
+        let number = |target: YearAndMonth, periods_off: &[YearAndMonth], is_expenses: bool| {
+            calculate_invoice_number(
+                &offset,
+                &target,
+                is_expenses,
+                &RecordOfPeriodsOff::new(periods_off.iter().copied()),
+                &reset,
+            )
+            .unwrap()
+        };
+        // Same fiscal year as the offset, numbering continues from the offset
+        assert_eq!(number(YearAndMonth::december(2024), &[], false), 244.into());
+        // First month of the next fiscal year restarts at 1
+        assert_eq!(number(YearAndMonth::january(2025), &[], false), 1.into());
+        assert_eq!(number(YearAndMonth::january(2025), &[], true), 2.into());
+        assert_eq!(number(YearAndMonth::march(2025), &[], false), 3.into());
+        // Periods off in the fiscal year, including its first, are skipped
+        assert_eq!(
+            number(
+                YearAndMonth::march(2025),
+                &[YearAndMonth::december(2024), YearAndMonth::january(2025)],
+                false
+            ),
+            2.into()
+        );
+    }
+
+    #[test]
+    fn test_calculate_invoice_number_yearly_reset_fiscal_year_starting_in_july() {
+        let offset = TimestampedInvoiceNumber::builder()
+            .period(YearAndQuarter::from_str("2024-Q4").unwrap())
+            .offset(42.into())
+            .build();
+        let reset = InvoiceNumberReset::Yearly {
+            fiscal_year_start: Month::July,
+        };
+        let number = |target: &str| {
+            calculate_invoice_number(
+                &offset,
+                &YearAndQuarter::from_str(target).unwrap(),
+                false,
+                &RecordOfPeriodsOff::default(),
+                &reset,
+            )
+            .unwrap()
+        };
+        assert_eq!(number("2025-Q2"), 44.into());
+        assert_eq!(number("2025-Q3"), 1.into());
+        assert_eq!(number("2026-Q2"), 4.into());
+    }
+
+    #[test]
+    fn test_calculate_invoice_number_yearly_reset_weekly_period_spanning_new_year() {
+        let week = |s: &str| YearAndWeek::from_str(s).unwrap();
+        let offset = TimestampedInvoiceNumber::builder()
+            .period(week("2024-W40"))
+            .offset(100.into())
+            .build();
+        let reset = InvoiceNumberReset::Yearly {
+            fiscal_year_start: Month::January,
+        };
+        // 2025-W01 is 2024-12-30 to 2025-01-05, belonging to fiscal year 2025
+        let invoice_number = calculate_invoice_number(
+            &offset,
+            &week("2025-W02"),
+            false,
+            &RecordOfPeriodsOff::default(),
+            &reset,
+        )
+        .unwrap();
+        assert_eq!(invoice_number, InvoiceNumber::from(2));
+    }
+
+    #[test]
+    fn working_days_in_rolling_fortnight() {
+        let period = RollingFortnight::new(Date::from_str("2024-12-23").unwrap());
+        let working_days =
+            working_days_in_period(&period, Some(HolidayCalendar::SE), &WorkSchedule::default())
+                .unwrap();
+        // Christmas Eve, Christmas Day, Boxing Day, New Year's Eve and New
+        // Year's Day excluded from the 10 weekdays of 2024-12-23..2025-01-05
+        assert_eq!(working_days.len(), 5);
+        assert_eq!(
+            Date::from(*working_days.last().unwrap()).to_string(),
+            "2025-01-03"
+        );
+    }
+
+    #[test]
+    fn quantity_in_period_week() {
+        let target_week = YearAndWeek::from_str("2025-W01").unwrap();
+        let quantity = |granularity, cadence| {
+            quantity_in_period(
+                &target_week,
+                granularity,
+                cadence,
+                &RecordOfPeriodsOff::default(),
+                Some(HolidayCalendar::SE),
+                &WorkSchedule::default(),
+                &ContractWindow::default(),
+            )
+        };
+        // Monday 2024-12-30 to Sunday 2025-01-05, New Year's Eve and Day excluded
+        assert_eq!(
+            quantity(Granularity::Day, Cadence::Weekly).unwrap(),
+            Quantity::from(dec!(3))
+        );
+        assert_eq!(
+            quantity(Granularity::Hour, Cadence::Weekly).unwrap(),
+            Quantity::from(dec!(24))
+        );
+        assert_eq!(
+            quantity(Granularity::Fortnight, Cadence::Weekly),
+            Err(Error::GranularityTooCoarse {
+                granularity: Granularity::Fortnight,
+                max_granularity: Granularity::Day,
+                target_period: "2025-W01".to_owned(),
+            })
+        );
+    }
+
     #[test]
     fn quantity_in_period_target_month_is_in_record_of_months_off() {
         let target_month = YearAndMonth::january(2024);
@@ -916,6 +1853,9 @@ mod tests {
             Granularity::Day,
             Cadence::Monthly,
             &months_off_record,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
         );
         assert!(result.is_err());
     }
@@ -929,6 +1869,9 @@ mod tests {
             Granularity::Day,
             Cadence::Monthly,
             &months_off_record,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
         );
         assert!(result.is_ok());
     }
@@ -958,6 +1901,9 @@ mod tests {
             Granularity::Month,
             Cadence::BiWeekly,
             &record_of_periods_off,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
         );
         assert!(result.is_err());
         if let Err(Error::CannotInvoiceForMonthWhenCadenceIsBiWeekly) = result {
@@ -976,6 +1922,9 @@ mod tests {
             Granularity::Fortnight,
             Cadence::Monthly,
             &record_of_periods_off,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), Quantity::TWO);
@@ -990,6 +1939,9 @@ mod tests {
             Granularity::Fortnight,
             Cadence::BiWeekly,
             &record_of_periods_off,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), Quantity::ONE);
@@ -1019,4 +1971,305 @@ mod tests {
             YearMonthAndFortnight::year_and_month_with_half(ym, MonthHalf::First)
         );
     }
+
+    #[test]
+    fn easter_sunday() {
+        for (year, expected) in [
+            (2024, "2024-03-31"),
+            (2025, "2025-04-20"),
+            (2026, "2026-04-05"),
+            (2038, "2038-04-25"),
+        ] {
+            assert_eq!(Year::from(year).easter_sunday().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn working_days_does_not_include_last_day_of_previous_month() {
+        // 2025-04-30 is a Wednesday, May 2025 has 22 working days
+        let result = quantity_in_period(
+            &MAY_2025,
+            Granularity::Day,
+            Cadence::Monthly,
+            &RecordOfPeriodsOff::default(),
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::from(dec!(22)));
+    }
+
+    #[test]
+    fn working_days_in_fortnight_only_counts_days_in_half() {
+        let first_half =
+            YearMonthAndFortnight::year_and_month_with_half(MAY_2025, MonthHalf::First);
+        let second_half =
+            YearMonthAndFortnight::year_and_month_with_half(MAY_2025, MonthHalf::Second);
+        let days = |period| {
+            quantity_in_period(
+                &period,
+                Granularity::Day,
+                Cadence::BiWeekly,
+                &RecordOfPeriodsOff::default(),
+                None,
+                &WorkSchedule::default(),
+                &ContractWindow::default(),
+            )
+            .unwrap()
+        };
+        assert_eq!(days(first_half), Quantity::from(dec!(11)));
+        assert_eq!(days(second_half), Quantity::from(dec!(11)));
+    }
+
+    #[test]
+    fn working_days_excludes_holidays_on_weekdays() {
+        // December 2025 has 23 weekdays, Christmas Eve, Christmas Day,
+        // Boxing Day and New Year's Eve are all on weekdays in Sweden.
+        let result = quantity_in_period(
+            &DEC_2025,
+            Granularity::Day,
+            Cadence::Monthly,
+            &RecordOfPeriodsOff::default(),
+            Some(HolidayCalendar::SE),
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::from(dec!(19)));
+    }
+
+    #[test]
+    fn working_hours_excludes_holidays_on_weekdays() {
+        // May 2025 has 22 weekdays, Early May and Spring bank holidays in the UK
+        let result = quantity_in_period(
+            &MAY_2025,
+            Granularity::Hour,
+            Cadence::Monthly,
+            &RecordOfPeriodsOff::default(),
+            Some(HolidayCalendar::UK),
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::from(dec!(160)));
+    }
+
+    #[test]
+    fn holidays_on_weekend_are_not_excluded() {
+        // 2025-11-01 All Saints' Day in Sweden is a Saturday
+        let holidays = holidays_on_working_days_in_period(
+            &NOV_2025,
+            HolidayCalendar::SE,
+            &WorkSchedule::default(),
+        );
+        assert!(holidays.is_empty());
+    }
+
+    #[test]
+    fn month_granularity_ignores_holidays() {
+        let result = quantity_in_period(
+            &DEC_2025,
+            Granularity::Month,
+            Cadence::Monthly,
+            &RecordOfPeriodsOff::default(),
+            Some(HolidayCalendar::SE),
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::ONE);
+    }
+
+    #[test]
+    fn working_hours_of_seven_and_a_half_hour_days() {
+        // May 2025 has 22 weekdays
+        let schedule = WorkSchedule::builder()
+            .monday(Quantity::from(dec!(7.5)))
+            .tuesday(Quantity::from(dec!(7.5)))
+            .wednesday(Quantity::from(dec!(7.5)))
+            .thursday(Quantity::from(dec!(7.5)))
+            .friday(Quantity::from(dec!(7.5)))
+            .build()
+            .unwrap();
+        let result = quantity_in_period(
+            &MAY_2025,
+            Granularity::Hour,
+            Cadence::Monthly,
+            &RecordOfPeriodsOff::default(),
+            None,
+            &schedule,
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::from(dec!(165)));
+    }
+
+    #[test]
+    fn working_days_of_four_day_week() {
+        // May 2025 has 5 Fridays
+        let schedule = WorkSchedule::builder()
+            .friday(Quantity::ZERO)
+            .build()
+            .unwrap();
+        let result = quantity_in_period(
+            &MAY_2025,
+            Granularity::Day,
+            Cadence::Monthly,
+            &RecordOfPeriodsOff::default(),
+            None,
+            &schedule,
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::from(dec!(17)));
+    }
+
+    #[test]
+    fn working_days_of_part_time() {
+        let schedule = WorkSchedule::builder()
+            .employment_percentage(Percentage::from_str("80").unwrap())
+            .build()
+            .unwrap();
+        let result = quantity_in_period(
+            &MAY_2025,
+            Granularity::Day,
+            Cadence::Monthly,
+            &RecordOfPeriodsOff::default(),
+            None,
+            &schedule,
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::from(dec!(17.6)));
+    }
+
+    #[test]
+    fn holiday_on_day_off_is_not_excluded() {
+        // Christmas Eve and New Year's Eve 2025 are Wednesdays
+        let schedule = WorkSchedule::builder()
+            .wednesday(Quantity::ZERO)
+            .build()
+            .unwrap();
+        let holidays =
+            holidays_on_working_days_in_period(&DEC_2025, HolidayCalendar::SE, &schedule);
+        assert_eq!(
+            holidays
+                .iter()
+                .map(|h| h.name().as_str())
+                .collect::<Vec<_>>(),
+            ["Christmas Day", "Boxing Day"]
+        );
+    }
+
+    #[test]
+    fn weekend_hours_are_counted() {
+        // May 2025 has 5 Saturdays
+        let schedule = WorkSchedule::builder()
+            .saturday(Quantity::from(dec!(4)))
+            .build()
+            .unwrap();
+        let result = quantity_in_period(
+            &MAY_2025,
+            Granularity::Hour,
+            Cadence::Monthly,
+            &RecordOfPeriodsOff::default(),
+            None,
+            &schedule,
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::from(dec!(196)));
+    }
+
+    #[test]
+    fn days_off_exclude_holidays_and_weekends_in_range() {
+        // National Day of Sweden 2025 is a Friday
+        let record = RecordOfDatesOff::new([DateOff::from_str("2025-06-02..2025-06-08").unwrap()]);
+        let days_off = |calendar| {
+            quantity_off_in_period(
+                &YearAndMonth::june(2025),
+                Granularity::Day,
+                &record,
+                calendar,
+                &WorkSchedule::default(),
+                &ContractWindow::default(),
+            )
+            .unwrap()
+        };
+        assert_eq!(days_off(None), Quantity::from(dec!(5)));
+        assert_eq!(days_off(Some(HolidayCalendar::SE)), Quantity::from(dec!(4)));
+    }
+
+    #[test]
+    fn days_off_only_counts_dates_in_target_period() {
+        let record = RecordOfDatesOff::new([DateOff::from_str("2025-05-26..2025-06-03").unwrap()]);
+        let result = quantity_off_in_period(
+            &MAY_2025,
+            Granularity::Day,
+            &record,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::from(dec!(5)));
+    }
+
+    #[test]
+    fn hours_off_of_half_days_and_hours() {
+        let record = RecordOfDatesOff::new([
+            DateOff::from_str("2025-05-02 2.5h").unwrap(),
+            DateOff::from_str("2025-05-05 half").unwrap(),
+        ]);
+        let result = quantity_off_in_period(
+            &MAY_2025,
+            Granularity::Hour,
+            &record,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::from(dec!(6.5)));
+    }
+
+    #[test]
+    fn days_off_of_part_time() {
+        let schedule = WorkSchedule::builder()
+            .employment_percentage(Percentage::from_str("80").unwrap())
+            .build()
+            .unwrap();
+        let record = RecordOfDatesOff::new([DateOff::from_str("2025-05-05..2025-05-06").unwrap()]);
+        let result = quantity_off_in_period(
+            &MAY_2025,
+            Granularity::Day,
+            &record,
+            None,
+            &schedule,
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::from(dec!(1.6)));
+    }
+
+    #[test]
+    fn days_off_only_counts_dates_in_contract_window() {
+        // The contract starts on Wednesday 2025-05-14
+        let record = RecordOfDatesOff::new([
+            DateOff::from_str("2025-05-05..2025-05-06").unwrap(),
+            DateOff::from_str("2025-05-20").unwrap(),
+        ]);
+        let result = quantity_off_in_period(
+            &MAY_2025,
+            Granularity::Day,
+            &record,
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::sample(),
+        );
+        assert_eq!(result.unwrap(), Quantity::from(dec!(1)));
+    }
+
+    #[test]
+    fn month_granularity_ignores_dates_off() {
+        let result = quantity_off_in_period(
+            &MAY_2025,
+            Granularity::Month,
+            &RecordOfDatesOff::new([DateOff::from_str("2025-05-05").unwrap()]),
+            None,
+            &WorkSchedule::default(),
+            &ContractWindow::default(),
+        );
+        assert_eq!(result.unwrap(), Quantity::ZERO);
+    }
 }
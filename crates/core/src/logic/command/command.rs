@@ -46,6 +46,39 @@ pub fn edit_data_at(
     Ok(())
 }
 
+/// Adds the client profile named `name` in the data directory at `path`, or
+/// edits it if it exists. A new profile is prefilled with the default client
+/// and service fees.
+pub fn edit_client_profile_at(
+    path: impl AsRef<Path>,
+    name: &str,
+    provide_profile: impl FnOnce(ClientProfile<PeriodAnno>) -> Result<ClientProfile<PeriodAnno>>,
+) -> Result<()> {
+    let path = path.as_ref();
+    let existing = match read_client_profile_with_base_path(path, name) {
+        Ok(existing) => {
+            info!("Editing client profile '{}' at: {}", name, path.display());
+            existing
+        }
+        Err(Error::NoClientProfileNamed { .. }) => {
+            info!("Adding client profile '{}' at: {}", name, path.display());
+            let data = read_data_from_disk_with_base_path(path)?;
+            ClientProfile::builder()
+                .client(data.client().clone())
+                .service_fees(data.service_fees().clone())
+                .build()
+        }
+        Err(e) => return Err(e),
+    };
+    let profile = provide_profile(existing)?;
+    save_client_profile_with_base_path(&profile, name, path)?;
+    info!(
+        "✅ Client profile done, invoice it with: `{} invoice --client {}`",
+        BINARY_NAME, name
+    );
+    Ok(())
+}
+
 pub fn init_data_at<Period: IsPeriod + Serialize + DeserializeOwned + HasSample>(
     write_path: impl AsRef<Path>,
     provide_data: impl FnOnce(Data<Period>) -> Result<Data<Period>>,
@@ -143,19 +176,13 @@ fn mutate<D: Serialize + DeserializeOwned + Clone>(
     Ok(())
 }
 
-/// Adds `expenses` to the specified `period` in the data file at `data_path`.
-///
-/// # Throws
-/// Throws an error if the period type is incompatible with the service fees cadence.
-pub fn record_expenses_with_base_path<Period: IsPeriod + Serialize + DeserializeOwned>(
+/// Asserts that expenses can be recorded for `period`, i.e. that we are not
+/// mixing kinds of periods, according to the cadence of the service fees in
+/// the data directory at `data_path`.
+fn validate_period_of_expenses<Period: IsPeriod>(
     period: &Period,
-    expenses: &[Item],
     data_path: impl AsRef<Path>,
 ) -> Result<()> {
-    let data_path = data_path.as_ref();
-    info!("Recording #{} expenses for: {:?}", expenses.len(), period);
-
-    // First we assert that we are not mixing months and fortnights
     let service_fees = service_fees(data_path)?;
     match (
         service_fees.cadence(),
@@ -173,12 +200,56 @@ pub fn record_expenses_with_base_path<Period: IsPeriod + Serialize + Deserialize
         (Cadence::BiWeekly, PeriodAnno::YearMonthAndFortnight(_)) => {
             // BiWeekly cadence is compatible with YearMonthAndFortnight
         }
+        (Cadence::Weekly, PeriodAnno::YearAndWeek(_)) => {
+            // Weekly cadence is compatible with YearAndWeek
+        }
+        (Cadence::Quarterly, PeriodAnno::YearAndQuarter(_)) => {
+            // Quarterly cadence is compatible with YearAndQuarter
+        }
+        (Cadence::Yearly, PeriodAnno::Year(_)) => {
+            // Yearly cadence is compatible with Year
+        }
+        (Cadence::RollingFortnight, PeriodAnno::RollingFortnight(period)) => {
+            // RollingFortnight cadence is compatible with RollingFortnight
+            // anchored on the anchor of the service fees
+            let anchor = service_fees.rolling_period_anchor_or_err()?;
+            if !period.is_anchored_on(&anchor) {
+                return Err(Error::RollingFortnightNotAnchored {
+                    period: period.to_string(),
+                    anchor: anchor.to_string(),
+                });
+            }
+        }
+        (cadence, period) => {
+            return Err(Error::CannotExpenseForPeriodWithCadence {
+                period: period.to_string(),
+                cadence: *cadence,
+            });
+        }
     }
+    Ok(())
+}
+
+/// Adds `expenses` to the specified `period` in the data file at `data_path`.
+///
+/// # Throws
+/// Throws an error if the period type is incompatible with the service fees cadence.
+pub fn record_expenses_with_base_path<Period: IsPeriod + Serialize + DeserializeOwned>(
+    period: &Period,
+    expenses: &[Item],
+    data_path: impl AsRef<Path>,
+) -> Result<()> {
+    let data_path = data_path.as_ref();
+    info!("Recording #{} expenses for: {:?}", expenses.len(), period);
+
+    // First we assert that we are not mixing kinds of periods
+    validate_period_of_expenses(period, data_path)?;
 
+    let expenses = save_receipts_of_items_with_base_path(data_path, expenses)?;
     mutate(
         expensed_periods_path(data_path),
         |data: &mut ExpensedPeriods<Period>| {
-            data.insert_expenses(period, expenses.to_vec());
+            data.insert_expenses(period, expenses);
         },
     )
     .inspect(|_| {
@@ -186,6 +257,110 @@ pub fn record_expenses_with_base_path<Period: IsPeriod + Serialize + Deserialize
     })
 }
 
+/// Reads the expenses recorded for `period` in the data file at `data_path`,
+/// in the order they are listed, which is the order addressed by
+/// [`ExpenseSelector::Index`].
+///
+/// # Throws
+/// Throws an error if no expenses have been recorded for the period.
+pub fn read_expenses_with_base_path<Period: IsPeriod + DeserializeOwned>(
+    period: &Period,
+    data_path: impl AsRef<Path>,
+) -> Result<Vec<Item>> {
+    expensed_periods::<Period>(data_path)?.get(period)
+}
+
+/// Reads the expensed periods in the data file at `data_path`, applies
+/// `update` to them and saves them, unless `update` fails.
+fn update_expensed_periods<Period: IsPeriod + Serialize + DeserializeOwned, T>(
+    data_path: impl AsRef<Path>,
+    update: impl FnOnce(&mut ExpensedPeriods<Period>) -> Result<T>,
+) -> Result<T> {
+    let path = expensed_periods_path(data_path);
+    let mut data = deserialize_contents_of_ron(&path)?;
+    let result = update(&mut data)?;
+    save_to_disk(&data, &path)?;
+    Ok(result)
+}
+
+/// Removes the expense addressed by `selector` from the expenses recorded for
+/// `period` in the data file at `data_path`, and returns it. Its receipt, if
+/// any, is kept in the receipts folder.
+///
+/// # Throws
+/// Throws an error if the period has no expenses recorded, or if no single
+/// expense is addressed by `selector`.
+pub fn remove_expense_with_base_path<Period: IsPeriod + Serialize + DeserializeOwned>(
+    period: &Period,
+    selector: &ExpenseSelector,
+    data_path: impl AsRef<Path>,
+) -> Result<Item> {
+    update_expensed_periods(data_path, |data| data.remove_expense(period, selector)).inspect(
+        |removed| {
+            info!("✅ Removed expense {} from: {:?}", removed, period);
+        },
+    )
+}
+
+/// Replaces the expense addressed by `selector` among the expenses recorded
+/// for `period` in the data file at `data_path` with the expense returned by
+/// `edit`, which is passed the current expense, and returns the edited
+/// expense. A receipt other than that of the current expense is copied into
+/// the receipts folder, just like when recording expenses.
+///
+/// # Throws
+/// Throws an error if the period has no expenses recorded, if no single
+/// expense is addressed by `selector`, or if `edit` fails.
+pub fn edit_expense_with_base_path<Period: IsPeriod + Serialize + DeserializeOwned>(
+    period: &Period,
+    selector: &ExpenseSelector,
+    edit: impl FnOnce(&Item) -> Result<Item>,
+    data_path: impl AsRef<Path>,
+) -> Result<Item> {
+    let data_path = data_path.as_ref();
+    let expenses = read_expenses_with_base_path(period, data_path)?;
+    let current = &expenses[selector.index_in(&expenses, period)?];
+    let mut edited = edit(current)?;
+    if edited.receipt() != current.receipt() {
+        edited = save_receipts_of_items_with_base_path(data_path, &[edited])?.remove(0);
+    }
+    update_expensed_periods(data_path, |data: &mut ExpensedPeriods<Period>| {
+        data.replace_expense(period, selector, edited.clone())
+    })?;
+    info!("✅ Edited expense of {:?}, now: {}", period, edited);
+    Ok(edited)
+}
+
+/// Moves the expense addressed by `selector` from the expenses recorded for
+/// `period` to those recorded for `target_period` in the data file at
+/// `data_path`, merging it with any expense of the target period that is the
+/// same except for its quantity, and returns it.
+///
+/// # Throws
+/// Throws an error if the target period is incompatible with the service fees
+/// cadence, if the period has no expenses recorded, or if no single expense is
+/// addressed by `selector`.
+pub fn move_expense_with_base_path<Period: IsPeriod + Serialize + DeserializeOwned>(
+    period: &Period,
+    selector: &ExpenseSelector,
+    target_period: &Period,
+    data_path: impl AsRef<Path>,
+) -> Result<Item> {
+    let data_path = data_path.as_ref();
+    validate_period_of_expenses(target_period, data_path)?;
+    update_expensed_periods(data_path, |data: &mut ExpensedPeriods<Period>| {
+        let moved = data.remove_expense(period, selector)?;
+        data.insert_expenses(target_period, vec![moved.clone()]);
+        Ok(moved)
+    })
+    .inspect(|moved| {
+        info!(
+            "✅ Moved expense {} from {:?} to {:?}",
+            moved, period, target_period
+        );
+    })
+}
+
 pub fn record_period_off_with_base_path<Period: IsPeriod + Serialize + DeserializeOwned>(
     period: &Period,
     base_path: impl AsRef<Path>,
@@ -200,6 +375,22 @@ pub fn record_period_off_with_base_path<Period: IsPeriod + Serialize + Deseriali
     })
 }
 
+/// Records `date_off` in the invoice information at `base_path`, subtracted
+/// from the quantity of future invoices per day or hour which include the date.
+pub fn record_date_off_with_base_path<Period: IsPeriod + Serialize + DeserializeOwned>(
+    date_off: &DateOff,
+    base_path: impl AsRef<Path>,
+) -> Result<()> {
+    info!("Recording date off: {}", date_off);
+    mutate(
+        proto_invoice_info_path(base_path),
+        |data: &mut ProtoInvoiceInfo<Period>| data.insert_date_off(date_off.clone()),
+    )
+    .inspect(|_| {
+        info!("✅ Date off recorded successfully");
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -274,6 +465,22 @@ mod tests {
         assert!(data.record_of_periods_off().contains(&month));
     }
 
+    #[test]
+    fn test_record_date_off_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_to_disk(
+            &ProtoInvoiceInfo::<YearAndMonth>::sample(),
+            proto_invoice_info_path(tempdir.path()),
+        )
+        .unwrap();
+        let date_off = DateOff::sample();
+
+        record_date_off_with_base_path::<YearAndMonth>(&date_off, tempdir.path()).unwrap();
+
+        let data = proto_invoice_info::<YearAndMonth>(tempdir.path()).unwrap();
+        assert!(data.record_of_dates_off().contains(&date_off));
+    }
+
     #[test]
     fn test_record_expenses_with_base_path() {
         let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
@@ -293,6 +500,104 @@ mod tests {
         assert!(data.contains(&month));
     }
 
+    fn save_expenses_for_may(tempdir: &tempfile::TempDir) -> YearAndMonth {
+        save_to_disk(&ServiceFees::sample(), service_fees_path(tempdir.path())).unwrap();
+        let month = YearAndMonth::may(2025);
+        save_to_disk(
+            &ExpensedPeriods::new(IndexMap::from_iter([(
+                month,
+                vec![
+                    Item::sample_expense_breakfast(),
+                    Item::sample_expense_coffee(),
+                ],
+            )])),
+            expensed_periods_path(tempdir.path()),
+        )
+        .unwrap();
+        month
+    }
+
+    #[test]
+    fn test_remove_expense_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let month = save_expenses_for_may(&tempdir);
+
+        let removed =
+            remove_expense_with_base_path(&month, &ExpenseSelector::Index(1), tempdir.path())
+                .unwrap();
+        assert_eq!(removed, Item::sample_expense_breakfast());
+        let expenses = read_expenses_with_base_path(&month, tempdir.path()).unwrap();
+        assert_eq!(expenses, vec![Item::sample_expense_coffee()]);
+
+        let result =
+            remove_expense_with_base_path(&month, &ExpenseSelector::Index(2), tempdir.path());
+        assert!(matches!(result, Err(Error::NoExpenseMatching { .. })));
+    }
+
+    #[test]
+    fn test_edit_expense_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let month = save_expenses_for_may(&tempdir);
+        let receipt = tempdir.path().join("coffee.jpg");
+        std::fs::write(&receipt, [0xff]).unwrap();
+        let selector = ExpenseSelector::Name("coffee".to_owned());
+
+        let edited = edit_expense_with_base_path(
+            &month,
+            &selector,
+            |current| {
+                assert_eq!(current, &Item::sample_expense_coffee());
+                Item::from_str(&format!(
+                    "Coffee, 4.5, GBP, 2, 2025-05-31, , {}",
+                    receipt.display()
+                ))
+            },
+            tempdir.path(),
+        )
+        .unwrap();
+        let saved_receipt = PathBuf::from("2025-05-31_coffee.jpg");
+        assert_eq!(edited.receipt(), &Some(saved_receipt.clone()));
+        assert!(receipts_dir(tempdir.path()).join(saved_receipt).exists());
+        let expenses = read_expenses_with_base_path(&month, tempdir.path()).unwrap();
+        assert_eq!(expenses[1], edited);
+
+        // Keeping the receipt does not copy it again
+        let edited_again = edit_expense_with_base_path(
+            &month,
+            &selector,
+            |current| Ok(current.clone().with_discount(Some(Discount::sample()))),
+            tempdir.path(),
+        )
+        .unwrap();
+        assert_eq!(edited_again.receipt(), edited.receipt());
+
+        let result = edit_expense_with_base_path(
+            &month,
+            &selector,
+            |_| Item::from_str("Coffee, 4.5, GBP"),
+            tempdir.path(),
+        );
+        assert!(matches!(result, Err(Error::InvalidExpenseItem { .. })));
+        let expenses = read_expenses_with_base_path(&month, tempdir.path()).unwrap();
+        assert_eq!(expenses[1], edited_again);
+    }
+
+    #[test]
+    fn test_move_expense_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let month = save_expenses_for_may(&tempdir);
+        let june = YearAndMonth::june(2025);
+
+        let moved =
+            move_expense_with_base_path(&month, &ExpenseSelector::Index(2), &june, tempdir.path())
+                .unwrap();
+        assert_eq!(moved, Item::sample_expense_coffee());
+        let may = read_expenses_with_base_path(&month, tempdir.path()).unwrap();
+        assert_eq!(may, vec![Item::sample_expense_breakfast()]);
+        let june_expenses = read_expenses_with_base_path(&june, tempdir.path()).unwrap();
+        assert_eq!(june_expenses, vec![Item::sample_expense_coffee()]);
+    }
+
     #[test]
     fn test_record_expenses_with_base_path_fail_because_wrong_period_kind() {
         let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
@@ -360,6 +665,11 @@ mod tests {
         assert!(selector.includes(DataSelector::ServiceFees));
         assert!(!selector.includes(DataSelector::Vendor));
         assert!(!selector.includes(DataSelector::All));
+
+        let selector = DataSelector::ClientProfile;
+        assert!(selector.includes(DataSelector::ClientProfile));
+        assert!(!selector.includes(DataSelector::Client));
+        assert!(!all_selector.includes(DataSelector::ClientProfile));
     }
 
     #[test]
@@ -383,6 +693,32 @@ mod tests {
         assert_eq!(*edited_data.client(), second);
     }
 
+    #[test]
+    fn test_edit_client_profile_at() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let data = Data::<PeriodAnno>::sample();
+        save_data_with_base_path(data.clone(), tempdir.path()).unwrap();
+
+        // A new profile is prefilled with the default client
+        edit_client_profile_at(tempdir.path(), "acme", |profile| {
+            assert_eq!(profile.client(), data.client());
+            Ok(profile.with_language(Some(Language::SV)))
+        })
+        .unwrap();
+        let profile = read_client_profile_with_base_path(tempdir.path(), "acme").unwrap();
+        assert_eq!(*profile.language(), Some(Language::SV));
+
+        // An existing profile is edited
+        edit_client_profile_at(tempdir.path(), "acme", |profile| {
+            assert_eq!(*profile.language(), Some(Language::SV));
+            Ok(profile.with_client(CompanyInformation::sample_other()))
+        })
+        .unwrap();
+        let profile = read_client_profile_with_base_path(tempdir.path(), "acme").unwrap();
+        assert_eq!(profile.client(), &CompanyInformation::sample_other());
+        assert_eq!(*profile.language(), Some(Language::SV));
+    }
+
     #[test]
     fn test_edit_email_data_at() {
         let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
@@ -678,4 +1014,78 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_record_expenses_with_base_path_weekly() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let services_fees: ServiceFees = ServiceFees::builder()
+            .cadence(Cadence::Weekly)
+            .rate(Rate::daily(UnitPrice::ONE))
+            .name("Sample Service Fees".to_owned())
+            .build()
+            .unwrap();
+        save_to_disk(&services_fees, service_fees_path(tempdir.path())).unwrap();
+        save_to_disk(
+            &ExpensedPeriods::<YearAndWeek>::default(),
+            expensed_periods_path(tempdir.path()),
+        )
+        .unwrap();
+
+        let result = record_expenses_with_base_path(
+            &YearAndWeek::sample(),
+            &[Item::sample_expense_breakfast()],
+            tempdir.path(),
+        );
+        assert!(result.is_ok(), "Expected success, got: {:?}", result);
+        let data = expensed_periods::<YearAndWeek>(tempdir.path()).unwrap();
+        assert!(data.contains(&YearAndWeek::sample()));
+
+        let result = record_expenses_with_base_path(
+            &YearAndMonth::may(2025),
+            &[Item::sample_expense_breakfast()],
+            tempdir.path(),
+        );
+        assert_eq!(
+            result,
+            Err(Error::CannotExpenseForPeriodWithCadence {
+                period: "2025-05".to_owned(),
+                cadence: Cadence::Weekly,
+            })
+        );
+    }
+
+    #[test]
+    fn test_record_expenses_with_base_path_rolling_fortnight_must_be_anchored() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let services_fees: ServiceFees = ServiceFees::builder()
+            .cadence(Cadence::RollingFortnight)
+            .rate(Rate::daily(UnitPrice::ONE))
+            .name("Sample Service Fees".to_owned())
+            .rolling_period_anchor(Date::from_str("2024-12-30").unwrap())
+            .build()
+            .unwrap();
+        save_to_disk(&services_fees, service_fees_path(tempdir.path())).unwrap();
+        save_to_disk(
+            &ExpensedPeriods::<RollingFortnight>::default(),
+            expensed_periods_path(tempdir.path()),
+        )
+        .unwrap();
+
+        let result = record_expenses_with_base_path(
+            &RollingFortnight::sample(),
+            &[Item::sample_expense_breakfast()],
+            tempdir.path(),
+        );
+        assert!(result.is_ok(), "Expected success, got: {:?}", result);
+
+        let result = record_expenses_with_base_path(
+            &RollingFortnight::new(Date::from_str("2025-07-07").unwrap()),
+            &[Item::sample_expense_breakfast()],
+            tempdir.path(),
+        );
+        assert!(matches!(
+            result,
+            Err(Error::RollingFortnightNotAnchored { .. })
+        ));
+    }
 }
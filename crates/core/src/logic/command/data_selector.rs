@@ -9,17 +9,21 @@ pub enum DataSelector {
     Information,
     PaymentInfo,
     ServiceFees,
+    /// A named client profile, which is edited on its own and not part of
+    /// `All`.
+    ClientProfile,
 }
 
 impl Select for DataSelector {
     fn includes(&self, target: Self) -> bool {
         match self {
-            DataSelector::All => true,
+            DataSelector::All => !matches!(target, DataSelector::ClientProfile),
             DataSelector::Vendor => matches!(target, DataSelector::Vendor),
             DataSelector::Client => matches!(target, DataSelector::Client),
             DataSelector::Information => matches!(target, DataSelector::Information),
             DataSelector::PaymentInfo => matches!(target, DataSelector::PaymentInfo),
             DataSelector::ServiceFees => matches!(target, DataSelector::ServiceFees),
+            DataSelector::ClientProfile => matches!(target, DataSelector::ClientProfile),
         }
     }
 }
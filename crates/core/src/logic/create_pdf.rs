@@ -7,20 +7,262 @@ pub fn create_pdf_with_data_base_path(
     input: ValidInput,
     render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
 ) -> Result<NamedPdf> {
-    let data = read_data_from_disk_with_base_path(data_base_path)?;
-    create_pdf_with_data(data, input, render)
+    let data_base_path = data_base_path.as_ref();
+    let (data, input) = read_data_for_input_with_base_path(data_base_path, input)?;
+    create_pdf_with_data(data, input, Some(data_base_path), render)
 }
 
 /// Compile the Typst source into a PDF and save it at the specified path, using
 /// the provided `Data` and `ValidInput`.
+///
+/// If `ledger_base_path` is provided, the issued invoice is recorded in the
+/// ledger in that directory, any amount drawn from the retainer of the
+/// client is recorded in the retainers in that directory, and the receipts
+/// of the expenses invoiced are read from its receipts folder. Nothing is
+/// rendered or recorded unless all receipts can be read.
+///
+/// # Errors
+/// Returns an error if the invoice number has already been issued for another
+/// period or kind of invoice, or if the milestone billed has already been
+/// billed, according to the ledger, if the retainer of the client is in
+/// another currency than the invoice, or if a receipt cannot be read.
 pub fn create_pdf_with_data<Period: IsPeriod>(
     data: Data<Period>,
     input: ValidInput,
+    ledger_base_path: Option<&Path>,
     render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
 ) -> Result<NamedPdf> {
     let l18n: L18n = get_localization(input.language())?;
     let layout = *input.layout();
+    let period = data.target_period(input.period())?;
+    let sequence_number = data.invoice_number_of_input(&period, &input)?;
+    let period: PeriodAnno = period.into();
+    let date_range = *input.date_range();
     let data = prepare_invoice_input_data(data, input, ExchangeRatesFetcher::default())?;
+    let Some(ledger_base_path) = ledger_base_path else {
+        return render_and_save_pdf(l18n, data, layout, render);
+    };
+    let receipts = read_receipts_with_base_path(ledger_base_path, &data)?;
+    let ledger = read_ledger_with_base_path(ledger_base_path)?;
+    ledger.validate_number(
+        data.information().number(),
+        data.client().company_name(),
+        &period,
+        &date_range,
+        InvoiceKind::from(&data),
+    )?;
+    if let Some(milestone) = data.information().milestone() {
+        if let Some(billed) = ledger.invoice_of_milestone(data.client().company_name(), milestone) {
+            return Err(Error::MilestoneAlreadyBilled {
+                milestone: milestone.clone(),
+                number: billed.number().to_string(),
+            });
+        }
+    }
+    let client = data.client().company_name().clone();
+    let information = data.information().clone();
+    // Invoices paying nothing from the retainer, e.g. once it is used up,
+    // leave it unchanged
+    let retainer = data
+        .retainer()
+        .clone()
+        .filter(|retainer| !retainer.drawn().is_zero());
+    let named_pdf = render_and_save_pdf(l18n, data, layout, render)?;
+    let entry = LedgerEntry::issued(
+        &named_pdf,
+        period,
+        sequence_number,
+        Date::from(chrono::Local::now().date_naive()),
+    )
+    .with_date_range(date_range);
+    record_in_ledger_with_base_path(entry, ledger_base_path)?;
+    if let Some(retainer) = retainer {
+        record_retainer_drawdown_with_base_path(
+            ledger_base_path,
+            &client,
+            information.number().clone(),
+            *information.invoice_date(),
+            *retainer.drawn(),
+        )?;
+    }
+    Ok(named_pdf.with_receipts(receipts))
+}
+
+/// Compile a payment reminder of a previously issued invoice into a PDF and
+/// save it, by reading data from disk at the provided path and using the
+/// provided `ValidReminderInput`.
+pub fn create_reminder_pdf_with_data_base_path(
+    data_base_path: impl AsRef<Path>,
+    input: ValidReminderInput,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+) -> Result<NamedPdf> {
+    let data_base_path = data_base_path.as_ref();
+    let (data, invoice) =
+        read_data_for_input_with_base_path(data_base_path, input.invoice().clone())?;
+    create_reminder_pdf_with_data(
+        data,
+        input.with_invoice(invoice),
+        Some(data_base_path),
+        render,
+    )
+}
+
+/// Compile a payment reminder of a previously issued invoice into a PDF and
+/// save it, using the provided `Data` and `ValidReminderInput`.
+///
+/// The original invoice is prepared again for its period, so that the
+/// reminder references the same invoice number. If the invoice is recorded
+/// in the ledger in `ledger_base_path`, if provided, the reminder uses the
+/// invoice date and due date recorded.
+///
+/// # Errors
+/// Returns an error if the number of the invoice for the period does not match
+/// the number of the input, if the invoice prepared again no longer matches
+/// the total recorded in the ledger, or if the invoice is not overdue.
+pub fn create_reminder_pdf_with_data<Period: IsPeriod>(
+    data: Data<Period>,
+    input: ValidReminderInput,
+    ledger_base_path: Option<&Path>,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+) -> Result<NamedPdf> {
+    let invoice = input.invoice().clone();
+    let l18n: L18n = get_localization(invoice.language())?;
+    let layout = *invoice.layout();
+    let period = invoice.period().to_string();
+    let data = prepare_invoice_input_data(data, invoice, ExchangeRatesFetcher::default())?;
+    if data.information().number() != input.number() {
+        return Err(Error::InvoiceNumberMismatch {
+            expected: data.information().number().to_string(),
+            found: input.number().to_string(),
+            period,
+        });
+    }
+    let data = match ledger_base_path {
+        Some(ledger_base_path) => {
+            let ledger = read_ledger_with_base_path(ledger_base_path)?;
+            match ledger.invoice_with_number(input.number()) {
+                Some(issued) => data.as_issued(issued)?,
+                None => data,
+            }
+        }
+        None => data,
+    };
+    let data = data.to_reminder(*input.date(), *input.is_paid())?;
+    render_and_save_pdf(l18n, data, layout, render)
+}
+
+/// Compile a credit note of a previously issued invoice into a PDF and save
+/// it, by reading data from disk at the provided path and using the provided
+/// `ValidCreditNoteInput`. The credit note is recorded in the ledger.
+pub fn create_credit_note_pdf_with_data_base_path(
+    data_base_path: impl AsRef<Path>,
+    input: ValidCreditNoteInput,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+) -> Result<NamedPdf> {
+    let data_base_path = data_base_path.as_ref();
+    let (data, invoice) =
+        read_data_for_input_with_base_path(data_base_path, input.invoice().clone())?;
+    create_credit_note_pdf_with_data(
+        data,
+        input.with_invoice(invoice),
+        Some(data_base_path),
+        render,
+    )
+}
+
+/// Compile a credit note of a previously issued invoice into a PDF and save
+/// it, using the provided `Data` and `ValidCreditNoteInput`.
+///
+/// The original invoice is prepared again for its period, so that the credit
+/// note references the same invoice number and reverses its line items. If
+/// the invoice is recorded in the ledger in `ledger_base_path`, if provided,
+/// the credit note references the invoice date recorded. The credit note is
+/// numbered in a series of its own, following the credit notes in the ledger,
+/// in which it is recorded, and the credited share of any amount drawn from
+/// the retainer of the client to pay the invoice is returned to its balance.
+///
+/// # Errors
+/// Returns an error if the percentage to credit is zero, if the number of the
+/// invoice for the period does not match the number of the input, if the
+/// invoice prepared again no longer matches the total recorded in the ledger,
+/// or if the number of the credit note has already been issued for another
+/// invoice according to the ledger.
+pub fn create_credit_note_pdf_with_data<Period: IsPeriod>(
+    data: Data<Period>,
+    input: ValidCreditNoteInput,
+    ledger_base_path: Option<&Path>,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+) -> Result<NamedPdf> {
+    if input.percentage().is_zero() {
+        return Err(Error::NothingToCredit {
+            number: input.number().to_string(),
+        });
+    }
+    let invoice = input.invoice().clone();
+    let l18n: L18n = get_localization(invoice.language())?;
+    let layout = *invoice.layout();
+    let period: PeriodAnno = data.target_period(invoice.period())?.into();
+    let information = data.information().clone();
+    let data = prepare_invoice_input_data(data, invoice, ExchangeRatesFetcher::default())?;
+    if data.information().number() != input.number() {
+        return Err(Error::InvoiceNumberMismatch {
+            expected: data.information().number().to_string(),
+            found: input.number().to_string(),
+            period: period.to_string(),
+        });
+    }
+    let ledger = match ledger_base_path {
+        Some(ledger_base_path) => read_ledger_with_base_path(ledger_base_path)?,
+        None => Ledger::default(),
+    };
+    let data = match ledger.invoice_with_number(input.number()) {
+        Some(issued) => data.as_issued(issued)?,
+        None => data,
+    };
+    let sequence_number = ledger.credit_note_sequence_number(
+        input.number(),
+        input.date(),
+        &data.credited_total(input.percentage()),
+        information.number_reset(),
+    );
+    let number = information.format_credit_note_number(&sequence_number, input.date());
+    ledger.validate_number(
+        &number,
+        data.client().company_name(),
+        &period,
+        &None,
+        InvoiceKind::CreditNote,
+    )?;
+    let client = data.client().company_name().clone();
+    let data = data.to_credit_note(number.clone(), *input.date(), *input.percentage());
+    let named_pdf = render_and_save_pdf(l18n, data, layout, render)?;
+    if let Some(ledger_base_path) = ledger_base_path {
+        let entry = LedgerEntry::issued(
+            &named_pdf,
+            period,
+            sequence_number,
+            Date::from(chrono::Local::now().date_naive()),
+        );
+        record_in_ledger_with_base_path(entry, ledger_base_path)?;
+        return_retainer_drawdown_with_base_path(
+            ledger_base_path,
+            &client,
+            number,
+            input.number().clone(),
+            *input.date(),
+            input.percentage(),
+        )?;
+    }
+    Ok(named_pdf)
+}
+
+/// Renders `data` into a PDF and saves it at its output path.
+fn render_and_save_pdf(
+    l18n: L18n,
+    data: PreparedData,
+    layout: Layout,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+) -> Result<NamedPdf> {
     let output_path_and_name = data.absolute_path_and_name()?;
     let output_path = output_path_and_name.path().to_owned();
     let name = output_path_and_name.name().to_owned();
@@ -62,16 +304,834 @@ mod tests {
             .period(YearMonthAndFortnight::sample())
             .build();
         let dummy_pdf_data = Vec::from(b"%PDF-1.4\n1 0 obj\n<< /Type /Catalog >>\nendobj\n");
-        let named_pdf = create_pdf_with_data::<YearAndMonth>(Data::sample(), input, |_, _, _| {
-            // Simulate PDF rendering
-            Ok(Pdf::from(dummy_pdf_data.clone()))
-        })
-        .unwrap();
+        let named_pdf =
+            create_pdf_with_data::<YearAndMonth>(Data::sample(), input, None, |_, _, _| {
+                // Simulate PDF rendering
+                Ok(Pdf::from(dummy_pdf_data.clone()))
+            })
+            .unwrap();
         assert_eq!(named_pdf.saved_at(), &out);
         let result = std::fs::read(named_pdf.saved_at()).unwrap();
         assert_eq!(result, dummy_pdf_data);
     }
 
+    #[test]
+    fn test_create_pdf_records_invoice_in_ledger() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let out = tempdir.path().join("invoice.pdf");
+        let input = ValidInput::builder()
+            .maybe_output_path(out.clone())
+            .period(YearMonthAndFortnight::sample())
+            .build();
+        let create = |pdf: Pdf| {
+            create_pdf_with_data::<YearAndMonth>(
+                Data::sample(),
+                input.clone(),
+                Some(tempdir.path()),
+                |_, _, _| Ok(pdf.clone()),
+            )
+            .unwrap()
+        };
+        let named_pdf = create(Pdf::sample());
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger.entries().len(), 1);
+        let entry = &ledger.entries()[0];
+        assert_eq!(
+            entry.number(),
+            named_pdf.prepared_data().information().number()
+        );
+        assert_eq!(
+            *entry.period(),
+            PeriodAnno::from(YearAndMonth::from(YearMonthAndFortnight::sample()))
+        );
+        assert_eq!(*entry.kind(), InvoiceKind::Services);
+        assert_eq!(entry.output_path(), &out);
+        assert_eq!(*entry.sha256(), PdfSha256::from(&Pdf::sample()));
+
+        // Generating the identical invoice again is not recorded twice
+        create(Pdf::sample());
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger.entries().len(), 1);
+
+        // But a changed invoice with the same number is
+        create(Pdf::sample_other());
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger.entries().len(), 2);
+    }
+
+    #[test]
+    fn test_create_pdf_for_client_profiles_in_shared_series() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::<PeriodAnno>::sample(), tempdir.path()).unwrap();
+        let acme = ClientProfile::<PeriodAnno>::sample();
+        let globex = acme.clone().with_client(CompanyInformation::sample_other());
+        save_client_profile_with_base_path(&acme, "acme", tempdir.path()).unwrap();
+        save_client_profile_with_base_path(&globex, "globex", tempdir.path()).unwrap();
+        assert_eq!(
+            client_profile_names_with_base_path(tempdir.path()),
+            vec!["acme".to_owned(), "globex".to_owned()]
+        );
+        let period = YearMonthAndFortnight::builder()
+            .year(2025.into())
+            .month(Month::August)
+            .half(MonthHalf::First)
+            .build();
+        let create = |client: &str| {
+            let input = ValidInput::builder()
+                .maybe_output_path(tempdir.path().join(format!("{client}.pdf")))
+                .period(period)
+                .client(client.to_owned())
+                .build();
+            create_pdf_with_data_base_path(tempdir.path(), input, |_, _, _| Ok(Pdf::sample()))
+                .unwrap()
+        };
+        let first = create("acme");
+        assert_eq!(
+            first.prepared_data().client().company_name(),
+            acme.client().company_name()
+        );
+        let second = create("globex");
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        let sequence_numbers = ledger
+            .entries()
+            .iter()
+            .map(|entry| **entry.sequence_number())
+            .collect::<Vec<_>>();
+        assert_eq!(sequence_numbers.len(), 2);
+        assert_eq!(sequence_numbers[1], sequence_numbers[0] + 1);
+        assert_ne!(
+            first.prepared_data().information().number(),
+            second.prepared_data().information().number()
+        );
+
+        // Generating the invoice of a client again keeps its number
+        let again = create("acme");
+        assert_eq!(
+            again.prepared_data().information().number(),
+            first.prepared_data().information().number()
+        );
+    }
+
+    #[test]
+    fn test_create_pdf_for_default_client_and_client_profile_in_shared_series() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::<PeriodAnno>::sample(), tempdir.path()).unwrap();
+        let acme =
+            ClientProfile::<PeriodAnno>::sample().with_client(CompanyInformation::sample_other());
+        save_client_profile_with_base_path(&acme, "acme", tempdir.path()).unwrap();
+        let create = |client: Option<&str>, month: Month| {
+            let input = ValidInput::builder()
+                .maybe_output_path(tempdir.path().join(format!("{client:?}_{month:?}.pdf")))
+                .period(
+                    YearMonthAndFortnight::builder()
+                        .year(2025.into())
+                        .month(month)
+                        .half(MonthHalf::Second)
+                        .build(),
+                )
+                .maybe_client(client.map(str::to_owned))
+                .build();
+            create_pdf_with_data_base_path(tempdir.path(), input, |_, _, _| Ok(Pdf::sample()))
+                .unwrap()
+                .prepared_data()
+                .information()
+                .number()
+                .to_string()
+        };
+        let first = create(None, Month::August);
+        let acme_first = create(Some("acme"), Month::August);
+        // The default client continues the shared series instead of taking
+        // the number of its next period, already issued to the profile
+        let second = create(None, Month::September);
+        assert_eq!(
+            acme_first.parse::<u16>().unwrap(),
+            first.parse::<u16>().unwrap() + 1
+        );
+        assert_eq!(
+            second.parse::<u16>().unwrap(),
+            acme_first.parse::<u16>().unwrap() + 1
+        );
+        assert_eq!(create(None, Month::August), first);
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert!(ledger.gaps().is_empty());
+    }
+
+    #[test]
+    fn test_create_pdf_for_client_profile_in_own_series() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let data = Data::<PeriodAnno>::sample();
+        let numbering = ClientNumbering::Own(data.information().offset().clone());
+        save_data_with_base_path(data, tempdir.path()).unwrap();
+        let create = |client: Option<&str>| {
+            let input = ValidInput::builder()
+                .maybe_output_path(tempdir.path().join(format!("{client:?}.pdf")))
+                .period(
+                    YearMonthAndFortnight::builder()
+                        .year(2025.into())
+                        .month(Month::August)
+                        .half(MonthHalf::Second)
+                        .build(),
+                )
+                .maybe_client(client.map(str::to_owned))
+                .build();
+            create_pdf_with_data_base_path(tempdir.path(), input, |_, _, _| Ok(Pdf::sample()))
+                .map(|named_pdf| named_pdf.prepared_data().information().number().to_string())
+        };
+        let number = create(None).unwrap();
+
+        // A series of its own printed like that of the default client
+        // collides with it
+        let acme = ClientProfile::<PeriodAnno>::sample()
+            .with_client(CompanyInformation::sample_other())
+            .with_numbering(numbering);
+        save_client_profile_with_base_path(&acme, "acme", tempdir.path()).unwrap();
+        let result = create(Some("acme"));
+        assert!(matches!(result, Err(Error::InvoiceNumberCollision { .. })));
+
+        // Unless printed with a format of its own
+        let acme =
+            acme.with_number_format(Some(InvoiceNumberFormat::builder().prefix("ACME-").build()));
+        save_client_profile_with_base_path(&acme, "acme", tempdir.path()).unwrap();
+        assert_eq!(create(Some("acme")).unwrap(), format!("ACME-{number}"));
+    }
+
+    #[test]
+    fn test_create_pdf_for_client_profile_outside_of_profiles_is_err() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::<PeriodAnno>::sample(), tempdir.path()).unwrap();
+        let input = ValidInput::builder()
+            .period(YearMonthAndFortnight::sample())
+            .client("../client".to_owned())
+            .build();
+        let result =
+            create_pdf_with_data_base_path(tempdir.path(), input, |_, _, _| Ok(Pdf::sample()));
+        assert!(matches!(
+            result,
+            Err(Error::InvalidClientProfileName { .. })
+        ));
+    }
+
+    #[test]
+    fn test_create_pdf_for_unknown_client_profile_is_err() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::<PeriodAnno>::sample(), tempdir.path()).unwrap();
+        let input = ValidInput::builder()
+            .period(YearMonthAndFortnight::sample())
+            .client("acme".to_owned())
+            .build();
+        let result =
+            create_pdf_with_data_base_path(tempdir.path(), input, |_, _, _| Ok(Pdf::sample()));
+        assert!(matches!(result, Err(Error::NoClientProfileNamed { .. })));
+    }
+
+    #[test]
+    fn test_create_pdf_number_collision_in_ledger() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let input = ValidInput::builder()
+            .maybe_output_path(tempdir.path().join("invoice.pdf"))
+            .period(YearMonthAndFortnight::sample())
+            .build();
+        let number = prepare_invoice_input_data(
+            Data::<YearAndMonth>::sample(),
+            input.clone(),
+            ExchangeRatesFetcher::default(),
+        )
+        .unwrap()
+        .information()
+        .number()
+        .clone();
+        let existing = LedgerEntry::builder()
+            .number(number)
+            .sequence_number(InvoiceNumber::sample())
+            .period(PeriodAnno::from(YearAndMonth::january(2020)))
+            .kind(InvoiceKind::Services)
+            .client("Holmes Ltd".to_owned())
+            .invoice_date(Date::sample())
+            .due_date(Date::sample())
+            .issued_on(Date::sample())
+            .total(Cost::from(dec!(100)))
+            .currency(Currency::EUR)
+            .output_path(PathBuf::from("old.pdf"))
+            .sha256(PdfSha256::sample())
+            .build();
+        append_to_ledger_with_base_path(&existing, tempdir.path()).unwrap();
+        let result = create_pdf_with_data::<YearAndMonth>(
+            Data::sample(),
+            input,
+            Some(tempdir.path()),
+            |_, _, _| Ok(Pdf::sample()),
+        );
+        assert!(matches!(result, Err(Error::InvoiceNumberCollision { .. })));
+        assert!(!tempdir.path().join("invoice.pdf").exists());
+    }
+
+    #[test]
+    fn test_create_pdf_services_and_expenses_of_consecutive_months() {
+        let mut data = Data::<YearAndMonth>::sample();
+        let currency = *data.payment_info().currency();
+        let taxi = |date: &str| Item::from_str(&format!("Taxi,30,{currency},1,{date}")).unwrap();
+        data.set_expensed_periods(ExpensedPeriods::new(IndexMap::from_iter([
+            (YearAndMonth::may(2025), vec![taxi("2025-05-20")]),
+            (YearAndMonth::june(2025), vec![taxi("2025-06-20")]),
+        ])));
+        let create = |data: &Data<YearAndMonth>,
+                      ledger_base_path: &Path,
+                      month: YearAndMonth,
+                      items: InvoicedItems| {
+            let input = ValidInput::builder()
+                .maybe_output_path(ledger_base_path.join(format!("{month}_{items}.pdf")))
+                .period(YearMonthAndFortnight::year_and_month_with_half(
+                    month,
+                    MonthHalf::Second,
+                ))
+                .items(items)
+                .build();
+            create_pdf_with_data(data.clone(), input, Some(ledger_base_path), |_, _, _| {
+                Ok(Pdf::sample())
+            })
+            .map(|named_pdf| named_pdf.prepared_data().information().number().to_string())
+        };
+        let services = InvoicedItems::Service { time_off: None };
+
+        // By default the expenses of May take the number of the services of June
+        let tempdir = tempfile::tempdir().unwrap();
+        let path = tempdir.path();
+        let may = YearAndMonth::may(2025);
+        let june = YearAndMonth::june(2025);
+        assert_eq!(create(&data, path, may, services.clone()).unwrap(), "33");
+        assert_eq!(
+            create(&data, path, may, InvoicedItems::Expenses).unwrap(),
+            "34"
+        );
+        let result = create(&data, path, june, services.clone());
+        assert!(matches!(result, Err(Error::InvoiceNumberCollision { .. })));
+
+        // Unless invoices of expenses are numbered in a series of their own
+        let information = data
+            .information()
+            .clone()
+            .with_expenses_number_format(Some(InvoiceNumberFormat::default().for_expenses()));
+        let data = data.with_information(information);
+        let tempdir = tempfile::tempdir().unwrap();
+        let path = tempdir.path();
+        let numbers = [
+            create(&data, path, may, services.clone()).unwrap(),
+            create(&data, path, may, InvoicedItems::Expenses).unwrap(),
+            create(&data, path, june, services).unwrap(),
+            create(&data, path, june, InvoicedItems::Expenses).unwrap(),
+        ];
+        assert_eq!(numbers, ["33", "34-E", "34", "35-E"]);
+        let ledger = read_ledger_with_base_path(path).unwrap();
+        assert_eq!(ledger.entries().len(), 4);
+        assert!(ledger.gaps().is_empty());
+    }
+
+    #[test]
+    fn test_create_pdf_with_missing_receipt_writes_nothing() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let data = Data::<YearAndMonth>::sample();
+        let client = data.client().company_name().clone();
+        let currency = *data.payment_info().currency();
+        let taxi = Item::from_str(&format!("Taxi,30,{currency},1,2025-05-20"))
+            .unwrap()
+            .with_receipt(Some(PathBuf::from("taxi.jpg")));
+        let retainer = top_up_retainer_with_base_path(
+            tempdir.path(),
+            &client,
+            Date::sample(),
+            Cost::from(dec!(1000)),
+            currency,
+        )
+        .unwrap();
+        let mut data = data.with_retainer(Some(retainer.clone()));
+        data.set_expensed_periods(ExpensedPeriods::new(IndexMap::from_iter([(
+            YearAndMonth::may(2025),
+            vec![taxi],
+        )])));
+        let output_path = tempdir.path().join("expenses.pdf");
+        let create = || {
+            let input = ValidInput::builder()
+                .maybe_output_path(output_path.clone())
+                .period(YearMonthAndFortnight::year_and_month_with_half(
+                    YearAndMonth::may(2025),
+                    MonthHalf::Second,
+                ))
+                .items(InvoicedItems::Expenses)
+                .build();
+            create_pdf_with_data(data.clone(), input, Some(tempdir.path()), |_, _, _| {
+                Ok(Pdf::sample())
+            })
+        };
+
+        let result = create();
+        assert!(matches!(result, Err(Error::FailedToReadReceipt { .. })));
+        assert!(!output_path.exists());
+        assert!(
+            read_ledger_with_base_path(tempdir.path())
+                .unwrap()
+                .entries()
+                .is_empty()
+        );
+        assert_eq!(
+            read_retainer_with_base_path(tempdir.path(), &client).unwrap(),
+            Some(retainer)
+        );
+
+        let dir = receipts_dir(tempdir.path());
+        create_folder_if_needed(&dir).unwrap();
+        std::fs::write(dir.join("taxi.jpg"), [1]).unwrap();
+        let invoice = create().unwrap();
+        assert_eq!(
+            *invoice.receipts(),
+            vec![Receipt::builder().name("taxi.jpg").content(vec![1]).build()]
+        );
+        assert!(output_path.exists());
+    }
+
+    #[test]
+    fn test_create_pdf_for_date_range_collides_with_invoice_of_period() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let date_range = DateRange::builder()
+            .start(Date::from_str("2025-07-07").unwrap())
+            .end(Date::from_str("2025-07-18").unwrap())
+            .build()
+            .unwrap();
+        let create = |date_range: Option<DateRange>, out: &str| {
+            let input = ValidInput::builder()
+                .maybe_output_path(tempdir.path().join(out))
+                .period(YearMonthAndFortnight::sample())
+                .maybe_date_range(date_range)
+                .build();
+            create_pdf_with_data::<YearAndMonth>(
+                Data::sample(),
+                input,
+                Some(tempdir.path()),
+                |_, _, _| Ok(Pdf::sample()),
+            )
+        };
+        create(Some(date_range), "range.pdf").unwrap();
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(*ledger.entries()[0].date_range(), Some(date_range));
+
+        // The same date range can be invoiced again
+        create(Some(date_range), "range.pdf").unwrap();
+
+        // But not the whole period, which would get the same number
+        let result = create(None, "period.pdf");
+        assert!(matches!(result, Err(Error::InvoiceNumberCollision { .. })));
+        assert!(!tempdir.path().join("period.pdf").exists());
+    }
+
+    #[test]
+    fn test_create_pdf_bills_milestones_once() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let data =
+            Data::<PeriodAnno>::sample().with_milestone_contract(Some(MilestoneContract::sample()));
+        save_data_with_base_path(data, tempdir.path()).unwrap();
+        let create = |items: InvoicedItems, out: &str| {
+            let input = ValidInput::builder()
+                .maybe_output_path(tempdir.path().join(out))
+                .period(
+                    YearMonthAndFortnight::builder()
+                        .year(2025.into())
+                        .month(Month::August)
+                        .half(MonthHalf::First)
+                        .build(),
+                )
+                .items(items)
+                .build();
+            create_pdf_with_data_base_path(tempdir.path(), input, |_, _, _| Ok(Pdf::sample()))
+        };
+        let milestone = |name: &str| InvoicedItems::Milestone {
+            name: name.to_owned(),
+        };
+        let number = |named_pdf: NamedPdf| named_pdf.prepared_data().information().number().clone();
+
+        // Milestones are numbered in a series of their own, so billing one on
+        // an empty ledger does not take the number of the services invoice
+        let kickoff = create(milestone("Kickoff"), "kickoff.pdf").unwrap();
+        assert_eq!(number(kickoff), FormattedInvoiceNumber::from("MS-1"));
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        let entry = ledger.entries().last().unwrap();
+        assert_eq!(*entry.kind(), InvoiceKind::Milestone);
+        assert_eq!(entry.milestone().as_deref(), Some("Kickoff"));
+        assert_eq!(**entry.total(), dec!(15000));
+        assert_eq!(**entry.sequence_number(), 1);
+        let services = create(InvoicedItems::default(), "services.pdf").unwrap();
+        assert_eq!(number(services), FormattedInvoiceNumber::from("18"));
+
+        let result = create(milestone("Kickoff"), "kickoff_again.pdf");
+        assert!(matches!(result, Err(Error::MilestoneAlreadyBilled { .. })));
+        let delivery = create(milestone("Delivery"), "delivery.pdf").unwrap();
+        assert_eq!(number(delivery), FormattedInvoiceNumber::from("MS-2"));
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert!(ledger.gaps().is_empty());
+        let result = create(milestone("Launch"), "launch.pdf");
+        assert!(matches!(result, Err(Error::NoMilestoneNamed { .. })));
+    }
+
+    #[test]
+    fn test_create_pdf_draws_from_retainer() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let data = Data::<PeriodAnno>::sample();
+        let client = data.client().company_name().clone();
+        let currency = *data.payment_info().currency();
+        save_data_with_base_path(data, tempdir.path()).unwrap();
+        top_up_retainer_with_base_path(
+            tempdir.path(),
+            &client,
+            Date::sample(),
+            Cost::from(dec!(1000)),
+            currency,
+        )
+        .unwrap();
+        let create_half = |half: MonthHalf| {
+            let input = ValidInput::builder()
+                .maybe_output_path(tempdir.path().join("invoice.pdf"))
+                .period(
+                    YearMonthAndFortnight::builder()
+                        .year(2025.into())
+                        .month(Month::August)
+                        .half(half)
+                        .build(),
+                )
+                .build();
+            create_pdf_with_data_base_path(tempdir.path(), input, |_, _, _| Ok(Pdf::sample()))
+                .unwrap()
+        };
+        let create = || create_half(MonthHalf::First);
+        let invoice = create();
+        let prepared = invoice.prepared_data();
+        let drawdown = prepared.retainer().clone().unwrap();
+        assert_eq!(**drawdown.drawn(), dec!(1000));
+        assert_eq!(**drawdown.remaining(), dec!(0));
+        assert_eq!(
+            *prepared.amount_due(),
+            *prepared.total_including_tax() - dec!(1000)
+        );
+
+        // Producing the same invoice again draws the same amount once
+        let again = create();
+        assert_eq!(again.prepared_data().retainer(), prepared.retainer());
+        let retainer = read_retainer_with_base_path(tempdir.path(), &client)
+            .unwrap()
+            .unwrap();
+        assert_eq!(*retainer.balance(), dec!(0));
+        assert_eq!(retainer.transactions().len(), 2);
+
+        // Once used up, invoices draw nothing and record no drawdown
+        let next = create_half(MonthHalf::Second);
+        let drawdown = next.prepared_data().retainer().clone().unwrap();
+        assert_eq!(**drawdown.drawn(), dec!(0));
+        let retainer = read_retainer_with_base_path(tempdir.path(), &client)
+            .unwrap()
+            .unwrap();
+        assert_eq!(retainer.transactions().len(), 2);
+    }
+
+    #[test]
+    fn test_create_credit_note_pdf_returns_drawdown_to_retainer() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let data = Data::<PeriodAnno>::sample();
+        let client = data.client().company_name().clone();
+        let currency = *data.payment_info().currency();
+        save_data_with_base_path(data, tempdir.path()).unwrap();
+        top_up_retainer_with_base_path(
+            tempdir.path(),
+            &client,
+            Date::sample(),
+            Cost::from(dec!(1000)),
+            currency,
+        )
+        .unwrap();
+        let input = |name: &str| {
+            ValidInput::builder()
+                .maybe_output_path(tempdir.path().join(name))
+                .period(
+                    YearMonthAndFortnight::builder()
+                        .year(2025.into())
+                        .month(Month::August)
+                        .half(MonthHalf::First)
+                        .build(),
+                )
+                .build()
+        };
+        let invoice =
+            create_pdf_with_data_base_path(tempdir.path(), input("invoice.pdf"), |_, _, _| {
+                Ok(Pdf::sample())
+            })
+            .unwrap();
+        let balance = || {
+            *read_retainer_with_base_path(tempdir.path(), &client)
+                .unwrap()
+                .unwrap()
+                .balance()
+        };
+        assert_eq!(balance(), dec!(0));
+
+        let credit = || {
+            let input = ValidCreditNoteInput::builder()
+                .invoice(input("credit_note.pdf"))
+                .number(invoice.prepared_data().information().number().clone())
+                .date(Date::from_str("2025-09-01").unwrap())
+                .percentage(Percentage::try_from(dec!(50)).unwrap())
+                .build();
+            create_credit_note_pdf_with_data_base_path(tempdir.path(), input, |_, _, _| {
+                Ok(Pdf::sample_other())
+            })
+            .unwrap()
+        };
+        let credit_note = credit();
+        assert!(credit_note.prepared_data().retainer().is_none());
+        assert_eq!(balance(), dec!(500));
+
+        // Generating the same credit note again returns the same amount once
+        credit();
+        assert_eq!(balance(), dec!(500));
+    }
+
+    #[test]
+    fn test_create_pdf_with_retainer_in_other_currency_is_err() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let data = Data::<PeriodAnno>::sample();
+        let client = data.client().company_name().clone();
+        let currency = *data.payment_info().currency();
+        save_data_with_base_path(data, tempdir.path()).unwrap();
+        let other = if currency == Currency::EUR {
+            Currency::USD
+        } else {
+            Currency::EUR
+        };
+        top_up_retainer_with_base_path(
+            tempdir.path(),
+            &client,
+            Date::sample(),
+            Cost::from(dec!(1000)),
+            other,
+        )
+        .unwrap();
+        let input = ValidInput::builder()
+            .maybe_output_path(tempdir.path().join("invoice.pdf"))
+            .period(
+                YearMonthAndFortnight::builder()
+                    .year(2025.into())
+                    .month(Month::August)
+                    .half(MonthHalf::First)
+                    .build(),
+            )
+            .build();
+        let result =
+            create_pdf_with_data_base_path(tempdir.path(), input, |_, _, _| Ok(Pdf::sample()));
+        assert!(matches!(
+            result,
+            Err(Error::RetainerCurrencyMismatch { .. })
+        ));
+        assert!(!tempdir.path().join("invoice.pdf").exists());
+        assert!(
+            read_ledger_with_base_path(tempdir.path())
+                .unwrap()
+                .entries()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_create_reminder_pdf() {
+        let out = NamedTempFile::new().unwrap().path().to_path_buf();
+        let invoice = ValidInput::builder()
+            .maybe_output_path(out.clone())
+            .period(YearMonthAndFortnight::sample())
+            .build();
+        let data = Data::<YearAndMonth>::sample();
+        let number = prepare_invoice_input_data(
+            data.clone(),
+            invoice.clone(),
+            ExchangeRatesFetcher::default(),
+        )
+        .unwrap()
+        .information()
+        .number()
+        .clone();
+        let input = ValidReminderInput::builder()
+            .invoice(invoice)
+            .number(number)
+            .date(Date::from_str("2025-12-31").unwrap())
+            .build();
+        let named_pdf = create_reminder_pdf_with_data(data, input, None, |_, data, _| {
+            assert!(data.reminder().is_some());
+            Ok(Pdf::sample())
+        })
+        .unwrap();
+        assert_eq!(named_pdf.saved_at(), &out);
+        assert!(named_pdf.prepared_data().reminder().is_some());
+    }
+
+    #[test]
+    fn test_create_reminder_pdf_number_mismatch() {
+        let input = ValidReminderInput::builder()
+            .invoice(
+                ValidInput::builder()
+                    .period(YearMonthAndFortnight::sample())
+                    .build(),
+            )
+            .number(FormattedInvoiceNumber::from("1"))
+            .date(Date::from_str("2025-12-31").unwrap())
+            .build();
+        let result = create_reminder_pdf_with_data(
+            Data::<YearAndMonth>::sample(),
+            input,
+            None,
+            |_, _, _| Ok(Pdf::sample()),
+        );
+        assert!(matches!(result, Err(Error::InvoiceNumberMismatch { .. })));
+    }
+
+    #[test]
+    fn test_create_reminder_pdf_of_issued_invoice() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let invoice = ValidInput::builder()
+            .maybe_output_path(tempdir.path().join("invoice.pdf"))
+            .period(YearMonthAndFortnight::sample())
+            .build();
+        let data = Data::<YearAndMonth>::sample();
+        let issued =
+            create_pdf_with_data(data.clone(), invoice, Some(tempdir.path()), |_, _, _| {
+                Ok(Pdf::sample())
+            })
+            .unwrap();
+        let issued = issued.prepared_data().information().clone();
+        let reminder = |data: Data<YearAndMonth>| {
+            let input = ValidReminderInput::builder()
+                .invoice(
+                    ValidInput::builder()
+                        .maybe_output_path(tempdir.path().join("reminder.pdf"))
+                        .period(YearMonthAndFortnight::sample())
+                        .build(),
+                )
+                .number(issued.number().clone())
+                .date(Date::from_str("2025-12-31").unwrap())
+                .build();
+            create_reminder_pdf_with_data(data, input, Some(tempdir.path()), |_, _, _| {
+                Ok(Pdf::sample())
+            })
+        };
+
+        let named_pdf = reminder(data.clone()).unwrap();
+        let information = named_pdf.prepared_data().information();
+        assert_eq!(information.invoice_date(), issued.invoice_date());
+        assert_eq!(information.due_date(), issued.due_date());
+
+        // The invoice prepared with another rate is not the one issued
+        let service_fees = data
+            .service_fees()
+            .clone()
+            .with_rate(Rate::daily(UnitPrice::from(dec!(1))));
+        let result = reminder(data.with_service_fees(service_fees));
+        assert!(matches!(result, Err(Error::IssuedInvoiceChanged { .. })));
+    }
+
+    #[test]
+    fn test_create_credit_note_pdf_records_credit_note_in_ledger() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let invoice = ValidInput::builder()
+            .maybe_output_path(tempdir.path().join("invoice.pdf"))
+            .period(YearMonthAndFortnight::sample())
+            .build();
+        let named_invoice = create_pdf_with_data::<YearAndMonth>(
+            Data::sample(),
+            invoice,
+            Some(tempdir.path()),
+            |_, _, _| Ok(Pdf::sample()),
+        )
+        .unwrap();
+        let invoice_number = named_invoice.prepared_data().information().number();
+        let out = tempdir.path().join("credit_note.pdf");
+        let credit = |data: Data<YearAndMonth>, percentage: rust_decimal::Decimal| {
+            let input = ValidCreditNoteInput::builder()
+                .invoice(
+                    ValidInput::builder()
+                        .maybe_output_path(out.clone())
+                        .period(YearMonthAndFortnight::sample())
+                        .build(),
+                )
+                .number(invoice_number.clone())
+                .date(Date::from_str("2025-07-15").unwrap())
+                .percentage(Percentage::try_from(percentage).unwrap())
+                .build();
+            create_credit_note_pdf_with_data(data, input, Some(tempdir.path()), |_, data, _| {
+                assert!(data.credit_note().is_some());
+                Ok(Pdf::sample_other())
+            })
+        };
+        let named_pdf = credit(Data::sample(), dec!(50)).unwrap();
+        assert_eq!(named_pdf.saved_at(), &out);
+        let data = named_pdf.prepared_data();
+        assert_eq!(
+            data.information().number(),
+            &FormattedInvoiceNumber::from("CN-1")
+        );
+        assert_eq!(
+            *data.line_items().grand_total(),
+            -*named_invoice.prepared_data().line_items().grand_total() / dec!(2)
+        );
+
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger.entries().len(), 2);
+        let entry = &ledger.entries()[1];
+        assert_eq!(*entry.kind(), InvoiceKind::CreditNote);
+        assert_eq!(entry.credited_invoice().as_ref(), Some(invoice_number));
+
+        // Generating the same credit note again keeps its number
+        let named_pdf = credit(Data::sample(), dec!(50)).unwrap();
+        assert_eq!(
+            named_pdf.prepared_data().information().number(),
+            &FormattedInvoiceNumber::from("CN-1")
+        );
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger.entries().len(), 2);
+
+        // But crediting another share of it on the same date is another one
+        let named_pdf = credit(Data::sample(), dec!(25)).unwrap();
+        assert_eq!(
+            named_pdf.prepared_data().information().number(),
+            &FormattedInvoiceNumber::from("CN-2")
+        );
+
+        let result = credit(Data::sample(), dec!(0));
+        assert!(matches!(result, Err(Error::NothingToCredit { .. })));
+
+        // The invoice prepared with another rate is not the one issued
+        let data = Data::<YearAndMonth>::sample();
+        let service_fees = data
+            .service_fees()
+            .clone()
+            .with_rate(Rate::daily(UnitPrice::from(dec!(1))));
+        let result = credit(data.with_service_fees(service_fees), dec!(50));
+        assert!(matches!(result, Err(Error::IssuedInvoiceChanged { .. })));
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger.entries().len(), 3);
+    }
+
+    #[test]
+    fn test_create_credit_note_pdf_number_mismatch() {
+        let input = ValidCreditNoteInput::builder()
+            .invoice(
+                ValidInput::builder()
+                    .period(YearMonthAndFortnight::sample())
+                    .build(),
+            )
+            .number(FormattedInvoiceNumber::from("1"))
+            .date(Date::from_str("2025-12-31").unwrap())
+            .build();
+        let result = create_credit_note_pdf_with_data(
+            Data::<YearAndMonth>::sample(),
+            input,
+            None,
+            |_, _, _| Ok(Pdf::sample()),
+        );
+        assert!(matches!(result, Err(Error::InvoiceNumberMismatch { .. })));
+    }
+
     #[test]
     fn test_save_pdf() {
         let tmp_file = NamedTempFile::new().unwrap();
@@ -9,13 +9,14 @@ pub trait FetchExchangeRates {
         target_currency: Currency,
         line_items: &LineItemsPricedInSourceCurrency,
     ) -> Result<ExchangeRates> {
-        let Ok(expenses) = line_items.clone().try_unwrap_expenses() else {
+        let expenses = line_items.expenses().to_vec();
+        if expenses.is_empty() {
             debug!("No expenses found, skipping exchange rate fetching.");
             return Ok(ExchangeRates::builder()
                 .target_currency(target_currency)
                 .rates(ExchangeRatesMap::new())
                 .build());
-        };
+        }
         debug!("☑️ Fetching rates for #{} expenses...", expenses.len());
         self.fetch_for_items(target_currency, expenses)
     }
@@ -1,5 +1,11 @@
 mod get_localization;
 mod read_data_from_disk;
+mod read_write_ledger;
+mod read_write_receipts;
+mod read_write_retainer;
 
 pub use get_localization::*;
 pub use read_data_from_disk::*;
+pub use read_write_ledger::*;
+pub use read_write_receipts::*;
+pub use read_write_retainer::*;
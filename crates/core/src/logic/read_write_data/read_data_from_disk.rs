@@ -77,6 +77,16 @@ pub fn save_data_with_base_path<Period: IsPeriod + Serialize>(
     save_to_disk(data.information(), proto_invoice_info_path(base_path))?;
     save_to_disk(data.payment_info(), payment_info_path(base_path))?;
     save_to_disk(data.service_fees(), service_fees_path(base_path))?;
+    save_to_disk(data.service_lines(), service_lines_path(base_path))?;
+    let milestone_contract_path = milestone_contract_path(base_path);
+    match data.milestone_contract() {
+        Some(milestone_contract) => save_to_disk(milestone_contract, milestone_contract_path)?,
+        None if milestone_contract_path.exists() => std::fs::remove_file(&milestone_contract_path)
+            .map_err(|e| Error::FailedToWriteDataToDisk {
+                underlying: format!("{:?}", e),
+            })?,
+        None => {}
+    }
     save_to_disk(data.expensed_periods(), expensed_periods_path(base_path))?;
     Ok(())
 }
@@ -94,6 +104,8 @@ const DATA_FILE_NAME_VENDOR: &str = "vendor";
 const DATA_FILE_NAME_CLIENT: &str = "client";
 const DATA_FILE_NAME_PAYMENT: &str = "payment";
 const DATA_FILE_NAME_SERVICE_FEES: &str = "service_fees";
+const DATA_FILE_NAME_SERVICE_LINES: &str = "service_lines";
+const DATA_FILE_NAME_MILESTONE_CONTRACT: &str = "milestones";
 const DATA_FILE_NAME_PROTO_INVOICE_INFO: &str = "invoice_info";
 const DATA_FILE_NAME_EXPENSES: &str = "expenses";
 const DATA_FILE_NAME_CACHED_RATES: &str = "cached_rates";
@@ -122,6 +134,14 @@ pub fn service_fees_path(base_path: impl AsRef<Path>) -> PathBuf {
     path_to_ron_file_with_base(base_path, DATA_FILE_NAME_SERVICE_FEES)
 }
 
+pub fn service_lines_path(base_path: impl AsRef<Path>) -> PathBuf {
+    path_to_ron_file_with_base(base_path, DATA_FILE_NAME_SERVICE_LINES)
+}
+
+pub fn milestone_contract_path(base_path: impl AsRef<Path>) -> PathBuf {
+    path_to_ron_file_with_base(base_path, DATA_FILE_NAME_MILESTONE_CONTRACT)
+}
+
 pub fn proto_invoice_info_path(base_path: impl AsRef<Path>) -> PathBuf {
     path_to_ron_file_with_base(base_path, DATA_FILE_NAME_PROTO_INVOICE_INFO)
 }
@@ -146,6 +166,26 @@ pub fn service_fees(base_path: impl AsRef<Path>) -> Result<ServiceFees> {
     deserialize_contents_of_ron(service_fees_path(base_path))
 }
 
+/// The service lines invoiced after the service fees, empty if none have
+/// been saved.
+pub fn service_lines(base_path: impl AsRef<Path>) -> Result<Vec<ServiceLine>> {
+    let path = service_lines_path(base_path);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    deserialize_contents_of_ron(path)
+}
+
+/// The fixed-price contract billed in milestones, none if none has been
+/// saved.
+pub fn milestone_contract(base_path: impl AsRef<Path>) -> Result<Option<MilestoneContract>> {
+    let path = milestone_contract_path(base_path);
+    if !path.exists() {
+        return Ok(None);
+    }
+    deserialize_contents_of_ron(path).map(Some)
+}
+
 pub fn proto_invoice_info<Period: IsPeriod + DeserializeOwned>(
     base_path: impl AsRef<Path>,
 ) -> Result<ProtoInvoiceInfo<Period>> {
@@ -158,6 +198,155 @@ pub fn expensed_periods<Period: IsPeriod + DeserializeOwned>(
     deserialize_contents_of_ron(expensed_periods_path(base_path))
 }
 
+const DATA_DIR_NAME_CLIENT_PROFILES: &str = "clients";
+
+/// The directory of client profiles in the data directory at `base_path`,
+/// one RON file per profile named after it.
+pub fn client_profiles_dir(base_path: impl AsRef<Path>) -> PathBuf {
+    base_path.as_ref().join(DATA_DIR_NAME_CLIENT_PROFILES)
+}
+
+/// The path to the client profile named `name` in the data directory at
+/// `base_path`, e.g. `clients/acme.ron`.
+///
+/// # Errors
+/// Returns `Error::InvalidClientProfileName` if `name` is empty, starts with
+/// a dot or contains a path separator, e.g. `../acme`, which would point
+/// outside of the directory of client profiles.
+pub fn client_profile_path(base_path: impl AsRef<Path>, name: &str) -> Result<PathBuf> {
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        return Err(Error::InvalidClientProfileName {
+            name: name.to_owned(),
+        });
+    }
+    Ok(path_to_ron_file_with_base(
+        client_profiles_dir(base_path),
+        name,
+    ))
+}
+
+/// The names of all client profiles in the data directory at `base_path`,
+/// sorted alphabetically, empty if there are none.
+pub fn client_profile_names_with_base_path(base_path: impl AsRef<Path>) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(client_profiles_dir(base_path)) else {
+        return Vec::new();
+    };
+    let mut names = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ron"))
+        .filter_map(|path| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+        })
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// Reads the client profile named `name` in the data directory at `base_path`.
+///
+/// # Errors
+/// Returns `Error::NoClientProfileNamed` if there is no such profile, or
+/// `Error::InvalidClientProfileName` if `name` is not a valid name.
+pub fn read_client_profile_with_base_path(
+    base_path: impl AsRef<Path>,
+    name: &str,
+) -> Result<ClientProfile<PeriodAnno>> {
+    let path = client_profile_path(base_path, name)?;
+    if !path.exists() {
+        return Err(Error::NoClientProfileNamed {
+            name: name.to_owned(),
+            path: path.display().to_string(),
+        });
+    }
+    deserialize_contents_of_ron(path)
+}
+
+/// Saves `profile` as the client profile named `name` in the data directory
+/// at `base_path`, replacing any existing profile of that name.
+pub fn save_client_profile_with_base_path<Period: IsPeriod + Serialize>(
+    profile: &ClientProfile<Period>,
+    name: &str,
+    base_path: impl AsRef<Path>,
+) -> Result<()> {
+    let base_path = base_path.as_ref();
+    let path = client_profile_path(base_path, name)?;
+    create_folder_if_needed(client_profiles_dir(base_path))?;
+    save_to_disk(profile, path)
+}
+
+/// Reads the data from disk at `base_path` for invoicing `input`, with the
+/// client profile of the input, if any, in place of the default client, see
+/// [`Data::with_client_profile`], and the retainer of the invoiced client,
+/// if any.
+///
+/// If the profile shares the series of invoice numbers of the vendor the
+/// number of the returned input is the next number of the series, see
+/// [`Ledger::shared_sequence_number`], and so is that of invoices to the
+/// default client if any profile shares it. Invoices billing a milestone are
+/// numbered in a series of their own instead, see
+/// [`Ledger::milestone_sequence_number`].
+pub fn read_data_for_input_with_base_path(
+    base_path: impl AsRef<Path>,
+    input: ValidInput,
+) -> Result<(Data<PeriodAnno>, ValidInput)> {
+    let base_path = base_path.as_ref();
+    let mut data = read_data_from_disk_with_base_path(base_path)?;
+    let mut profile = None;
+    if let Some(name) = input.client() {
+        let client_profile = read_client_profile_with_base_path(base_path, name)?;
+        let retainer =
+            read_retainer_with_base_path(base_path, client_profile.client().company_name())?;
+        data = data
+            .with_client_profile(client_profile.clone())
+            .with_retainer(retainer);
+        profile = Some(client_profile);
+    }
+    if let InvoicedItems::Milestone { name } = input.items() {
+        let period = data.target_period(input.period())?;
+        let number = read_ledger_with_base_path(base_path)?.milestone_sequence_number(
+            data.client().company_name(),
+            name,
+            &input.invoice_date_for(&period),
+            data.information().number_reset(),
+        );
+        return Ok((data, input.with_invoice_number(Some(number))));
+    }
+    let is_shared = match &profile {
+        Some(profile) => profile.numbering().is_shared(),
+        None => any_client_profile_shares_numbering_with_base_path(base_path)?,
+    };
+    let input = if is_shared {
+        let period = data.target_period(input.period())?;
+        let number = read_ledger_with_base_path(base_path)?.shared_sequence_number(
+            data.client().company_name(),
+            &period,
+            input.date_range(),
+            input.items().is_expenses(),
+            &input.invoice_date_for(&period),
+            data.information().number_reset(),
+        );
+        input.with_invoice_number(number)
+    } else {
+        input
+    };
+    Ok((data, input))
+}
+
+/// Whether any client profile in the data directory at `base_path` shares
+/// the series of invoice numbers of the vendor, see [`ClientNumbering`].
+fn any_client_profile_shares_numbering_with_base_path(base_path: &Path) -> Result<bool> {
+    for name in client_profile_names_with_base_path(base_path) {
+        if read_client_profile_with_base_path(base_path, &name)?
+            .numbering()
+            .is_shared()
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
 pub fn read_email_data_from_disk_with_base_path(
     base_path: impl AsRef<Path>,
 ) -> Result<EncryptedEmailSettings> {
@@ -173,14 +362,20 @@ pub fn read_data_from_disk_with_base_path(base_path: impl AsRef<Path>) -> Result
     let vendor = vendor(base_path)?;
     let payment_info = payment_info(base_path)?;
     let service_fees = service_fees(base_path)?;
+    let service_lines = service_lines(base_path)?;
+    let milestone_contract = milestone_contract(base_path)?;
     let proto_invoice_info = proto_invoice_info(base_path)?;
     let expensed_periods = expensed_periods(base_path)?;
+    let retainer = read_retainer_with_base_path(base_path, client.company_name())?;
 
     let input_data = Data::builder()
         .client(client)
         .vendor(vendor)
         .payment_info(payment_info)
         .service_fees(service_fees)
+        .service_lines(service_lines)
+        .maybe_milestone_contract(milestone_contract)
+        .maybe_retainer(retainer)
         .information(proto_invoice_info)
         .expensed_periods(expensed_periods)
         .build();
@@ -201,4 +396,39 @@ mod tests {
         let loaded_data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
         assert_eq!(loaded_data, data, "Loaded data should match saved data");
     }
+
+    #[test]
+    fn read_data_without_service_lines() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let data = Data::<PeriodAnno>::sample().with_service_lines(vec![ServiceLine::sample()]);
+        save_data_with_base_path(data.clone(), tempdir.path()).unwrap();
+        let loaded_data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(loaded_data, data);
+
+        std::fs::remove_file(service_lines_path(tempdir.path())).unwrap();
+        let loaded_data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert!(loaded_data.service_lines().is_empty());
+    }
+
+    #[test]
+    fn read_data_with_milestone_contract() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let data = Data::<PeriodAnno>::sample();
+        save_data_with_base_path(data.clone(), tempdir.path()).unwrap();
+        assert!(!milestone_contract_path(tempdir.path()).exists());
+        let loaded_data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(*loaded_data.milestone_contract(), None);
+
+        let data = data.with_milestone_contract(Some(MilestoneContract::sample()));
+        save_data_with_base_path(data.clone(), tempdir.path()).unwrap();
+        let loaded_data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(loaded_data, data);
+
+        // Saving data without a contract removes the previous one
+        let data = data.with_milestone_contract(None);
+        save_data_with_base_path(data.clone(), tempdir.path()).unwrap();
+        assert!(!milestone_contract_path(tempdir.path()).exists());
+        let loaded_data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(loaded_data, data);
+    }
 }
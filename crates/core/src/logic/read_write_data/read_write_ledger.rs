@@ -0,0 +1,127 @@
+use std::io::Write;
+
+use crate::prelude::*;
+
+const DATA_FILE_NAME_LEDGER: &str = "ledger";
+
+/// The path to the ledger of issued invoices in the data directory at
+/// `base_path`, a JSON Lines file with one [`LedgerEntry`] per line.
+pub fn ledger_path(base_path: impl AsRef<Path>) -> PathBuf {
+    base_path
+        .as_ref()
+        .join(format!("{}.jsonl", DATA_FILE_NAME_LEDGER))
+}
+
+/// Reads the ledger of issued invoices in the data directory at `base_path`,
+/// which is empty if no invoice has been issued yet.
+pub fn read_ledger_with_base_path(base_path: impl AsRef<Path>) -> Result<Ledger> {
+    let path = ledger_path(base_path);
+    if !path.exists() {
+        return Ok(Ledger::default());
+    }
+    let contents = std::fs::read_to_string(&path).map_err(|e| Error::FailedToReadLedger {
+        underlying: format!("Read {}: {}", path.display(), e),
+    })?;
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str::<LedgerEntry>(line).map_err(|e| Error::FailedToReadLedger {
+                underlying: format!("Invalid entry '{}': {}", line, e),
+            })
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(Ledger::from)
+}
+
+/// Appends `entry` to the ledger of issued invoices in the data directory at
+/// `base_path`, creating the ledger if needed. Existing entries are never
+/// modified.
+pub fn append_to_ledger_with_base_path(
+    entry: &LedgerEntry,
+    base_path: impl AsRef<Path>,
+) -> Result<()> {
+    let path = ledger_path(base_path);
+    let line = serde_json::to_string(entry).map_err(|e| Error::FailedToWriteLedger {
+        underlying: format!("Serialize entry: {}", e),
+    })?;
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{}", line))
+        .map_err(|e| Error::FailedToWriteLedger {
+            underlying: format!("Append to {}: {}", path.display(), e),
+        })?;
+    info!("📒 Recorded invoice {} in ledger", entry.number());
+    Ok(())
+}
+
+/// Records `entry` in the ledger in the data directory at `base_path`, unless
+/// the same invoice has already been recorded, warning about any gap in the
+/// invoice numbers it causes.
+pub fn record_in_ledger_with_base_path(
+    entry: LedgerEntry,
+    base_path: impl AsRef<Path>,
+) -> Result<()> {
+    let base_path = base_path.as_ref();
+    let ledger = read_ledger_with_base_path(base_path)?;
+    if ledger.contains_same_invoice_as(&entry) {
+        debug!("Invoice {} already recorded in ledger", entry.number());
+        return Ok(());
+    }
+    append_to_ledger_with_base_path(&entry, base_path)?;
+    let number = entry.number().clone();
+    let mut entries = ledger.entries().clone();
+    entries.push(entry);
+    for (before, after) in Ledger::from(entries).gaps() {
+        if before == number || after == number {
+            warn!(
+                "⚠️ Gap in invoice numbers between {} and {}, invoices in between have not been issued",
+                before, after
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn read_missing_ledger_is_empty() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger, Ledger::default());
+    }
+
+    #[test]
+    fn append_and_read() {
+        let tempdir = tempfile::tempdir().unwrap();
+        append_to_ledger_with_base_path(&LedgerEntry::sample(), tempdir.path()).unwrap();
+        append_to_ledger_with_base_path(&LedgerEntry::sample_other(), tempdir.path()).unwrap();
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger, Ledger::sample_other());
+        let contents = std::fs::read_to_string(ledger_path(tempdir.path())).unwrap();
+        assert_eq!(contents.lines().count(), 2);
+    }
+
+    #[test]
+    fn record_same_invoice_twice_appends_once() {
+        let tempdir = tempfile::tempdir().unwrap();
+        record_in_ledger_with_base_path(LedgerEntry::sample(), tempdir.path()).unwrap();
+        record_in_ledger_with_base_path(LedgerEntry::sample(), tempdir.path()).unwrap();
+        let ledger = read_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(ledger, Ledger::sample());
+    }
+
+    #[test]
+    fn read_invalid_ledger_is_err() {
+        let tempdir = tempfile::tempdir().unwrap();
+        std::fs::write(ledger_path(tempdir.path()), "not json\n").unwrap();
+        let result = read_ledger_with_base_path(tempdir.path());
+        assert!(matches!(result, Err(Error::FailedToReadLedger { .. })));
+    }
+}
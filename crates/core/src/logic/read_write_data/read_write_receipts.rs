@@ -0,0 +1,211 @@
+use crate::prelude::*;
+
+const DATA_DIR_NAME_RECEIPTS: &str = "receipts";
+
+/// The directory of receipts of expenses in the data directory at
+/// `base_path`, into which receipts are copied when expenses are recorded.
+pub fn receipts_dir(base_path: impl AsRef<Path>) -> PathBuf {
+    base_path.as_ref().join(DATA_DIR_NAME_RECEIPTS)
+}
+
+/// Copies the receipt at `path` of an expense dated `date` into the receipts
+/// folder of the data directory at `base_path`, named after the date and the
+/// file name of the receipt, e.g. `2025-05-31_coffee.jpg`, and returns the
+/// name of the copy. Another receipt with the same name is never replaced,
+/// instead the copy is suffixed with a number, e.g. `2025-05-31_coffee_1.jpg`.
+///
+/// # Errors
+/// Returns `Error::FailedToSaveReceipt` if the receipt cannot be read or
+/// copied.
+pub fn save_receipt_with_base_path(
+    base_path: impl AsRef<Path>,
+    path: impl AsRef<Path>,
+    date: &Date,
+) -> Result<PathBuf> {
+    let path = path.as_ref();
+    let failed = |underlying: String| Error::FailedToSaveReceipt {
+        path: path.display().to_string(),
+        underlying,
+    };
+    let content = std::fs::read(path).map_err(|e| failed(format!("Read: {}", e)))?;
+    let dir = receipts_dir(base_path);
+    create_folder_if_needed(&dir)?;
+    let stem = path
+        .file_stem()
+        .ok_or_else(|| failed("No file name".to_owned()))?
+        .to_string_lossy();
+    let extension = path
+        .extension()
+        .map(|extension| format!(".{}", extension.to_string_lossy()))
+        .unwrap_or_default();
+    let mut suffix = 0;
+    loop {
+        let name = if suffix == 0 {
+            format!("{}_{}{}", date, stem, extension)
+        } else {
+            format!("{}_{}_{}{}", date, stem, suffix, extension)
+        };
+        let destination = dir.join(&name);
+        if !destination.exists() {
+            std::fs::write(&destination, &content)
+                .map_err(|e| failed(format!("Write {}: {}", destination.display(), e)))?;
+            info!("🧾 Saved receipt at: {}", destination.display());
+            return Ok(PathBuf::from(name));
+        }
+        if std::fs::read(&destination).is_ok_and(|existing| existing == content) {
+            debug!("Receipt already saved at: {}", destination.display());
+            return Ok(PathBuf::from(name));
+        }
+        suffix += 1;
+    }
+}
+
+/// Copies the receipts of `items`, if any, into the receipts folder of the
+/// data directory at `base_path`, and returns the items with their receipts
+/// replaced by the names of the copies, see [`save_receipt_with_base_path`].
+///
+/// # Errors
+/// Returns an error if any receipt cannot be saved.
+pub fn save_receipts_of_items_with_base_path(
+    base_path: impl AsRef<Path>,
+    items: &[Item],
+) -> Result<Vec<Item>> {
+    let base_path = base_path.as_ref();
+    items
+        .iter()
+        .map(|item| {
+            let Some(receipt) = item.receipt() else {
+                return Ok(item.clone());
+            };
+            let name = save_receipt_with_base_path(base_path, receipt, item.transaction_date())?;
+            Ok(item.clone().with_receipt(Some(name)))
+        })
+        .collect()
+}
+
+/// Reads the receipts of the items invoiced in `data` from the receipts
+/// folder of the data directory at `base_path`, in the order of the items.
+///
+/// # Errors
+/// Returns `Error::FailedToReadReceipt` if a receipt cannot be read.
+pub fn read_receipts_with_base_path(
+    base_path: impl AsRef<Path>,
+    data: &PreparedData,
+) -> Result<Vec<Receipt>> {
+    let dir = receipts_dir(base_path);
+    data.line_items()
+        .all_items()
+        .filter_map(|item| item.receipt().clone())
+        .collect::<IndexSet<_>>()
+        .into_iter()
+        .map(|name| {
+            let path = dir.join(&name);
+            let content = std::fs::read(&path).map_err(|e| Error::FailedToReadReceipt {
+                path: path.display().to_string(),
+                underlying: format!("{}", e),
+            })?;
+            Ok(Receipt::builder()
+                .name(name.to_string_lossy())
+                .content(content)
+                .build())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn save_receipts_of_items() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let original = tempdir.path().join("coffee.jpg");
+        std::fs::write(&original, [0xff, 0xd8]).unwrap();
+        let item = Item::sample_expense_coffee().with_receipt(Some(original.clone()));
+        let items = [item.clone(), item, Item::sample_expense_breakfast()];
+
+        let saved = save_receipts_of_items_with_base_path(tempdir.path(), &items).unwrap();
+        let name = PathBuf::from(format!("{}_coffee.jpg", Date::sample()));
+        assert_eq!(saved[0].receipt(), &Some(name.clone()));
+        assert_eq!(saved[1].receipt(), &Some(name.clone()));
+        assert_eq!(saved[2].receipt(), &None);
+        assert_eq!(
+            std::fs::read(receipts_dir(tempdir.path()).join(&name)).unwrap(),
+            vec![0xff, 0xd8]
+        );
+
+        // Another receipt with the same name is not replaced
+        std::fs::write(&original, [0x00]).unwrap();
+        let other =
+            save_receipt_with_base_path(tempdir.path(), &original, &Date::sample()).unwrap();
+        assert_eq!(
+            other,
+            PathBuf::from(format!("{}_coffee_1.jpg", Date::sample()))
+        );
+    }
+
+    #[test]
+    fn save_missing_receipt_is_err() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let item =
+            Item::sample_expense_coffee().with_receipt(Some(tempdir.path().join("missing.pdf")));
+        let result = save_receipts_of_items_with_base_path(tempdir.path(), &[item]);
+        assert!(matches!(result, Err(Error::FailedToSaveReceipt { .. })));
+    }
+
+    fn prepared_data_with_receipts(receipts: &[&str]) -> PreparedData {
+        let items = receipts
+            .iter()
+            .map(|receipt| {
+                ItemConvertedIntoTargetCurrency::builder()
+                    .in_source_currency(
+                        Item::sample_expense_coffee().with_receipt(Some(PathBuf::from(receipt))),
+                    )
+                    .total_cost(Cost::sample())
+                    .build()
+            })
+            .chain(std::iter::once(ItemConvertedIntoTargetCurrency::sample()))
+            .collect::<Vec<_>>();
+        PreparedData::builder()
+            .information(InvoiceInfoFull::sample())
+            .vendor(CompanyInformation::sample_vendor())
+            .client(CompanyInformation::sample_client())
+            .line_items(
+                LineItemsFlat::builder()
+                    .is_expenses(true)
+                    .items(items)
+                    .build(),
+            )
+            .payment_info(PaymentInformation::sample())
+            .output_path(OutputPath::Name("invoice.pdf".into()))
+            .build()
+    }
+
+    #[test]
+    fn read_receipts_of_items() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let dir = receipts_dir(tempdir.path());
+        create_folder_if_needed(&dir).unwrap();
+        std::fs::write(dir.join("a.jpg"), [1]).unwrap();
+        std::fs::write(dir.join("b.pdf"), [2]).unwrap();
+        let data = prepared_data_with_receipts(&["b.pdf", "a.jpg", "b.pdf"]);
+
+        let receipts = read_receipts_with_base_path(tempdir.path(), &data).unwrap();
+        assert_eq!(
+            receipts,
+            vec![
+                Receipt::builder().name("b.pdf").content(vec![2]).build(),
+                Receipt::builder().name("a.jpg").content(vec![1]).build(),
+            ]
+        );
+    }
+
+    #[test]
+    fn read_missing_receipt_is_err() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let data = prepared_data_with_receipts(&["missing.jpg"]);
+        let result = read_receipts_with_base_path(tempdir.path(), &data);
+        assert!(matches!(result, Err(Error::FailedToReadReceipt { .. })));
+    }
+}
@@ -0,0 +1,231 @@
+use crate::prelude::*;
+
+const DATA_FILE_NAME_RETAINERS: &str = "retainers";
+
+/// The path to the retainers of the clients in the data directory at
+/// `base_path`, a RON file with one [`Retainer`] per client, keyed by the
+/// company name of the client.
+pub fn retainers_path(base_path: impl AsRef<Path>) -> PathBuf {
+    path_to_ron_file_with_base(base_path, DATA_FILE_NAME_RETAINERS)
+}
+
+/// Reads the retainers of the clients in the data directory at `base_path`,
+/// keyed by the company name of the client, empty if no retainer has been
+/// topped up yet.
+pub fn read_retainers_with_base_path(
+    base_path: impl AsRef<Path>,
+) -> Result<IndexMap<String, Retainer>> {
+    let path = retainers_path(base_path);
+    if !path.exists() {
+        return Ok(IndexMap::new());
+    }
+    deserialize_contents_of_ron(path)
+}
+
+/// Reads the retainer of the client with the company name `client` in the
+/// data directory at `base_path`, none if the client pays no retainer.
+pub fn read_retainer_with_base_path(
+    base_path: impl AsRef<Path>,
+    client: &str,
+) -> Result<Option<Retainer>> {
+    read_retainers_with_base_path(base_path).map(|mut retainers| retainers.shift_remove(client))
+}
+
+/// Updates the retainer of the client with the company name `client` in the
+/// data directory at `base_path` using `update`, starting from an empty
+/// retainer if the client has none, and returns the updated retainer.
+fn update_retainer_with_base_path(
+    base_path: impl AsRef<Path>,
+    client: &str,
+    update: impl FnOnce(Retainer) -> Result<Retainer>,
+) -> Result<Retainer> {
+    let base_path = base_path.as_ref();
+    let mut retainers = read_retainers_with_base_path(base_path)?;
+    let retainer = update(retainers.get(client).cloned().unwrap_or_default())?;
+    retainers.insert(client.to_owned(), retainer.clone());
+    save_to_disk(&retainers, retainers_path(base_path))?;
+    Ok(retainer)
+}
+
+/// Adds `amount` in `currency` prepaid by the client with the company name
+/// `client` on `date` to the balance of its retainer in the data directory
+/// at `base_path`, and returns the updated retainer.
+///
+/// # Errors
+/// Returns an error if `amount` is not positive, if the retainer is in
+/// another currency, or if the retainers cannot be read or saved.
+pub fn top_up_retainer_with_base_path(
+    base_path: impl AsRef<Path>,
+    client: &str,
+    date: Date,
+    amount: Cost,
+    currency: Currency,
+) -> Result<Retainer> {
+    let retainer = update_retainer_with_base_path(base_path, client, |retainer| {
+        retainer.with_top_up(date, amount, currency)
+    })?;
+    info!(
+        "💰 Topped up retainer of {} with {}, balance: {}",
+        client,
+        amount,
+        retainer.balance()
+    );
+    Ok(retainer)
+}
+
+/// Records `amount` drawn from the retainer of the client with the company
+/// name `client` in the data directory at `base_path` to pay the invoice
+/// numbered `invoice_number`, dated `date`, replacing any drawdown already
+/// recorded for that invoice.
+pub fn record_retainer_drawdown_with_base_path(
+    base_path: impl AsRef<Path>,
+    client: &str,
+    invoice_number: FormattedInvoiceNumber,
+    date: Date,
+    amount: Cost,
+) -> Result<()> {
+    let retainer = update_retainer_with_base_path(base_path, client, |retainer| {
+        Ok(retainer.with_drawdown(invoice_number, date, amount))
+    })?;
+    info!(
+        "💰 Drew {} from retainer of {}, balance: {}",
+        amount,
+        client,
+        retainer.balance()
+    );
+    Ok(())
+}
+
+/// Returns `percentage` of the amount drawn from the retainer of the client
+/// with the company name `client` in the data directory at `base_path` to
+/// pay the invoice numbered `invoice_number` to the balance, by the credit
+/// note numbered `credit_note_number`, dated `date`, see
+/// [`Retainer::with_return`]. Nothing is returned if the client pays no
+/// retainer.
+///
+/// # Errors
+/// Returns an error if the retainers cannot be read or saved.
+pub fn return_retainer_drawdown_with_base_path(
+    base_path: impl AsRef<Path>,
+    client: &str,
+    credit_note_number: FormattedInvoiceNumber,
+    invoice_number: FormattedInvoiceNumber,
+    date: Date,
+    percentage: &Percentage,
+) -> Result<()> {
+    let base_path = base_path.as_ref();
+    if read_retainer_with_base_path(base_path, client)?.is_none() {
+        return Ok(());
+    }
+    let retainer = update_retainer_with_base_path(base_path, client, |retainer| {
+        Ok(retainer.with_return(credit_note_number, invoice_number, date, percentage))
+    })?;
+    info!(
+        "💰 Returned drawdown to retainer of {}, balance: {}",
+        client,
+        retainer.balance()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn read_missing_retainers_is_empty() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let retainers = read_retainers_with_base_path(tempdir.path()).unwrap();
+        assert!(retainers.is_empty());
+        let retainer = read_retainer_with_base_path(tempdir.path(), "Acme").unwrap();
+        assert_eq!(retainer, None);
+    }
+
+    #[test]
+    fn top_up_and_draw_per_client() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let date = Date::sample();
+        top_up_retainer_with_base_path(
+            tempdir.path(),
+            "Acme",
+            date,
+            Cost::from(dec!(1000)),
+            Currency::EUR,
+        )
+        .unwrap();
+        top_up_retainer_with_base_path(
+            tempdir.path(),
+            "Other",
+            date,
+            Cost::from(dec!(50)),
+            Currency::SEK,
+        )
+        .unwrap();
+        let number = FormattedInvoiceNumber::sample();
+        record_retainer_drawdown_with_base_path(
+            tempdir.path(),
+            "Acme",
+            number.clone(),
+            date,
+            Cost::from(dec!(300)),
+        )
+        .unwrap();
+        record_retainer_drawdown_with_base_path(
+            tempdir.path(),
+            "Acme",
+            number,
+            date,
+            Cost::from(dec!(400)),
+        )
+        .unwrap();
+        let acme = read_retainer_with_base_path(tempdir.path(), "Acme")
+            .unwrap()
+            .unwrap();
+        assert_eq!(*acme.balance(), dec!(600));
+        let other = read_retainer_with_base_path(tempdir.path(), "Other")
+            .unwrap()
+            .unwrap();
+        assert_eq!(*other.balance(), dec!(50));
+        assert_eq!(*other.currency(), Some(Currency::SEK));
+    }
+
+    #[test]
+    fn top_up_in_other_currency_is_err() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let date = Date::sample();
+        let top_up = |currency| {
+            top_up_retainer_with_base_path(
+                tempdir.path(),
+                "Acme",
+                date,
+                Cost::from(dec!(100)),
+                currency,
+            )
+        };
+        top_up(Currency::EUR).unwrap();
+        let result = top_up(Currency::USD);
+        assert!(matches!(
+            result,
+            Err(Error::RetainerCurrencyMismatch { .. })
+        ));
+        let acme = read_retainer_with_base_path(tempdir.path(), "Acme")
+            .unwrap()
+            .unwrap();
+        assert_eq!(*acme.balance(), dec!(100));
+    }
+
+    #[test]
+    fn top_up_with_negative_amount_is_err() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let result = top_up_retainer_with_base_path(
+            tempdir.path(),
+            "Acme",
+            Date::sample(),
+            Cost::from(dec!(-10)),
+            Currency::EUR,
+        );
+        assert!(matches!(result, Err(Error::InvalidRetainerTopUp { .. })));
+        assert!(!retainers_path(tempdir.path()).exists());
+    }
+}
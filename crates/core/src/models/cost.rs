@@ -1,9 +1,22 @@
 use crate::prelude::*;
+use derive_more::FromStr;
 
 /// The total cost of an item, e.g. the total cost of a consulting service.
 /// Being the quantity multiplied by the unit price.
 #[derive(
-    Clone, Copy, Display, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize, From, Deref,
+    Clone,
+    Copy,
+    Display,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize,
+    From,
+    FromStr,
+    Deref,
 )]
 #[from(forward)]
 #[deref(forward)]
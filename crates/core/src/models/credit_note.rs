@@ -0,0 +1,83 @@
+use crate::prelude::*;
+
+/// A credit note reversing a previously issued invoice, in full or in part,
+/// referencing the number and date of the original invoice.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Builder, Getters)]
+pub struct CreditNote {
+    /// The number of the credited invoice as printed on it, e.g. `"237"`.
+    #[getset(get = "pub")]
+    invoice_number: FormattedInvoiceNumber,
+
+    /// The date of the credited invoice, e.g. `2025-05-31`.
+    #[getset(get = "pub")]
+    invoice_date: Date,
+
+    /// The grand total of the credited invoice.
+    #[getset(get = "pub")]
+    invoice_amount: Cost,
+
+    /// The share of the invoice which is credited, `100%` for a full
+    /// reversal.
+    #[getset(get = "pub")]
+    percentage: Percentage,
+}
+
+impl CreditNote {
+    /// Whether only a part of the invoice is credited.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert!(!CreditNote::sample().is_partial());
+    /// assert!(CreditNote::sample_other().is_partial());
+    /// ```
+    pub fn is_partial(&self) -> bool {
+        self.percentage != Percentage::HUNDRED
+    }
+}
+
+impl HasSample for CreditNote {
+    fn sample() -> Self {
+        Self::builder()
+            .invoice_number(FormattedInvoiceNumber::sample())
+            .invoice_date(Date::sample())
+            .invoice_amount(Cost::sample())
+            .percentage(Percentage::HUNDRED)
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .invoice_number(FormattedInvoiceNumber::sample_other())
+            .invoice_date(Date::sample_other())
+            .invoice_amount(Cost::sample_other())
+            .percentage(Percentage::try_from(dec!(50)).expect("Valid percentage"))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = CreditNote;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let sut = Sut::sample_other();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron::from_str::<Sut>(&ron).unwrap(), sut);
+    }
+}
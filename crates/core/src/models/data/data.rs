@@ -7,11 +7,11 @@ use crate::prelude::*;
 )]
 pub struct Data<Period: IsPeriod> {
     /// Information about this specific invoice.
-    #[getset(get = "pub")]
+    #[getset(get = "pub", set_with = "pub")]
     information: ProtoInvoiceInfo<Period>,
 
     /// The company that issued the invoice, the vendor/seller/supplier/issuer.
-    #[getset(get = "pub")]
+    #[getset(get = "pub", set_with = "pub")]
     vendor: CompanyInformation,
 
     /// The company that pays the invoice, the customer/buyer.
@@ -25,9 +25,28 @@ pub struct Data<Period: IsPeriod> {
     payment_info: PaymentInformation,
 
     /// Price of service, if applicable.
-    #[getset(get = "pub")]
+    #[getset(get = "pub", set_with = "pub")]
     service_fees: ServiceFees,
 
+    /// Services invoiced on lines of their own after the service fees, e.g.
+    /// on-call hours at another rate.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[getset(get = "pub", set_with = "pub")]
+    service_lines: Vec<ServiceLine>,
+
+    /// A fixed-price contract billed in milestones instead of per period, if
+    /// any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    milestone_contract: Option<MilestoneContract>,
+
+    /// The balance prepaid by the client, drawn from to pay its invoices, if
+    /// the client pays a retainer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    retainer: Option<Retainer>,
+
     /// Any expenses that you might have incurred.
     #[getset(get = "pub", set = "pub")]
     expensed_periods: ExpensedPeriods<Period>,
@@ -50,23 +69,509 @@ impl<Period: IsPeriod> Data<Period> {
     /// ```
     pub fn validate(self) -> Result<Self> {
         self.information.validate()?;
+        self.service_fees.validate_rate_history()?;
+        if let Some(milestone_contract) = &self.milestone_contract {
+            milestone_contract.validate()?;
+        }
+        for line in &self.service_lines {
+            self.service_fees
+                .cadence()
+                .validate(line.rate().granularity())?;
+        }
         Ok(self)
     }
 
+    /// The public holidays to exclude from working days, the client's calendar
+    /// takes precedence over the vendor's.
+    fn holiday_calendar(&self) -> Option<HolidayCalendar> {
+        self.client()
+            .holiday_calendar()
+            .or(*self.vendor().holiday_calendar())
+    }
+
+    /// The holidays excluded from the quantity of the services invoiced for
+    /// the target period, empty unless invoicing per day or hour.
+    fn excluded_holidays(
+        &self,
+        target_period: &Period,
+        date_range: &Option<DateRange>,
+    ) -> Vec<Holiday> {
+        let granularity = self.service_fees().rate().granularity();
+        let work_schedule = self.service_fees().work_schedule();
+        match (granularity, self.holiday_calendar(), date_range) {
+            (Granularity::Day | Granularity::Hour, Some(calendar), Some(date_range)) => {
+                holidays_on_working_days_in_date_range(date_range, calendar, work_schedule)
+            }
+            (Granularity::Day | Granularity::Hour, Some(calendar), None) => {
+                holidays_on_working_days_in_period(target_period, calendar, work_schedule)
+            }
+            _ => Vec::new(),
+        }
+    }
+
     fn billable_quantity(
         &self,
         target_period: &Period,
-        cadence: Cadence,
+        granularity: Granularity,
         time_off: &Option<TimeOff>,
     ) -> Result<Quantity> {
-        let granularity = self.service_fees().rate().granularity();
+        let cadence = *self.service_fees().cadence();
         let periods_off = self.information().record_of_periods_off();
-        let quantity_in_period =
-            quantity_in_period(target_period, granularity, cadence, periods_off)?;
-        let billable_quantity = quantity_in_period - time_off.map(|d| *d).unwrap_or(Quantity::ZERO);
+        let quantity_in_period = quantity_in_period(
+            target_period,
+            granularity,
+            cadence,
+            periods_off,
+            self.holiday_calendar(),
+            self.service_fees().work_schedule(),
+            self.service_fees().contract_window(),
+        )?;
+        let quantity_off_in_period = quantity_off_in_period(
+            target_period,
+            granularity,
+            self.information().record_of_dates_off(),
+            self.holiday_calendar(),
+            self.service_fees().work_schedule(),
+            self.service_fees().contract_window(),
+        )?;
+        let billable_quantity = quantity_in_period
+            - quantity_off_in_period
+            - time_off.map(|d| *d).unwrap_or(Quantity::ZERO);
+        Ok(billable_quantity)
+    }
+
+    fn billable_quantity_in_date_range(
+        &self,
+        date_range: &DateRange,
+        granularity: Granularity,
+        time_off: &Option<TimeOff>,
+    ) -> Result<Quantity> {
+        self.validate_no_period_off_in(date_range)?;
+        let quantity_in_date_range = quantity_in_date_range(
+            date_range,
+            granularity,
+            self.holiday_calendar(),
+            self.service_fees().work_schedule(),
+            self.service_fees().contract_window(),
+        )?;
+        let quantity_off_in_date_range = quantity_off_in_date_range(
+            date_range,
+            granularity,
+            self.information().record_of_dates_off(),
+            self.holiday_calendar(),
+            self.service_fees().work_schedule(),
+            self.service_fees().contract_window(),
+        )?;
+        let billable_quantity = quantity_in_date_range
+            - quantity_off_in_date_range
+            - time_off.map(|d| *d).unwrap_or(Quantity::ZERO);
         Ok(billable_quantity)
     }
 
+    /// Validates that `date_range` does not overlap any period off.
+    ///
+    /// # Errors
+    /// Returns an error if `date_range` overlaps a period off.
+    fn validate_no_period_off_in(&self, date_range: &DateRange) -> Result<()> {
+        let periods_off = self.information().record_of_periods_off();
+        if let Some(period_off) = periods_off.period_overlapping(date_range) {
+            return Err(Error::DateRangeOverlapsPeriodOff {
+                date_range: date_range.to_string(),
+                period_off: format!("{:?}", period_off),
+            });
+        }
+        Ok(())
+    }
+
+    /// The billable quantity of the services invoiced for the target period,
+    /// or the date range if any, less `time_off`.
+    ///
+    /// # Errors
+    /// Returns an error if the granularity of `time_off` differs from that of
+    /// the rate of the service fees.
+    fn service_quantity(
+        &self,
+        target_period: &Period,
+        date_range: &Option<DateRange>,
+        time_off: &Option<TimeOff>,
+    ) -> Result<Quantity> {
+        if let Some(time_off) = time_off {
+            if time_off.granularity() != self.service_fees().rate().granularity() {
+                return Err(Error::InvalidGranularityForTimeOff {
+                    free_granularity: time_off.granularity(),
+                    service_fees_granularity: self.service_fees().rate().granularity(),
+                });
+            }
+        }
+        let granularity = self.service_fees().rate().granularity();
+        match date_range {
+            Some(date_range) => {
+                self.billable_quantity_in_date_range(date_range, granularity, time_off)
+            }
+            None => self.billable_quantity(target_period, granularity, time_off),
+        }
+    }
+
+    /// The dates invoiced for the target period, or the date range if any.
+    fn invoiced_dates(target_period: &Period, date_range: &Option<DateRange>) -> DateRange {
+        date_range.unwrap_or_else(|| {
+            DateRange::builder()
+                .start(target_period.to_date_start_of_period())
+                .end(target_period.to_date_end_of_period())
+                .build()
+                .expect("A period never ends before it starts")
+        })
+    }
+
+    /// The `quantity` of the service fees invoiced for `dates` split by the
+    /// rates in effect, see [`ServiceFees::rates_in`]. Unless the rate changed
+    /// during `dates` the whole quantity is invoiced at the rate in effect,
+    /// else the quantity of each rate is `quantity_in` its dates, less the
+    /// excess over `quantity`, e.g. time off, deducted from the latest rates
+    /// first. Any shortfall of the quantities of the rates from `quantity`,
+    /// e.g. by rounding, is invoiced at the latest rate.
+    fn quantities_per_rate(
+        &self,
+        dates: &DateRange,
+        quantity: Quantity,
+        quantity_in: impl Fn(&DateRange) -> Result<Quantity>,
+    ) -> Result<Vec<(DateRange, Rate, Quantity)>> {
+        let rates = self.service_fees.rates_in(dates);
+        if let [(dates, rate)] = rates.as_slice() {
+            return Ok(vec![(*dates, *rate, quantity)]);
+        }
+        let mut quantities = rates
+            .into_iter()
+            .map(|(dates, rate)| {
+                let quantity = match quantity_in(&dates) {
+                    Err(Error::TargetPeriodOutsideContractWindow { .. }) => Quantity::ZERO,
+                    quantity => quantity?,
+                };
+                Ok((dates, rate, *quantity))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let summed = quantities
+            .iter()
+            .map(|(_, _, quantity)| *quantity)
+            .sum::<rust_decimal::Decimal>();
+        if summed < *quantity {
+            if let Some((_, _, last)) = quantities.last_mut() {
+                *last += *quantity - summed;
+            }
+        } else {
+            let mut excess = summed - *quantity;
+            for (_, _, quantity) in quantities.iter_mut().rev() {
+                let deducted = excess.min(*quantity);
+                *quantity -= deducted;
+                excess -= deducted;
+            }
+        }
+        if quantities
+            .iter()
+            .any(|(_, _, quantity)| !quantity.is_zero())
+        {
+            quantities.retain(|(_, _, quantity)| !quantity.is_zero());
+        } else {
+            quantities.drain(..quantities.len() - 1);
+        }
+        let quantities = quantities
+            .into_iter()
+            .map(|(dates, rate, quantity)| (dates, rate, Quantity::from(quantity)))
+            .collect::<Vec<_>>();
+        Ok(quantities)
+    }
+
+    /// The items of the service fees invoiced for `dates`, a single item
+    /// unless the rate changed during `dates`, in which case there is an item
+    /// per rate with its dates after the name, see
+    /// [`Data::quantities_per_rate`].
+    fn service_items(
+        &self,
+        dates: &DateRange,
+        quantity: Quantity,
+        quantity_in: impl Fn(&DateRange) -> Result<Quantity>,
+        invoice_date: Date,
+        discount: Option<Discount>,
+    ) -> Result<Vec<Item>> {
+        let quantities = self.quantities_per_rate(dates, quantity, quantity_in)?;
+        let is_split = quantities.len() > 1;
+        let last = quantities.len().saturating_sub(1);
+        let items = quantities
+            .into_iter()
+            .enumerate()
+            .map(|(index, (dates, rate, quantity))| {
+                let name = if is_split {
+                    format!("{} ({})", self.service_fees.name(), dates)
+                } else {
+                    self.service_fees.name().clone()
+                };
+                // A fixed amount is only discounted once, from the latest rate
+                let discount =
+                    discount.filter(|discount| discount.is_percentage() || index == last);
+                Item::builder()
+                    .name(name)
+                    .transaction_date(invoice_date)
+                    .quantity(quantity)
+                    .unit_price(rate.unit_price())
+                    .currency(*self.payment_info.currency())
+                    .maybe_tax_rate(*self.service_fees.tax_rate())
+                    .maybe_discount(discount)
+                    .build()
+            })
+            .collect();
+        Ok(items)
+    }
+
+    /// The item billing the milestone named `name` of the milestone contract,
+    /// named after the contract and the milestone, e.g.
+    /// `"Website redesign: Kickoff"`.
+    ///
+    /// # Errors
+    /// Returns an error if there is no milestone contract or it has no
+    /// milestone named `name`.
+    fn milestone_item(&self, name: &str, invoice_date: Date) -> Result<Item> {
+        let contract =
+            self.milestone_contract
+                .as_ref()
+                .ok_or_else(|| Error::NoMilestoneContract {
+                    name: name.to_owned(),
+                })?;
+        let milestone = contract.milestone_named(name)?;
+        Ok(Item::builder()
+            .name(format!("{}: {}", contract.name(), milestone.name()))
+            .transaction_date(invoice_date)
+            .quantity(Quantity::ONE)
+            .unit_price(UnitPrice::from(*contract.amount_of(milestone)))
+            .currency(*self.payment_info.currency())
+            .maybe_tax_rate(contract.tax_rate().or(*self.service_fees.tax_rate()))
+            .build())
+    }
+
+    /// The items of the service lines for the target period, or the date
+    /// range if any, with `worked` giving the quantity of the lines of
+    /// [`ServiceLineQuantity::Worked`] in the granularity of their rate.
+    fn service_line_items(
+        &self,
+        target_period: &Period,
+        date_range: &Option<DateRange>,
+        worked: &dyn Fn(Granularity) -> Result<Quantity>,
+        invoice_date: Date,
+    ) -> Result<Vec<Item>> {
+        self.service_lines
+            .iter()
+            .map(|line| {
+                let quantity = match line.quantity() {
+                    ServiceLineQuantity::Fixed(quantity) => {
+                        self.fixed_quantity(*quantity, target_period, date_range)?
+                    }
+                    ServiceLineQuantity::Worked => worked(line.rate().granularity())?,
+                };
+                Ok(Item::builder()
+                    .name(line.description())
+                    .transaction_date(invoice_date)
+                    .quantity(quantity)
+                    .unit_price(line.rate().unit_price())
+                    .currency(*self.payment_info.currency())
+                    .maybe_tax_rate(line.tax_rate().or(*self.service_fees.tax_rate()))
+                    .maybe_discount(*line.discount())
+                    .build())
+            })
+            .collect()
+    }
+
+    /// The quantity worked in the granularity of the rate of a service line
+    /// for the target period, or the date range if any, less `time_off` if
+    /// of the same granularity.
+    fn worked_quantity(
+        &self,
+        target_period: &Period,
+        date_range: &Option<DateRange>,
+        granularity: Granularity,
+        time_off: &Option<TimeOff>,
+    ) -> Result<Quantity> {
+        let time_off = time_off.filter(|time_off| time_off.granularity() == granularity);
+        match date_range {
+            Some(date_range) => {
+                self.billable_quantity_in_date_range(date_range, granularity, &time_off)
+            }
+            None => self.billable_quantity(target_period, granularity, &time_off),
+        }
+    }
+
+    /// The fixed `quantity` of a service line for a whole period, pro-rated
+    /// by the share of the working days of the target period that are
+    /// invoiced, i.e. within the contract window, or the working days of the
+    /// date range if any.
+    ///
+    /// # Errors
+    /// Returns an error if the target period is a period off, if the date
+    /// range overlaps a period off, or if either is entirely outside of the
+    /// contract window.
+    fn fixed_quantity(
+        &self,
+        quantity: Quantity,
+        target_period: &Period,
+        date_range: &Option<DateRange>,
+    ) -> Result<Quantity> {
+        let holiday_calendar = self.holiday_calendar();
+        let work_schedule = self.service_fees().work_schedule();
+        let invoiced = match date_range {
+            Some(date_range) => {
+                self.validate_no_period_off_in(date_range)?;
+                quantity_in_date_range(
+                    date_range,
+                    Granularity::Day,
+                    holiday_calendar,
+                    work_schedule,
+                    self.service_fees().contract_window(),
+                )?
+            }
+            None => quantity_in_period(
+                target_period,
+                Granularity::Day,
+                *self.service_fees().cadence(),
+                self.information().record_of_periods_off(),
+                holiday_calendar,
+                work_schedule,
+                self.service_fees().contract_window(),
+            )?,
+        };
+        let whole = quantity_in_date_range(
+            &Self::invoiced_dates(target_period, &None),
+            Granularity::Day,
+            holiday_calendar,
+            work_schedule,
+            &ContractWindow::default(),
+        )?;
+        if whole.is_zero() || invoiced == whole {
+            return Ok(quantity);
+        }
+        Ok(Quantity::from((*quantity * *invoiced / *whole).round_dp(2)))
+    }
+
+    /// The expenses of the target period, or within the date range if any.
+    ///
+    /// # Errors
+    /// Returns an error if no expenses have been recorded for the period or
+    /// within the date range.
+    fn expenses(
+        &self,
+        target_period: &Period,
+        date_range: &Option<DateRange>,
+    ) -> Result<Vec<Item>> {
+        match date_range {
+            Some(date_range) => self.expensed_periods.get_in_date_range(date_range),
+            None => self.expensed_periods.get(target_period),
+        }
+    }
+
+    /// The period invoiced for the input `period`, which is the month of a
+    /// fortnight if the offset is a month.
+    ///
+    /// # Errors
+    /// Returns an error if `period` is of another kind than the offset.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let data = Data::<YearAndMonth>::sample();
+    /// let period = PeriodAnno::from(YearMonthAndFortnight::year_and_month_with_half(
+    ///     YearAndMonth::may(2025),
+    ///     MonthHalf::First,
+    /// ));
+    /// assert_eq!(data.target_period(&period).unwrap(), YearAndMonth::may(2025));
+    /// ```
+    pub fn target_period(&self, period: &PeriodAnno) -> Result<Period> {
+        match (
+            Into::<PeriodAnno>::into(self.information().offset().period().clone()),
+            period.clone(),
+        ) {
+            (PeriodAnno::YearAndMonth(_), PeriodAnno::YearMonthAndFortnight(period)) => {
+                Period::try_from_period_anno(PeriodAnno::from(YearAndMonth::from(period)))
+            }
+            (_, period) => Period::try_from_period_anno(period),
+        }
+    }
+
+    /// The unformatted number of the invoice for `target_period`, see
+    /// [`calculate_invoice_number`].
+    pub fn invoice_number(
+        &self,
+        target_period: &Period,
+        is_expenses: bool,
+    ) -> Result<InvoiceNumber> {
+        calculate_invoice_number(
+            self.information().offset(),
+            target_period,
+            is_expenses,
+            self.information().record_of_periods_off(),
+            self.information().number_reset(),
+        )
+    }
+
+    /// The unformatted number of the invoice of `input` for `target_period`,
+    /// the number of the input if it overrides it, else see
+    /// [`Data::invoice_number`]. Invoices billing milestones are numbered in
+    /// a series of their own, after the ledger, see
+    /// [`Ledger::milestone_sequence_number`], starting at `1`.
+    pub fn invoice_number_of_input(
+        &self,
+        target_period: &Period,
+        input: &ValidInput,
+    ) -> Result<InvoiceNumber> {
+        match (input.invoice_number(), input.items()) {
+            (Some(number), _) => Ok(number.clone()),
+            (None, InvoicedItems::Milestone { .. }) => Ok(InvoiceNumber::from(1)),
+            (None, items) => self.invoice_number(target_period, items.is_expenses()),
+        }
+    }
+
+    /// Replaces the client, service fees, service lines and milestone contract
+    /// with those of `profile`, as well as the purchase order, and the payment
+    /// terms, number offset and number format if the profile has any. The
+    /// retainer of the default client is removed, see [`Data::with_retainer`].
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let profile = ClientProfile::<YearAndMonth>::sample_other();
+    /// let data = Data::<YearAndMonth>::sample().with_client_profile(profile.clone());
+    /// assert_eq!(data.client(), profile.client());
+    /// assert_eq!(data.service_fees(), profile.service_fees());
+    /// assert_eq!(data.information().purchase_order(), &None);
+    /// assert_eq!(
+    ///     data.information().number_format(),
+    ///     profile.number_format().as_ref().unwrap()
+    /// );
+    /// ```
+    pub fn with_client_profile(self, profile: ClientProfile<Period>) -> Self {
+        let mut information = self
+            .information
+            .with_purchase_order(profile.purchase_order().clone());
+        if let ClientNumbering::Own(offset) = profile.numbering() {
+            information = information.with_offset(offset.clone());
+        }
+        if let Some(number_format) = profile.number_format() {
+            information = information.with_number_format(number_format.clone());
+        }
+        let payment_info = match profile.payment_terms() {
+            Some(terms) => self.payment_info.with_terms(terms.clone()),
+            None => self.payment_info,
+        };
+        Self {
+            information,
+            client: profile.client().clone(),
+            payment_info,
+            service_fees: profile.service_fees().clone(),
+            service_lines: profile.service_lines().clone(),
+            milestone_contract: profile.milestone_contract().clone(),
+            retainer: None,
+            ..self
+        }
+    }
+
     /// Converts the `Data` into a `DataWithItemsPricedInSourceCurrency`
     /// using the provided `ValidInput`.
     /// This method prepares the invoice data for rendering by creating an
@@ -85,28 +590,51 @@ impl<Period: IsPeriod> Data<Period> {
     /// assert!(result.is_ok(), "Expected conversion to succeed, got: {:?}", result);
     /// ```
     pub fn to_partial(self, input: ValidInput) -> Result<DataWithItemsPricedInSourceCurrency> {
-        let target_period =
-            match Into::<PeriodAnno>::into(self.information().offset().period().clone()) {
-                PeriodAnno::YearMonthAndFortnight(_) => {
-                    Period::try_from_period_anno(PeriodAnno::from(*input.period()))?
-                }
-                PeriodAnno::YearAndMonth(_) => Period::try_from_period_anno(PeriodAnno::from(
-                    YearAndMonth::from(*input.period()),
-                ))?,
-            };
+        let target_period = self.target_period(input.period())?;
         let items = input.items();
-        let invoice_date = target_period.to_date_end_of_period();
-        let due_date = invoice_date.advance(self.payment_info().terms());
+        let date_range = *input.date_range();
+        let invoice_date = input.invoice_date_for(&target_period);
+        let due_date = self
+            .payment_info()
+            .due_date(&invoice_date, self.holiday_calendar());
+        let discount_deadline = self
+            .payment_info()
+            .discount_deadline(&invoice_date, self.holiday_calendar());
+        let l18n = L18n::new(*input.language())?;
+        let terms = self
+            .payment_info()
+            .terms()
+            .localized(l18n.content().invoice_info());
         let is_expenses = items.is_expenses();
 
-        let number = calculate_invoice_number(
-            self.information().offset(),
-            &target_period,
-            is_expenses,
-            self.information().record_of_periods_off(),
-        )?;
+        let number = self.invoice_number_of_input(&target_period, &input)?;
+        let number = match items {
+            InvoicedItems::Milestone { .. } => self
+                .information()
+                .format_milestone_invoice_number(&number, &target_period),
+            InvoicedItems::Expenses => self
+                .information()
+                .format_expenses_invoice_number(&number, &target_period),
+            InvoicedItems::Service { .. }
+            | InvoicedItems::Timesheet { .. }
+            | InvoicedItems::ServiceAndExpenses { .. } => self
+                .information()
+                .format_invoice_number(&number, &target_period),
+        };
         let is_expenses_str_or_empty = if is_expenses { "_expenses" } else { "" };
         let vendor_name = self.vendor.company_name().replace(' ', "_");
+        let excluded_holidays = match items {
+            InvoicedItems::Service { .. } | InvoicedItems::ServiceAndExpenses { .. } => {
+                self.excluded_holidays(&target_period, &date_range)
+            }
+            InvoicedItems::Timesheet { .. }
+            | InvoicedItems::Expenses
+            | InvoicedItems::Milestone { .. } => Vec::new(),
+        };
+        // The standing discount is only given on invoices of services
+        let discount = input
+            .discount()
+            .or_else(|| self.information().discount().filter(|_| !is_expenses));
 
         let output_path = input
             .maybe_output_path()
@@ -120,9 +648,20 @@ impl<Period: IsPeriod> Data<Period> {
                 ))
             });
 
+        let retainer_balance = self
+            .retainer()
+            .as_ref()
+            .map(|retainer| {
+                retainer.validate_currency(*self.payment_info().currency())?;
+                Ok(retainer.balance_before(&number))
+            })
+            .transpose()?;
+
         let full_info = InvoiceInfoFull::builder()
             .due_date(due_date)
             .invoice_date(invoice_date)
+            .terms(terms)
+            .maybe_discount_deadline(discount_deadline)
             .emphasize_color_hex(
                 self.information()
                     .emphasize_color_hex()
@@ -132,45 +671,140 @@ impl<Period: IsPeriod> Data<Period> {
             .maybe_footer_text(self.information().footer_text().clone())
             .number(number)
             .maybe_purchase_order(self.information().purchase_order().clone())
+            .excluded_holidays(excluded_holidays)
+            .maybe_discount(discount)
+            .maybe_milestone(match items {
+                InvoicedItems::Milestone { name } => Some(name.clone()),
+                _ => None,
+            })
+            .maybe_retainer_balance(retainer_balance)
             .build();
 
+        let invoiced_dates = Self::invoiced_dates(&target_period, &date_range);
+        let granularity = self.service_fees().rate().granularity();
+        let service_discount = input.service_discount().or(*self.service_fees.discount());
+        let service = |quantity: Quantity,
+                       quantity_in: &dyn Fn(&DateRange) -> Result<Quantity>|
+         -> Result<Vec<Item>> {
+            self.service_items(
+                &invoiced_dates,
+                quantity,
+                quantity_in,
+                invoice_date,
+                service_discount,
+            )
+        };
+        let worked = |dates: &DateRange| -> Result<Quantity> {
+            self.billable_quantity_in_date_range(dates, granularity, &None)
+        };
+
+        let services = |service: Vec<Item>,
+                        worked: &dyn Fn(Granularity) -> Result<Quantity>|
+         -> Result<Vec<Item>> {
+            let lines =
+                self.service_line_items(&target_period, &date_range, worked, invoice_date)?;
+            Ok(service.into_iter().chain(lines).collect())
+        };
+
+        let line_items = match items {
+            InvoicedItems::Service { time_off } => {
+                LineItemsPricedInSourceCurrency::Service(services(
+                    service(
+                        self.service_quantity(&target_period, &date_range, time_off)?,
+                        &worked,
+                    )?,
+                    &|granularity| {
+                        self.worked_quantity(&target_period, &date_range, granularity, time_off)
+                    },
+                )?)
+            }
+            InvoicedItems::Timesheet { timesheet } => {
+                let dates_off = self.information().record_of_dates_off();
+                let work_schedule = self.service_fees().work_schedule();
+                let quantity = match &date_range {
+                    Some(date_range) => quantity_in_timesheet_in_date_range(
+                        timesheet,
+                        date_range,
+                        granularity,
+                        dates_off,
+                        work_schedule,
+                    )?,
+                    None => quantity_in_timesheet(
+                        timesheet,
+                        &target_period,
+                        granularity,
+                        self.information().record_of_periods_off(),
+                        dates_off,
+                        work_schedule,
+                    )?,
+                };
+                // The whole timesheet has been validated against the invoiced
+                // dates above, so only the entries of each rate are summed.
+                let logged = |dates: &DateRange| {
+                    quantity_in_timesheet_in_date_range(
+                        &timesheet.within(dates),
+                        dates,
+                        granularity,
+                        dates_off,
+                        work_schedule,
+                    )
+                };
+                // The lines worked are the hours of the timesheet too, in the
+                // granularity of their rate.
+                let logged_in = |granularity: Granularity| match &date_range {
+                    Some(date_range) => quantity_in_timesheet_in_date_range(
+                        timesheet,
+                        date_range,
+                        granularity,
+                        dates_off,
+                        work_schedule,
+                    ),
+                    None => quantity_in_timesheet(
+                        timesheet,
+                        &target_period,
+                        granularity,
+                        self.information().record_of_periods_off(),
+                        dates_off,
+                        work_schedule,
+                    ),
+                };
+                LineItemsPricedInSourceCurrency::Service(services(
+                    service(quantity, &logged)?,
+                    &logged_in,
+                )?)
+            }
+            InvoicedItems::Expenses => LineItemsPricedInSourceCurrency::Expenses(
+                self.expenses(&target_period, &date_range)?,
+            ),
+            InvoicedItems::ServiceAndExpenses { time_off } => {
+                // A period without expenses is invoiced for services only
+                let expenses = match self.expenses(&target_period, &date_range) {
+                    Err(Error::TargetPeriodMustHaveExpenses { .. }) => Vec::new(),
+                    expenses => expenses?,
+                };
+                LineItemsPricedInSourceCurrency::ServiceAndExpenses {
+                    services: services(
+                        service(
+                            self.service_quantity(&target_period, &date_range, time_off)?,
+                            &worked,
+                        )?,
+                        &|granularity| {
+                            self.worked_quantity(&target_period, &date_range, granularity, time_off)
+                        },
+                    )?,
+                    expenses,
+                }
+            }
+            InvoicedItems::Milestone { name } => LineItemsPricedInSourceCurrency::Service(vec![
+                self.milestone_item(name, invoice_date)?,
+            ]),
+        };
+
         let input_unpriced =
             DataFromDiskWithItemsOfKind::<LineItemsPricedInSourceCurrency>::builder()
                 .client(self.client.clone())
                 .information(full_info)
-                .line_items(match items {
-                    InvoicedItems::Service { time_off } => {
-                        if let Some(time_off) = time_off {
-                            if time_off.granularity() != self.service_fees().rate().granularity() {
-                                return Err(Error::InvalidGranularityForTimeOff {
-                                    free_granularity: time_off.granularity(),
-                                    service_fees_granularity: self
-                                        .service_fees()
-                                        .rate()
-                                        .granularity(),
-                                });
-                            }
-                        }
-
-                        let quantity = self.billable_quantity(
-                            &target_period,
-                            *self.service_fees().cadence(),
-                            time_off,
-                        )?;
-                        let service = Item::builder()
-                            .name(self.service_fees.name().clone())
-                            .transaction_date(invoice_date)
-                            .quantity(quantity)
-                            .unit_price(self.service_fees.unit_price())
-                            .currency(*self.payment_info.currency())
-                            .build();
-                        LineItemsPricedInSourceCurrency::Service(service)
-                    }
-                    InvoicedItems::Expenses => {
-                        let expenses = self.expensed_periods.get(&target_period)?;
-                        LineItemsPricedInSourceCurrency::Expenses(expenses.clone())
-                    }
-                })
+                .line_items(line_items)
                 .payment_info(self.payment_info)
                 .vendor(self.vendor)
                 .output_path(output_path)
@@ -246,6 +880,57 @@ mod tests {
         assert!(partial.line_items().is_expenses());
     }
 
+    #[test]
+    fn service_and_expenses_uses_number_of_services() {
+        let sut = Sut::sample();
+        let input = |items: InvoicedItems| {
+            ValidInput::builder()
+                .items(items)
+                .period(YearMonthAndFortnight::year_and_month_with_half(
+                    YearAndMonth::may(2025),
+                    MonthHalf::First,
+                ))
+                .build()
+        };
+        let services = sut
+            .clone()
+            .to_partial(input(InvoicedItems::Service { time_off: None }))
+            .unwrap();
+        let partial = sut
+            .to_partial(input(InvoicedItems::ServiceAndExpenses { time_off: None }))
+            .unwrap();
+        assert_eq!(
+            partial.information().number(),
+            services.information().number()
+        );
+        let LineItemsPricedInSourceCurrency::ServiceAndExpenses {
+            services: items,
+            expenses,
+        } = partial.line_items()
+        else {
+            panic!("Expected service and expenses");
+        };
+        assert_eq!(
+            LineItemsPricedInSourceCurrency::Service(items.clone()),
+            *services.line_items()
+        );
+        assert_eq!(expenses, &vec![Item::sample_expense_breakfast()]);
+    }
+
+    #[test]
+    fn service_and_expenses_without_expenses_in_period() {
+        let sut = Sut::sample();
+        let input = ValidInput::builder()
+            .items(InvoicedItems::ServiceAndExpenses { time_off: None })
+            .period(YearMonthAndFortnight::year_and_month_with_half(
+                YearAndMonth::june(2025),
+                MonthHalf::First,
+            ))
+            .build();
+        let partial = sut.to_partial(input).unwrap();
+        assert!(partial.line_items().expenses().is_empty());
+    }
+
     #[test]
     fn test_worked_days_when_ooo_is_greater_than_0() {
         let sut = Sut::sample();
@@ -260,14 +945,570 @@ mod tests {
             )
             .unwrap();
         assert_eq!(
+            partial.line_items().clone().try_unwrap_service().unwrap()[0].quantity(),
+            &Quantity::from(dec!(21.0))
+        );
+    }
+
+    #[test]
+    fn test_service_lines_follow_service_fees() {
+        let sut = Sut::sample()
+            .with_service_lines(vec![ServiceLine::sample(), ServiceLine::sample_other()]);
+        let partial = sut
+            .to_partial(
+                ValidInput::builder()
+                    .items(InvoicedItems::Service {
+                        time_off: Some(TimeOff::Days(Quantity::from(dec!(2.0)))),
+                    })
+                    .period(YearMonthAndFortnight::sample())
+                    .build(),
+            )
+            .unwrap();
+        let items = partial.line_items().clone().try_unwrap_service().unwrap();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[1].name(), "On-call");
+        assert_eq!(items[1].quantity(), &Quantity::from(dec!(10)));
+        assert_eq!(items[2].name(), "Project Moriarty (PO-67890)");
+        // Worked days less the time off in days, just like the service fees
+        assert_eq!(items[2].quantity(), items[0].quantity());
+        assert_eq!(items[2].unit_price(), &UnitPrice::from(dec!(500)));
+    }
+
+    fn on_call(sut: Sut, input: ValidInput) -> Result<Quantity> {
+        let partial = sut
+            .with_service_lines(vec![ServiceLine::sample()])
+            .to_partial(input)?;
+        let items = partial.line_items().clone().try_unwrap_service().unwrap();
+        Ok(*items.last().unwrap().quantity())
+    }
+
+    #[test]
+    fn test_fixed_service_line_is_pro_rated_for_date_range() {
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service { time_off: None })
+            .period(YearMonthAndFortnight::year_and_month_with_half(
+                YearAndMonth::june(2025),
+                MonthHalf::First,
+            ))
+            .date_range(
+                DateRange::builder()
+                    .start(Date::from_str("2025-06-01").unwrap())
+                    .end(Date::from_str("2025-06-15").unwrap())
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+        // 10 of the 21 working days of June
+        assert_eq!(
+            on_call(Sut::sample(), input).unwrap(),
+            Quantity::from(dec!(4.76))
+        );
+    }
+
+    #[test]
+    fn test_fixed_service_line_is_pro_rated_to_contract_window() {
+        let sut = Sut::sample().with_service_fees(
+            ServiceFees::sample().with_contract_window(ContractWindow::sample()),
+        );
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service { time_off: None })
+            .period(YearMonthAndFortnight::year_and_month_with_half(
+                YearAndMonth::may(2025),
+                MonthHalf::First,
+            ))
+            .build();
+        // 13 of the 22 working days of May, from 2025-05-14
+        assert_eq!(on_call(sut, input).unwrap(), Quantity::from(dec!(5.91)));
+    }
+
+    #[test]
+    fn test_fixed_service_line_in_date_range_overlapping_period_off_is_err() {
+        let sut = Sut::sample();
+        let mut information = sut.information().clone();
+        information.insert_period_off(YearAndMonth::may(2025));
+        let result = on_call(
+            sut.with_information(information),
+            date_range_input(InvoicedItems::Timesheet {
+                timesheet: Timesheet::parse("2025-06-02,8", None).unwrap(),
+            }),
+        );
+        assert!(matches!(
+            result,
+            Err(Error::DateRangeOverlapsPeriodOff { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rate_in_effect_for_period() {
+        let until = |date: &str, rate: rust_decimal::Decimal| {
+            HistoricRate::builder()
+                .rate(Rate::daily(rate))
+                .until(Date::from_str(date).unwrap())
+                .build()
+        };
+        let services = |rate_history: Vec<HistoricRate>| {
+            let sut = Sut::sample()
+                .with_service_fees(ServiceFees::sample().with_rate_history(rate_history));
+            let partial = sut
+                .to_partial(
+                    ValidInput::builder()
+                        .items(InvoicedItems::Service {
+                            time_off: Some(TimeOff::Days(Quantity::from(dec!(2.0)))),
+                        })
+                        .period(YearMonthAndFortnight::sample())
+                        .build(),
+                )
+                .unwrap();
             partial
                 .line_items()
                 .clone()
                 .try_unwrap_service()
                 .unwrap()
-                .quantity(),
-            &Quantity::from(dec!(22.0))
+                .into_iter()
+                .map(|item| (item.name().clone(), **item.unit_price(), **item.quantity()))
+                .collect::<Vec<_>>()
+        };
+
+        let name = ServiceFees::sample().name().clone();
+        assert_eq!(services(vec![]), vec![(name.clone(), dec!(777), dec!(21))]);
+        assert_eq!(
+            services(vec![until("2025-07-31", dec!(700))]),
+            vec![(name.clone(), dec!(700), dec!(21))]
+        );
+        // Split at the change, time off deducted from the latest rate
+        assert_eq!(
+            services(vec![until("2025-07-15", dec!(700))]),
+            vec![
+                (
+                    format!("{name} (2025-07-01..2025-07-15)"),
+                    dec!(700),
+                    dec!(11)
+                ),
+                (
+                    format!("{name} (2025-07-16..2025-07-31)"),
+                    dec!(777),
+                    dec!(10)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_quantities_per_rate_with_shortfall_at_latest_rate() {
+        let sut = Sut::sample().with_service_fees(ServiceFees::sample().with_rate_history(vec![
+                HistoricRate::builder()
+                    .rate(Rate::daily(dec!(700)))
+                    .until(Date::from_str("2025-07-15").unwrap())
+                    .build(),
+            ]));
+        let dates = DateRange::builder()
+            .start(Date::from_str("2025-07-01").unwrap())
+            .end(Date::from_str("2025-07-31").unwrap())
+            .build()
+            .unwrap();
+        let quantities = sut
+            .quantities_per_rate(&dates, Quantity::from(dec!(10)), |_| {
+                Ok(Quantity::from(dec!(4)))
+            })
+            .unwrap()
+            .into_iter()
+            .map(|(_, rate, quantity)| (*rate.unit_price(), *quantity))
+            .collect::<Vec<_>>();
+        assert_eq!(quantities, vec![(dec!(700), dec!(4)), (dec!(777), dec!(6))]);
+    }
+
+    #[test]
+    fn test_milestone() {
+        let input = |name: &str| {
+            ValidInput::builder()
+                .items(InvoicedItems::Milestone {
+                    name: name.to_owned(),
+                })
+                .period(YearMonthAndFortnight::sample())
+                .build()
+        };
+        let sut = Sut::sample();
+        assert!(matches!(
+            sut.clone().to_partial(input("Kickoff")),
+            Err(Error::NoMilestoneContract { .. })
+        ));
+
+        let sut = sut.with_milestone_contract(Some(MilestoneContract::sample()));
+        let partial = sut.to_partial(input("Delivery")).unwrap();
+        assert_eq!(
+            partial.information().milestone().as_deref(),
+            Some("Delivery")
+        );
+        assert!(partial.information().excluded_holidays().is_empty());
+        let items = partial.line_items().clone().try_unwrap_service().unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name(), "Website redesign: Delivery");
+        assert_eq!(items[0].quantity(), &Quantity::ONE);
+        assert_eq!(items[0].unit_price(), &UnitPrice::from(dec!(20000)));
+    }
+
+    #[test]
+    fn test_discounts() {
+        let discount = |s: &str| Some(Discount::from_str(s).unwrap());
+        let sut = Sut::sample()
+            .with_service_fees(ServiceFees::sample().with_discount(discount("10%")))
+            .with_information(ProtoInvoiceInfo::sample().with_discount(discount("5%")));
+        let input = |items: InvoicedItems| {
+            ValidInput::builder()
+                .items(items)
+                .period(YearMonthAndFortnight::sample())
+                .build()
+        };
+
+        let partial = sut
+            .clone()
+            .to_partial(input(InvoicedItems::Service { time_off: None }))
+            .unwrap();
+        assert_eq!(*partial.information().discount(), discount("5%"));
+        let items = partial.line_items().clone().try_unwrap_service().unwrap();
+        assert_eq!(*items[0].discount(), discount("10%"));
+
+        let partial = sut
+            .clone()
+            .to_partial(
+                input(InvoicedItems::Service { time_off: None })
+                    .with_discount(discount("100"))
+                    .with_service_discount(discount("500")),
+            )
+            .unwrap();
+        assert_eq!(*partial.information().discount(), discount("100"));
+        let items = partial.line_items().clone().try_unwrap_service().unwrap();
+        assert_eq!(*items[0].discount(), discount("500"));
+
+        let expenses = ValidInput::builder()
+            .items(InvoicedItems::Expenses)
+            .period(
+                YearMonthAndFortnight::builder()
+                    .year(2025.into())
+                    .month(Month::May)
+                    .half(MonthHalf::First)
+                    .build(),
+            )
+            .build();
+        let partial = sut.to_partial(expenses).unwrap();
+        assert_eq!(*partial.information().discount(), None);
+    }
+
+    #[test]
+    fn test_service_lines_are_validated_against_cadence() {
+        let sut = Sut::sample().with_service_lines(vec![
+            ServiceLine::sample().with_rate(Rate::yearly(dec!(1000))),
+        ]);
+        assert!(sut.validate().is_err());
+    }
+
+    #[test]
+    fn test_worked_days_excludes_holidays_of_client_calendar() {
+        let sut = Sut::sample().with_client(
+            CompanyInformation::sample_client().with_holiday_calendar(Some(HolidayCalendar::SE)),
         );
+        let partial = sut
+            .to_partial(
+                ValidInput::builder()
+                    .items(InvoicedItems::Service { time_off: None })
+                    .period(YearMonthAndFortnight::year_and_month_with_half(
+                        YearAndMonth::june(2025),
+                        MonthHalf::First,
+                    ))
+                    .build(),
+            )
+            .unwrap();
+        // June 2025 has 21 weekdays, National Day and Midsummer Eve excluded
+        assert_eq!(
+            partial.line_items().clone().try_unwrap_service().unwrap()[0].quantity(),
+            &Quantity::from(dec!(19.0))
+        );
+        assert_eq!(
+            partial
+                .information()
+                .excluded_holidays()
+                .iter()
+                .map(|h| h.name().as_str())
+                .collect::<Vec<_>>(),
+            ["National Day of Sweden", "Midsummer Eve"]
+        );
+    }
+
+    #[test]
+    fn test_worked_days_excludes_recorded_dates_off() {
+        let sut = Sut::sample();
+        let mut information = sut.information().clone();
+        information.insert_date_off(DateOff::from_str("2025-06-02..2025-06-04").unwrap());
+        information.insert_date_off(DateOff::from_str("2025-06-05 half").unwrap());
+        let partial = sut
+            .with_information(information)
+            .to_partial(
+                ValidInput::builder()
+                    .items(InvoicedItems::Service { time_off: None })
+                    .period(YearMonthAndFortnight::year_and_month_with_half(
+                        YearAndMonth::june(2025),
+                        MonthHalf::First,
+                    ))
+                    .build(),
+            )
+            .unwrap();
+        // June 2025 has 21 weekdays
+        assert_eq!(
+            partial.line_items().clone().try_unwrap_service().unwrap()[0].quantity(),
+            &Quantity::from(dec!(17.5))
+        );
+    }
+
+    fn date_range_input(items: InvoicedItems) -> ValidInput {
+        let date_range = DateRange::sample(); // 2025-05-10..2025-06-15
+        ValidInput::builder()
+            .items(items)
+            .period(YearMonthAndFortnight::year_and_month_with_half(
+                YearAndMonth::june(2025),
+                MonthHalf::First,
+            ))
+            .date_range(date_range)
+            .build()
+    }
+
+    #[test]
+    fn test_date_range_worked_days() {
+        let partial = Sut::sample()
+            .to_partial(date_range_input(InvoicedItems::Service { time_off: None }))
+            .unwrap();
+        // 15 weekdays from 2025-05-10 and 10 weekdays until 2025-06-15
+        assert_eq!(
+            partial.line_items().clone().try_unwrap_service().unwrap()[0].quantity(),
+            &Quantity::from(dec!(25.0))
+        );
+        assert_eq!(
+            partial.information().invoice_date().to_string(),
+            "2025-06-15"
+        );
+    }
+
+    #[test]
+    fn test_date_range_overlapping_period_off_is_err() {
+        let sut = Sut::sample();
+        let mut information = sut.information().clone();
+        information.insert_period_off(YearAndMonth::may(2025));
+        let result = sut
+            .with_information(information)
+            .to_partial(date_range_input(InvoicedItems::Service { time_off: None }));
+        assert!(matches!(
+            result,
+            Err(Error::DateRangeOverlapsPeriodOff { .. })
+        ));
+    }
+
+    #[test]
+    fn test_date_range_has_number_of_period_containing_end() {
+        let items = InvoicedItems::Service { time_off: None };
+        let for_range = Sut::sample()
+            .to_partial(date_range_input(items.clone()))
+            .unwrap();
+        let for_period = Sut::sample()
+            .to_partial(
+                ValidInput::builder()
+                    .items(items)
+                    .period(YearMonthAndFortnight::year_and_month_with_half(
+                        YearAndMonth::june(2025),
+                        MonthHalf::First,
+                    ))
+                    .build(),
+            )
+            .unwrap();
+        assert_eq!(
+            for_range.information().number(),
+            for_period.information().number()
+        );
+    }
+
+    #[test]
+    fn test_number_format_and_yearly_reset() {
+        let sut = Sut::sample();
+        let information = sut
+            .information()
+            .clone()
+            .with_offset(
+                TimestampedInvoiceNumber::builder()
+                    .offset(41.into())
+                    .period(YearAndMonth::november(2024))
+                    .build(),
+            )
+            .with_number_format(InvoiceNumberFormat::sample_other())
+            .with_number_reset(InvoiceNumberReset::Yearly {
+                fiscal_year_start: Month::January,
+            });
+        let partial = sut
+            .with_information(information)
+            .to_partial(
+                ValidInput::builder()
+                    .items(InvoicedItems::Service { time_off: None })
+                    .period(YearMonthAndFortnight::year_and_month_with_half(
+                        YearAndMonth::february(2025),
+                        MonthHalf::First,
+                    ))
+                    .build(),
+            )
+            .unwrap();
+        assert_eq!(
+            partial.information().number(),
+            &FormattedInvoiceNumber::from("LUP-2025-0002")
+        );
+        let path_and_name = partial.absolute_path_and_name().unwrap();
+        assert!(
+            path_and_name.name().ends_with("_invoice_LUP-2025-0002.pdf"),
+            "{}",
+            path_and_name.name()
+        );
+    }
+
+    #[test]
+    fn test_date_range_expenses_by_transaction_date() {
+        let partial = Sut::sample()
+            .to_partial(date_range_input(InvoicedItems::Expenses))
+            .unwrap();
+        assert_eq!(
+            partial.line_items().clone().try_unwrap_expenses().unwrap(),
+            vec![Item::sample_expense_breakfast()]
+        );
+    }
+
+    #[test]
+    fn test_date_range_without_expenses_is_err() {
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Expenses)
+            .period(YearMonthAndFortnight::sample())
+            .date_range(DateRange::sample_other())
+            .build();
+        let result = Sut::sample().to_partial(input);
+        assert!(matches!(
+            result,
+            Err(Error::TargetPeriodMustHaveExpenses { .. })
+        ));
+    }
+
+    #[test]
+    fn test_chosen_invoice_date() {
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service { time_off: None })
+            .period(YearMonthAndFortnight::sample())
+            .invoice_date(Date::from_str("2025-06-02").unwrap())
+            .build();
+        let partial = Sut::sample().to_partial(input).unwrap();
+        assert_eq!(
+            partial.information().invoice_date().to_string(),
+            "2025-06-02"
+        );
+    }
+
+    fn timesheet_input(csv: &str) -> ValidInput {
+        ValidInput::builder()
+            .items(InvoicedItems::Timesheet {
+                timesheet: Timesheet::parse(csv, None).unwrap(),
+            })
+            .period(YearMonthAndFortnight::year_and_month_with_half(
+                YearAndMonth::may(2025),
+                MonthHalf::First,
+            ))
+            .build()
+    }
+
+    #[test]
+    fn test_timesheet_is_quantity() {
+        let partial = Sut::sample()
+            .to_partial(timesheet_input("2025-05-02,8\n2025-05-05,8\n2025-05-06,4"))
+            .unwrap();
+        assert_eq!(
+            partial.line_items().clone().try_unwrap_service().unwrap()[0].quantity(),
+            &Quantity::from(dec!(2.5))
+        );
+    }
+
+    #[test]
+    fn test_timesheet_is_quantity_of_service_lines_worked() {
+        let partial = Sut::sample()
+            .with_service_lines(vec![
+                ServiceLine::sample_other(),
+                ServiceLine::sample_other().with_rate(Rate::hourly(dec!(60))),
+            ])
+            .to_partial(timesheet_input("2025-05-02,8\n2025-05-05,8\n2025-05-06,4"))
+            .unwrap();
+        let items = partial.line_items().clone().try_unwrap_service().unwrap();
+        assert_eq!(items[1].quantity(), &Quantity::from(dec!(2.5)));
+        assert_eq!(items[2].quantity(), &Quantity::from(dec!(20)));
+    }
+
+    #[test]
+    fn test_timesheet_with_rate_changed_during_period() {
+        let rate_history = vec![
+            HistoricRate::builder()
+                .rate(Rate::daily(dec!(700)))
+                .until(Date::from_str("2025-05-09").unwrap())
+                .build(),
+        ];
+        let partial = Sut::sample()
+            .with_service_fees(ServiceFees::sample().with_rate_history(rate_history))
+            .to_partial(timesheet_input("2025-05-02,8\n2025-05-20,8\n2025-05-21,4"))
+            .unwrap();
+        let items = partial
+            .line_items()
+            .clone()
+            .try_unwrap_service()
+            .unwrap()
+            .into_iter()
+            .map(|item| (**item.unit_price(), **item.quantity()))
+            .collect::<Vec<_>>();
+        assert_eq!(items, vec![(dec!(700), dec!(1)), (dec!(777), dec!(1.5))]);
+    }
+
+    #[test]
+    fn test_timesheet_entry_outside_target_period_is_err() {
+        let result = Sut::sample().to_partial(timesheet_input("2025-05-02,8\n2025-06-02,8"));
+        assert!(matches!(
+            result,
+            Err(Error::TimesheetEntryOutsideTargetPeriod { .. })
+        ));
+    }
+
+    #[test]
+    fn test_timesheet_entry_on_date_off_is_err() {
+        let sut = Sut::sample();
+        let mut information = sut.information().clone();
+        information.insert_date_off(DateOff::from_str("2025-05-05..2025-05-09").unwrap());
+        let result = sut
+            .with_information(information)
+            .to_partial(timesheet_input("2025-05-02,8\n2025-05-06,8"));
+        assert!(matches!(result, Err(Error::TimesheetEntryOnDateOff { .. })));
+    }
+
+    #[test]
+    fn test_timesheet_in_period_off_is_err() {
+        let sut = Sut::sample();
+        let mut information = sut.information().clone();
+        information.insert_period_off(YearAndMonth::may(2025));
+        let result = sut
+            .with_information(information)
+            .to_partial(timesheet_input("2025-05-02,8"));
+        assert!(matches!(
+            result,
+            Err(Error::TargetPeriodMustNotBeInRecordOfPeriodsOff { .. })
+        ));
+    }
+
+    #[test]
+    fn test_client_holiday_calendar_takes_precedence_over_vendor() {
+        let sut = Sut::sample()
+            .with_client(
+                CompanyInformation::sample_client()
+                    .with_holiday_calendar(Some(HolidayCalendar::UK)),
+            )
+            .with_vendor(
+                CompanyInformation::sample_vendor()
+                    .with_holiday_calendar(Some(HolidayCalendar::SE)),
+            );
+        assert_eq!(sut.holiday_calendar(), Some(HolidayCalendar::UK));
     }
     #[test]
     fn to_partial_with_free_time_with_invalid_granularity_hour_instead_of_expected_day() {
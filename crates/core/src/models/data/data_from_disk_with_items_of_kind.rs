@@ -40,6 +40,36 @@ pub struct DataFromDiskWithItemsOfKind<Items: Serialize + MaybeIsExpenses> {
     #[getset(get = "pub")]
     payment_info: PaymentInformation,
 
+    /// The VAT or sales tax added to the grand total, if any line item has a
+    /// tax rate, computed once the line items are in the target currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    tax: Option<Tax>,
+
+    /// The cash discount for early payment of the grand total, if the payment
+    /// terms offer one, computed once the line items are in the target currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    early_payment_discount: Option<EarlyPaymentDiscount>,
+
+    /// The amount drawn from the balance prepaid by the client, deducted from
+    /// the grand total including tax, if the client pays a retainer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    retainer: Option<RetainerDrawdown>,
+
+    /// A payment reminder of this invoice if overdue, rendered in a section
+    /// referencing the original invoice.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    reminder: Option<Reminder>,
+
+    /// The invoice credited by this credit note, rendered in a section
+    /// referencing the original invoice.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    credit_note: Option<CreditNote>,
+
     /// Where to save the output PDF file.
     output_path: OutputPath,
 }
@@ -130,6 +160,11 @@ impl DataWithItemsPricedInSourceCurrency {
     /// which is compatible with Typst rendering.
     /// This method prepares the invoice data for rendering by creating an
     /// `ExchangeRates` object and converting the line items into a flat structure.
+    /// The discount of the invoice, if any, is then deducted and the tax of the
+    /// line items computed, reverse charged if the vendor and client are in
+    /// different EU member states. Finally the amount due is reduced by what is
+    /// drawn from the balance prepaid by the client and by the early payment
+    /// discount of the payment terms, if any.
     ///
     /// # Errors
     /// Returns an error if the line items cannot be converted to a flat structure.
@@ -145,8 +180,46 @@ impl DataWithItemsPricedInSourceCurrency {
     /// ```
     pub fn to_typst(self, exchange_rates: ExchangeRates) -> Result<PreparedData> {
         let line_items = LineItemsFlat::try_from((self.line_items, exchange_rates))?;
+        let line_items = match self.information.discount() {
+            Some(discount) => {
+                line_items.with_invoice_discount(discount, *self.information.invoice_date())
+            }
+            None => line_items,
+        };
+        let tax = Tax::new(&line_items, self.vendor.is_reverse_charge_to(&self.client));
+        let total = tax.as_ref().map_or_else(
+            || line_items.grand_total(),
+            |tax| *tax.total_including_tax(),
+        );
+        let retainer = self.information.retainer_balance().map(|balance| {
+            RetainerDrawdown::builder()
+                .balance(balance)
+                .total(total)
+                .build()
+        });
+        let total = retainer
+            .as_ref()
+            .map_or(total, |retainer| *retainer.amount_due());
+        let early_payment_discount = match (
+            self.payment_info.terms().discount(),
+            self.information.discount_deadline(),
+        ) {
+            (Some(percentage), Some(deadline)) => Some(
+                EarlyPaymentDiscount::builder()
+                    .percentage(*percentage)
+                    .deadline(*deadline)
+                    .total(total)
+                    .build(),
+            ),
+            _ => None,
+        };
         Ok(PreparedData {
             line_items,
+            tax,
+            early_payment_discount,
+            retainer,
+            reminder: None,
+            credit_note: None,
             information: self.information,
             vendor: self.vendor,
             client: self.client,
@@ -156,6 +229,150 @@ impl DataWithItemsPricedInSourceCurrency {
     }
 }
 
+impl PreparedData {
+    /// The grand total of the line items including tax, if any.
+    pub fn total_including_tax(&self) -> Cost {
+        self.tax.as_ref().map_or_else(
+            || self.line_items.grand_total(),
+            |tax| *tax.total_including_tax(),
+        )
+    }
+
+    /// The amount to pay, the grand total including tax less the amount drawn
+    /// from the balance prepaid by the client, if any.
+    pub fn amount_due(&self) -> Cost {
+        self.retainer.as_ref().map_or_else(
+            || self.total_including_tax(),
+            |retainer| *retainer.amount_due(),
+        )
+    }
+
+    /// This invoice prepared again as it was `issued` according to the
+    /// ledger, i.e. with the invoice date and due date recorded, so that
+    /// documents referring to it, e.g. a payment reminder, match it.
+    ///
+    /// # Errors
+    /// Returns an error if the total including tax or the currency differs
+    /// from the one recorded, e.g. because the data changed since.
+    pub fn as_issued(self, issued: &LedgerEntry) -> Result<Self> {
+        let total = self.total_including_tax();
+        let currency = *self.payment_info.currency();
+        if total != *issued.total() || currency != *issued.currency() {
+            return Err(Error::IssuedInvoiceChanged {
+                number: issued.number().to_string(),
+                issued: format!("{:.2} {}", **issued.total(), issued.currency()),
+                total: format!("{:.2} {}", *total, currency),
+            });
+        }
+        Ok(Self {
+            information: self
+                .information
+                .as_issued(*issued.invoice_date(), *issued.due_date()),
+            ..self
+        })
+    }
+
+    /// Turns this invoice into a payment reminder charging late payment
+    /// interest until `date` and a reminder fee, according to the late payment
+    /// settings of the payment information. If `is_paid` the invoice was paid
+    /// on `date`, otherwise it is still unpaid at `date`.
+    ///
+    /// Unless an absolute output path was specified, the reminder is saved
+    /// under a name of its own, e.g. `2025-07-31_Lupin_et_Associés_reminder_9876.pdf`.
+    ///
+    /// # Errors
+    /// Returns an error if the invoice is not overdue at `date`.
+    pub fn to_reminder(self, date: Date, is_paid: bool) -> Result<Self> {
+        let reminder = Reminder::builder()
+            .late_payment(self.payment_info.late_payment())
+            .invoice_amount(self.amount_due())
+            .due_date(*self.information.due_date())
+            .date(date)
+            .is_paid(is_paid)
+            .build()?;
+        let output_path = match self.output_path {
+            OutputPath::AbsolutePath(path) => OutputPath::AbsolutePath(path),
+            OutputPath::Name(_) => OutputPath::Name(format!(
+                "{}_{}_reminder_{}.pdf",
+                date,
+                self.vendor.company_name().replace(' ', "_"),
+                self.information.number()
+            )),
+        };
+        Ok(Self {
+            reminder: Some(reminder),
+            output_path,
+            ..self
+        })
+    }
+
+    /// The line items and tax of a credit note reversing `percentage` of
+    /// this invoice.
+    fn credited(&self, percentage: &Percentage) -> (LineItemsFlat, Option<Tax>) {
+        let line_items = self.line_items.credited(percentage);
+        let tax = self
+            .tax
+            .as_ref()
+            .and_then(|tax| Tax::new(&line_items, *tax.is_reverse_charge()));
+        (line_items, tax)
+    }
+
+    /// The grand total including tax of a credit note reversing `percentage`
+    /// of this invoice, negative, e.g. `-5000` for `100%` of an invoice of
+    /// `5000`.
+    pub fn credited_total(&self, percentage: &Percentage) -> Cost {
+        let (line_items, tax) = self.credited(percentage);
+        tax.as_ref().map_or_else(
+            || line_items.grand_total(),
+            |tax| *tax.total_including_tax(),
+        )
+    }
+
+    /// Turns this invoice into a credit note numbered `number` and dated
+    /// `date`, reversing `percentage` of the line items with negative
+    /// quantities and total costs, `100%` for a full reversal. The credit
+    /// note draws nothing from the balance prepaid by the client, the
+    /// credited share of what the invoice drew is returned to it when the
+    /// credit note is recorded, see [`Retainer::with_return`].
+    ///
+    /// Unless an absolute output path was specified, the credit note is saved
+    /// under a name of its own, e.g. `2025-07-31_Lupin_et_Associés_credit_note_CN-1.pdf`.
+    pub fn to_credit_note(
+        self,
+        number: FormattedInvoiceNumber,
+        date: Date,
+        percentage: Percentage,
+    ) -> Self {
+        let credit_note = CreditNote::builder()
+            .invoice_number(self.information.number().clone())
+            .invoice_date(*self.information.invoice_date())
+            .invoice_amount(self.total_including_tax())
+            .percentage(percentage)
+            .build();
+        let (line_items, tax) = self.credited(&percentage);
+        let output_path = match self.output_path {
+            OutputPath::AbsolutePath(path) => OutputPath::AbsolutePath(path),
+            OutputPath::Name(_) => OutputPath::Name(format!(
+                "{}_{}_credit_note_{}.pdf",
+                date,
+                self.vendor.company_name().replace(' ', "_"),
+                number
+            )),
+        };
+        Self {
+            information: self.information.to_credit_note(number, date),
+            line_items,
+            tax,
+            early_payment_discount: None,
+            retainer: None,
+            reminder: None,
+            credit_note: Some(credit_note),
+            output_path,
+            ..self
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,4 +414,251 @@ mod tests {
         let data = DataFromDiskWithItemsOfKind::<LineItemsPricedInSourceCurrency>::sample();
         assert_eq!(data.output_path, OutputPath::Name("invoice.pdf".into()));
     }
+
+    #[test]
+    fn to_typst_applies_early_payment_discount() {
+        let exchange_rates = ExchangeRates::builder()
+            .rates(ExchangeRatesMap::from_iter([(
+                Currency::GBP,
+                UnitPrice::from(dec!(10.0)),
+            )]))
+            .target_currency(Currency::EUR)
+            .build();
+        let payment_info =
+            PaymentInformation::sample().with_terms(PaymentTerms::from_str("2/10 Net 30").unwrap());
+        let invoice_date = Date::from_str("2025-05-31").unwrap();
+        let information = InvoiceInfoFull::builder()
+            .number(FormattedInvoiceNumber::sample())
+            .invoice_date(invoice_date)
+            .due_date(payment_info.due_date(&invoice_date, None))
+            .terms(payment_info.terms().to_string())
+            .maybe_discount_deadline(payment_info.discount_deadline(&invoice_date, None))
+            .build();
+        let data = DataWithItemsPricedInSourceCurrency::builder()
+            .output_path(OutputPath::Name("invoice.pdf".to_owned()))
+            .information(information)
+            .vendor(CompanyInformation::sample())
+            .client(CompanyInformation::sample())
+            .payment_info(payment_info)
+            .line_items(LineItemsPricedInSourceCurrency::sample())
+            .build();
+        let prepared = data.to_typst(exchange_rates).unwrap();
+        let discount = prepared.early_payment_discount().clone().unwrap();
+        assert_eq!(discount.deadline().to_string(), "2025-06-10");
+        assert_eq!(
+            *discount.percentage(),
+            Percentage::try_from(dec!(2)).unwrap()
+        );
+        assert_eq!(
+            **discount.discounted_amount(),
+            (*prepared.line_items().grand_total() * dec!(0.98)).round_dp(2)
+        );
+    }
+
+    #[test]
+    fn to_typst_without_early_payment_discount() {
+        let exchange_rates = ExchangeRates::builder()
+            .rates(ExchangeRatesMap::from_iter([(
+                Currency::GBP,
+                UnitPrice::from(dec!(10.0)),
+            )]))
+            .target_currency(Currency::EUR)
+            .build();
+        let prepared = DataWithItemsPricedInSourceCurrency::sample()
+            .to_typst(exchange_rates)
+            .unwrap();
+        assert!(prepared.early_payment_discount().is_none());
+    }
+
+    #[test]
+    fn to_typst_computes_tax() {
+        let exchange_rates = ExchangeRates::builder()
+            .rates(ExchangeRatesMap::new())
+            .target_currency(Currency::EUR)
+            .build();
+        let data = |client: CompanyInformation| {
+            DataWithItemsPricedInSourceCurrency::builder()
+                .output_path(OutputPath::Name("invoice.pdf".to_owned()))
+                .information(InvoiceInfoFull::sample())
+                .vendor(CompanyInformation::sample_vendor())
+                .client(client)
+                .payment_info(PaymentInformation::sample())
+                .line_items(LineItemsPricedInSourceCurrency::Service(vec![
+                    Item::from_str("Consulting, 500, EUR, 10, 2025-05-31, 20%").unwrap(),
+                ]))
+                .build()
+        };
+
+        let domestic = CompanyInformation::sample_client().with_vat_number("FR12345678901".into());
+        let prepared = data(domestic).to_typst(exchange_rates.clone()).unwrap();
+        let tax = prepared.tax().clone().unwrap();
+        assert!(!tax.is_reverse_charge());
+        assert_eq!(**tax.total_tax(), dec!(1000));
+        assert_eq!(*prepared.total_including_tax(), dec!(6000));
+
+        let credit_note = prepared.to_credit_note(
+            FormattedInvoiceNumber::from("CN-1"),
+            Date::sample(),
+            Percentage::HUNDRED,
+        );
+        assert_eq!(*credit_note.total_including_tax(), dec!(-6000));
+
+        let swedish = CompanyInformation::sample_client().with_vat_number("SE556677889901".into());
+        let prepared = data(swedish).to_typst(exchange_rates).unwrap();
+        let tax = prepared.tax().clone().unwrap();
+        assert!(tax.is_reverse_charge());
+        assert_eq!(**tax.total_tax(), dec!(0));
+        assert_eq!(*prepared.total_including_tax(), dec!(5000));
+    }
+
+    #[test]
+    fn to_typst_draws_from_retainer() {
+        let exchange_rates = ExchangeRates::builder()
+            .rates(ExchangeRatesMap::new())
+            .target_currency(Currency::EUR)
+            .build();
+        let payment_info =
+            PaymentInformation::sample().with_terms(PaymentTerms::from_str("2/10 Net 30").unwrap());
+        let invoice_date = Date::from_str("2025-05-31").unwrap();
+        let information = InvoiceInfoFull::builder()
+            .number(FormattedInvoiceNumber::sample())
+            .invoice_date(invoice_date)
+            .due_date(payment_info.due_date(&invoice_date, None))
+            .terms(payment_info.terms().to_string())
+            .maybe_discount_deadline(payment_info.discount_deadline(&invoice_date, None))
+            .retainer_balance(Cost::from(dec!(3000)))
+            .build();
+        let prepared = DataWithItemsPricedInSourceCurrency::builder()
+            .output_path(OutputPath::Name("invoice.pdf".to_owned()))
+            .information(information)
+            .vendor(CompanyInformation::sample())
+            .client(CompanyInformation::sample())
+            .payment_info(payment_info)
+            .line_items(LineItemsPricedInSourceCurrency::Service(vec![
+                Item::from_str("Consulting, 500, EUR, 10, 2025-05-31").unwrap(),
+            ]))
+            .build()
+            .to_typst(exchange_rates)
+            .unwrap();
+        let retainer = prepared.retainer().clone().unwrap();
+        assert_eq!(**retainer.drawn(), dec!(3000));
+        assert_eq!(**retainer.remaining(), dec!(0));
+        assert_eq!(*prepared.total_including_tax(), dec!(5000));
+        assert_eq!(*prepared.amount_due(), dec!(2000));
+        let discount = prepared.early_payment_discount().clone().unwrap();
+        assert_eq!(**discount.discounted_amount(), dec!(1960));
+
+        let credit_note = prepared.to_credit_note(
+            FormattedInvoiceNumber::from("CN-1"),
+            Date::sample(),
+            Percentage::HUNDRED,
+        );
+        assert!(credit_note.retainer().is_none());
+        assert_eq!(*credit_note.amount_due(), dec!(-5000));
+    }
+
+    #[test]
+    fn to_reminder() {
+        let sut = PreparedData::sample();
+        let due_date = *sut.information().due_date();
+        let date = Date::from(due_date.to_datetime() + chrono::Duration::days(10));
+        let reminder = sut.to_reminder(date, false).unwrap();
+        assert_eq!(*reminder.reminder().as_ref().unwrap().days_overdue(), 10);
+        let path_and_name = reminder.absolute_path_and_name().unwrap();
+        assert_eq!(
+            path_and_name.name(),
+            &format!("{}_Lupin_et_Associés_reminder_9876.pdf", date)
+        );
+    }
+
+    #[test]
+    fn to_credit_note() {
+        let sut = PreparedData::sample();
+        let invoice_number = sut.information().number().clone();
+        let grand_total = sut.line_items().grand_total();
+        let date = Date::from_str("2025-07-31").unwrap();
+        let credit_note = sut.to_credit_note(
+            FormattedInvoiceNumber::from("CN-1"),
+            date,
+            Percentage::HUNDRED,
+        );
+        let reference = credit_note.credit_note().clone().unwrap();
+        assert_eq!(*reference.invoice_number(), invoice_number);
+        assert_eq!(*reference.invoice_amount(), grand_total);
+        assert_eq!(
+            credit_note.information().number(),
+            &FormattedInvoiceNumber::from("CN-1")
+        );
+        assert_eq!(*credit_note.information().invoice_date(), date);
+        assert_eq!(*credit_note.line_items().grand_total(), -*grand_total);
+        let path_and_name = credit_note.absolute_path_and_name().unwrap();
+        assert_eq!(
+            path_and_name.name(),
+            "2025-07-31_Lupin_et_Associés_credit_note_CN-1.pdf"
+        );
+    }
+
+    #[test]
+    fn to_credit_note_partial() {
+        let sut = PreparedData::sample();
+        let grand_total = sut.line_items().grand_total();
+        let credit_note = sut.to_credit_note(
+            FormattedInvoiceNumber::from("CN-1"),
+            Date::sample(),
+            Percentage::try_from(dec!(50)).unwrap(),
+        );
+        assert!(credit_note.credit_note().as_ref().unwrap().is_partial());
+        assert_eq!(
+            *credit_note.line_items().grand_total(),
+            -*grand_total / dec!(2)
+        );
+    }
+
+    fn issued(data: PreparedData) -> LedgerEntry {
+        let named_pdf = NamedPdf::builder()
+            .pdf(Pdf::sample())
+            .saved_at(PathBuf::from("invoice.pdf"))
+            .name("invoice.pdf".to_owned())
+            .prepared_data(data)
+            .build();
+        LedgerEntry::issued(
+            &named_pdf,
+            PeriodAnno::sample(),
+            InvoiceNumber::sample(),
+            Date::sample(),
+        )
+    }
+
+    #[test]
+    fn as_issued_uses_recorded_dates() {
+        let sut = PreparedData::sample();
+        let invoice_date = Date::from_str("2025-05-30").unwrap();
+        let due_date = Date::from_str("2025-06-29").unwrap();
+        let entry = issued(PreparedData {
+            information: sut.information().clone().as_issued(invoice_date, due_date),
+            ..sut.clone()
+        });
+        let as_issued = sut.as_issued(&entry).unwrap();
+        assert_eq!(*as_issued.information().invoice_date(), invoice_date);
+        assert_eq!(*as_issued.information().due_date(), due_date);
+    }
+
+    #[test]
+    fn as_issued_with_other_total_is_err() {
+        let sut = PreparedData::sample();
+        let entry = issued(PreparedData {
+            line_items: sut.line_items().credited(&Percentage::HUNDRED),
+            ..sut.clone()
+        });
+        let result = sut.as_issued(&entry);
+        assert!(matches!(result, Err(Error::IssuedInvoiceChanged { .. })));
+    }
+
+    #[test]
+    fn to_reminder_not_overdue() {
+        let sut = PreparedData::sample();
+        let due_date = *sut.information().due_date();
+        let result = sut.to_reminder(due_date, true);
+        assert!(matches!(result, Err(Error::InvoiceNotOverdue { .. })));
+    }
 }
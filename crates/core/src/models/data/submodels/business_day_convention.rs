@@ -0,0 +1,123 @@
+use derive_more::FromStr;
+
+use crate::prelude::*;
+
+/// How to move a due date falling on a non-business day, i.e. a Saturday, a
+/// Sunday or a public holiday of the holiday calendar, if any.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Display,
+    FromStr,
+    Default,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+)]
+pub enum BusinessDayConvention {
+    /// The due date is not moved.
+    #[default]
+    Unadjusted,
+    /// The due date is moved to the next business day.
+    Following,
+    /// The due date is moved to the previous business day.
+    Preceding,
+}
+
+impl BusinessDayConvention {
+    /// Moves `date` according to this convention if it is not a business day.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let saturday = Date::from_str("2025-05-31").unwrap();
+    /// assert_eq!(BusinessDayConvention::Following.adjust(saturday, None).to_string(), "2025-06-02");
+    /// assert_eq!(BusinessDayConvention::Preceding.adjust(saturday, None).to_string(), "2025-05-30");
+    /// assert_eq!(BusinessDayConvention::Unadjusted.adjust(saturday, None), saturday);
+    /// ```
+    pub fn adjust(&self, date: Date, holiday_calendar: Option<HolidayCalendar>) -> Date {
+        let is_business_day = |date: &NaiveDate| {
+            !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+                && holiday_calendar.is_none_or(|calendar| {
+                    let date = Date::from(*date);
+                    calendar.holidays_between(&date, &date).is_empty()
+                })
+        };
+        let step = |date: NaiveDate| match self {
+            Self::Unadjusted => None,
+            Self::Following => date.succ_opt(),
+            Self::Preceding => date.pred_opt(),
+        };
+        let mut adjusted = date.to_datetime().date();
+        while !is_business_day(&adjusted) {
+            match step(adjusted) {
+                Some(next) => adjusted = next,
+                None => return date,
+            }
+        }
+        Date::from(adjusted)
+    }
+}
+
+impl HasSample for BusinessDayConvention {
+    fn sample() -> Self {
+        Self::Unadjusted
+    }
+
+    fn sample_other() -> Self {
+        Self::Following
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = BusinessDayConvention;
+
+    fn date(s: &str) -> Date {
+        Date::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn business_day_is_not_moved() {
+        for sut in Sut::iter() {
+            assert_eq!(sut.adjust(date("2025-06-02"), None), date("2025-06-02"));
+        }
+    }
+
+    #[test]
+    fn following_skips_holidays() {
+        // Midsummer Eve is a Friday, followed by a weekend
+        let adjusted = Sut::Following.adjust(date("2025-06-20"), Some(HolidayCalendar::SE));
+        assert_eq!(adjusted, date("2025-06-23"));
+    }
+
+    #[test]
+    fn preceding_skips_holidays() {
+        // Easter Monday, preceded by the Easter weekend and Good Friday
+        let adjusted = Sut::Preceding.adjust(date("2025-04-21"), Some(HolidayCalendar::UK));
+        assert_eq!(adjusted, date("2025-04-17"));
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Sut::from_str("Following").unwrap(), Sut::Following);
+    }
+}
@@ -2,7 +2,7 @@ use derive_more::FromStr;
 
 use crate::prelude::*;
 
-/// How often you invoice, e.g. once or twice per month
+/// How often you invoice, e.g. once or twice per month, or once per quarter
 #[derive(
     Clone, Copy, Debug, Display, FromStr, Default, Serialize, Deserialize, PartialEq, EnumIter,
 )]
@@ -12,6 +12,15 @@ pub enum Cadence {
     Monthly,
     /// Invoicing **twice** per month.
     BiWeekly,
+    /// Invoicing **once** per ISO week, Monday to Sunday.
+    Weekly,
+    /// Invoicing **once** per quarter.
+    Quarterly,
+    /// Invoicing **once** per year.
+    Yearly,
+    /// Invoicing **once** per rolling 14 days period, e.g. every other Monday,
+    /// anchored on the `rolling_period_anchor` of the service fees.
+    RollingFortnight,
 }
 
 impl Cadence {
@@ -19,10 +28,25 @@ impl Cadence {
         use Cadence::*;
         use Granularity::*;
         let granularity = granularity.into();
+        let too_coarse = || {
+            Err(Error::CannotInvoiceForGranularityWithCadence {
+                granularity,
+                cadence: *self,
+            })
+        };
         match (self, granularity) {
             (BiWeekly, Month) => Err(Error::CannotInvoiceForMonthWhenCadenceIsBiWeekly),
+            (BiWeekly, Quarter | Year) => too_coarse(),
             (BiWeekly, Fortnight | Day | Hour) => Ok(()),
+            (Weekly, Year | Quarter | Month | Fortnight) => too_coarse(),
+            (Weekly, Day | Hour) => Ok(()),
+            (Monthly, Quarter | Year) => too_coarse(),
             (Monthly, Fortnight | Day | Hour | Month) => Ok(()),
+            (Quarterly, Year) => too_coarse(),
+            (Quarterly, Quarter | Month | Fortnight | Day | Hour) => Ok(()),
+            (Yearly, Year | Quarter | Month | Fortnight | Day | Hour) => Ok(()),
+            (RollingFortnight, Year | Quarter | Month) => too_coarse(),
+            (RollingFortnight, Fortnight | Day | Hour) => Ok(()),
         }
     }
 }
@@ -66,5 +90,25 @@ mod tests {
         assert!(Sut::BiWeekly.validate(Granularity::Fortnight).is_ok());
         assert!(Sut::BiWeekly.validate(Granularity::Day).is_ok());
         assert!(Sut::BiWeekly.validate(Granularity::Hour).is_ok());
+
+        assert!(Sut::Weekly.validate(Granularity::Month).is_err());
+        assert!(Sut::Weekly.validate(Granularity::Fortnight).is_err());
+        assert!(Sut::Weekly.validate(Granularity::Day).is_ok());
+        assert!(Sut::Weekly.validate(Granularity::Hour).is_ok());
+
+        assert!(Sut::Monthly.validate(Granularity::Quarter).is_err());
+        assert!(Sut::Quarterly.validate(Granularity::Year).is_err());
+        assert!(Sut::Quarterly.validate(Granularity::Quarter).is_ok());
+        assert!(Sut::Quarterly.validate(Granularity::Day).is_ok());
+        assert!(Sut::Yearly.validate(Granularity::Year).is_ok());
+        assert!(Sut::Yearly.validate(Granularity::Month).is_ok());
+
+        assert!(Sut::RollingFortnight.validate(Granularity::Month).is_err());
+        assert!(
+            Sut::RollingFortnight
+                .validate(Granularity::Fortnight)
+                .is_ok()
+        );
+        assert!(Sut::RollingFortnight.validate(Granularity::Hour).is_ok());
     }
 }
@@ -0,0 +1,136 @@
+use crate::prelude::*;
+
+/// A named client with fees and invoicing preferences of its own, stored in
+/// the data directory next to the default client, e.g. `clients/acme.ron`,
+/// and selected per invoice, e.g. `klirr invoice --client acme`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder, Getters, WithSetters)]
+pub struct ClientProfile<Period: IsPeriod> {
+    /// The company that pays the invoices of this profile, the customer/buyer.
+    #[getset(get = "pub", set_with = "pub")]
+    client: CompanyInformation,
+
+    /// Price of service for this client.
+    #[getset(get = "pub", set_with = "pub")]
+    service_fees: ServiceFees,
+
+    /// Services invoiced to this client on lines of their own after the
+    /// service fees, replacing those of the default client.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    service_lines: Vec<ServiceLine>,
+
+    /// A fixed-price contract with this client billed in milestones,
+    /// replacing that of the default client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    milestone_contract: Option<MilestoneContract>,
+
+    /// A purchase order agreed upon with this client, e.g. `"PO-12345"`,
+    /// replacing the purchase order of the invoice information.
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    purchase_order: Option<PurchaseOrder>,
+
+    /// The payment terms agreed upon with this client, e.g.
+    /// `Net { due_in: 30 }`, defaults to those of the payment information.
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    payment_terms: Option<PaymentTerms>,
+
+    /// The language of invoices to this client unless specified, defaults to
+    /// English.
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    language: Option<Language>,
+
+    /// The recipients of invoices emailed to this client, replacing those of
+    /// the email settings unless empty.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    recipients: IndexSet<EmailAddress>,
+
+    /// How invoices to this client are numbered, defaults to the series
+    /// shared by all clients of the vendor.
+    #[builder(default)]
+    #[serde(default = "ClientNumbering::default")]
+    #[getset(get = "pub", set_with = "pub")]
+    numbering: ClientNumbering<Period>,
+
+    /// How the numbers of invoices to this client are printed, replacing the
+    /// number format of the invoice information, e.g. with a prefix of its
+    /// own, so that a series of its own never prints the number of an
+    /// invoice to another client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    number_format: Option<InvoiceNumberFormat>,
+}
+
+/// How the invoices of a [`ClientProfile`] are numbered.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, IsVariant)]
+pub enum ClientNumbering<Period: IsPeriod> {
+    /// The series of invoice numbers shared by all clients of the vendor,
+    /// continuing after the last invoice recorded in the ledger, or from the
+    /// offset of the invoice information if none has been recorded.
+    #[default]
+    Shared,
+
+    /// A series of its own, calculated from an offset just like the numbers
+    /// of the default client, e.g. `Own((offset: 1, period: "2025-05"))`.
+    Own(TimestampedInvoiceNumber<Period>),
+}
+
+impl<Period: IsPeriod + HasSample> HasSample for ClientProfile<Period> {
+    fn sample() -> Self {
+        Self::builder()
+            .client(CompanyInformation::sample_client())
+            .service_fees(ServiceFees::sample())
+            .purchase_order(PurchaseOrder::sample())
+            .language(Language::SV)
+            .recipients(IndexSet::from([EmailAddress::sample_bob()]))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .client(CompanyInformation::sample_other())
+            .service_fees(ServiceFees::sample_other())
+            .payment_terms(PaymentTerms::sample_other())
+            .numbering(ClientNumbering::Own(TimestampedInvoiceNumber::sample()))
+            .number_format(InvoiceNumberFormat::builder().prefix("OTH-").build())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = ClientProfile<YearAndMonth>;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn numbering_defaults_to_shared() {
+        assert!(Sut::sample().numbering().is_shared());
+        assert!(Sut::sample_other().numbering().is_own());
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let sut = Sut::sample_other();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron::from_str::<Sut>(&ron).unwrap(), sut);
+    }
+}
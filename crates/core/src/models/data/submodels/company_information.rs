@@ -23,6 +23,47 @@ pub struct CompanyInformation {
     /// The VAT number of the company, e.g. `"GB123456789"`.
     #[getset(get = "pub", set_with = "pub")]
     vat_number: String,
+    /// The public holidays observed by the company, e.g. `Some(SE)`, which
+    /// are excluded from the working days when invoicing per day or hour. The
+    /// client's calendar takes precedence over the vendor's.
+    #[getset(get = "pub", set_with = "pub")]
+    holiday_calendar: Option<HolidayCalendar>,
+}
+
+/// The country codes prefixing the VAT numbers of the EU member states, with
+/// `EL` for Greece.
+const EU_VAT_COUNTRY_CODES: [&str; 27] = [
+    "AT", "BE", "BG", "CY", "CZ", "DE", "DK", "EE", "EL", "ES", "FI", "FR", "HR", "HU", "IE", "IT",
+    "LT", "LU", "LV", "MT", "NL", "PL", "PT", "RO", "SE", "SI", "SK",
+];
+
+impl CompanyInformation {
+    /// The EU member state prefixing the VAT number, e.g. `Some("FR")` for
+    /// `"FR74185293012"`, none if not an EU VAT number.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(CompanyInformation::sample_vendor().eu_vat_country_code(), Some("FR"));
+    /// assert_eq!(CompanyInformation::sample_client().eu_vat_country_code(), None);
+    /// ```
+    pub fn eu_vat_country_code(&self) -> Option<&str> {
+        let code = self.vat_number.trim().get(..2)?;
+        EU_VAT_COUNTRY_CODES
+            .iter()
+            .find(|eu| eu.eq_ignore_ascii_case(code))
+            .copied()
+    }
+
+    /// Whether the VAT of services sold by this vendor to `client` is reverse
+    /// charged, i.e. accounted for by the client, which is the case when both
+    /// are registered for VAT in different EU member states.
+    pub fn is_reverse_charge_to(&self, client: &CompanyInformation) -> bool {
+        match (self.eu_vat_country_code(), client.eu_vat_country_code()) {
+            (Some(vendor), Some(client)) => vendor != client,
+            _ => false,
+        }
+    }
 }
 
 impl HasSample for CompanyInformation {
@@ -73,4 +114,42 @@ mod tests {
     fn inequality() {
         assert_ne!(Sut::sample(), Sut::sample_other());
     }
+
+    #[test]
+    fn reverse_charge_between_eu_member_states() {
+        let vendor = Sut::sample_vendor();
+        let swedish = Sut::sample_client().with_vat_number("SE556677889901".into());
+        let french = Sut::sample_client().with_vat_number("fr12345678901".into());
+        assert!(vendor.is_reverse_charge_to(&swedish));
+        assert!(!vendor.is_reverse_charge_to(&french));
+        assert!(!vendor.is_reverse_charge_to(&Sut::sample_client()));
+        assert!(
+            !swedish
+                .with_vat_number("".into())
+                .is_reverse_charge_to(&vendor)
+        );
+    }
+
+    #[test]
+    fn deserialize_without_holiday_calendar() {
+        let ron = r#"
+        CompanyInformation(
+            contact_person: None,
+            organisation_number: "123",
+            company_name: "Acme",
+            postal_address: PostalAddress(
+                street_address: StreetAddress(
+                    line_1: "Street 1",
+                    line_2: "",
+                ),
+                zip: "12345",
+                country: "Sweden",
+                city: "Stockholm",
+            ),
+            vat_number: "SE123",
+        )
+        "#;
+        let sut: Sut = ron::de::from_str(ron).unwrap();
+        assert_eq!(sut.holiday_calendar(), &None);
+    }
 }
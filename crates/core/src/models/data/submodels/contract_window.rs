@@ -0,0 +1,181 @@
+use crate::prelude::*;
+
+/// The start and end dates of a contract, both inclusive and both optional,
+/// e.g. an engagement starting on `2025-05-14` without an end date.
+///
+/// Fees of periods partially outside the window are pro-rated by the working
+/// days inside the window, and periods entirely outside it are not invoiced.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Getters)]
+#[serde(try_from = "UncheckedContractWindow")]
+pub struct ContractWindow {
+    /// The first day of the contract, if any.
+    #[serde(default)]
+    #[getset(get = "pub")]
+    start: Option<Date>,
+
+    /// The last day of the contract, if any.
+    #[serde(default)]
+    #[getset(get = "pub")]
+    end: Option<Date>,
+}
+
+/// A `ContractWindow` as read from disk, before being validated.
+#[derive(Deserialize)]
+#[serde(rename = "ContractWindow")]
+struct UncheckedContractWindow {
+    #[serde(default)]
+    start: Option<Date>,
+    #[serde(default)]
+    end: Option<Date>,
+}
+
+impl TryFrom<UncheckedContractWindow> for ContractWindow {
+    type Error = crate::prelude::Error;
+    fn try_from(unchecked: UncheckedContractWindow) -> Result<Self> {
+        Self::builder()
+            .maybe_start(unchecked.start)
+            .maybe_end(unchecked.end)
+            .build()
+    }
+}
+
+#[bon]
+impl ContractWindow {
+    /// Creates a new contract window.
+    ///
+    /// # Errors
+    /// Returns an error if `end` is before `start`.
+    #[builder]
+    pub fn new(start: Option<Date>, end: Option<Date>) -> Result<Self> {
+        match (start, end) {
+            (Some(start), Some(end)) if end < start => Err(Error::InvalidContractWindow {
+                start: start.to_string(),
+                end: end.to_string(),
+            }),
+            _ => Ok(Self { start, end }),
+        }
+    }
+}
+
+impl std::fmt::Display for ContractWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let date = |date: &Option<Date>| date.map(|d| d.to_string()).unwrap_or_default();
+        write!(f, "{}..{}", date(&self.start), date(&self.end))
+    }
+}
+
+impl ContractWindow {
+    /// Whether `date` is within the contract window.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let window = ContractWindow::builder()
+    ///     .start(Date::from_str("2025-05-14").unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(!window.contains(&Date::from_str("2025-05-13").unwrap()));
+    /// assert!(window.contains(&Date::from_str("2025-05-14").unwrap()));
+    /// ```
+    pub fn contains(&self, date: &Date) -> bool {
+        self.start.is_none_or(|start| *date >= start) && self.end.is_none_or(|end| *date <= end)
+    }
+
+    /// Whether the whole `period` is within the contract window.
+    pub fn covers(&self, period: &impl IsPeriod) -> bool {
+        self.contains(&period.to_date_start_of_period())
+            && self.contains(&period.to_date_end_of_period())
+    }
+
+    /// Whether any day of `period` is within the contract window.
+    pub fn overlaps(&self, period: &impl IsPeriod) -> bool {
+        self.overlaps_between(
+            &period.to_date_start_of_period(),
+            &period.to_date_end_of_period(),
+        )
+    }
+
+    /// Whether any day of `date_range` is within the contract window.
+    pub fn overlaps_date_range(&self, date_range: &DateRange) -> bool {
+        self.overlaps_between(date_range.start(), date_range.end())
+    }
+
+    fn overlaps_between(&self, first_day: &Date, last_day: &Date) -> bool {
+        self.start.is_none_or(|start| *last_day >= start)
+            && self.end.is_none_or(|end| *first_day <= end)
+    }
+}
+
+impl HasSample for ContractWindow {
+    fn sample() -> Self {
+        Self::builder()
+            .start(Date::from_str("2025-05-14").expect("Valid date"))
+            .build()
+            .expect("Valid window")
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .start(Date::from_str("2025-01-01").expect("Valid date"))
+            .end(Date::from_str("2025-06-20").expect("Valid date"))
+            .build()
+            .expect("Valid window")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = ContractWindow;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn throws_when_end_is_before_start() {
+        let result = Sut::builder()
+            .start(Date::from_str("2025-05-14").unwrap())
+            .end(Date::from_str("2025-05-13").unwrap())
+            .build();
+        assert!(matches!(result, Err(Error::InvalidContractWindow { .. })));
+    }
+
+    #[test]
+    fn deserialize_ron() {
+        let sut: Sut = ron::from_str(r#"(start: Some("2025-05-14"))"#).unwrap();
+        assert_eq!(sut, Sut::sample());
+    }
+
+    #[test]
+    fn deserialization_throws_when_end_is_before_start() {
+        let ron = r#"(start: Some("2025-05-14"), end: Some("2025-05-13"))"#;
+        assert!(ron::from_str::<Sut>(ron).is_err());
+    }
+
+    #[test]
+    fn covers_and_overlaps() {
+        let sut = Sut::sample_other();
+        assert!(sut.covers(&YearAndMonth::may(2025)));
+        assert!(!sut.covers(&YearAndMonth::june(2025)));
+        assert!(sut.overlaps(&YearAndMonth::june(2025)));
+        assert!(!sut.overlaps(&YearAndMonth::july(2025)));
+        assert!(!sut.overlaps(&YearAndMonth::december(2024)));
+        assert!(Sut::default().covers(&YearAndMonth::july(2025)));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Sut::sample().to_string(), "2025-05-14..");
+        assert_eq!(Sut::sample_other().to_string(), "2025-01-01..2025-06-20");
+    }
+}
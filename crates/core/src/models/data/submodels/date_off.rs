@@ -0,0 +1,241 @@
+use crate::prelude::*;
+
+/// A concrete date or range of dates when you were off, e.g. a vacation
+/// `2025-07-01..2025-07-25`, a half day `2025-07-28 half` or a couple of hours
+/// on a date `2025-07-29 2.5h`.
+///
+/// Only the dates which are working days are subtracted from the quantity
+/// invoiced, i.e. weekends and public holidays within a range are ignored.
+#[derive(Clone, Debug, Display, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub enum DateOff {
+    /// A full day off, e.g. `2025-07-01`.
+    #[display("{_0}")]
+    Day(Date),
+
+    /// A range of full days off, both inclusive, e.g. `2025-07-01..2025-07-25`.
+    #[display("{first}..{last}")]
+    Range { first: Date, last: Date },
+
+    /// Half of the working hours of a date off, e.g. `2025-07-28 half`.
+    #[display("{_0} half")]
+    HalfDay(Date),
+
+    /// A number of hours off on a date, e.g. `2025-07-29 2.5h`.
+    #[display("{date} {hours}h")]
+    Hours { date: Date, hours: Quantity },
+}
+
+impl DateOff {
+    /// Creates a range of days off, `first` and `last` both inclusive.
+    ///
+    /// # Errors
+    /// Returns an error if `last` is before `first`.
+    pub fn range(first: Date, last: Date) -> Result<Self> {
+        if last < first {
+            return Err(Error::InvalidDateOff {
+                invalid_string: format!("{first}..{last}"),
+                reason: "The last date must not be before the first date".to_owned(),
+            });
+        }
+        Ok(Self::Range { first, last })
+    }
+
+    /// Creates a number of hours off on `date`.
+    ///
+    /// # Errors
+    /// Returns an error if `hours` is not more than 0 and at most 24.
+    pub fn hours(date: Date, hours: Quantity) -> Result<Self> {
+        if hours <= Quantity::ZERO || hours > Quantity::from(dec!(24)) {
+            return Err(Error::InvalidDateOff {
+                invalid_string: format!("{date} {hours}h"),
+                reason: "Hours must be more than 0 and at most 24".to_owned(),
+            });
+        }
+        Ok(Self::Hours { date, hours })
+    }
+
+    /// The first date of this date off.
+    pub fn first(&self) -> &Date {
+        match self {
+            Self::Day(date) | Self::HalfDay(date) => date,
+            Self::Range { first, .. } => first,
+            Self::Hours { date, .. } => date,
+        }
+    }
+
+    /// The last date of this date off, same as [`Self::first`] unless this is
+    /// a range.
+    pub fn last(&self) -> &Date {
+        match self {
+            Self::Range { last, .. } => last,
+            _ => self.first(),
+        }
+    }
+
+    /// Whether `date` is a full day off, i.e. within a day or range off.
+    pub fn is_full_day_off(&self, date: &Date) -> bool {
+        matches!(self, Self::Day(_) | Self::Range { .. })
+            && date >= self.first()
+            && date <= self.last()
+    }
+
+    /// The hours off on `date`, given that you work `hours_on_date` that day,
+    /// e.g. half of them for a half day off.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    ///
+    /// let date_off = DateOff::from_str("2025-07-28 half").unwrap();
+    /// let date = Date::from_str("2025-07-28").unwrap();
+    /// assert_eq!(date_off.hours_off_on(&date, Quantity::EIGHT), Quantity::from(dec!(4)));
+    /// ```
+    pub fn hours_off_on(&self, date: &Date, hours_on_date: Quantity) -> Quantity {
+        if date < self.first() || date > self.last() {
+            return Quantity::ZERO;
+        }
+        match self {
+            Self::Day(_) | Self::Range { .. } => hours_on_date,
+            Self::HalfDay(_) => Quantity::from(*hours_on_date / rust_decimal::Decimal::TWO),
+            Self::Hours { hours, .. } => {
+                if *hours < hours_on_date {
+                    *hours
+                } else {
+                    hours_on_date
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for DateOff {
+    type Err = crate::Error;
+
+    /// Parses a date off, e.g. `"2025-07-01"`, `"2025-07-01..2025-07-25"`,
+    /// `"2025-07-28 half"` or `"2025-07-29 2.5h"`.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not in any of the formats above, if
+    /// the range is reversed, or if the hours are not between 0 and 24.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: String| Error::InvalidDateOff {
+            invalid_string: s.to_owned(),
+            reason,
+        };
+        let parse_date = |s: &str| Date::from_str(s.trim()).map_err(|e| invalid(e.to_string()));
+        let s = s.trim();
+        if let Some((first, last)) = s.split_once("..") {
+            return Self::range(parse_date(first)?, parse_date(last)?);
+        }
+        match s.split_once(' ') {
+            None => parse_date(s).map(Self::Day),
+            Some((date, "half")) => parse_date(date).map(Self::HalfDay),
+            Some((date, amount)) => {
+                let hours = amount.trim().strip_suffix('h').ok_or_else(|| {
+                    invalid(format!(
+                        "Expected 'half' or hours, e.g. '2.5h', got: '{amount}'"
+                    ))
+                })?;
+                let hours =
+                    Quantity::from_str(hours.trim()).map_err(|e| invalid(format!("{e:?}")))?;
+                Self::hours(parse_date(date)?, hours)
+            }
+        }
+    }
+}
+
+impl HasSample for DateOff {
+    fn sample() -> Self {
+        Self::range(
+            Date::from_str("2025-07-01").expect("Valid date"),
+            Date::from_str("2025-07-25").expect("Valid date"),
+        )
+        .expect("Valid range")
+    }
+
+    fn sample_other() -> Self {
+        Self::HalfDay(Date::from_str("2025-05-02").expect("Valid date"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = DateOff;
+
+    fn date(s: &str) -> Date {
+        Date::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn display_from_str_roundtrip() {
+        for s in [
+            "2025-07-01",
+            "2025-07-01..2025-07-25",
+            "2025-07-28 half",
+            "2025-07-29 2.5h",
+        ] {
+            assert_eq!(Sut::from_str(s).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for s in [
+            "",
+            "2025-07",
+            "2025-07-25..2025-07-01",
+            "2025-07-28 quarter",
+            "2025-07-29 0h",
+            "2025-07-29 25h",
+            "2025-07-29 xh",
+        ] {
+            assert!(
+                matches!(Sut::from_str(s), Err(Error::InvalidDateOff { .. })),
+                "Expected error for: {s}"
+            );
+        }
+    }
+
+    #[test]
+    fn hours_off_on() {
+        let eight = Quantity::EIGHT;
+        let range = Sut::sample();
+        assert_eq!(range.hours_off_on(&date("2025-07-01"), eight), eight);
+        assert_eq!(range.hours_off_on(&date("2025-07-25"), eight), eight);
+        assert_eq!(
+            range.hours_off_on(&date("2025-07-26"), eight),
+            Quantity::ZERO
+        );
+
+        let hours = Sut::from_str("2025-07-29 10h").unwrap();
+        assert_eq!(hours.hours_off_on(&date("2025-07-29"), eight), eight);
+        let hours = Sut::from_str("2025-07-29 2.5h").unwrap();
+        assert_eq!(
+            hours.hours_off_on(&date("2025-07-29"), eight),
+            Quantity::from(dec!(2.5))
+        );
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let sut = Sut::sample();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron, "\"2025-07-01..2025-07-25\"");
+        let deserialized: Sut = ron::from_str(&ron).unwrap();
+        assert_eq!(deserialized, sut);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::prelude::*;
+
+/// A custom range of dates to invoice for, both inclusive, e.g.
+/// `2025-05-10..2025-06-15` for a wrap-up engagement not aligned with any
+/// period.
+#[derive(Clone, Copy, Debug, Display, Serialize, Deserialize, PartialEq, Eq, Hash, Getters)]
+#[display("{start}..{end}")]
+#[serde(try_from = "UncheckedDateRange")]
+pub struct DateRange {
+    /// The first day of the range, e.g. `2025-05-10`.
+    #[getset(get = "pub")]
+    start: Date,
+
+    /// The last day of the range, e.g. `2025-06-15`.
+    #[getset(get = "pub")]
+    end: Date,
+}
+
+/// A `DateRange` as read from disk, before being validated.
+#[derive(Deserialize)]
+#[serde(rename = "DateRange")]
+struct UncheckedDateRange {
+    start: Date,
+    end: Date,
+}
+
+impl TryFrom<UncheckedDateRange> for DateRange {
+    type Error = crate::prelude::Error;
+    fn try_from(unchecked: UncheckedDateRange) -> Result<Self> {
+        Self::builder()
+            .start(unchecked.start)
+            .end(unchecked.end)
+            .build()
+    }
+}
+
+#[bon]
+impl DateRange {
+    /// Creates a new date range.
+    ///
+    /// # Errors
+    /// Returns an error if `end` is before `start`.
+    #[builder]
+    pub fn new(start: Date, end: Date) -> Result<Self> {
+        if end < start {
+            return Err(Error::InvalidDateRange {
+                start: start.to_string(),
+                end: end.to_string(),
+            });
+        }
+        Ok(Self { start, end })
+    }
+}
+
+impl DateRange {
+    /// Whether `date` is within the range.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let range = DateRange::builder()
+    ///     .start(Date::from_str("2025-05-10").unwrap())
+    ///     .end(Date::from_str("2025-06-15").unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(range.contains(&Date::from_str("2025-06-15").unwrap()));
+    /// assert!(!range.contains(&Date::from_str("2025-06-16").unwrap()));
+    /// ```
+    pub fn contains(&self, date: &Date) -> bool {
+        *date >= self.start && *date <= self.end
+    }
+}
+
+impl HasSample for DateRange {
+    fn sample() -> Self {
+        Self::builder()
+            .start(Date::from_str("2025-05-10").expect("Valid date"))
+            .end(Date::from_str("2025-06-15").expect("Valid date"))
+            .build()
+            .expect("Valid range")
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .start(Date::from_str("2025-07-01").expect("Valid date"))
+            .end(Date::from_str("2025-07-04").expect("Valid date"))
+            .build()
+            .expect("Valid range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = DateRange;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn throws_when_end_is_before_start() {
+        let result = Sut::builder()
+            .start(Date::from_str("2025-05-10").unwrap())
+            .end(Date::from_str("2025-05-09").unwrap())
+            .build();
+        assert!(matches!(result, Err(Error::InvalidDateRange { .. })));
+    }
+
+    #[test]
+    fn single_day() {
+        let day = Date::from_str("2025-05-10").unwrap();
+        let sut = Sut::builder().start(day).end(day).build().unwrap();
+        assert!(sut.contains(&day));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Sut::sample();
+        let json = serde_json::to_string(&sut).unwrap();
+        assert_eq!(serde_json::from_str::<Sut>(&json).unwrap(), sut);
+    }
+
+    #[test]
+    fn deserialization_throws_when_end_is_before_start() {
+        let json = r#"{"start":"2025-05-10","end":"2025-05-09"}"#;
+        assert!(serde_json::from_str::<Sut>(json).is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Sut::sample().to_string(), "2025-05-10..2025-06-15");
+    }
+}
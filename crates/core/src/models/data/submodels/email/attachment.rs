@@ -1,6 +1,9 @@
 use crate::prelude::*;
 
+// An email has a single invoice PDF, so boxing it would gain nothing.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, From)]
 pub enum Attachment {
     Pdf(NamedPdf),
+    Receipt(Receipt),
 }
@@ -46,13 +46,17 @@ impl Email {
 impl From<(DecryptedEmailSettings, NamedPdf)> for Email {
     fn from((settings, pdf): (DecryptedEmailSettings, NamedPdf)) -> Self {
         let (subject, body) = settings.template().materialize(pdf.prepared_data());
+        let receipts = pdf.receipts().clone();
+        let attachments = std::iter::once(Attachment::Pdf(pdf))
+            .chain(receipts.into_iter().map(Attachment::Receipt))
+            .collect::<IndexSet<_>>();
         Email::builder()
             .subject(subject)
             .body(body)
             .public_recipients(settings.recipients().clone())
             .cc_recipients(settings.cc_recipients().clone())
             .bcc_recipients(settings.bcc_recipients().clone())
-            .attachments(IndexSet::from([Attachment::Pdf(pdf)]))
+            .attachments(attachments)
             .build()
     }
 }
@@ -100,4 +104,19 @@ mod tests {
     fn inequality() {
         assert_ne!(Sut::sample(), Sut::sample_other());
     }
+
+    #[test]
+    fn from_settings_and_pdf_attaches_receipts() {
+        let pdf =
+            NamedPdf::sample().with_receipts(vec![Receipt::sample(), Receipt::sample_other()]);
+        let sut = Sut::from((DecryptedEmailSettings::sample(), pdf.clone()));
+        assert_eq!(
+            sut.attachments().iter().collect::<Vec<_>>(),
+            vec![
+                &Attachment::Pdf(pdf),
+                &Attachment::Receipt(Receipt::sample()),
+                &Attachment::Receipt(Receipt::sample_other()),
+            ]
+        );
+    }
 }
@@ -54,7 +54,7 @@ pub struct EmailSettings<AppPassword: Zeroize> {
     sender: EmailAccount,
 
     /// Public recipients of the email.
-    #[getset(get = "pub")]
+    #[getset(get = "pub", set_with = "pub")]
     #[zeroize(skip)]
     recipients: IndexSet<EmailAddress>,
 
@@ -31,6 +31,7 @@ impl From<Attachment> for SinglePart {
     fn from(attachment: Attachment) -> Self {
         match attachment {
             Attachment::Pdf(named_pdf) => named_pdf.into(),
+            Attachment::Receipt(receipt) => receipt.into(),
         }
     }
 }
@@ -41,6 +42,15 @@ impl From<NamedPdf> for SinglePart {
     }
 }
 
+impl From<Receipt> for SinglePart {
+    fn from(receipt: Receipt) -> Self {
+        let content_type = ContentType::parse(receipt.content_type())
+            .expect("Content type of receipt should be valid");
+        lettre::message::Attachment::new(receipt.name().clone())
+            .body(receipt.content().clone(), content_type)
+    }
+}
+
 impl From<EmailAddress> for lettre::Address {
     fn from(address: EmailAddress) -> Self {
         (*address).clone()
@@ -116,6 +126,15 @@ mod tests {
     use lettre::transport::smtp::authentication::Mechanism;
     use test_log::test;
 
+    #[test]
+    fn test_singlepart_from_receipt() {
+        let attachment: Attachment = Receipt::sample_other().into();
+        let single_part: SinglePart = attachment.into();
+        let formatted = String::from_utf8(single_part.formatted()).unwrap();
+        assert!(formatted.contains("filename=\"2025-05-20_breakfast.PDF\""));
+        assert!(formatted.contains("Content-Type: application/pdf"));
+    }
+
     #[test]
     fn test_singlepart_from_attachment() {
         let named_pdf = NamedPdf::builder()
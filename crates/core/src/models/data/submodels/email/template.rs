@@ -1,7 +1,7 @@
 use crate::prelude::*;
 
 /// Template is a struct that contains the subject and body format for an email.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Builder, Getters, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Builder, Getters, Serialize, Deserialize)]
 pub struct Template {
     /// A formatting taking one argument: Invoice number, e.g. "Invoice{}".
     /// At time of composing the email, the subject will be
@@ -13,12 +13,39 @@ pub struct Template {
     /// formatted with the invoice number.
     #[getset(get = "pub")]
     body_format: TemplatePart,
+    /// The subject of emails sending a payment reminder of an invoice, e.g.
+    /// "Reminder: Invoice <INV_NO>".
+    #[serde(default = "TemplatePart::reminder_subject")]
+    #[builder(default = TemplatePart::reminder_subject())]
+    #[getset(get = "pub")]
+    reminder_subject_format: TemplatePart,
+    /// The body of emails sending a payment reminder of an invoice.
+    #[serde(default = "TemplatePart::reminder_body")]
+    #[builder(default = TemplatePart::reminder_body())]
+    #[getset(get = "pub")]
+    reminder_body_format: TemplatePart,
+}
+
+impl Default for Template {
+    fn default() -> Self {
+        Self::builder()
+            .subject_format(TemplatePart::default())
+            .body_format(TemplatePart::default())
+            .build()
+    }
 }
 
 impl Template {
+    /// The subject and body of an email sending `data`, using the reminder
+    /// formats if `data` is a payment reminder.
     pub fn materialize(&self, data: &PreparedData) -> (String, String) {
-        let subject = self.subject_format.materialize(data);
-        let body = self.body_format.materialize(data);
+        let (subject_format, body_format) = if data.reminder().is_some() {
+            (&self.reminder_subject_format, &self.reminder_body_format)
+        } else {
+            (&self.subject_format, &self.body_format)
+        };
+        let subject = subject_format.materialize(data);
+        let body = body_format.materialize(data);
         (subject, body)
     }
 }
@@ -29,13 +56,43 @@ mod tests {
 
     #[test]
     fn test_template_materialization() {
-        let template = Template {
-            subject_format: TemplatePart::from("Invoice <INV_NO>"),
-            body_format: TemplatePart::from("Please pay invoice <INV_NO>"),
-        };
+        let template = Template::builder()
+            .subject_format(TemplatePart::from("Invoice <INV_NO>"))
+            .body_format(TemplatePart::from("Please pay invoice <INV_NO>"))
+            .build();
         let data = PreparedData::sample();
         let (subject, body) = template.materialize(&data);
         assert_eq!(subject, "Invoice 9876");
         assert_eq!(body, "Please pay invoice 9876");
     }
+
+    #[test]
+    fn test_template_materialization_of_reminder() {
+        let data = PreparedData::sample();
+        let due_date = *data.information().due_date();
+        let data = data
+            .to_reminder(
+                Date::from(due_date.to_datetime() + chrono::Duration::days(10)),
+                false,
+            )
+            .unwrap();
+        let (subject, body) = Template::default().materialize(&data);
+        assert_eq!(
+            subject,
+            "Payment reminder: Invoice 9876 from Lupin et Associés"
+        );
+        assert!(body.contains("invoice 9876"), "{body}");
+    }
+
+    #[test]
+    fn test_deserialize_without_reminder_formats() {
+        let template: Template = ron::from_str(
+            r#"(subject_format: "Invoice <INV_NO>", body_format: "Please pay <INV_NO>")"#,
+        )
+        .unwrap();
+        assert_eq!(
+            *template.reminder_subject_format(),
+            TemplatePart::reminder_subject()
+        );
+    }
 }
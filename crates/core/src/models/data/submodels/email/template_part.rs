@@ -21,14 +21,34 @@ impl TemplatePart {
     const VENDOR: &str = "<FROM_CO>";
     const CLIENT: &str = "<TO_CO>";
     const INVOICE_DATE: &str = "<INV_DATE>";
+    const TOTAL: &str = "<TOTAL>";
+
+    /// The default subject of emails sending a payment reminder.
+    pub fn reminder_subject() -> Self {
+        Self(format!(
+            "Payment reminder: Invoice {} from {}",
+            Self::NUMBER,
+            Self::VENDOR
+        ))
+    }
+
+    /// The default body of emails sending a payment reminder.
+    pub fn reminder_body() -> Self {
+        Self(format!(
+            "Our invoice {} of {} is overdue, please find a payment reminder attached.",
+            Self::NUMBER,
+            Self::INVOICE_DATE
+        ))
+    }
 
     pub fn tutorial() -> String {
         format!(
-            "Placeholders: '{}', '{}', '{}', '{}'. Example: 'Invoice {} from {}' → 'Invoice 42 from Lupin et Associés'. Placeholders are case-sensitive and must include '<' and '>'.",
+            "Placeholders: '{}', '{}', '{}', '{}', '{}'. Example: 'Invoice {} from {}' → 'Invoice 42 from Lupin et Associés'. Placeholders are case-sensitive and must include '<' and '>'.",
             Self::NUMBER,
             Self::VENDOR,
             Self::CLIENT,
             Self::INVOICE_DATE,
+            Self::TOTAL,
             Self::NUMBER,
             Self::VENDOR
         )
@@ -46,6 +66,15 @@ impl TemplatePart {
             Self::INVOICE_DATE,
             data.information().invoice_date().to_string().as_str(),
         );
+        raw = raw.replace(
+            Self::TOTAL,
+            format!(
+                "{:.2} {}",
+                *data.amount_due(),
+                data.payment_info().currency()
+            )
+            .as_str(),
+        );
 
         #[cfg(debug_assertions)]
         {
@@ -84,6 +113,26 @@ mod tests {
         assert!(tutorial.contains(TemplatePart::VENDOR));
         assert!(tutorial.contains(TemplatePart::CLIENT));
         assert!(tutorial.contains(TemplatePart::INVOICE_DATE));
+        assert!(tutorial.contains(TemplatePart::TOTAL));
+    }
+
+    #[test]
+    fn test_replace_total() {
+        let template = TemplatePart::from("Amount due: <TOTAL>");
+        let data = PreparedData::builder()
+            .information(InvoiceInfoFull::sample())
+            .vendor(CompanyInformation::sample_vendor())
+            .client(CompanyInformation::sample_client())
+            .line_items(LineItemsFlat::sample_taxed())
+            .payment_info(PaymentInformation::sample())
+            .tax(Tax::sample())
+            .output_path(OutputPath::Name("invoice.pdf".into()))
+            .build();
+        let result = template.materialize(&data);
+        assert_eq!(
+            result,
+            format!("Amount due: 437.50 {}", data.payment_info().currency())
+        );
     }
 
     #[test]
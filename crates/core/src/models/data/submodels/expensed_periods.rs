@@ -84,6 +84,42 @@ impl<Period: IsPeriod> ExpensedPeriods<Period> {
         }
     }
 
+    /// Retrieves the expenses of all periods with a transaction date within
+    /// the `date_range`.
+    ///
+    /// # Errors
+    /// Returns an error if no expenses have been recorded within the range.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let expensed_months = ExpensedPeriods::new(IndexMap::from_iter([
+    ///     (YearAndMonth::may(2025), vec![Item::sample_expense_breakfast()]), // 2025-05-20
+    ///     (YearAndMonth::june(2025), vec![Item::sample_expense_coffee()]), // 2025-05-31
+    /// ]));
+    /// let date_range = DateRange::builder()
+    ///     .start(Date::from_str("2025-05-25").unwrap())
+    ///     .end(Date::from_str("2025-06-15").unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// let expenses = expensed_months.get_in_date_range(&date_range).unwrap();
+    /// assert_eq!(expenses, vec![Item::sample_expense_coffee()]);
+    /// ```
+    pub fn get_in_date_range(&self, date_range: &DateRange) -> Result<Vec<Item>> {
+        let items = self
+            .expenses_for_periods()
+            .values()
+            .flat_map(|expenses| expenses.items())
+            .filter(|item| date_range.contains(item.transaction_date()))
+            .collect::<Vec<_>>();
+        if items.is_empty() {
+            return Err(Error::TargetPeriodMustHaveExpenses {
+                target_period: date_range.to_string(),
+            });
+        }
+        Ok(items)
+    }
+
     /// Inserts expenses for a specific month into the collection of expenses.
     /// If the month already exists, it merges the new items with the existing ones,
     /// ensuring that items that are the same except for their quantity are combined.
@@ -113,6 +149,60 @@ impl<Period: IsPeriod> ExpensedPeriods<Period> {
             }
         }
     }
+
+    /// Removes the expense addressed by `selector` from the expenses recorded
+    /// for `period` and returns it. The period is removed once it has no
+    /// expenses left.
+    ///
+    /// # Errors
+    /// Returns an error if the period has no expenses recorded, or if no
+    /// single expense is addressed by `selector`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let month = YearAndMonth::may(2025);
+    /// let mut expensed_months = ExpensedPeriods::new(IndexMap::from_iter([
+    ///     (month, vec![Item::sample_expense_breakfast(), Item::sample_expense_coffee()]),
+    /// ]));
+    /// let removed = expensed_months.remove_expense(&month, &ExpenseSelector::Index(1)).unwrap();
+    /// assert_eq!(removed, Item::sample_expense_breakfast());
+    /// assert_eq!(expensed_months.get(&month).unwrap(), vec![Item::sample_expense_coffee()]);
+    /// ```
+    pub fn remove_expense(&mut self, period: &Period, selector: &ExpenseSelector) -> Result<Item> {
+        let index = selector.index_in(&self.get(period)?, period)?;
+        let expenses = self
+            .expenses_for_periods
+            .get_mut(period)
+            .expect("Period has expenses");
+        let removed = expenses.remove(index);
+        if expenses.is_empty() {
+            self.expenses_for_periods.shift_remove(period);
+        }
+        Ok(removed)
+    }
+
+    /// Replaces the expense addressed by `selector` among the expenses
+    /// recorded for `period` with `item`, merging it with any other expense
+    /// that is the same except for its quantity, and returns the replaced
+    /// expense.
+    ///
+    /// # Errors
+    /// Returns an error if the period has no expenses recorded, or if no
+    /// single expense is addressed by `selector`.
+    pub fn replace_expense(
+        &mut self,
+        period: &Period,
+        selector: &ExpenseSelector,
+        item: Item,
+    ) -> Result<Item> {
+        let index = selector.index_in(&self.get(period)?, period)?;
+        let expenses = self
+            .expenses_for_periods
+            .get_mut(period)
+            .expect("Period has expenses");
+        Ok(expenses.replace(index, item))
+    }
 }
 
 #[cfg(test)]
@@ -230,4 +320,50 @@ mod tests {
         let expensed_periods = ExpensedPeriods::<YearAndMonth>::default();
         assert!(expensed_periods.expenses_for_periods.is_empty());
     }
+
+    #[test]
+    fn test_remove_last_expense_removes_period() {
+        let month = YearAndMonth::may(2025);
+        let mut sut = Sut::new(IndexMap::from_iter([(
+            month,
+            vec![Item::sample_expense_coffee()],
+        )]));
+        let selector = ExpenseSelector::Name("coffee".to_owned());
+        assert_eq!(
+            sut.remove_expense(&month, &selector).unwrap(),
+            Item::sample_expense_coffee()
+        );
+        assert!(!sut.contains(&month));
+        assert!(matches!(
+            sut.remove_expense(&month, &selector),
+            Err(Error::TargetPeriodMustHaveExpenses { .. })
+        ));
+    }
+
+    #[test]
+    fn test_replace_expense_merges_identical() {
+        let month = YearAndMonth::may(2025);
+        let coffee = Item::sample_expense_coffee();
+        let mut sut = Sut::new(IndexMap::from_iter([(
+            month,
+            vec![
+                coffee.clone(),
+                Item::sample_expense_breakfast(),
+                coffee
+                    .clone()
+                    .with_receipt(Some(PathBuf::from("coffee.jpg"))),
+            ],
+        )]));
+        let replaced = sut
+            .replace_expense(&month, &ExpenseSelector::Index(3), coffee.clone())
+            .unwrap();
+        assert_eq!(replaced.receipt(), &Some(PathBuf::from("coffee.jpg")));
+        let expenses = sut.get(&month).unwrap();
+        assert_eq!(expenses.len(), 2);
+        assert_eq!(*expenses[0].quantity(), Quantity::from(dec!(4.0)));
+        assert!(matches!(
+            sut.replace_expense(&month, &ExpenseSelector::Index(3), coffee),
+            Err(Error::NoExpenseMatching { .. })
+        ));
+    }
 }
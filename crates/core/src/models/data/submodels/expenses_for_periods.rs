@@ -26,6 +26,9 @@ impl ExpensesForPeriods {
                 .unit_price(*item.unit_price())
                 .currency(*item.currency())
                 .quantity(QuantityIgnored)
+                .maybe_tax_rate(*item.tax_rate())
+                .maybe_discount(*item.discount())
+                .maybe_receipt(item.receipt().clone())
                 .build();
 
             map.entry(identifier)
@@ -42,6 +45,9 @@ impl ExpensesForPeriods {
                 .unit_price(*identifier.unit_price())
                 .currency(*identifier.currency())
                 .quantity(quantity)
+                .maybe_tax_rate(*identifier.tax_rate())
+                .maybe_discount(*identifier.discount())
+                .maybe_receipt(identifier.receipt().clone())
                 .build();
             self.0.push(item);
         }
@@ -59,4 +65,23 @@ impl ExpensesForPeriods {
     pub(super) fn items(&self) -> Vec<Item> {
         self.0.clone()
     }
+
+    /// Removes and returns the item at `index`.
+    pub(super) fn remove(&mut self, index: usize) -> Item {
+        self.0.remove(index)
+    }
+
+    /// Replaces the item at `index` with `item`, merging it with any other item
+    /// that is the same except for its quantity, and returns the replaced item.
+    pub(super) fn replace(&mut self, index: usize, item: Item) -> Item {
+        let mut items = self.items();
+        let replaced = std::mem::replace(&mut items[index], item);
+        *self = Self::new(items);
+        replaced
+    }
+
+    /// Returns true if there are no items.
+    pub(super) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 }
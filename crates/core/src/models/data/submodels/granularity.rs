@@ -3,7 +3,7 @@ use derive_more::FromStr;
 use crate::prelude::*;
 
 /// The granularity of invoiced quantity, i.e. if you are invoicing fixed rate
-/// per month, per day or per hour.
+/// per year, per quarter, per month, per day or per hour.
 #[derive(
     Clone,
     Copy,
@@ -26,6 +26,8 @@ pub enum Granularity {
     Day,
     Fortnight,
     Month,
+    Quarter,
+    Year,
 }
 
 impl Granularity {
@@ -35,6 +37,8 @@ impl Granularity {
             Self::Day => "$1,000",
             Self::Fortnight => "$9,000",
             Self::Month => "$15,000",
+            Self::Quarter => "$45,000",
+            Self::Year => "$180,000",
         }
         .to_owned()
     }
@@ -72,6 +76,8 @@ mod tests {
         assert!(!Sut::Month.example_rate().is_empty());
         assert!(!Sut::Fortnight.example_rate().is_empty());
         assert!(!Sut::Hour.example_rate().is_empty());
+        assert!(!Sut::Quarter.example_rate().is_empty());
+        assert!(!Sut::Year.example_rate().is_empty());
     }
 
     #[test]
@@ -85,5 +91,8 @@ mod tests {
         assert!(Sut::Day > Sut::Hour);
         assert!(Sut::Hour < Sut::Day);
         assert!(Sut::Hour < Sut::Month);
+
+        assert!(Sut::Quarter > Sut::Month);
+        assert!(Sut::Year > Sut::Quarter);
     }
 }
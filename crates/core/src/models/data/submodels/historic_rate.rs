@@ -0,0 +1,58 @@
+use crate::prelude::*;
+
+/// A rate of the service fees which was in effect until a date, after which
+/// a later rate took effect, e.g. `1000` EUR per day until `2025-03-31`
+/// before a yearly price increase.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Builder, Getters)]
+pub struct HistoricRate {
+    /// The rate which was in effect, e.g. `Daily(UnitPrice(1000.0))`.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    rate: Rate,
+
+    /// The last day the rate was in effect, inclusive, e.g. `2025-03-31`.
+    #[getset(get = "pub")]
+    until: Date,
+}
+
+impl HasSample for HistoricRate {
+    fn sample() -> Self {
+        Self::builder()
+            .rate(Rate::daily(dec!(700.0)))
+            .until(Date::from_str("2025-03-31").expect("Valid date"))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .rate(Rate::hourly(dec!(120.0)))
+            .until(Date::from_str("2024-12-31").expect("Valid date"))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = HistoricRate;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let sut = Sut::sample();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron::from_str::<Sut>(&ron).unwrap(), sut);
+    }
+}
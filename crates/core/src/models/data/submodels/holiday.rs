@@ -0,0 +1,55 @@
+use crate::prelude::*;
+
+/// A public holiday on a specific date, e.g. `2025-06-20 Midsummer Eve`.
+#[derive(Clone, Debug, Display, Serialize, Deserialize, PartialEq, Eq, Hash, Builder, Getters)]
+#[display("{date} {name}")]
+pub struct Holiday {
+    /// The date the holiday is observed, e.g. `2025-12-25`.
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// The name of the holiday, e.g. `"Christmas Day"`.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    name: String,
+}
+
+impl HasSample for Holiday {
+    fn sample() -> Self {
+        Self::builder()
+            .date(Date::from_str("2025-06-20").expect("Valid date"))
+            .name("Midsummer Eve")
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .date(Date::from_str("2025-12-25").expect("Valid date"))
+            .name("Christmas Day")
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Holiday;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Sut::sample().to_string(), "2025-06-20 Midsummer Eve");
+    }
+}
@@ -0,0 +1,526 @@
+use derive_more::FromStr;
+
+use crate::prelude::*;
+
+/// A rule based calendar of public holidays for a country, used to exclude
+/// holidays falling on working days when calculating the quantity of days
+/// or hours worked in a period.
+#[derive(
+    Clone, Copy, Debug, Display, FromStr, Serialize, Deserialize, PartialEq, Eq, Hash, EnumIter,
+)]
+pub enum HolidayCalendar {
+    /// 🇸🇪 Sweden, including the de facto holidays Midsummer Eve, Christmas
+    /// Eve and New Year's Eve.
+    SE,
+    /// 🇬🇧 United Kingdom (England and Wales bank holidays), with substitute
+    /// days for holidays falling on a weekend.
+    UK,
+    /// 🇩🇪 Germany, nationwide holidays only.
+    DE,
+    /// 🇫🇷 France
+    FR,
+    /// 🇺🇸 United States federal holidays, observed on the closest weekday
+    /// when falling on a weekend.
+    US,
+}
+
+/// How a holiday falling on a weekend is moved, if at all.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Observance {
+    /// The holiday is not moved.
+    Actual,
+    /// The holiday is moved to the next weekday which is not already a holiday.
+    SubstituteNextWeekday,
+    /// Saturday holidays are observed on Friday, Sunday holidays on Monday.
+    NearestWeekday,
+}
+
+/// How to find the date of a holiday in a given year.
+#[derive(Clone, Copy, Debug)]
+enum RuleDate {
+    /// Same date every year, e.g. `(12, 25)` for Christmas Day.
+    Fixed { month: u32, day: u32 },
+    /// A number of days relative to Easter Sunday, e.g. `-2` for Good Friday.
+    EasterOffset(i64),
+    /// The `nth` (1 based) `weekday` of a month, e.g. first Monday of May.
+    NthWeekday {
+        month: u32,
+        weekday: Weekday,
+        nth: u32,
+    },
+    /// The last `weekday` of a month, e.g. last Monday of May.
+    LastWeekday { month: u32, weekday: Weekday },
+    /// The first `weekday` on or after a date, e.g. the Friday between 19th
+    /// and 25th of June for Midsummer Eve.
+    FirstWeekdayFrom {
+        month: u32,
+        day: u32,
+        weekday: Weekday,
+    },
+}
+
+#[derive(Clone, Copy, Debug)]
+struct HolidayRule {
+    name: &'static str,
+    date: RuleDate,
+    observance: Observance,
+    /// The first year the holiday was celebrated, if recent.
+    since: Option<i32>,
+}
+
+impl HolidayRule {
+    const fn new(name: &'static str, date: RuleDate) -> Self {
+        Self {
+            name,
+            date,
+            observance: Observance::Actual,
+            since: None,
+        }
+    }
+
+    const fn observed(self, observance: Observance) -> Self {
+        Self { observance, ..self }
+    }
+
+    const fn since(self, year: i32) -> Self {
+        Self {
+            since: Some(year),
+            ..self
+        }
+    }
+
+    fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        if self.since.is_some_and(|since| year < since) {
+            return None;
+        }
+        match self.date {
+            RuleDate::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            RuleDate::EasterOffset(offset) => {
+                let easter = Year::from(year).easter_sunday().to_datetime().date();
+                easter.checked_add_signed(chrono::Duration::days(offset))
+            }
+            RuleDate::NthWeekday {
+                month,
+                weekday,
+                nth,
+            } => NaiveDate::from_weekday_of_month_opt(year, month, weekday, nth as u8),
+            RuleDate::LastWeekday { month, weekday } => {
+                let (next_year, next_month) = if month == 12 {
+                    (year + 1, 1)
+                } else {
+                    (year, month + 1)
+                };
+                let mut date = NaiveDate::from_ymd_opt(next_year, next_month, 1)?.pred_opt()?;
+                while date.weekday() != weekday {
+                    date = date.pred_opt()?;
+                }
+                Some(date)
+            }
+            RuleDate::FirstWeekdayFrom {
+                month,
+                day,
+                weekday,
+            } => {
+                let mut date = NaiveDate::from_ymd_opt(year, month, day)?;
+                while date.weekday() != weekday {
+                    date = date.succ_opt()?;
+                }
+                Some(date)
+            }
+        }
+    }
+}
+
+fn is_weekend(date: &NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+impl HolidayCalendar {
+    fn rules(&self) -> Vec<HolidayRule> {
+        use Observance::*;
+        use RuleDate::*;
+        let fixed = |name, month, day| HolidayRule::new(name, Fixed { month, day });
+        let easter = |name, offset| HolidayRule::new(name, EasterOffset(offset));
+        let nth = |name, month, weekday, nth| {
+            HolidayRule::new(
+                name,
+                NthWeekday {
+                    month,
+                    weekday,
+                    nth,
+                },
+            )
+        };
+        let last = |name, month, weekday| HolidayRule::new(name, LastWeekday { month, weekday });
+        let first_from = |name, month, day, weekday| {
+            HolidayRule::new(
+                name,
+                FirstWeekdayFrom {
+                    month,
+                    day,
+                    weekday,
+                },
+            )
+        };
+        match self {
+            Self::SE => vec![
+                fixed("New Year's Day", 1, 1),
+                fixed("Epiphany", 1, 6),
+                easter("Good Friday", -2),
+                easter("Easter Sunday", 0),
+                easter("Easter Monday", 1),
+                fixed("May Day", 5, 1),
+                easter("Ascension Day", 39),
+                easter("Whit Sunday", 49),
+                fixed("National Day of Sweden", 6, 6),
+                first_from("Midsummer Eve", 6, 19, Weekday::Fri),
+                first_from("Midsummer Day", 6, 20, Weekday::Sat),
+                first_from("All Saints' Day", 10, 31, Weekday::Sat),
+                fixed("Christmas Eve", 12, 24),
+                fixed("Christmas Day", 12, 25),
+                fixed("Boxing Day", 12, 26),
+                fixed("New Year's Eve", 12, 31),
+            ],
+            Self::UK => vec![
+                fixed("New Year's Day", 1, 1).observed(SubstituteNextWeekday),
+                easter("Good Friday", -2),
+                easter("Easter Monday", 1),
+                nth("Early May bank holiday", 5, Weekday::Mon, 1),
+                last("Spring bank holiday", 5, Weekday::Mon),
+                last("Summer bank holiday", 8, Weekday::Mon),
+                fixed("Christmas Day", 12, 25).observed(SubstituteNextWeekday),
+                fixed("Boxing Day", 12, 26).observed(SubstituteNextWeekday),
+            ],
+            Self::DE => vec![
+                fixed("New Year's Day", 1, 1),
+                easter("Good Friday", -2),
+                easter("Easter Monday", 1),
+                fixed("Labour Day", 5, 1),
+                easter("Ascension Day", 39),
+                easter("Whit Monday", 50),
+                fixed("German Unity Day", 10, 3),
+                fixed("Christmas Day", 12, 25),
+                fixed("Second Day of Christmas", 12, 26),
+            ],
+            Self::FR => vec![
+                fixed("New Year's Day", 1, 1),
+                easter("Easter Monday", 1),
+                fixed("Labour Day", 5, 1),
+                fixed("Victory in Europe Day", 5, 8),
+                easter("Ascension Day", 39),
+                easter("Whit Monday", 50),
+                fixed("Bastille Day", 7, 14),
+                fixed("Assumption of Mary", 8, 15),
+                fixed("All Saints' Day", 11, 1),
+                fixed("Armistice Day", 11, 11),
+                fixed("Christmas Day", 12, 25),
+            ],
+            Self::US => vec![
+                fixed("New Year's Day", 1, 1).observed(NearestWeekday),
+                nth("Martin Luther King Jr. Day", 1, Weekday::Mon, 3),
+                nth("Washington's Birthday", 2, Weekday::Mon, 3),
+                last("Memorial Day", 5, Weekday::Mon),
+                fixed("Juneteenth", 6, 19)
+                    .observed(NearestWeekday)
+                    .since(2021),
+                fixed("Independence Day", 7, 4).observed(NearestWeekday),
+                nth("Labor Day", 9, Weekday::Mon, 1),
+                nth("Columbus Day", 10, Weekday::Mon, 2),
+                fixed("Veterans Day", 11, 11).observed(NearestWeekday),
+                nth("Thanksgiving Day", 11, Weekday::Thu, 4),
+                fixed("Christmas Day", 12, 25).observed(NearestWeekday),
+            ],
+        }
+    }
+
+    /// Returns all holidays of this calendar celebrated in `year`, sorted by
+    /// date, including holidays falling on a weekend. Holidays observed on
+    /// another day than the actual date - e.g. substitute bank holidays in
+    /// the UK - are returned with the observed date.
+    ///
+    /// N.B. the observed date might fall in an adjacent year, e.g. in the US
+    /// New Year's Day 2022 was observed on Friday 2021-12-31.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    ///
+    /// let holidays = HolidayCalendar::SE.holidays_in_year(2025);
+    /// let midsummer_eve = holidays
+    ///     .iter()
+    ///     .find(|h| h.name() == "Midsummer Eve")
+    ///     .unwrap();
+    /// assert_eq!(midsummer_eve.date().to_string(), "2025-06-20");
+    /// ```
+    pub fn holidays_in_year(&self, year: i32) -> Vec<Holiday> {
+        let actual = self
+            .rules()
+            .into_iter()
+            .filter_map(|rule| rule.date_in(year).map(|date| (rule, date)))
+            .collect::<Vec<_>>();
+
+        let mut taken = actual
+            .iter()
+            .map(|(_, date)| *date)
+            .filter(|date| !is_weekend(date))
+            .collect::<IndexSet<_>>();
+
+        let mut holidays = actual
+            .iter()
+            .filter_map(|(rule, date)| {
+                let observed = match rule.observance {
+                    Observance::Actual => *date,
+                    _ if !is_weekend(date) => *date,
+                    Observance::NearestWeekday => match date.weekday() {
+                        Weekday::Sat => date.pred_opt()?,
+                        _ => date.succ_opt()?,
+                    },
+                    Observance::SubstituteNextWeekday => {
+                        let mut candidate = date.succ_opt()?;
+                        while is_weekend(&candidate) || taken.contains(&candidate) {
+                            candidate = candidate.succ_opt()?;
+                        }
+                        taken.insert(candidate);
+                        candidate
+                    }
+                };
+                let name = if observed == *date {
+                    rule.name.to_owned()
+                } else {
+                    format!("{} (observed)", rule.name)
+                };
+                Some(
+                    Holiday::builder()
+                        .date(Date::from(observed))
+                        .name(name)
+                        .build(),
+                )
+            })
+            .collect::<Vec<_>>();
+        holidays.sort_by_key(|holiday| *holiday.date());
+        holidays
+    }
+
+    /// Returns all holidays of this calendar observed between `start` and
+    /// `end`, both inclusive, sorted by date, including holidays falling on
+    /// a weekend.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    ///
+    /// let holidays = HolidayCalendar::UK.holidays_between(
+    ///     &Date::from_str("2025-12-01").unwrap(),
+    ///     &Date::from_str("2025-12-31").unwrap(),
+    /// );
+    /// assert_eq!(holidays.len(), 2); // Christmas Day and Boxing Day
+    /// ```
+    pub fn holidays_between(&self, start: &Date, end: &Date) -> Vec<Holiday> {
+        let first_year = **start.year() as i32 - 1;
+        let last_year = **end.year() as i32 + 1;
+        (first_year..=last_year)
+            .flat_map(|year| self.holidays_in_year(year))
+            .filter(|holiday| holiday.date() >= start && holiday.date() <= end)
+            .collect()
+    }
+}
+
+impl HasSample for HolidayCalendar {
+    fn sample() -> Self {
+        Self::SE
+    }
+
+    fn sample_other() -> Self {
+        Self::UK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = HolidayCalendar;
+
+    fn dates_of(calendar: Sut, year: i32) -> Vec<String> {
+        calendar
+            .holidays_in_year(year)
+            .iter()
+            .map(|h| h.date().to_string())
+            .collect()
+    }
+
+    fn date_of(calendar: Sut, year: i32, name: &str) -> String {
+        calendar
+            .holidays_in_year(year)
+            .into_iter()
+            .find(|h| h.name() == name)
+            .unwrap_or_else(|| panic!("Expected {name} in {calendar} {year}"))
+            .date()
+            .to_string()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Sut::from_str("DE").unwrap(), Sut::DE);
+        assert!(Sut::from_str("XX").is_err());
+    }
+
+    #[test]
+    fn sweden_2025() {
+        assert_eq!(
+            dates_of(Sut::SE, 2025),
+            [
+                "2025-01-01",
+                "2025-01-06",
+                "2025-04-18",
+                "2025-04-20",
+                "2025-04-21",
+                "2025-05-01",
+                "2025-05-29",
+                "2025-06-06",
+                "2025-06-08",
+                "2025-06-20",
+                "2025-06-21",
+                "2025-11-01",
+                "2025-12-24",
+                "2025-12-25",
+                "2025-12-26",
+                "2025-12-31",
+            ]
+        );
+    }
+
+    #[test]
+    fn germany_2024() {
+        assert_eq!(
+            dates_of(Sut::DE, 2024),
+            [
+                "2024-01-01",
+                "2024-03-29",
+                "2024-04-01",
+                "2024-05-01",
+                "2024-05-09",
+                "2024-05-20",
+                "2024-10-03",
+                "2024-12-25",
+                "2024-12-26",
+            ]
+        );
+    }
+
+    #[test]
+    fn france_2025() {
+        assert_eq!(date_of(Sut::FR, 2025, "Ascension Day"), "2025-05-29");
+        assert_eq!(date_of(Sut::FR, 2025, "Whit Monday"), "2025-06-09");
+        assert_eq!(Sut::FR.holidays_in_year(2025).len(), 11);
+    }
+
+    #[test]
+    fn uk_substitute_days_when_christmas_on_saturday() {
+        // 2021-12-25 is a Saturday and 2021-12-26 a Sunday
+        assert_eq!(
+            date_of(Sut::UK, 2021, "Christmas Day (observed)"),
+            "2021-12-27"
+        );
+        assert_eq!(
+            date_of(Sut::UK, 2021, "Boxing Day (observed)"),
+            "2021-12-28"
+        );
+    }
+
+    #[test]
+    fn uk_substitute_day_when_christmas_on_sunday() {
+        // 2022-12-25 is a Sunday, Boxing Day is on Monday
+        assert_eq!(date_of(Sut::UK, 2022, "Boxing Day"), "2022-12-26");
+        assert_eq!(
+            date_of(Sut::UK, 2022, "Christmas Day (observed)"),
+            "2022-12-27"
+        );
+    }
+
+    #[test]
+    fn uk_2025_bank_holidays() {
+        assert_eq!(
+            dates_of(Sut::UK, 2025),
+            [
+                "2025-01-01",
+                "2025-04-18",
+                "2025-04-21",
+                "2025-05-05",
+                "2025-05-26",
+                "2025-08-25",
+                "2025-12-25",
+                "2025-12-26",
+            ]
+        );
+    }
+
+    #[test]
+    fn us_2025_federal_holidays() {
+        assert_eq!(
+            dates_of(Sut::US, 2025),
+            [
+                "2025-01-01",
+                "2025-01-20",
+                "2025-02-17",
+                "2025-05-26",
+                "2025-06-19",
+                "2025-07-04",
+                "2025-09-01",
+                "2025-10-13",
+                "2025-11-11",
+                "2025-11-27",
+                "2025-12-25",
+            ]
+        );
+    }
+
+    #[test]
+    fn us_observed_on_friday_when_on_saturday() {
+        // 2026-07-04 is a Saturday
+        assert_eq!(
+            date_of(Sut::US, 2026, "Independence Day (observed)"),
+            "2026-07-03"
+        );
+    }
+
+    #[test]
+    fn us_juneteenth_not_before_2021() {
+        assert!(
+            !Sut::US
+                .holidays_in_year(2020)
+                .iter()
+                .any(|h| h.name().starts_with("Juneteenth"))
+        );
+    }
+
+    #[test]
+    fn holidays_between_includes_observed_date_in_previous_year() {
+        // 2022-01-01 is a Saturday, observed on Friday 2021-12-31
+        let holidays = Sut::US.holidays_between(
+            &Date::from_str("2021-12-01").unwrap(),
+            &Date::from_str("2021-12-31").unwrap(),
+        );
+        assert_eq!(
+            holidays.iter().map(|h| h.to_string()).collect::<Vec<_>>(),
+            [
+                "2021-12-24 Christmas Day (observed)",
+                "2021-12-31 New Year's Day (observed)"
+            ]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::prelude::*;
+
+/// How an [`InvoiceNumber`] is printed on the invoice, in the PDF file name
+/// and in emails, e.g. `LUP-2025-0042` for prefix `"LUP-"`, the year included
+/// and zero padding to 4 digits.
+///
+/// The default format prints the bare number, e.g. `42`.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    Builder,
+    Getters,
+    WithSetters,
+)]
+pub struct InvoiceNumberFormat {
+    /// Text printed before the number, e.g. `"LUP-"`.
+    #[serde(default)]
+    #[builder(default, into)]
+    #[getset(get = "pub", set_with = "pub")]
+    prefix: String,
+
+    /// Whether the (fiscal) year of the invoiced period is printed after the
+    /// prefix, followed by a dash, e.g. `2025-`.
+    #[serde(default)]
+    #[builder(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    include_year: bool,
+
+    /// The minimum number of digits of the number, padded with leading
+    /// zeros, e.g. `4` for `0042`.
+    #[serde(default)]
+    #[builder(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    padding: u8,
+
+    /// Text printed after the number, e.g. `"-B"` for a separate series.
+    #[serde(default)]
+    #[builder(default, into)]
+    #[getset(get = "pub", set_with = "pub")]
+    suffix: String,
+}
+
+impl InvoiceNumberFormat {
+    /// The default format of credit notes, a separate series from invoices
+    /// with the prefix `"CN-"`, e.g. `CN-7`.
+    pub fn credit_note() -> Self {
+        Self::builder().prefix("CN-").build()
+    }
+
+    /// The default format of invoices billing milestones of fixed-price
+    /// contracts, a separate series from invoices with the prefix `"MS-"`,
+    /// e.g. `MS-3`.
+    pub fn milestone() -> Self {
+        Self::builder().prefix("MS-").build()
+    }
+
+    /// A format of invoices of expenses in a separate series from invoices of
+    /// services: this format followed by `"-E"`, e.g. `42-E`, so that the
+    /// number of the expenses of a period, which follows the number of its
+    /// services, never is that of the services of the next period.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let format = InvoiceNumberFormat::builder().prefix("LUP-").build().for_expenses();
+    /// let formatted = format.format(&InvoiceNumber::from(42), &Year::from(2025));
+    /// assert_eq!(formatted.to_string(), "LUP-42-E");
+    /// ```
+    pub fn for_expenses(&self) -> Self {
+        self.clone().with_suffix(format!("{}-E", self.suffix))
+    }
+
+    /// Formats `number` according to this format, using `year` if the year
+    /// is included.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let format = InvoiceNumberFormat::builder()
+    ///     .prefix("LUP-")
+    ///     .include_year(true)
+    ///     .padding(4)
+    ///     .build();
+    /// let formatted = format.format(&InvoiceNumber::from(42), &Year::from(2025));
+    /// assert_eq!(formatted.to_string(), "LUP-2025-0042");
+    ///
+    /// let formatted = InvoiceNumberFormat::default().format(&InvoiceNumber::from(42), &Year::from(2025));
+    /// assert_eq!(formatted.to_string(), "42");
+    /// ```
+    pub fn format(&self, number: &InvoiceNumber, year: &Year) -> FormattedInvoiceNumber {
+        let year = if self.include_year {
+            format!("{}-", year)
+        } else {
+            String::new()
+        };
+        FormattedInvoiceNumber::from(format!(
+            "{}{}{:0>width$}{}",
+            self.prefix,
+            year,
+            **number,
+            self.suffix,
+            width = self.padding as usize
+        ))
+    }
+}
+
+impl HasSample for InvoiceNumberFormat {
+    fn sample() -> Self {
+        Self::default()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .prefix("LUP-")
+            .include_year(true)
+            .padding(4)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = InvoiceNumberFormat;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn format_default_is_bare_number() {
+        let formatted = Sut::default().format(&InvoiceNumber::from(237), &Year::from(2025));
+        assert_eq!(formatted, FormattedInvoiceNumber::from("237"));
+    }
+
+    #[test]
+    fn format_with_prefix_year_and_padding() {
+        let formatted = Sut::sample_other().format(&InvoiceNumber::from(42), &Year::from(2025));
+        assert_eq!(formatted, FormattedInvoiceNumber::from("LUP-2025-0042"));
+    }
+
+    #[test]
+    fn format_padding_does_not_truncate() {
+        let sut = Sut::default().with_padding(2);
+        let formatted = sut.format(&InvoiceNumber::from(12345), &Year::from(2025));
+        assert_eq!(formatted, FormattedInvoiceNumber::from("12345"));
+    }
+
+    #[test]
+    fn format_with_suffix() {
+        let sut = Sut::default().with_padding(3).with_suffix("-B".to_owned());
+        let formatted = sut.format(&InvoiceNumber::from(7), &Year::from(2025));
+        assert_eq!(formatted, FormattedInvoiceNumber::from("007-B"));
+    }
+
+    #[test]
+    fn deserialize_empty() {
+        let sut: Sut = ron::from_str("()").unwrap();
+        assert_eq!(sut, Sut::default());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::prelude::*;
+
+/// Whether invoice numbers restart at `1` every fiscal year, e.g. for
+/// numbers like `2025-0001`, `2025-0002`, ..., `2026-0001`.
+///
+/// A period belongs to the fiscal year containing its last day, and a fiscal
+/// year is identified by the calendar year in which it starts.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash, IsVariant)]
+pub enum InvoiceNumberReset {
+    /// Invoice numbers keep increasing from the offset, never restarting.
+    #[default]
+    Never,
+
+    /// Invoice numbers restart at `1` for the first invoiced period of every
+    /// fiscal year, starting on the first day of `fiscal_year_start`.
+    Yearly { fiscal_year_start: Month },
+}
+
+impl InvoiceNumberReset {
+    /// The month the fiscal year starts in, January if numbers never reset.
+    fn fiscal_year_start_month(&self) -> Month {
+        match self {
+            Self::Never => Month::January,
+            Self::Yearly { fiscal_year_start } => *fiscal_year_start,
+        }
+    }
+
+    /// The fiscal year containing `date`, identified by the calendar year in
+    /// which it starts. If numbers never reset this is the calendar year.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let reset = InvoiceNumberReset::Yearly { fiscal_year_start: Month::July };
+    /// assert_eq!(reset.fiscal_year(&Date::from_str("2026-03-31").unwrap()), Year::from(2025));
+    /// assert_eq!(reset.fiscal_year(&Date::from_str("2026-07-31").unwrap()), Year::from(2026));
+    /// ```
+    pub fn fiscal_year(&self, date: &Date) -> Year {
+        if date.month() >= &self.fiscal_year_start_month() {
+            *date.year()
+        } else {
+            date.year().one_year_earlier()
+        }
+    }
+
+    /// The first day of the fiscal year containing `date`, or `None` if
+    /// numbers never reset.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let reset = InvoiceNumberReset::Yearly { fiscal_year_start: Month::July };
+    /// let date = Date::from_str("2026-03-31").unwrap();
+    /// assert_eq!(reset.start_of_fiscal_year_containing(&date), Some(Date::from_str("2025-07-01").unwrap()));
+    /// assert_eq!(InvoiceNumberReset::Never.start_of_fiscal_year_containing(&date), None);
+    /// ```
+    pub fn start_of_fiscal_year_containing(&self, date: &Date) -> Option<Date> {
+        match self {
+            Self::Never => None,
+            Self::Yearly { fiscal_year_start } => Some(
+                Date::builder()
+                    .year(self.fiscal_year(date))
+                    .month(*fiscal_year_start)
+                    .day(Day::try_from(1).expect("LEQ 31 days"))
+                    .build(),
+            ),
+        }
+    }
+}
+
+impl HasSample for InvoiceNumberReset {
+    fn sample() -> Self {
+        Self::Never
+    }
+
+    fn sample_other() -> Self {
+        Self::Yearly {
+            fiscal_year_start: Month::July,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = InvoiceNumberReset;
+
+    fn date(s: &str) -> Date {
+        Date::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn fiscal_year_never_is_calendar_year() {
+        assert_eq!(
+            Sut::Never.fiscal_year(&date("2025-01-01")),
+            Year::from(2025)
+        );
+        assert_eq!(
+            Sut::Never.fiscal_year(&date("2025-12-31")),
+            Year::from(2025)
+        );
+    }
+
+    #[test]
+    fn fiscal_year_starting_in_january_is_calendar_year() {
+        let sut = Sut::Yearly {
+            fiscal_year_start: Month::January,
+        };
+        assert_eq!(sut.fiscal_year(&date("2025-01-01")), Year::from(2025));
+        assert_eq!(
+            sut.start_of_fiscal_year_containing(&date("2025-12-31")),
+            Some(date("2025-01-01"))
+        );
+    }
+
+    #[test]
+    fn fiscal_year_starting_in_july() {
+        let sut = Sut::sample_other();
+        assert_eq!(sut.fiscal_year(&date("2025-06-30")), Year::from(2024));
+        assert_eq!(sut.fiscal_year(&date("2025-07-01")), Year::from(2025));
+        assert_eq!(
+            sut.start_of_fiscal_year_containing(&date("2025-06-30")),
+            Some(date("2024-07-01"))
+        );
+    }
+
+    #[test]
+    fn ron_roundtrip() {
+        let sut = Sut::sample_other();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron, "Yearly(fiscal_year_start:July)");
+        assert_eq!(ron::from_str::<Sut>(&ron).unwrap(), sut);
+    }
+}
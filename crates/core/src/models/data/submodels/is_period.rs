@@ -21,7 +21,11 @@ impl<T: Eq + PartialOrd + Hash + Clone + std::fmt::Debug + Into<PeriodAnno> + Tr
 pub trait IsPeriod: PeriodMarker {
     fn max_granularity(&self) -> Granularity;
     fn elapsed_periods_since(&self, start: impl Borrow<Self>) -> Result<u16>;
+    fn to_date_start_of_period(&self) -> Date;
     fn to_date_end_of_period(&self) -> Date;
     fn year(&self) -> &Year;
     fn month(&self) -> &Month;
+    /// The period of the same kind as `self` which contains `date`, e.g. the
+    /// month of `date` for a `YearAndMonth`.
+    fn period_of_same_kind_containing(&self, date: &Date) -> Self;
 }
@@ -0,0 +1,159 @@
+use crate::prelude::*;
+
+/// Statutory late payment interest and reminder fee charged on overdue
+/// invoices, e.g. the reference rate of the central bank plus a margin of
+/// eight percentage points as in the Swedish Interest Act and the EU Late
+/// Payment Directive.
+#[derive(
+    Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Builder, Getters, WithSetters,
+)]
+pub struct LatePayment {
+    /// The reference rate per year, e.g. `2%`, typically set by the central
+    /// bank and updated twice a year.
+    #[serde(default = "LatePayment::default_reference_rate")]
+    #[builder(default = LatePayment::default_reference_rate())]
+    #[getset(get = "pub", set_with = "pub")]
+    reference_rate: Percentage,
+
+    /// The margin added to the reference rate, e.g. `8%`.
+    #[serde(default = "LatePayment::default_margin")]
+    #[builder(default = LatePayment::default_margin())]
+    #[getset(get = "pub", set_with = "pub")]
+    margin: Percentage,
+
+    /// A fixed fee charged for each reminder, in the currency of the invoice,
+    /// e.g. `60` SEK.
+    #[serde(default)]
+    #[builder(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    reminder_fee: Cost,
+}
+
+impl Default for LatePayment {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl LatePayment {
+    fn default_reference_rate() -> Percentage {
+        Percentage::ZERO
+    }
+
+    fn default_margin() -> Percentage {
+        Percentage::try_from(dec!(8)).expect("Valid percentage")
+    }
+
+    /// The late payment interest rate per year, i.e. the reference rate plus
+    /// the margin.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let late_payment = LatePayment::default().with_reference_rate(Percentage::try_from(dec!(2)).unwrap());
+    /// assert_eq!(
+    ///     late_payment.annual_interest_rate().unwrap(),
+    ///     Percentage::try_from(dec!(10)).unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidPercentage` if the reference rate plus the
+    /// margin exceeds `100%`.
+    pub fn annual_interest_rate(&self) -> Result<Percentage> {
+        Percentage::try_from(*self.reference_rate + *self.margin)
+    }
+
+    /// The simple interest on `amount` for `days` days overdue, using the
+    /// actual/365 day count convention, rounded to two decimals.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let late_payment = LatePayment::default().with_reference_rate(Percentage::try_from(dec!(2)).unwrap());
+    /// // 10% per year of 1000 for 73 days
+    /// assert_eq!(*late_payment.interest(&Cost::from(dec!(1000)), 73).unwrap(), dec!(20));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the annual interest rate is invalid, see
+    /// [`LatePayment::annual_interest_rate`].
+    pub fn interest(&self, amount: &Cost, days: u32) -> Result<Cost> {
+        let interest = **amount
+            * self.annual_interest_rate()?.as_fraction()
+            * rust_decimal::Decimal::from(days)
+            / rust_decimal::Decimal::from(365);
+        Ok(Cost::from(interest.round_dp(2)))
+    }
+}
+
+impl HasSample for LatePayment {
+    fn sample() -> Self {
+        Self::default()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .reference_rate(Percentage::try_from(dec!(2)).expect("Valid percentage"))
+            .margin(Percentage::try_from(dec!(8)).expect("Valid percentage"))
+            .reminder_fee(Cost::from(dec!(60)))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = LatePayment;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn default_is_margin_only() {
+        let sut = Sut::default();
+        assert_eq!(
+            sut.annual_interest_rate().unwrap(),
+            Percentage::try_from(dec!(8)).unwrap()
+        );
+        assert_eq!(**sut.reminder_fee(), dec!(0));
+    }
+
+    #[test]
+    fn interest_is_rounded() {
+        let sut = Sut::sample_other();
+        assert_eq!(
+            *sut.interest(&Cost::from(dec!(350)), 31).unwrap(),
+            dec!(2.97)
+        );
+        assert_eq!(*sut.interest(&Cost::from(dec!(350)), 0).unwrap(), dec!(0));
+    }
+
+    #[test]
+    fn annual_interest_rate_above_hundred_is_err() {
+        let sut = Sut::default().with_reference_rate(Percentage::HUNDRED);
+        assert!(matches!(
+            sut.annual_interest_rate(),
+            Err(Error::InvalidPercentage { .. })
+        ));
+    }
+
+    #[test]
+    fn deserialize_with_defaults() {
+        let sut: Sut = ron::from_str("(reference_rate: Percentage(2.5))").unwrap();
+        assert_eq!(
+            sut.annual_interest_rate().unwrap(),
+            Percentage::try_from(dec!(10.5)).unwrap()
+        );
+    }
+}
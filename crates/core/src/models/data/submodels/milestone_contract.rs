@@ -0,0 +1,298 @@
+use crate::prelude::*;
+
+/// A fixed-price contract billed in milestones instead of per period, e.g.
+/// 30% at kickoff, 40% at delivery and 30% at acceptance, each invoiced once
+/// using `klirr invoice milestone <name>`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Getters, WithSetters)]
+pub struct MilestoneContract {
+    /// Description of the contract printed before the name of the milestone,
+    /// e.g. `"Website redesign"`.
+    #[getset(get = "pub", set_with = "pub")]
+    name: String,
+
+    /// The fixed price of the whole contract in the currency of the payment
+    /// information, e.g. `50000`.
+    #[getset(get = "pub", set_with = "pub")]
+    total: Cost,
+
+    /// The milestones of the contract, in the order they are billed.
+    #[getset(get = "pub", set_with = "pub")]
+    milestones: Vec<Milestone>,
+
+    /// The VAT or sales tax rate of the milestones, e.g. `25%`, defaults to
+    /// the tax rate of the service fees.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    tax_rate: Option<Percentage>,
+}
+
+/// A named milestone of a [`MilestoneContract`], e.g. `"Kickoff"` at `30%`
+/// of the contract total.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder, Getters)]
+pub struct Milestone {
+    /// The name of the milestone, used to select it when invoicing, e.g.
+    /// `"Kickoff"`.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    name: String,
+
+    /// The share of the contract total billed at this milestone.
+    #[getset(get = "pub")]
+    share: MilestoneShare,
+}
+
+/// The share of the total of a [`MilestoneContract`] billed at a milestone.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, IsVariant)]
+pub enum MilestoneShare {
+    /// A percentage of the contract total, e.g. `Percentage(Percentage(30.0))`.
+    Percentage(Percentage),
+
+    /// A fixed amount, e.g. `Amount(Cost(15000.0))`.
+    Amount(Cost),
+}
+
+#[bon]
+impl MilestoneContract {
+    /// Creates a new milestone contract.
+    ///
+    /// # Errors
+    /// Returns an error if two milestones have the same name, or if the
+    /// milestones add up to more than the contract total.
+    #[builder]
+    pub fn new(
+        name: impl AsRef<str>,
+        total: impl Into<Cost>,
+        milestones: Vec<Milestone>,
+        tax_rate: Option<Percentage>,
+    ) -> Result<Self> {
+        let contract = Self {
+            name: name.as_ref().to_owned(),
+            total: total.into(),
+            milestones,
+            tax_rate,
+        };
+        contract.validate()?;
+        Ok(contract)
+    }
+}
+
+impl MilestoneContract {
+    /// Validates that the milestones have unique names and do not add up to
+    /// more than the contract total.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidMilestoneContract` if invalid.
+    pub fn validate(&self) -> Result<()> {
+        let names = self
+            .milestones
+            .iter()
+            .map(|milestone| milestone.name())
+            .collect::<IndexSet<_>>();
+        if names.len() != self.milestones.len() {
+            return Err(Error::InvalidMilestoneContract {
+                reason: "Milestones must have unique names".to_owned(),
+            });
+        }
+        let billed = self
+            .milestones
+            .iter()
+            .map(|milestone| *self.amount_of(milestone))
+            .sum::<rust_decimal::Decimal>();
+        if billed > *self.total {
+            return Err(Error::InvalidMilestoneContract {
+                reason: format!(
+                    "Milestones add up to {}, more than the contract total {}",
+                    billed, self.total
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// The amount billed at `milestone`, a percentage of the contract total
+    /// is rounded to two decimals.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let contract = MilestoneContract::sample();
+    /// let kickoff = contract.milestone_named("Kickoff").unwrap();
+    /// assert_eq!(*contract.amount_of(kickoff), dec!(15000));
+    /// ```
+    pub fn amount_of(&self, milestone: &Milestone) -> Cost {
+        match milestone.share() {
+            MilestoneShare::Percentage(percentage) => {
+                Cost::from((*self.total * percentage.as_fraction()).round_dp(2))
+            }
+            MilestoneShare::Amount(amount) => *amount,
+        }
+    }
+
+    /// The milestone named `name`.
+    ///
+    /// # Errors
+    /// Returns `Error::NoMilestoneNamed` if the contract has no such
+    /// milestone.
+    pub fn milestone_named(&self, name: &str) -> Result<&Milestone> {
+        self.milestones
+            .iter()
+            .find(|milestone| milestone.name() == name)
+            .ok_or_else(|| Error::NoMilestoneNamed {
+                name: name.to_owned(),
+                available: self
+                    .milestones
+                    .iter()
+                    .map(|milestone| milestone.name().clone())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            })
+    }
+}
+
+impl HasSample for MilestoneContract {
+    fn sample() -> Self {
+        Self::builder()
+            .name("Website redesign")
+            .total(Cost::from(dec!(50000)))
+            .milestones(vec![
+                Milestone::builder()
+                    .name("Kickoff")
+                    .share(MilestoneShare::Percentage(
+                        Percentage::try_from(dec!(30)).expect("Valid percentage"),
+                    ))
+                    .build(),
+                Milestone::builder()
+                    .name("Delivery")
+                    .share(MilestoneShare::Percentage(
+                        Percentage::try_from(dec!(40)).expect("Valid percentage"),
+                    ))
+                    .build(),
+                Milestone::builder()
+                    .name("Acceptance")
+                    .share(MilestoneShare::Percentage(
+                        Percentage::try_from(dec!(30)).expect("Valid percentage"),
+                    ))
+                    .build(),
+            ])
+            .build()
+            .expect("Sample should be valid")
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .name("Security audit")
+            .total(Cost::from(dec!(12000)))
+            .milestones(vec![
+                Milestone::builder()
+                    .name("Report")
+                    .share(MilestoneShare::Amount(Cost::from(dec!(10000))))
+                    .build(),
+                Milestone::builder()
+                    .name("Retest")
+                    .share(MilestoneShare::Amount(Cost::from(dec!(2000))))
+                    .build(),
+            ])
+            .tax_rate(Percentage::try_from(dec!(25)).expect("Valid percentage"))
+            .build()
+            .expect("Sample should be valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = MilestoneContract;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let sut = Sut::sample_other();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron::from_str::<Sut>(&ron).unwrap(), sut);
+    }
+
+    #[test]
+    fn deserialize_ron() {
+        let ron = r#"
+        MilestoneContract(
+            name: "Website redesign",
+            total: Cost(50000.0),
+            milestones: [
+                Milestone(name: "Kickoff", share: Percentage(Percentage(30.0))),
+                Milestone(name: "Acceptance", share: Amount(Cost(15000.0))),
+            ],
+        )
+        "#;
+        let sut: Sut = ron::de::from_str(ron).unwrap();
+        assert_eq!(*sut.tax_rate(), None);
+        let kickoff = sut.milestone_named("Kickoff").unwrap();
+        assert_eq!(*sut.amount_of(kickoff), dec!(15000));
+    }
+
+    #[test]
+    fn amount_of_amount() {
+        let sut = Sut::sample_other();
+        let retest = sut.milestone_named("Retest").unwrap();
+        assert_eq!(*sut.amount_of(retest), dec!(2000));
+    }
+
+    #[test]
+    fn milestone_named_unknown() {
+        let sut = Sut::sample();
+        let result = sut.milestone_named("Launch");
+        assert!(matches!(result, Err(Error::NoMilestoneNamed { .. })));
+    }
+
+    #[test]
+    fn milestones_must_not_exceed_total() {
+        let result = Sut::builder()
+            .name("Audit")
+            .total(Cost::from(dec!(1000)))
+            .milestones(vec![
+                Milestone::builder()
+                    .name("Report")
+                    .share(MilestoneShare::Percentage(
+                        Percentage::try_from(dec!(80)).unwrap(),
+                    ))
+                    .build(),
+                Milestone::builder()
+                    .name("Retest")
+                    .share(MilestoneShare::Amount(Cost::from(dec!(300))))
+                    .build(),
+            ])
+            .build();
+        assert!(matches!(
+            result,
+            Err(Error::InvalidMilestoneContract { .. })
+        ));
+    }
+
+    #[test]
+    fn milestones_must_have_unique_names() {
+        let milestone = Milestone::builder()
+            .name("Report")
+            .share(MilestoneShare::Amount(Cost::from(dec!(100))))
+            .build();
+        let result = Sut::builder()
+            .name("Audit")
+            .total(Cost::from(dec!(1000)))
+            .milestones(vec![milestone.clone(), milestone])
+            .build();
+        assert!(matches!(
+            result,
+            Err(Error::InvalidMilestoneContract { .. })
+        ));
+    }
+}
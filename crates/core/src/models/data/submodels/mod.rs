@@ -1,13 +1,25 @@
+mod business_day_convention;
 mod cadence;
+mod client_profile;
 mod company_information;
+mod contract_window;
 mod currency;
+mod date_off;
+mod date_range;
 mod email;
 mod expensed_periods;
 mod expenses_for_periods;
 mod footer_text;
 mod granularity;
 mod hex_color;
+mod historic_rate;
+mod holiday;
+mod holiday_calendar;
+mod invoice_number_format;
+mod invoice_number_reset;
 mod is_period;
+mod late_payment;
+mod milestone_contract;
 mod month_half;
 mod net_days;
 mod payment_information;
@@ -16,23 +28,43 @@ mod period_anno;
 mod postal_address;
 mod proto_invoice_info;
 mod purchase_order;
+mod quarter;
 mod rate;
+mod record_of_dates_off;
 mod record_of_periods_off;
+mod retainer;
+mod rolling_fortnight;
 mod service_fees;
+mod service_line;
 mod street_address;
 mod time_off;
 mod timestamped_invoice_number;
+mod work_schedule;
+mod year_and_quarter;
+mod year_and_week;
 mod year_month_and_fortnight;
 
+pub use business_day_convention::*;
 pub use cadence::*;
+pub use client_profile::*;
 pub use company_information::*;
+pub use contract_window::*;
 pub use currency::*;
+pub use date_off::*;
+pub use date_range::*;
 pub use email::*;
 pub use expensed_periods::*;
 pub use footer_text::*;
 pub use granularity::*;
 pub use hex_color::*;
+pub use historic_rate::*;
+pub use holiday::*;
+pub use holiday_calendar::*;
+pub use invoice_number_format::*;
+pub use invoice_number_reset::*;
 pub use is_period::*;
+pub use late_payment::*;
+pub use milestone_contract::*;
 pub use month_half::*;
 pub use net_days::*;
 pub use payment_information::*;
@@ -41,10 +73,18 @@ pub use period_anno::*;
 pub use postal_address::*;
 pub use proto_invoice_info::*;
 pub use purchase_order::*;
+pub use quarter::*;
 pub use rate::*;
+pub use record_of_dates_off::*;
 pub use record_of_periods_off::*;
+pub use retainer::*;
+pub use rolling_fortnight::*;
 pub use service_fees::*;
+pub use service_line::*;
 pub use street_address::*;
 pub use time_off::*;
 pub use timestamped_invoice_number::*;
+pub use work_schedule::*;
+pub use year_and_quarter::*;
+pub use year_and_week::*;
 pub use year_month_and_fortnight::*;
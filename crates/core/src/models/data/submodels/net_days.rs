@@ -1,7 +1,7 @@
 use crate::prelude::*;
 
 /// A typical payment terms structure that includes net payment due in a
-/// specified number of days, e.g. `Net 30`.
+/// specified number of days, e.g. `Net 30` or `Net 90`.
 #[derive(
     Clone,
     Copy,
@@ -17,15 +17,16 @@ use crate::prelude::*;
 )]
 #[display("Net {}", due_in)]
 pub struct NetDays {
-    /// The number of days until payment is due
+    /// The number of days until payment is due, at least one.
     #[getset(get = "pub")]
-    due_in: Day,
+    due_in: u16,
 }
 
 impl FromStr for NetDays {
     type Err = crate::prelude::Error;
 
-    /// Tries to parse a string in the format "Net {days}", e.g. "Net 30".
+    /// Tries to parse a string in the format "Net {days}", e.g. "Net 30", where
+    /// days is a positive number.
     /// /// # Errors
     /// Returns an error if the string is not in the correct format or if
     /// the number of days is invalid.
@@ -34,7 +35,9 @@ impl FromStr for NetDays {
     /// extern crate klirr_core;
     /// use klirr_core::prelude::*;
     /// let net_days: NetDays = "Net 30".parse().unwrap();
-    /// assert_eq!(net_days.due_in(), &Day::try_from(30).unwrap());
+    /// assert_eq!(*net_days.due_in(), 30);
+    /// let net_days: NetDays = "Net 90".parse().unwrap();
+    /// assert_eq!(*net_days.due_in(), 90);
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let days = s
@@ -43,27 +46,27 @@ impl FromStr for NetDays {
             .ok_or(Error::FailedToParsePaymentTermsNetDays {
                 invalid_string: s.to_owned(),
             })?;
-        let days = Day::from_str(days).map_err(|_| Error::FailedToParsePaymentTermsNetDays {
-            invalid_string: s.to_owned(),
-        })?;
+        let days = u16::from_str(days).ok().filter(|days| *days > 0).ok_or(
+            Error::FailedToParsePaymentTermsNetDays {
+                invalid_string: s.to_owned(),
+            },
+        )?;
         Ok(Self::builder().due_in(days).build())
     }
 }
 
 impl NetDays {
     pub fn net30() -> Self {
-        Self::builder()
-            .due_in(Day::try_from(30).expect("LEQ 31 days"))
-            .build()
+        Self::builder().due_in(30).build()
     }
 }
 
 impl HasSample for NetDays {
     fn sample() -> Self {
-        Self::builder().due_in(Day::sample()).build()
+        Self::net30()
     }
     fn sample_other() -> Self {
-        Self::builder().due_in(Day::sample_other()).build()
+        Self::builder().due_in(60).build()
     }
 }
 
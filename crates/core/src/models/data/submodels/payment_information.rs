@@ -26,6 +26,68 @@ pub struct PaymentInformation {
     /// The payment terms of this invoice, e.g. `Net { due_in: 30 }`
     #[getset(get = "pub", set_with = "pub")]
     terms: PaymentTerms,
+
+    /// How to move due dates falling on a weekend or a public holiday,
+    /// unadjusted by default.
+    #[serde(default)]
+    #[builder(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    business_day_convention: BusinessDayConvention,
+
+    /// Late payment interest and reminder fee for overdue invoices, the
+    /// statutory margin of 8% without reference rate or fee by default.
+    #[serde(default)]
+    #[builder(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    late_payment: LatePayment,
+}
+
+impl PaymentInformation {
+    /// The due date of an invoice dated `invoice_date` according to the
+    /// payment terms, moved according to the business day convention if it
+    /// falls on a weekend or a holiday of `holiday_calendar`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let payment_info = PaymentInformation::sample()
+    ///     .with_business_day_convention(BusinessDayConvention::Following);
+    /// let invoice_date = Date::from_str("2025-05-31").unwrap();
+    /// // 30 days later is Monday 2025-06-30
+    /// assert_eq!(payment_info.due_date(&invoice_date, None).to_string(), "2025-06-30");
+    /// let invoice_date = Date::from_str("2025-05-02").unwrap();
+    /// // 30 days later is Sunday 2025-06-01
+    /// assert_eq!(payment_info.due_date(&invoice_date, None).to_string(), "2025-06-02");
+    /// ```
+    pub fn due_date(&self, invoice_date: &Date, holiday_calendar: Option<HolidayCalendar>) -> Date {
+        self.business_day_convention
+            .adjust(invoice_date.advance(&self.terms), holiday_calendar)
+    }
+
+    /// The last day to pay an invoice dated `invoice_date` with the cash
+    /// discount of the payment terms, if any, moved according to the business
+    /// day convention like the due date.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let payment_info = PaymentInformation::sample()
+    ///     .with_terms(PaymentTerms::from_str("2/10 Net 30").unwrap());
+    /// let invoice_date = Date::from_str("2025-05-31").unwrap();
+    /// assert_eq!(payment_info.discount_deadline(&invoice_date, None).unwrap().to_string(), "2025-06-10");
+    /// assert_eq!(PaymentInformation::sample().discount_deadline(&invoice_date, None), None);
+    /// ```
+    pub fn discount_deadline(
+        &self,
+        invoice_date: &Date,
+        holiday_calendar: Option<HolidayCalendar>,
+    ) -> Option<Date> {
+        self.terms.discount_days().map(|days| {
+            let net = PaymentTerms::Net(NetDays::builder().due_in(days).build());
+            self.business_day_convention
+                .adjust(invoice_date.advance(&net), holiday_calendar)
+        })
+    }
 }
 
 impl HasSample for PaymentInformation {
@@ -46,6 +108,7 @@ impl HasSample for PaymentInformation {
             .bic("NWBKGB2L".into())
             .currency(Currency::USD)
             .terms(PaymentTerms::sample_other())
+            .late_payment(LatePayment::sample_other())
             .build()
     }
 }
@@ -1,30 +1,119 @@
 use crate::prelude::*;
 
-/// The payment terms of this invoice, e.g. `Net { due_in: 30 }`
-#[derive(Clone, Debug, Display, Serialize, PartialEq, Eq, Hash, Deserialize)]
-#[serde(untagged)]
+/// The payment terms of this invoice, e.g. `Net 30`, `EOM + 15`,
+/// `Due on receipt`, `Due 2025-07-31` or `2/10 Net 30`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
 pub enum PaymentTerms {
-    /// Net payment due in a specific number of days, e.g. `Net(30)`
+    /// Net payment due in a specific number of days, e.g. `Net 30`
     Net(NetDays),
+
+    /// Payment due a number of days after the end of the month of the invoice
+    /// date, e.g. `EOM + 15`, or `EOM` for the last day of the month.
+    EndOfMonth { days: u16 },
+
+    /// Payment due on receipt, i.e. the due date is the invoice date.
+    DueOnReceipt,
+
+    /// Payment due on a fixed date, e.g. `Due 2025-07-31`, or on the invoice
+    /// date if it is later.
+    FixedDate(Date),
+
+    /// Net payment due in a specific number of days, with a cash discount if
+    /// paid within a shorter number of days, e.g. `2/10 Net 30` for a 2%
+    /// discount if paid within 10 days, otherwise net payment due in 30 days.
+    EarlyPaymentDiscount {
+        discount: Percentage,
+        within: u16,
+        net: NetDays,
+    },
+}
+
+impl std::fmt::Display for PaymentTerms {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Net(net_days) => write!(f, "{net_days}"),
+            Self::EndOfMonth { days: 0 } => write!(f, "EOM"),
+            Self::EndOfMonth { days } => write!(f, "EOM + {days}"),
+            Self::DueOnReceipt => write!(f, "Due on receipt"),
+            Self::FixedDate(date) => write!(f, "Due {date}"),
+            Self::EarlyPaymentDiscount {
+                discount,
+                within,
+                net,
+            } => write!(f, "{}/{within} {net}", **discount),
+        }
+    }
 }
 
 impl FromStr for PaymentTerms {
     type Err = crate::prelude::Error;
 
-    /// Parses a string into `PaymentTerms`, e.g. "Net 30" into `PaymentTerms::Net(NetDays { due_in: 30 })`.
+    /// Parses a string into `PaymentTerms`, e.g. "Net 30" into
+    /// `PaymentTerms::Net(NetDays { due_in: 30 })`, "EOM + 15" into
+    /// `PaymentTerms::EndOfMonth { days: 15 }`, "Due on receipt" into
+    /// `PaymentTerms::DueOnReceipt`, "Due 2025-07-31" into
+    /// `PaymentTerms::FixedDate` and "2/10 Net 30" into
+    /// `PaymentTerms::EarlyPaymentDiscount`.
     /// # Errors
     /// Returns an error if the string is not in the correct format or if
-    /// the number of days is invalid.
+    /// the number of days is invalid, or if the discount window is not
+    /// shorter than the net days.
     /// # Examples
     /// ```
     /// extern crate klirr_core;
     /// use klirr_core::prelude::*;
     /// let payment_terms: PaymentTerms = "Net 30".parse().unwrap();
     /// assert!(matches!(payment_terms, PaymentTerms::Net(_)));
+    /// let payment_terms: PaymentTerms = "EOM + 15".parse().unwrap();
+    /// assert_eq!(payment_terms, PaymentTerms::EndOfMonth { days: 15 });
+    /// let payment_terms: PaymentTerms = "2/10 Net 30".parse().unwrap();
+    /// assert_eq!(payment_terms.discount(), Some(&Percentage::try_from(dec!(2)).unwrap()));
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let net_days = NetDays::from_str(s)?;
-        Ok(PaymentTerms::Net(net_days))
+        let s = s.trim();
+        let invalid = || Error::FailedToParsePaymentTerms {
+            invalid_string: s.to_owned(),
+        };
+        if let Some((discount, rest)) = s.split_once('/') {
+            let discount = Percentage::from_str(discount)
+                .ok()
+                .filter(|discount| !discount.is_zero())
+                .ok_or_else(invalid)?;
+            let (within, net) = rest.trim().split_once(' ').ok_or_else(invalid)?;
+            let within = u16::from_str(within).map_err(|_| invalid())?;
+            let net = NetDays::from_str(net.trim())?;
+            if within == 0 || within >= *net.due_in() {
+                return Err(invalid());
+            }
+            return Ok(Self::EarlyPaymentDiscount {
+                discount,
+                within,
+                net,
+            });
+        }
+        if s.starts_with("Net") {
+            return NetDays::from_str(s).map(Self::Net);
+        }
+        if s.eq_ignore_ascii_case("Due on receipt") {
+            return Ok(Self::DueOnReceipt);
+        }
+        if let Some(date) = s.strip_prefix("Due ") {
+            return Date::from_str(date.trim())
+                .map(Self::FixedDate)
+                .map_err(|_| invalid());
+        }
+        if let Some(days) = s.strip_prefix("EOM") {
+            let days = days.trim();
+            if days.is_empty() {
+                return Ok(Self::EndOfMonth { days: 0 });
+            }
+            let days = days
+                .strip_prefix('+')
+                .and_then(|days| u16::from_str(days.trim()).ok())
+                .ok_or_else(invalid)?;
+            return Ok(Self::EndOfMonth { days });
+        }
+        Err(invalid())
     }
 }
 
@@ -39,6 +128,56 @@ impl PaymentTerms {
     pub fn net30() -> Self {
         PaymentTerms::Net(NetDays::net30())
     }
+
+    /// The cash discount of early payment, if any, e.g. `2%` for `2/10 Net 30`.
+    pub fn discount(&self) -> Option<&Percentage> {
+        match self {
+            Self::EarlyPaymentDiscount { discount, .. } => Some(discount),
+            _ => None,
+        }
+    }
+
+    /// The number of days after the invoice date within which payment earns
+    /// the cash discount, if any, e.g. `10` for `2/10 Net 30`.
+    pub fn discount_days(&self) -> Option<u16> {
+        match self {
+            Self::EarlyPaymentDiscount { within, .. } => Some(*within),
+            _ => None,
+        }
+    }
+
+    /// The payment terms in the language of `l18n`, e.g. `Netto 30 dagar` in
+    /// Swedish for `Net 30`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let l18n = L18nInvoiceInfo::swedish();
+    /// assert_eq!(PaymentTerms::net30().localized(&l18n), "Netto 30 dagar");
+    /// assert_eq!(PaymentTerms::DueOnReceipt.localized(&l18n), "Betalas vid mottagande");
+    /// ```
+    pub fn localized(&self, l18n: &L18nInvoiceInfo) -> String {
+        match self {
+            Self::Net(net_days) => l18n
+                .net_terms()
+                .replace("{days}", &net_days.due_in().to_string()),
+            Self::EndOfMonth { days: 0 } => l18n.end_of_month_terms().clone(),
+            Self::EndOfMonth { days } => l18n
+                .end_of_month_plus_days_terms()
+                .replace("{days}", &days.to_string()),
+            Self::DueOnReceipt => l18n.due_on_receipt_terms().clone(),
+            Self::FixedDate(date) => l18n.fixed_date_terms().replace("{date}", &date.to_string()),
+            Self::EarlyPaymentDiscount {
+                discount,
+                within,
+                net,
+            } => l18n
+                .early_payment_discount_terms()
+                .replace("{percentage}", &discount.to_string())
+                .replace("{discount_days}", &within.to_string())
+                .replace("{days}", &net.due_in().to_string()),
+        }
+    }
 }
 
 impl HasSample for PaymentTerms {
@@ -47,11 +186,7 @@ impl HasSample for PaymentTerms {
     }
 
     fn sample_other() -> Self {
-        Self::Net(
-            NetDays::builder()
-                .due_in(Day::try_from(15).unwrap())
-                .build(),
-        )
+        Self::Net(NetDays::builder().due_in(15).build())
     }
 }
 
@@ -76,7 +211,7 @@ mod tests {
     #[test]
     fn test_payment_terms_net_days() {
         let net_days: NetDays = "Net 30".parse().unwrap();
-        assert_eq!(net_days.due_in(), &Day::try_from(30).unwrap());
+        assert_eq!(*net_days.due_in(), 30);
         assert_ron_snapshot!(net_days);
     }
 
@@ -93,7 +228,7 @@ mod tests {
             "Net",          // Missing days
             "Net 0",        // Invalid days (0)
             "Net -30",      // Invalid days (negative)
-            "Net 32",       // Invalid days (more than 31)
+            "Net 70000",    // Invalid days (too many)
             "Net abc",      // Non-numeric days
             "Net 30 extra", // Extra text after valid input
         ];
@@ -109,4 +244,84 @@ mod tests {
         let payment_terms: PaymentTerms = "Net 30".parse().unwrap();
         assert!(matches!(payment_terms, PaymentTerms::Net(_)));
     }
+
+    #[test]
+    fn test_payment_terms_from_str_invalid() {
+        for invalid in [
+            "EOM 15",
+            "EOM + abc",
+            "Due tomorrow",
+            "Cash",
+            "",
+            "0/10 Net 30",
+            "2/0 Net 30",
+            "2/30 Net 30",
+            "2/10",
+            "200/10 Net 30",
+            "abc/10 Net 30",
+        ] {
+            assert!(
+                Sut::from_str(invalid).is_err(),
+                "Expected error for '{invalid}'"
+            );
+        }
+    }
+
+    #[test]
+    fn test_payment_terms_roundtrip() {
+        for terms in [
+            Sut::Net(NetDays::builder().due_in(90).build()),
+            Sut::EndOfMonth { days: 0 },
+            Sut::EndOfMonth { days: 15 },
+            Sut::DueOnReceipt,
+            Sut::FixedDate(Date::sample()),
+            Sut::EarlyPaymentDiscount {
+                discount: Percentage::try_from(dec!(2)).unwrap(),
+                within: 10,
+                net: NetDays::net30(),
+            },
+            Sut::EarlyPaymentDiscount {
+                discount: Percentage::try_from(dec!(1.5)).unwrap(),
+                within: 15,
+                net: NetDays::sample_other(),
+            },
+        ] {
+            assert_eq!(Sut::from_str(&terms.to_string()).unwrap(), terms);
+            let ron = ron::to_string(&terms).unwrap();
+            assert_eq!(ron::from_str::<Sut>(&ron).unwrap(), terms);
+        }
+    }
+
+    #[test]
+    fn test_payment_terms_localized() {
+        let english = L18nInvoiceInfo::english();
+        let localized = |terms: Sut| terms.localized(&english);
+        assert_eq!(localized(Sut::net30()), "Net 30");
+        assert_eq!(localized(Sut::EndOfMonth { days: 0 }), "End of month");
+        assert_eq!(
+            localized(Sut::EndOfMonth { days: 15 }),
+            "End of month + 15 days"
+        );
+        assert_eq!(localized(Sut::DueOnReceipt), "Due on receipt");
+        assert_eq!(localized(Sut::FixedDate(Date::sample())), "Due 2025-05-31");
+        assert_eq!(
+            localized(Sut::from_str("2/10 Net 30").unwrap()),
+            "2% 10 days, Net 30"
+        );
+    }
+
+    #[test]
+    fn test_payment_terms_early_payment_discount() {
+        let sut = Sut::from_str("2/10 Net 30").unwrap();
+        assert_eq!(sut.to_string(), "2/10 Net 30");
+        assert_eq!(
+            sut.discount(),
+            Some(&Percentage::try_from(dec!(2)).unwrap())
+        );
+        assert_eq!(sut.discount_days(), Some(10));
+        assert_eq!(Sut::net30().discount(), None);
+        assert_eq!(Sut::net30().discount_days(), None);
+        let swedish = L18nInvoiceInfo::swedish();
+        assert_eq!(sut.localized(&swedish), "2% 10 dagar, netto 30 dagar");
+    }
 }
@@ -22,6 +22,14 @@ pub enum PeriodAnno {
     YearAndMonth(YearAndMonth),
     /// A year, month and fortnight, e.g. `2024-12-second-half`.
     YearMonthAndFortnight(YearMonthAndFortnight),
+    /// An ISO 8601 week, e.g. `2025-W27`.
+    YearAndWeek(YearAndWeek),
+    /// A year and quarter, e.g. `2025-Q3`.
+    YearAndQuarter(YearAndQuarter),
+    /// A whole year, e.g. `2025`.
+    Year(Year),
+    /// A rolling period of 14 days, e.g. `2025-06-30..2025-07-13`.
+    RollingFortnight(RollingFortnight),
 }
 
 impl std::str::FromStr for PeriodAnno {
@@ -32,6 +40,14 @@ impl std::str::FromStr for PeriodAnno {
             Ok(Self::YearAndMonth(period))
         } else if let Ok(period) = YearMonthAndFortnight::from_str(s) {
             Ok(Self::YearMonthAndFortnight(period))
+        } else if let Ok(period) = YearAndWeek::from_str(s) {
+            Ok(Self::YearAndWeek(period))
+        } else if let Ok(period) = YearAndQuarter::from_str(s) {
+            Ok(Self::YearAndQuarter(period))
+        } else if let Ok(period) = Year::from_str(s) {
+            Ok(Self::Year(period))
+        } else if let Ok(period) = RollingFortnight::from_str(s) {
+            Ok(Self::RollingFortnight(period))
         } else {
             Err(Error::InvalidPeriod {
                 bad_value: s.to_owned(),
@@ -63,6 +79,10 @@ impl IsPeriod for PeriodAnno {
         match self {
             Self::YearAndMonth(period) => period.max_granularity(),
             Self::YearMonthAndFortnight(period) => period.max_granularity(),
+            Self::YearAndWeek(period) => period.max_granularity(),
+            Self::YearAndQuarter(period) => period.max_granularity(),
+            Self::Year(period) => period.max_granularity(),
+            Self::RollingFortnight(period) => period.max_granularity(),
         }
     }
 
@@ -73,22 +93,54 @@ impl IsPeriod for PeriodAnno {
             (Self::YearMonthAndFortnight(lhs), Self::YearMonthAndFortnight(rhs)) => {
                 lhs.elapsed_periods_since(rhs)
             }
-            (Self::YearAndMonth(_), Self::YearMonthAndFortnight(_)) => {
-                Err(Error::PeriodIsNotYearAndMonth)
+            (Self::YearAndWeek(lhs), Self::YearAndWeek(rhs)) => lhs.elapsed_periods_since(rhs),
+            (Self::YearAndQuarter(lhs), Self::YearAndQuarter(rhs)) => {
+                lhs.elapsed_periods_since(rhs)
             }
-            (Self::YearMonthAndFortnight(_), Self::YearAndMonth(_)) => {
-                Err(Error::PeriodIsNotYearMonthAndFortnight)
+            (Self::Year(lhs), Self::Year(rhs)) => lhs.elapsed_periods_since(rhs),
+            (Self::RollingFortnight(lhs), Self::RollingFortnight(rhs)) => {
+                lhs.elapsed_periods_since(rhs)
             }
+            (Self::YearAndMonth(_), _) => Err(Error::PeriodIsNotYearAndMonth),
+            (Self::YearMonthAndFortnight(_), _) => Err(Error::PeriodIsNotYearMonthAndFortnight),
+            (Self::YearAndWeek(_), _) => Err(Error::PeriodIsNotYearAndWeek),
+            (Self::YearAndQuarter(_), _) => Err(Error::PeriodIsNotYearAndQuarter),
+            (Self::Year(_), _) => Err(Error::PeriodIsNotYear),
+            (Self::RollingFortnight(_), _) => Err(Error::PeriodIsNotRollingFortnight),
+        }
+    }
+
+    /// Converts the period into a date that represents the start of the period.
+    /// For `YearAndMonth`, this is the first day of the month, for
+    /// `YearMonthAndFortnight`, this is the first day of the fortnight, and for
+    /// `YearAndWeek`, this is the Monday of the week. For `YearAndQuarter` and
+    /// `Year` it is the first day of the quarter or year, and for
+    /// `RollingFortnight` the first of its 14 days.
+    fn to_date_start_of_period(&self) -> Date {
+        match self {
+            Self::YearAndMonth(period) => period.to_date_start_of_period(),
+            Self::YearMonthAndFortnight(period) => period.to_date_start_of_period(),
+            Self::YearAndWeek(period) => period.to_date_start_of_period(),
+            Self::YearAndQuarter(period) => period.to_date_start_of_period(),
+            Self::Year(period) => period.to_date_start_of_period(),
+            Self::RollingFortnight(period) => period.to_date_start_of_period(),
         }
     }
 
     /// Converts the period into a date that represents the end of the period.
-    /// For `YearAndMonth`, this is the last day of the month, and for
-    /// `YearMonthAndFortnight`, this is the last day of the fortnight.
+    /// For `YearAndMonth`, this is the last day of the month, for
+    /// `YearMonthAndFortnight`, this is the last day of the fortnight, and for
+    /// `YearAndWeek`, this is the Sunday of the week. For `YearAndQuarter` and
+    /// `Year` it is the last day of the quarter or year, and for
+    /// `RollingFortnight` the last of its 14 days.
     fn to_date_end_of_period(&self) -> Date {
         match self {
             Self::YearAndMonth(period) => period.to_date_end_of_period(),
             Self::YearMonthAndFortnight(period) => period.to_date_end_of_period(),
+            Self::YearAndWeek(period) => period.to_date_end_of_period(),
+            Self::YearAndQuarter(period) => period.to_date_end_of_period(),
+            Self::Year(period) => period.to_date_end_of_period(),
+            Self::RollingFortnight(period) => period.to_date_end_of_period(),
         }
     }
 
@@ -97,6 +149,10 @@ impl IsPeriod for PeriodAnno {
         match self {
             Self::YearAndMonth(period) => period.year(),
             Self::YearMonthAndFortnight(period) => period.year(),
+            Self::YearAndWeek(period) => period.year(),
+            Self::YearAndQuarter(period) => period.year(),
+            Self::Year(period) => period.year(),
+            Self::RollingFortnight(period) => period.year(),
         }
     }
 
@@ -105,6 +161,24 @@ impl IsPeriod for PeriodAnno {
         match self {
             Self::YearAndMonth(period) => period.month(),
             Self::YearMonthAndFortnight(period) => period.month(),
+            Self::YearAndWeek(period) => period.month(),
+            Self::YearAndQuarter(period) => period.month(),
+            Self::Year(period) => period.month(),
+            Self::RollingFortnight(period) => period.month(),
+        }
+    }
+
+    /// Returns the period of the same variant containing `date`.
+    fn period_of_same_kind_containing(&self, date: &Date) -> Self {
+        match self {
+            Self::YearAndMonth(period) => period.period_of_same_kind_containing(date).into(),
+            Self::YearMonthAndFortnight(period) => {
+                period.period_of_same_kind_containing(date).into()
+            }
+            Self::YearAndWeek(period) => period.period_of_same_kind_containing(date).into(),
+            Self::YearAndQuarter(period) => period.period_of_same_kind_containing(date).into(),
+            Self::Year(period) => period.period_of_same_kind_containing(date).into(),
+            Self::RollingFortnight(period) => period.period_of_same_kind_containing(date).into(),
         }
     }
 }
@@ -179,6 +253,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_date_start_of_period_year_and_month() {
+        let period = Sut::YearAndMonth(YearAndMonth::december(2024));
+        assert_eq!(
+            period.to_date_start_of_period(),
+            Date::from_str("2024-12-01").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_to_date_start_of_period_year_and_fortnight() {
+        let period = Sut::YearMonthAndFortnight(
+            YearMonthAndFortnight::builder()
+                .year(2024.into())
+                .month(Month::December)
+                .half(MonthHalf::Second)
+                .build(),
+        );
+        assert_eq!(
+            period.to_date_start_of_period(),
+            Date::from_str("2024-12-16").unwrap()
+        );
+    }
+
     #[test]
     fn serde_fortnight() {
         assert_ron_snapshot!(Sut::YearMonthAndFortnight(
@@ -226,6 +324,69 @@ mod tests {
         assert!(result.is_err(), "Expected error when mixing period kinds");
     }
 
+    #[test]
+    fn test_elapsed_periods_since_year_and_week() {
+        let early = Sut::from_str("2024-W52").unwrap();
+        let late = Sut::from_str("2025-W02").unwrap();
+        assert_eq!(late.elapsed_periods_since(&early).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_deserialize_ron_year_and_week() {
+        let period: Sut = ron::de::from_str(r#""2025-W27""#).expect("Failed to deserialize RON");
+        assert_eq!(period, Sut::YearAndWeek(YearAndWeek::sample()));
+        assert_eq!(period.max_granularity(), Granularity::Day);
+    }
+
+    #[test]
+    fn test_quarter_and_year() {
+        let quarter = Sut::from_str("2025-Q2").unwrap();
+        assert_eq!(
+            quarter,
+            Sut::YearAndQuarter(YearAndQuarter::new(2025.into(), Quarter::Second))
+        );
+        assert_eq!(
+            quarter.to_date_end_of_period(),
+            Date::from_str("2025-06-30").unwrap()
+        );
+        assert_eq!(quarter.max_granularity(), Granularity::Quarter);
+
+        let year = Sut::from_str("2025").unwrap();
+        assert_eq!(year, Sut::Year(Year::from(2025)));
+        assert_eq!(
+            year.to_date_start_of_period(),
+            Date::from_str("2025-01-01").unwrap()
+        );
+        assert_eq!(
+            year.to_date_end_of_period(),
+            Date::from_str("2025-12-31").unwrap()
+        );
+        assert_eq!(
+            year.elapsed_periods_since(Sut::Year(Year::from(2023)))
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            year.elapsed_periods_since(quarter),
+            Err(Error::PeriodIsNotYear)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_ron_quarter_and_year() {
+        let quarter: Sut = ron::de::from_str(r#""2025-Q3""#).unwrap();
+        assert_eq!(quarter, Sut::YearAndQuarter(YearAndQuarter::sample()));
+        let year: Sut = ron::de::from_str("2025").unwrap();
+        assert_eq!(year, Sut::Year(Year::from(2025)));
+    }
+
+    #[test]
+    fn mix_week_ym_throws() {
+        let result = Sut::YearAndWeek(YearAndWeek::sample())
+            .elapsed_periods_since(Sut::YearAndMonth(YearAndMonth::sample()));
+        assert_eq!(result, Err(Error::PeriodIsNotYearAndWeek));
+    }
+
     #[test]
     fn year_month_and_fortnight_get_year() {
         let sut = Sut::YearMonthAndFortnight(
@@ -261,12 +422,22 @@ mod tests {
             "2024-12-second-half",
             "2024-12-second",
             "2024-12-2",
+            "2024-W52",
+            "2020-w53",
+            "2025-Q3",
+            "2025",
+            "2025-06-30..2025-07-13",
         ];
         for format in valid_formats {
             let period: Sut = format.parse().expect("Failed to parse valid format");
             assert!(matches!(
                 period,
-                Sut::YearAndMonth(_) | Sut::YearMonthAndFortnight(_)
+                Sut::YearAndMonth(_)
+                    | Sut::YearMonthAndFortnight(_)
+                    | Sut::YearAndWeek(_)
+                    | Sut::YearAndQuarter(_)
+                    | Sut::Year(_)
+                    | Sut::RollingFortnight(_)
             ));
         }
     }
@@ -283,6 +454,7 @@ mod tests {
             "2024-12-fourth-half", // Invalid fortnight
             "2024-12-fourth",      // Invalid fortnight
             "2024-12-4",           // Invalid fortnight
+            "2025-W53",            // Invalid week, 2025 has 52 weeks
         ];
         for format in invalid_formats {
             let result: Result<Sut, _> = format.parse();
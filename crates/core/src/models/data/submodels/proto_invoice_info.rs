@@ -1,7 +1,9 @@
 use crate::prelude::*;
 
 /// Partial information about the invoice which can be used to derive a [`InvoiceInfoFull`]
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder, Getters, Setters)]
+#[derive(
+    Clone, Debug, Serialize, Deserialize, PartialEq, Builder, Getters, Setters, WithSetters,
+)]
 pub struct ProtoInvoiceInfo<Period: IsPeriod> {
     /// An offset which is used to calculate the invoice number, e.g. `(237, 2025-05)`.
     /// This is enables us to calculate the next invoice number based on the current
@@ -14,6 +16,13 @@ pub struct ProtoInvoiceInfo<Period: IsPeriod> {
     #[getset(get = "pub", set = "pub")]
     record_of_periods_off: RecordOfPeriodsOff<Period>,
 
+    /// Record of concrete dates off, subtracted from the quantity when invoicing
+    /// per day or hour, e.g. `["2025-07-01..2025-07-25", "2025-07-28 half"]`.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set = "pub")]
+    record_of_dates_off: RecordOfDatesOff,
+
     /// A purchase order number associated with this invoice, e.g. `"PO-12345"`
     /// Typically agreed upon between the vendor and client before the
     /// invoice is issued.
@@ -27,6 +36,49 @@ pub struct ProtoInvoiceInfo<Period: IsPeriod> {
     /// Hex color code for the color emphasis of the invoice, e.g. `"#e6007a"`.
     #[getset(get = "pub", set_with = "pub")]
     emphasize_color_hex: Option<HexColor>,
+
+    /// How the invoice number is printed, e.g. `LUP-2025-0042`, defaults to
+    /// the bare number, e.g. `42`.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    number_format: InvoiceNumberFormat,
+
+    /// How the numbers of invoices of expenses are printed, if in a series of
+    /// their own, e.g. the number format followed by `"-E"`, see
+    /// [`InvoiceNumberFormat::for_expenses`]. Defaults to the number format,
+    /// so that the expenses of a period may take the number of the services
+    /// of the next period.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    expenses_number_format: Option<InvoiceNumberFormat>,
+
+    /// Whether invoice numbers restart at `1` every fiscal year, defaults to
+    /// never.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    number_reset: InvoiceNumberReset,
+
+    /// How the numbers of credit notes are printed, a series of their own,
+    /// defaults to the prefix `"CN-"`, e.g. `CN-7`.
+    #[builder(default = InvoiceNumberFormat::credit_note())]
+    #[serde(default = "InvoiceNumberFormat::credit_note")]
+    #[getset(get = "pub", set_with = "pub")]
+    credit_note_number_format: InvoiceNumberFormat,
+
+    /// How the numbers of invoices billing milestones are printed, a series
+    /// of their own, defaults to the prefix `"MS-"`, e.g. `MS-3`.
+    #[builder(default = InvoiceNumberFormat::milestone())]
+    #[serde(default = "InvoiceNumberFormat::milestone")]
+    #[getset(get = "pub", set_with = "pub")]
+    milestone_number_format: InvoiceNumberFormat,
+
+    /// A standing discount of the whole invoice, e.g. `"5%"` for loyalty, on
+    /// every invoice of services unless another discount is passed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    discount: Option<Discount>,
 }
 
 impl<Period: IsPeriod> ProtoInvoiceInfo<Period> {
@@ -46,6 +98,113 @@ impl<Period: IsPeriod> ProtoInvoiceInfo<Period> {
         periods_off.insert(period);
         self.set_record_of_periods_off(periods_off);
     }
+
+    /// Inserts a new date off into the dates off record.
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut invoice_info = ProtoInvoiceInfo::<YearAndMonth>::sample();
+    /// let date_off = DateOff::from_str("2025-07-01..2025-07-25").unwrap();
+    /// invoice_info.insert_date_off(date_off.clone());
+    /// assert!(invoice_info.record_of_dates_off().contains(&date_off));
+    /// ```
+    pub fn insert_date_off(&mut self, date_off: DateOff) {
+        self.record_of_dates_off.insert(date_off);
+    }
+
+    /// Formats `number` of the invoice for `period` according to the number
+    /// format, using the fiscal year of the last day of the period.
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let invoice_info = ProtoInvoiceInfo::<YearAndMonth>::sample()
+    ///     .with_number_format(InvoiceNumberFormat::sample_other())
+    ///     .with_number_reset(InvoiceNumberReset::Yearly { fiscal_year_start: Month::July });
+    /// let formatted = invoice_info.format_invoice_number(&InvoiceNumber::from(42), &YearAndMonth::may(2026));
+    /// assert_eq!(formatted.to_string(), "LUP-2025-0042");
+    /// ```
+    pub fn format_invoice_number(
+        &self,
+        number: &InvoiceNumber,
+        period: &Period,
+    ) -> FormattedInvoiceNumber {
+        let fiscal_year = self
+            .number_reset
+            .fiscal_year(&period.to_date_end_of_period());
+        self.number_format.format(number, &fiscal_year)
+    }
+
+    /// Formats `number` of the invoice of expenses for `period` according to
+    /// the expenses number format, if any, else the number format, using the
+    /// fiscal year of the last day of the period.
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let invoice_info = ProtoInvoiceInfo::<YearAndMonth>::sample();
+    /// let formatted = invoice_info.format_expenses_invoice_number(&InvoiceNumber::from(42), &YearAndMonth::may(2025));
+    /// assert_eq!(formatted.to_string(), "42");
+    ///
+    /// let invoice_info = invoice_info.with_expenses_number_format(Some(InvoiceNumberFormat::default().for_expenses()));
+    /// let formatted = invoice_info.format_expenses_invoice_number(&InvoiceNumber::from(42), &YearAndMonth::may(2025));
+    /// assert_eq!(formatted.to_string(), "42-E");
+    /// ```
+    pub fn format_expenses_invoice_number(
+        &self,
+        number: &InvoiceNumber,
+        period: &Period,
+    ) -> FormattedInvoiceNumber {
+        let fiscal_year = self
+            .number_reset
+            .fiscal_year(&period.to_date_end_of_period());
+        self.expenses_number_format
+            .as_ref()
+            .unwrap_or(&self.number_format)
+            .format(number, &fiscal_year)
+    }
+
+    /// Formats `number` of the invoice billing a milestone for `period`
+    /// according to the milestone number format, using the fiscal year of the
+    /// last day of the period.
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let invoice_info = ProtoInvoiceInfo::<YearAndMonth>::sample();
+    /// let formatted = invoice_info.format_milestone_invoice_number(&InvoiceNumber::from(3), &YearAndMonth::may(2025));
+    /// assert_eq!(formatted.to_string(), "MS-3");
+    /// ```
+    pub fn format_milestone_invoice_number(
+        &self,
+        number: &InvoiceNumber,
+        period: &Period,
+    ) -> FormattedInvoiceNumber {
+        let fiscal_year = self
+            .number_reset
+            .fiscal_year(&period.to_date_end_of_period());
+        self.milestone_number_format.format(number, &fiscal_year)
+    }
+
+    /// Formats `number` of a credit note dated `date` according to the credit
+    /// note number format, using the fiscal year of `date`.
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let invoice_info = ProtoInvoiceInfo::<YearAndMonth>::sample();
+    /// let formatted = invoice_info.format_credit_note_number(&InvoiceNumber::from(7), &Date::sample());
+    /// assert_eq!(formatted.to_string(), "CN-7");
+    /// ```
+    pub fn format_credit_note_number(
+        &self,
+        number: &InvoiceNumber,
+        date: &Date,
+    ) -> FormattedInvoiceNumber {
+        let fiscal_year = self.number_reset.fiscal_year(date);
+        self.credit_note_number_format.format(number, &fiscal_year)
+    }
 }
 
 impl<Period: IsPeriod + HasSample> HasSample for ProtoInvoiceInfo<Period> {
@@ -148,4 +307,67 @@ mod tests {
         invoice_info.insert_period_off(period);
         assert!(invoice_info.record_of_periods_off().contains(&period));
     }
+
+    #[test]
+    fn test_deserialize_without_record_of_dates_off() {
+        let sut = Sut::sample();
+        let ron = ron::to_string(&sut).unwrap();
+        let ron = ron.replace("record_of_dates_off:([]),", "");
+        assert!(!ron.contains("record_of_dates_off"));
+        let deserialized: Sut = ron::from_str(&ron).unwrap();
+        assert_eq!(deserialized, sut);
+    }
+
+    #[test]
+    fn test_deserialize_without_number_format_and_reset() {
+        let sut = Sut::sample();
+        let ron = ron::to_string(&sut).unwrap();
+        let ron = ron
+            .replace(
+                ",credit_note_number_format:(prefix:\"CN-\",include_year:false,padding:0,suffix:\"\")",
+                "",
+            )
+            .replace(
+                ",milestone_number_format:(prefix:\"MS-\",include_year:false,padding:0,suffix:\"\")",
+                "",
+            )
+            .replace(
+                ",number_format:(prefix:\"\",include_year:false,padding:0,suffix:\"\")",
+                "",
+            )
+            .replace(",number_reset:Never", "");
+        assert!(!ron.contains("number_format"));
+        assert!(!ron.contains("number_reset"));
+        let deserialized: Sut = ron::from_str(&ron).unwrap();
+        assert_eq!(deserialized, sut);
+    }
+
+    #[test]
+    fn test_format_invoice_number_default() {
+        let formatted = Sut::sample()
+            .format_invoice_number(&InvoiceNumber::from(237), &YearAndMonth::may(2025));
+        assert_eq!(formatted, FormattedInvoiceNumber::from("237"));
+    }
+
+    #[test]
+    fn test_format_invoice_number_uses_calendar_year_without_reset() {
+        let sut = Sut::sample().with_number_format(InvoiceNumberFormat::sample_other());
+        let formatted =
+            sut.format_invoice_number(&InvoiceNumber::from(42), &YearAndMonth::may(2025));
+        assert_eq!(formatted, FormattedInvoiceNumber::from("LUP-2025-0042"));
+    }
+
+    #[test]
+    fn test_format_credit_note_number_uses_fiscal_year_of_date() {
+        let sut = Sut::sample()
+            .with_credit_note_number_format(InvoiceNumberFormat::sample_other())
+            .with_number_reset(InvoiceNumberReset::Yearly {
+                fiscal_year_start: Month::July,
+            });
+        let formatted = sut.format_credit_note_number(
+            &InvoiceNumber::from(3),
+            &Date::from_str("2026-03-15").unwrap(),
+        );
+        assert_eq!(formatted, FormattedInvoiceNumber::from("LUP-2025-0003"));
+    }
 }
@@ -0,0 +1,143 @@
+use crate::prelude::*;
+
+/// A quarter of a year, three months starting in January, April, July or
+/// October.
+#[derive(
+    Clone,
+    Copy,
+    derive_more::Debug,
+    Display,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SerializeDisplay,
+    DeserializeFromStr,
+    EnumIter,
+)]
+pub enum Quarter {
+    /// January to March.
+    #[display("Q1")]
+    First,
+    /// April to June.
+    #[display("Q2")]
+    Second,
+    /// July to September.
+    #[display("Q3")]
+    Third,
+    /// October to December.
+    #[display("Q4")]
+    Fourth,
+}
+
+impl From<Quarter> for i16 {
+    /// Converts `Quarter::First` into `1`, ..., `Quarter::Fourth` into `4`.
+    fn from(quarter: Quarter) -> Self {
+        match quarter {
+            Quarter::First => 1,
+            Quarter::Second => 2,
+            Quarter::Third => 3,
+            Quarter::Fourth => 4,
+        }
+    }
+}
+
+impl From<Month> for Quarter {
+    fn from(month: Month) -> Self {
+        match *month {
+            1..=3 => Self::First,
+            4..=6 => Self::Second,
+            7..=9 => Self::Third,
+            _ => Self::Fourth,
+        }
+    }
+}
+
+impl Quarter {
+    /// The first month of the quarter, e.g. April for the second quarter.
+    pub fn first_month(&self) -> &'static Month {
+        match self {
+            Self::First => &Month::January,
+            Self::Second => &Month::April,
+            Self::Third => &Month::July,
+            Self::Fourth => &Month::October,
+        }
+    }
+
+    /// The last month of the quarter, e.g. June for the second quarter.
+    pub fn last_month(&self) -> &'static Month {
+        match self {
+            Self::First => &Month::March,
+            Self::Second => &Month::June,
+            Self::Third => &Month::September,
+            Self::Fourth => &Month::December,
+        }
+    }
+}
+
+impl FromStr for Quarter {
+    type Err = crate::Error;
+
+    /// Parses `"Q1"`, `"q1"` or `"1"` into `Quarter::First`, and analogously
+    /// for the other quarters.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().trim_start_matches('q') {
+            "1" => Ok(Self::First),
+            "2" => Ok(Self::Second),
+            "3" => Ok(Self::Third),
+            "4" => Ok(Self::Fourth),
+            _ => Err(Error::FailedToParseDate {
+                underlying: "Invalid Format Quarter".to_owned(),
+            }),
+        }
+    }
+}
+
+impl HasSample for Quarter {
+    fn sample() -> Self {
+        Self::Third
+    }
+
+    fn sample_other() -> Self {
+        Self::Fourth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Quarter;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn test_from_str_all_valid() {
+        for quarter in Sut::iter() {
+            let number = i16::from(quarter).to_string();
+            assert_eq!(Sut::from_str(&number).unwrap(), quarter);
+            assert_eq!(Sut::from_str(&quarter.to_string()).unwrap(), quarter);
+            assert_eq!(Sut::from_str(&format!("q{number}")).unwrap(), quarter);
+        }
+        assert!(Sut::from_str("Q5").is_err());
+    }
+
+    #[test]
+    fn from_month() {
+        assert_eq!(Sut::from(Month::March), Sut::First);
+        assert_eq!(Sut::from(Month::April), Sut::Second);
+        assert_eq!(Sut::from(Month::September), Sut::Third);
+        assert_eq!(Sut::from(Month::December), Sut::Fourth);
+    }
+}
@@ -2,22 +2,29 @@ use std::ops::Deref;
 
 use crate::prelude::*;
 
-/// Invoice rate, a fixed price per month, per day or per hour.
+/// Invoice rate, a fixed price per year, per quarter, per month, per day or
+/// per hour.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
 pub enum Rate {
+    /// A fixed rate per year, invoiced yearly
+    Yearly(UnitPrice),
+    /// A fixed rate per quarter, invoiced quarterly or yearly
+    Quarterly(UnitPrice),
     /// A fixed rate per month, invoiced monthly
     Monthly(UnitPrice),
     /// A fixed rate per fortnight, invoiced bi-weekly
     Fortnight(UnitPrice),
-    /// A fixed rate per day, invoice monthly or bi-weekly
+    /// A fixed rate per day, invoice monthly, bi-weekly or weekly
     Daily(UnitPrice),
-    /// A fixed rate per hour, invoice monthly or bi-weekly
+    /// A fixed rate per hour, invoice monthly, bi-weekly or weekly
     Hourly(UnitPrice),
 }
 
 impl From<(UnitPrice, Granularity)> for Rate {
     fn from((price, granularity): (UnitPrice, Granularity)) -> Self {
         match granularity {
+            Granularity::Year => Self::Yearly(price),
+            Granularity::Quarter => Self::Quarterly(price),
             Granularity::Month => Self::Monthly(price),
             Granularity::Fortnight => Self::Fortnight(price),
             Granularity::Day => Self::Daily(price),
@@ -27,6 +34,16 @@ impl From<(UnitPrice, Granularity)> for Rate {
 }
 
 impl Rate {
+    /// A yearly fixed rate
+    pub fn yearly(rate: impl Into<UnitPrice>) -> Self {
+        Self::Yearly(rate.into())
+    }
+
+    /// A quarterly fixed rate
+    pub fn quarterly(rate: impl Into<UnitPrice>) -> Self {
+        Self::Quarterly(rate.into())
+    }
+
     /// A monthly fixed rate
     pub fn monthly(rate: impl Into<UnitPrice>) -> Self {
         Self::Monthly(rate.into())
@@ -50,6 +67,8 @@ impl Rate {
     /// Discriminator
     pub fn granularity(&self) -> Granularity {
         match self {
+            Self::Yearly(_) => Granularity::Year,
+            Self::Quarterly(_) => Granularity::Quarter,
             Self::Monthly(_) => Granularity::Month,
             Self::Fortnight(_) => Granularity::Fortnight,
             Self::Daily(_) => Granularity::Day,
@@ -68,6 +87,8 @@ impl std::ops::Deref for Rate {
 
     fn deref(&self) -> &Self::Target {
         match self {
+            Self::Yearly(price) => price,
+            Self::Quarterly(price) => price,
             Self::Monthly(price) => price,
             Self::Fortnight(price) => price,
             Self::Daily(price) => price,
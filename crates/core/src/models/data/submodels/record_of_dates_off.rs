@@ -0,0 +1,86 @@
+use crate::prelude::*;
+
+/// A record of concrete dates off, e.g. `["2025-07-01..2025-07-25", "2025-07-28 half"]`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Deref, From)]
+pub struct RecordOfDatesOff(IndexSet<DateOff>);
+
+impl RecordOfDatesOff {
+    /// Creates a new `RecordOfDatesOff` from an iterator of `DateOff`.
+    pub fn new(dates_off: impl IntoIterator<Item = DateOff>) -> Self {
+        Self(IndexSet::from_iter(dates_off))
+    }
+
+    /// Inserts a new date off into the record.
+    pub fn insert(&mut self, date_off: DateOff) {
+        self.0.insert(date_off);
+    }
+
+    /// The hours off on `date`, given that you work `hours_on_date` that day,
+    /// never more than `hours_on_date` even if several dates off overlap.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    ///
+    /// let record = RecordOfDatesOff::new([
+    ///     DateOff::from_str("2025-07-01..2025-07-25").unwrap(),
+    ///     DateOff::from_str("2025-07-25 half").unwrap(),
+    /// ]);
+    /// let date = Date::from_str("2025-07-25").unwrap();
+    /// assert_eq!(record.hours_off_on(&date, Quantity::EIGHT), Quantity::EIGHT);
+    /// ```
+    pub fn hours_off_on(&self, date: &Date, hours_on_date: Quantity) -> Quantity {
+        let hours_off = self
+            .0
+            .iter()
+            .map(|date_off| date_off.hours_off_on(date, hours_on_date))
+            .fold(Quantity::ZERO, |acc, hours| acc + hours);
+        if hours_off < hours_on_date {
+            hours_off
+        } else {
+            hours_on_date
+        }
+    }
+}
+
+impl HasSample for RecordOfDatesOff {
+    fn sample() -> Self {
+        Self::new([DateOff::sample()])
+    }
+
+    fn sample_other() -> Self {
+        Self::new([DateOff::sample_other()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = RecordOfDatesOff;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn hours_off_on_sums_partial_days() {
+        let date = Date::from_str("2025-07-29").unwrap();
+        let sut = Sut::new([
+            DateOff::from_str("2025-07-29 half").unwrap(),
+            DateOff::from_str("2025-07-29 1.5h").unwrap(),
+        ]);
+        assert_eq!(
+            sut.hours_off_on(&date, Quantity::EIGHT),
+            Quantity::from(dec!(5.5))
+        );
+    }
+}
@@ -30,6 +30,15 @@ impl<Period: IsPeriod> RecordOfPeriodsOff<Period> {
     pub fn contains(&self, period: &Period) -> bool {
         self.0.contains(period)
     }
+
+    /// The first period off in this record with any day in `date_range`, if
+    /// any.
+    pub fn period_overlapping(&self, date_range: &DateRange) -> Option<&Period> {
+        self.0.iter().find(|period| {
+            period.to_date_start_of_period() <= *date_range.end()
+                && period.to_date_end_of_period() >= *date_range.start()
+        })
+    }
 }
 
 impl<Period: IsPeriod + HasSample> HasSample for RecordOfPeriodsOff<Period> {
@@ -58,4 +67,28 @@ mod tests {
     fn inequality() {
         assert_ne!(Sut::sample(), Sut::sample_other());
     }
+
+    #[test]
+    fn period_overlapping_date_range() {
+        let sut = Sut::new([YearAndMonth::may(2025)]);
+        let date_range = |start: &str, end: &str| {
+            DateRange::builder()
+                .start(Date::from_str(start).unwrap())
+                .end(Date::from_str(end).unwrap())
+                .build()
+                .unwrap()
+        };
+        assert_eq!(
+            sut.period_overlapping(&date_range("2025-05-10", "2025-05-20")),
+            Some(&YearAndMonth::may(2025))
+        );
+        assert_eq!(
+            sut.period_overlapping(&date_range("2025-04-20", "2025-05-01")),
+            Some(&YearAndMonth::may(2025))
+        );
+        assert_eq!(
+            sut.period_overlapping(&date_range("2025-06-01", "2025-06-20")),
+            None
+        );
+    }
 }
@@ -0,0 +1,486 @@
+use crate::prelude::*;
+
+/// A balance prepaid by a client, topped up as the client prepays and drawn
+/// from to pay the invoices of the client as they are produced, stored per
+/// client in `retainers.ron` in the data directory.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Getters)]
+pub struct Retainer {
+    /// The currency of the balance, that of the payment information when the
+    /// retainer was first topped up, e.g. `Some("EUR")`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    currency: Option<Currency>,
+
+    /// The top-ups and drawdowns of the balance, in the order they were
+    /// recorded.
+    #[getset(get = "pub")]
+    transactions: Vec<RetainerTransaction>,
+}
+
+/// A change of the balance of a [`Retainer`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, IsVariant)]
+pub enum RetainerTransaction {
+    /// An amount prepaid by the client, added to the balance, e.g.
+    /// `TopUp(date: "2025-01-01", amount: Cost(10000.0))`.
+    TopUp { date: Date, amount: Cost },
+
+    /// An amount drawn from the balance to pay the invoice numbered
+    /// `invoice_number`, dated `date`.
+    Drawdown {
+        date: Date,
+        invoice_number: FormattedInvoiceNumber,
+        amount: Cost,
+    },
+
+    /// An amount drawn to pay the invoice numbered `invoice_number` returned
+    /// to the balance by the credit note numbered `credit_note_number`, dated
+    /// `date`.
+    Return {
+        date: Date,
+        credit_note_number: FormattedInvoiceNumber,
+        invoice_number: FormattedInvoiceNumber,
+        amount: Cost,
+    },
+}
+
+impl RetainerTransaction {
+    /// The change of the balance, negative for a drawdown.
+    pub fn change(&self) -> rust_decimal::Decimal {
+        match self {
+            Self::TopUp { amount, .. } | Self::Return { amount, .. } => **amount,
+            Self::Drawdown { amount, .. } => -**amount,
+        }
+    }
+
+    /// The number of the invoice paid by this transaction, if a drawdown.
+    pub fn invoice_number(&self) -> Option<&FormattedInvoiceNumber> {
+        match self {
+            Self::TopUp { .. } | Self::Return { .. } => None,
+            Self::Drawdown { invoice_number, .. } => Some(invoice_number),
+        }
+    }
+
+    /// The number of the credit note returning an amount to the balance, if
+    /// a return.
+    pub fn credit_note_number(&self) -> Option<&FormattedInvoiceNumber> {
+        match self {
+            Self::TopUp { .. } | Self::Drawdown { .. } => None,
+            Self::Return {
+                credit_note_number, ..
+            } => Some(credit_note_number),
+        }
+    }
+}
+
+impl Retainer {
+    /// The current balance, i.e. all top-ups less all drawdowns.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let retainer = Retainer::sample();
+    /// assert_eq!(*retainer.balance(), dec!(6000));
+    /// ```
+    pub fn balance(&self) -> Cost {
+        Self::sum(&self.transactions)
+    }
+
+    /// The balance available to pay the invoice numbered `invoice_number`:
+    /// the balance before its drawdown, if already recorded, so that the
+    /// invoice can be produced again with the same drawdown, or else the
+    /// current balance. A drawdown already recorded is capped so that the
+    /// balance never falls below zero after the transactions recorded after
+    /// it, e.g. the drawdowns of later invoices.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let retainer = Retainer::sample();
+    /// let drawn = FormattedInvoiceNumber::from("1");
+    /// assert_eq!(*retainer.balance_before(&drawn), dec!(10000));
+    /// let next = FormattedInvoiceNumber::from("2");
+    /// assert_eq!(*retainer.balance_before(&next), dec!(6000));
+    /// ```
+    pub fn balance_before(&self, invoice_number: &FormattedInvoiceNumber) -> Cost {
+        let Some(position) = self.position_of_drawdown(invoice_number) else {
+            return self.balance();
+        };
+        let before = *Self::sum(&self.transactions[..position]);
+        let mut balance = before;
+        let mut lowest = before;
+        for transaction in &self.transactions[position + 1..] {
+            balance += transaction.change();
+            lowest = lowest.min(balance);
+        }
+        Cost::from(lowest.max(rust_decimal::Decimal::ZERO))
+    }
+
+    /// The amount drawn from the balance to pay the invoice numbered
+    /// `invoice_number`, less any amount already returned to it by credit
+    /// notes other than the one numbered `credit_note_number`, or zero if
+    /// nothing was drawn.
+    fn returnable(
+        &self,
+        invoice_number: &FormattedInvoiceNumber,
+        credit_note_number: &FormattedInvoiceNumber,
+    ) -> rust_decimal::Decimal {
+        self.transactions
+            .iter()
+            .map(|transaction| match transaction {
+                RetainerTransaction::Drawdown {
+                    invoice_number: drawn_for,
+                    amount,
+                    ..
+                } if drawn_for == invoice_number => **amount,
+                RetainerTransaction::Return {
+                    credit_note_number: returned_by,
+                    invoice_number: returned_for,
+                    amount,
+                    ..
+                } if returned_for == invoice_number && returned_by != credit_note_number => {
+                    -**amount
+                }
+                _ => rust_decimal::Decimal::ZERO,
+            })
+            .sum::<rust_decimal::Decimal>()
+            .max(rust_decimal::Decimal::ZERO)
+    }
+
+    /// This retainer with `percentage` of the amount drawn to pay the invoice
+    /// numbered `invoice_number` returned to the balance by the credit note
+    /// numbered `credit_note_number`, dated `date`, at most what has not
+    /// already been returned by other credit notes, replacing any return
+    /// already recorded for that credit note, e.g. when it is produced again.
+    /// Unchanged if nothing is left to return.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let retainer = Retainer::sample().with_return(
+    ///     FormattedInvoiceNumber::from("CN-1"),
+    ///     FormattedInvoiceNumber::from("1"),
+    ///     Date::from_str("2025-02-10").unwrap(),
+    ///     &Percentage::try_from(dec!(25)).unwrap(),
+    /// );
+    /// assert_eq!(*retainer.balance(), dec!(7000));
+    /// ```
+    pub fn with_return(
+        mut self,
+        credit_note_number: FormattedInvoiceNumber,
+        invoice_number: FormattedInvoiceNumber,
+        date: Date,
+        percentage: &Percentage,
+    ) -> Self {
+        let returnable = self.returnable(&invoice_number, &credit_note_number);
+        let drawn = self
+            .transactions
+            .iter()
+            .find(|transaction| transaction.invoice_number() == Some(&invoice_number))
+            .map_or(rust_decimal::Decimal::ZERO, RetainerTransaction::change)
+            .abs();
+        let amount = (drawn * percentage.as_fraction())
+            .round_dp(2)
+            .min(returnable);
+        let position = self
+            .transactions
+            .iter()
+            .position(|transaction| transaction.credit_note_number() == Some(&credit_note_number));
+        if amount <= rust_decimal::Decimal::ZERO {
+            if let Some(position) = position {
+                self.transactions.remove(position);
+            }
+            return self;
+        }
+        let returned = RetainerTransaction::Return {
+            date,
+            credit_note_number,
+            invoice_number,
+            amount: Cost::from(amount),
+        };
+        match position {
+            Some(position) => self.transactions[position] = returned,
+            None => self.transactions.push(returned),
+        }
+        self
+    }
+
+    /// Checks that an amount in `currency` can be added to or drawn from the
+    /// balance, i.e. that the retainer is in `currency` or has no currency.
+    ///
+    /// # Errors
+    /// Returns `Error::RetainerCurrencyMismatch` if the retainer is in
+    /// another currency.
+    pub fn validate_currency(&self, currency: Currency) -> Result<()> {
+        match self.currency {
+            Some(retainer_currency) if retainer_currency != currency => {
+                Err(Error::RetainerCurrencyMismatch {
+                    retainer_currency,
+                    currency,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// This retainer with `amount` in `currency` prepaid by the client on
+    /// `date` added to the balance, in `currency` from now on.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidRetainerTopUp` unless `amount` is positive, and
+    /// `Error::RetainerCurrencyMismatch` if the retainer is in another
+    /// currency.
+    pub fn with_top_up(mut self, date: Date, amount: Cost, currency: Currency) -> Result<Self> {
+        if *amount <= rust_decimal::Decimal::ZERO {
+            return Err(Error::InvalidRetainerTopUp {
+                amount: amount.to_string(),
+            });
+        }
+        self.validate_currency(currency)?;
+        self.currency = Some(currency);
+        self.transactions
+            .push(RetainerTransaction::TopUp { date, amount });
+        Ok(self)
+    }
+
+    /// This retainer with `amount` drawn from the balance to pay the invoice
+    /// numbered `invoice_number`, dated `date`, replacing any drawdown already
+    /// recorded for that invoice, e.g. when it is produced again.
+    pub fn with_drawdown(
+        mut self,
+        invoice_number: FormattedInvoiceNumber,
+        date: Date,
+        amount: Cost,
+    ) -> Self {
+        let drawdown = RetainerTransaction::Drawdown {
+            date,
+            invoice_number,
+            amount,
+        };
+        let position = drawdown
+            .invoice_number()
+            .and_then(|number| self.position_of_drawdown(number));
+        match position {
+            Some(position) => self.transactions[position] = drawdown,
+            None => self.transactions.push(drawdown),
+        }
+        self
+    }
+
+    fn position_of_drawdown(&self, invoice_number: &FormattedInvoiceNumber) -> Option<usize> {
+        self.transactions
+            .iter()
+            .position(|transaction| transaction.invoice_number() == Some(invoice_number))
+    }
+
+    fn sum(transactions: &[RetainerTransaction]) -> Cost {
+        Cost::from(
+            transactions
+                .iter()
+                .map(RetainerTransaction::change)
+                .sum::<rust_decimal::Decimal>(),
+        )
+    }
+}
+
+impl HasSample for Retainer {
+    fn sample() -> Self {
+        Self::default()
+            .with_top_up(
+                Date::from_str("2025-01-01").expect("Valid date"),
+                Cost::from(dec!(10000)),
+                Currency::EUR,
+            )
+            .expect("Sample should be valid")
+            .with_drawdown(
+                FormattedInvoiceNumber::from("1"),
+                Date::from_str("2025-01-31").expect("Valid date"),
+                Cost::from(dec!(4000)),
+            )
+    }
+
+    fn sample_other() -> Self {
+        Self::default()
+            .with_top_up(
+                Date::from_str("2025-06-01").expect("Valid date"),
+                Cost::from(dec!(2500)),
+                Currency::SEK,
+            )
+            .expect("Sample should be valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Retainer;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let sut = Sut::sample();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron::from_str::<Sut>(&ron).unwrap(), sut);
+    }
+
+    #[test]
+    fn deserialize_ron() {
+        let ron = r#"
+        Retainer(
+            currency: Some("EUR"),
+            transactions: [
+                TopUp(date: "2025-01-01", amount: Cost(10000.0)),
+                Drawdown(date: "2025-01-31", invoice_number: "1", amount: Cost(4000.0)),
+                TopUp(date: "2025-02-01", amount: Cost(500.0)),
+            ],
+        )
+        "#;
+        let sut: Sut = ron::de::from_str(ron).unwrap();
+        assert_eq!(*sut.balance(), dec!(6500));
+        assert_eq!(*sut.currency(), Some(Currency::EUR));
+    }
+
+    #[test]
+    fn deserialize_ron_without_currency() {
+        let ron = r#"
+        Retainer(
+            transactions: [
+                TopUp(date: "2025-01-01", amount: Cost(10000.0)),
+            ],
+        )
+        "#;
+        let sut: Sut = ron::de::from_str(ron).unwrap();
+        assert_eq!(*sut.currency(), None);
+    }
+
+    #[test]
+    fn drawdown_of_same_invoice_is_replaced() {
+        let number = FormattedInvoiceNumber::from("1");
+        let date = Date::from_str("2025-01-31").unwrap();
+        let sut = Sut::sample()
+            .with_drawdown(number.clone(), date, Cost::from(dec!(3000)))
+            .with_top_up(date, Cost::from(dec!(1000)), Currency::EUR)
+            .unwrap();
+        assert_eq!(sut.transactions().len(), 3);
+        assert_eq!(*sut.balance_before(&number), dec!(10000));
+        assert_eq!(*sut.balance(), dec!(8000));
+    }
+
+    #[test]
+    fn redrawn_amount_is_capped_by_later_drawdowns() {
+        let first = FormattedInvoiceNumber::from("1");
+        let date = Date::from_str("2025-02-28").unwrap();
+        let sut = Sut::sample().with_drawdown(
+            FormattedInvoiceNumber::from("2"),
+            date,
+            Cost::from(dec!(5000)),
+        );
+        assert_eq!(*sut.balance(), dec!(1000));
+        // Drawing more than 5000 for the first invoice again would leave too
+        // little for the second
+        assert_eq!(*sut.balance_before(&first), dec!(5000));
+
+        let sut = sut
+            .with_top_up(date, Cost::from(dec!(2000)), Currency::EUR)
+            .unwrap();
+        assert_eq!(*sut.balance_before(&first), dec!(5000));
+
+        let sut = sut.with_drawdown(
+            FormattedInvoiceNumber::from("3"),
+            date,
+            Cost::from(dec!(3000)),
+        );
+        assert_eq!(*sut.balance(), dec!(0));
+        assert_eq!(*sut.balance_before(&first), dec!(4000));
+    }
+
+    #[test]
+    fn credit_notes_return_drawdown_to_balance() {
+        let invoice = FormattedInvoiceNumber::from("1");
+        let date = Date::from_str("2025-02-10").unwrap();
+        let credit = |sut: Sut, number: &str, percentage: rust_decimal::Decimal| {
+            sut.with_return(
+                FormattedInvoiceNumber::from(number.to_owned()),
+                invoice.clone(),
+                date,
+                &Percentage::try_from(percentage).unwrap(),
+            )
+        };
+        let sut = credit(Sut::sample(), "CN-1", dec!(25));
+        assert_eq!(*sut.balance(), dec!(7000));
+
+        // Producing the same credit note again returns the same amount
+        let sut = credit(sut, "CN-1", dec!(25));
+        assert_eq!(sut.transactions().len(), 3);
+        assert_eq!(*sut.balance(), dec!(7000));
+
+        // At most what was drawn is returned
+        let sut = credit(sut, "CN-2", dec!(100));
+        assert_eq!(*sut.balance(), dec!(10000));
+
+        // Nothing is returned for invoices not paid from the retainer
+        let unchanged = Sut::sample().with_return(
+            FormattedInvoiceNumber::from("CN-3"),
+            FormattedInvoiceNumber::from("2"),
+            date,
+            &Percentage::HUNDRED,
+        );
+        assert_eq!(unchanged, Sut::sample());
+    }
+
+    #[test]
+    fn deserialize_ron_with_return() {
+        let ron = r#"
+        Retainer(
+            currency: Some("EUR"),
+            transactions: [
+                TopUp(date: "2025-01-01", amount: Cost(10000.0)),
+                Drawdown(date: "2025-01-31", invoice_number: "1", amount: Cost(4000.0)),
+                Return(date: "2025-02-10", credit_note_number: "CN-1", invoice_number: "1", amount: Cost(4000.0)),
+            ],
+        )
+        "#;
+        let sut: Sut = ron::de::from_str(ron).unwrap();
+        assert_eq!(*sut.balance(), dec!(10000));
+    }
+
+    #[test]
+    fn top_up_must_be_positive() {
+        let result = Sut::default().with_top_up(Date::sample(), Cost::from(dec!(0)), Currency::EUR);
+        assert!(matches!(result, Err(Error::InvalidRetainerTopUp { .. })));
+    }
+
+    #[test]
+    fn top_up_in_other_currency_is_err() {
+        let result = Sut::sample().with_top_up(Date::sample(), Cost::from(dec!(10)), Currency::SEK);
+        assert!(matches!(
+            result,
+            Err(Error::RetainerCurrencyMismatch {
+                retainer_currency: Currency::EUR,
+                currency: Currency::SEK,
+            })
+        ));
+    }
+
+    #[test]
+    fn retainer_without_currency_accepts_any_currency() {
+        let sut = Sut::default();
+        assert!(sut.validate_currency(Currency::SEK).is_ok());
+        let sut = sut
+            .with_top_up(Date::sample(), Cost::from(dec!(10)), Currency::SEK)
+            .unwrap();
+        assert_eq!(*sut.currency(), Some(Currency::SEK));
+        assert!(sut.validate_currency(Currency::EUR).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+use crate::prelude::*;
+
+/// A rolling period of 14 days, e.g. `2025-06-30..2025-07-13`, used to model
+/// an "every other Monday" bi-weekly cycle which, unlike
+/// [`YearMonthAndFortnight`], is not aligned with months.
+///
+/// Periods are anchored on the `rolling_period_anchor` of the service fees,
+/// i.e. the first day of every period is a multiple of 14 days from the
+/// anchor.
+#[derive(
+    Clone,
+    Copy,
+    derive_more::Debug,
+    Display,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SerializeDisplay,
+    DeserializeFromStr,
+    Getters,
+)]
+#[display("{first_day}..{}", self.last_day())]
+#[debug("{first_day}..{}", self.last_day())]
+pub struct RollingFortnight {
+    /// The first day of the period, e.g. `2025-06-30`.
+    #[getset(get = "pub")]
+    first_day: Date,
+}
+
+impl RollingFortnight {
+    /// The number of days in a period.
+    pub const DAYS: i64 = 14;
+
+    /// Creates a period of 14 days starting on `first_day`.
+    pub fn new(first_day: Date) -> Self {
+        Self { first_day }
+    }
+
+    /// The period anchored on `anchor` which contains `date`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let anchor = Date::from_str("2024-12-23").unwrap();
+    /// let date = Date::from_str("2025-01-08").unwrap();
+    /// let period = RollingFortnight::containing(&date, &anchor);
+    /// assert_eq!(period.to_string(), "2025-01-06..2025-01-19");
+    /// ```
+    pub fn containing(date: &Date, anchor: &Date) -> Self {
+        let anchor = anchor.to_datetime().date();
+        let days = (date.to_datetime().date() - anchor).num_days();
+        let periods = days.div_euclid(Self::DAYS);
+        Self::new(Date::from(
+            anchor + chrono::Duration::days(periods * Self::DAYS),
+        ))
+    }
+
+    /// The period anchored on `anchor` containing today's date.
+    pub fn current(anchor: &Date) -> Self {
+        Self::containing(&Date::from(chrono::Local::now().date_naive()), anchor)
+    }
+
+    /// The period before the current period anchored on `anchor`.
+    pub fn last(anchor: &Date) -> Self {
+        Self::current(anchor).one_fortnight_earlier()
+    }
+
+    /// Returns the period 14 days before this one.
+    pub fn one_fortnight_earlier(&self) -> Self {
+        Self::new(self.advanced_by_days(-Self::DAYS))
+    }
+
+    /// The last day of the period, 13 days after the first day.
+    pub fn last_day(&self) -> Date {
+        self.advanced_by_days(Self::DAYS - 1)
+    }
+
+    /// Whether this period starts a multiple of 14 days from `anchor`.
+    pub fn is_anchored_on(&self, anchor: &Date) -> bool {
+        Self::containing(&self.first_day, anchor) == *self
+    }
+
+    fn advanced_by_days(&self, days: i64) -> Date {
+        Date::from(self.first_day.to_datetime() + chrono::Duration::days(days))
+    }
+}
+
+impl IsPeriod for RollingFortnight {
+    fn max_granularity(&self) -> Granularity {
+        Granularity::Fortnight
+    }
+
+    fn elapsed_periods_since(&self, start: impl std::borrow::Borrow<Self>) -> Result<u16> {
+        let start = start.borrow();
+        if start > self {
+            return Err(Error::StartPeriodAfterEndPeriod {
+                start: start.to_string(),
+                end: self.to_string(),
+            });
+        }
+        let days = (self.first_day.to_datetime() - start.first_day.to_datetime()).num_days();
+        if days % Self::DAYS != 0 {
+            return Err(Error::RollingFortnightsNotAligned {
+                start: start.to_string(),
+                end: self.to_string(),
+            });
+        }
+        Ok((days / Self::DAYS) as u16)
+    }
+
+    fn to_date_start_of_period(&self) -> Date {
+        self.first_day
+    }
+
+    fn to_date_end_of_period(&self) -> Date {
+        self.last_day()
+    }
+
+    fn year(&self) -> &Year {
+        self.first_day.year()
+    }
+
+    fn month(&self) -> &Month {
+        self.first_day.month()
+    }
+
+    fn period_of_same_kind_containing(&self, date: &Date) -> Self {
+        Self::containing(date, &self.first_day)
+    }
+}
+
+impl FromStr for RollingFortnight {
+    type Err = crate::Error;
+
+    /// Parses `"YYYY-MM-DD..YYYY-MM-DD"` into a `RollingFortnight`, e.g.
+    /// `"2025-06-30..2025-07-13"`, the last day must be 13 days after the
+    /// first day.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || Error::InvalidPeriod {
+            bad_value: s.to_owned(),
+        };
+        let (first_day, last_day) = s.split_once("..").ok_or_else(invalid)?;
+        let period = Self::new(Date::from_str(first_day.trim())?);
+        if period.last_day() != Date::from_str(last_day.trim())? {
+            return Err(invalid());
+        }
+        Ok(period)
+    }
+}
+
+impl HasSample for RollingFortnight {
+    fn sample() -> Self {
+        Self::new(Date::from_str("2025-06-30").expect("Valid date"))
+    }
+
+    fn sample_other() -> Self {
+        Self::new(Date::from_str("2025-07-14").expect("Valid date"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_ron_snapshot;
+    use test_log::test;
+
+    type Sut = RollingFortnight;
+
+    fn date(s: &str) -> Date {
+        Date::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn end_of_period_across_year_boundary() {
+        let sut = Sut::new(date("2024-12-23"));
+        assert_eq!(sut.to_date_end_of_period(), date("2025-01-05"));
+        assert_eq!(sut.to_string(), "2024-12-23..2025-01-05");
+    }
+
+    #[test]
+    fn containing() {
+        let anchor = Sut::sample().first_day;
+        assert_eq!(Sut::containing(&date("2025-07-13"), &anchor), Sut::sample());
+        assert_eq!(
+            Sut::containing(&date("2025-07-14"), &anchor),
+            Sut::sample_other()
+        );
+        assert_eq!(
+            Sut::containing(&date("2025-06-29"), &anchor),
+            Sut::new(date("2025-06-16"))
+        );
+    }
+
+    #[test]
+    fn is_anchored_on() {
+        let anchor = date("2024-12-30");
+        assert!(Sut::sample().is_anchored_on(&anchor));
+        assert!(!Sut::new(date("2025-07-07")).is_anchored_on(&anchor));
+    }
+
+    #[test]
+    fn elapsed_periods_since() {
+        assert_eq!(
+            Sut::sample_other()
+                .elapsed_periods_since(Sut::sample())
+                .unwrap(),
+            1
+        );
+        let start = Sut::new(date("2024-12-30"));
+        assert_eq!(Sut::sample().elapsed_periods_since(start).unwrap(), 13);
+    }
+
+    #[test]
+    fn elapsed_periods_since_throws_when_not_aligned() {
+        let result = Sut::sample_other().elapsed_periods_since(Sut::new(date("2025-07-07")));
+        assert!(matches!(
+            result,
+            Err(Error::RollingFortnightsNotAligned { .. })
+        ));
+    }
+
+    #[test]
+    fn elapsed_periods_since_throws_when_start_is_after_end() {
+        let result = Sut::sample().elapsed_periods_since(Sut::sample_other());
+        assert!(matches!(
+            result,
+            Err(Error::StartPeriodAfterEndPeriod { .. })
+        ));
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            Sut::from_str("2025-06-30..2025-07-13").unwrap(),
+            Sut::sample()
+        );
+        assert!(Sut::from_str("2025-06-30..2025-07-14").is_err());
+        assert!(Sut::from_str("2025-06-30").is_err());
+    }
+
+    #[test]
+    fn serde_sample() {
+        assert_ron_snapshot!(&Sut::sample())
+    }
+}
@@ -12,9 +12,46 @@ pub struct ServiceFees {
     #[getset(get = "pub", set_with = "pub")]
     rate: Rate,
 
+    /// Earlier rates, each in effect until a date, e.g. the rate before a
+    /// yearly price increase, used instead of `rate` when invoicing periods
+    /// before the increase.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[getset(get = "pub", set_with = "pub")]
+    rate_history: Vec<HistoricRate>,
+
     /// How often you invoice, cannot be
     #[getset(get = "pub")]
     cadence: Cadence,
+
+    /// Hours worked per weekday and employment percentage, used to calculate
+    /// the quantity when invoicing per day or hour.
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    work_schedule: WorkSchedule,
+
+    /// The first day of any rolling 14 days period, e.g. a Monday, required
+    /// when cadence is `RollingFortnight`.
+    #[serde(default)]
+    #[getset(get = "pub")]
+    rolling_period_anchor: Option<Date>,
+
+    /// The start and end dates of the contract, monthly and fortnight fees of
+    /// periods partially outside of it are pro-rated by working days.
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    contract_window: ContractWindow,
+
+    /// The VAT or sales tax rate of the service, e.g. `25%`, added to the
+    /// invoice total, none if the service is not taxed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    tax_rate: Option<Percentage>,
+
+    /// A standing discount of the service fees, e.g. `"10%"`, deducted on a
+    /// line of its own after the service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    discount: Option<Discount>,
 }
 
 #[bon]
@@ -23,15 +60,32 @@ impl ServiceFees {
     pub fn new(
         name: impl AsRef<str>,
         rate: impl Into<Rate>,
+        #[builder(default)] rate_history: Vec<HistoricRate>,
         cadence: Cadence,
+        #[builder(default)] work_schedule: WorkSchedule,
+        rolling_period_anchor: Option<Date>,
+        #[builder(default)] contract_window: ContractWindow,
+        tax_rate: Option<Percentage>,
+        discount: Option<Discount>,
     ) -> Result<Self, Error> {
         let rate = rate.into();
         cadence.validate(rate.granularity())?;
-        Ok(Self {
+        if cadence == Cadence::RollingFortnight && rolling_period_anchor.is_none() {
+            return Err(Error::MissingRollingPeriodAnchor);
+        }
+        let fees = Self {
             name: name.as_ref().to_owned(),
             rate,
+            rate_history,
             cadence,
-        })
+            work_schedule,
+            rolling_period_anchor,
+            contract_window,
+            tax_rate,
+            discount,
+        };
+        fees.validate_rate_history()?;
+        Ok(fees)
     }
 }
 
@@ -39,6 +93,109 @@ impl ServiceFees {
     pub fn unit_price(&self) -> UnitPrice {
         self.rate.unit_price()
     }
+
+    /// Validates that the rate history uses the granularity of the rate.
+    ///
+    /// # Errors
+    /// Returns an error if any historic rate has another granularity.
+    pub fn validate_rate_history(&self) -> Result<()> {
+        let granularity = self.rate.granularity();
+        match self
+            .rate_history
+            .iter()
+            .find(|historic| historic.rate().granularity() != granularity)
+        {
+            Some(historic) => Err(Error::InvalidGranularityForHistoricRate {
+                until: historic.until().to_string(),
+                historic_granularity: historic.rate().granularity(),
+                service_fees_granularity: granularity,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// The rate in effect on `date`, which is the historic rate ending first
+    /// on or after `date`, or the current rate if none.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let fees = ServiceFees::sample().with_rate_history(vec![HistoricRate::sample()]);
+    /// let rate = |date: &str| **fees.rate_on(&Date::from_str(date).unwrap());
+    /// assert_eq!(rate("2025-03-31"), dec!(700.0));
+    /// assert_eq!(rate("2025-04-01"), dec!(777.0));
+    /// ```
+    pub fn rate_on(&self, date: &Date) -> Rate {
+        self.rate_history
+            .iter()
+            .filter(|historic| historic.until() >= date)
+            .min_by_key(|historic| *historic.until())
+            .map_or(self.rate, |historic| *historic.rate())
+    }
+
+    /// The rates in effect during `date_range` in chronological order, each
+    /// with the dates it was in effect, a single rate unless the rate changed
+    /// during `date_range`.
+    pub fn rates_in(&self, date_range: &DateRange) -> Vec<(DateRange, Rate)> {
+        let range = |start: Date, end: Date| {
+            DateRange::builder()
+                .start(start)
+                .end(end)
+                .build()
+                .expect("Start is never after end")
+        };
+        let mut ends = self
+            .rate_history
+            .iter()
+            .map(|historic| *historic.until())
+            .filter(|until| until >= date_range.start() && until < date_range.end())
+            .collect::<Vec<_>>();
+        ends.sort();
+        ends.dedup();
+
+        let mut start = *date_range.start();
+        let mut rates = Vec::new();
+        for end in ends {
+            rates.push((range(start, end), self.rate_on(&start)));
+            start = Date::from(end.to_datetime() + chrono::Duration::days(1));
+        }
+        rates.push((range(start, *date_range.end()), self.rate_on(&start)));
+        rates
+    }
+
+    /// The anchor date of rolling fortnights.
+    ///
+    /// # Errors
+    /// Returns an error if no anchor date has been set.
+    pub fn rolling_period_anchor_or_err(&self) -> Result<Date> {
+        self.rolling_period_anchor
+            .ok_or(Error::MissingRollingPeriodAnchor)
+    }
+
+    /// The period of the kind matching the cadence which contains `date`,
+    /// e.g. the week of the date for weekly cadence. For monthly and bi-weekly
+    /// cadence a `YearMonthAndFortnight` is returned.
+    ///
+    /// # Errors
+    /// Returns an error if cadence is `RollingFortnight` and no anchor date
+    /// has been set.
+    pub fn period_containing(&self, date: &Date) -> Result<PeriodAnno> {
+        let period = match self.cadence {
+            Cadence::Monthly | Cadence::BiWeekly => YearMonthAndFortnight::builder()
+                .year(*date.year())
+                .month(*date.month())
+                .half(MonthHalf::from(*date))
+                .build()
+                .into(),
+            Cadence::Weekly => YearAndWeek::from(date.to_datetime().date()).into(),
+            Cadence::Quarterly => YearAndQuarter::from(YearAndMonth::from(*date)).into(),
+            Cadence::Yearly => (*date.year()).into(),
+            Cadence::RollingFortnight => {
+                RollingFortnight::containing(date, &self.rolling_period_anchor_or_err()?).into()
+            }
+        };
+        Ok(period)
+    }
 }
 
 impl HasSample for ServiceFees {
@@ -56,6 +213,7 @@ impl HasSample for ServiceFees {
             .name("Consulting Services".to_string())
             .rate(Rate::hourly(dec!(150.0)))
             .cadence(Cadence::BiWeekly)
+            .work_schedule(WorkSchedule::sample_other())
             .build()
             .expect("Sample should be valid")
     }
@@ -84,4 +242,119 @@ mod tests {
     fn test_serde() {
         assert_ron_snapshot!(Sut::sample())
     }
+
+    #[test]
+    fn deserialize_without_work_schedule_uses_default() {
+        let ron = r#"
+        ServiceFees(
+            name: "Consulting",
+            rate: Daily(UnitPrice(500.0)),
+            cadence: Monthly,
+        )
+        "#;
+        let sut: Sut = ron::de::from_str(ron).unwrap();
+        assert_eq!(sut.work_schedule(), &WorkSchedule::default());
+        assert_eq!(*sut.rolling_period_anchor(), None);
+        assert_eq!(sut.contract_window(), &ContractWindow::default());
+    }
+
+    #[test]
+    fn rates_in_splits_at_rate_changes() {
+        let sut = Sut::sample().with_rate_history(vec![
+            HistoricRate::builder()
+                .rate(Rate::daily(dec!(700.0)))
+                .until(Date::from_str("2025-05-14").unwrap())
+                .build(),
+            HistoricRate::builder()
+                .rate(Rate::daily(dec!(600.0)))
+                .until(Date::from_str("2025-04-30").unwrap())
+                .build(),
+        ]);
+        let rates = |start: &str, end: &str| {
+            sut.rates_in(
+                &DateRange::builder()
+                    .start(Date::from_str(start).unwrap())
+                    .end(Date::from_str(end).unwrap())
+                    .build()
+                    .unwrap(),
+            )
+            .into_iter()
+            .map(|(range, rate)| (range.to_string(), **rate))
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            rates("2025-04-01", "2025-04-30"),
+            vec![("2025-04-01..2025-04-30".to_owned(), dec!(600.0))]
+        );
+        assert_eq!(
+            rates("2025-05-01", "2025-05-31"),
+            vec![
+                ("2025-05-01..2025-05-14".to_owned(), dec!(700.0)),
+                ("2025-05-15..2025-05-31".to_owned(), dec!(777.0)),
+            ]
+        );
+        assert_eq!(
+            rates("2025-04-30", "2025-05-15"),
+            vec![
+                ("2025-04-30..2025-04-30".to_owned(), dec!(600.0)),
+                ("2025-05-01..2025-05-14".to_owned(), dec!(700.0)),
+                ("2025-05-15..2025-05-15".to_owned(), dec!(777.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn rate_history_must_use_granularity_of_rate() {
+        let result = Sut::builder()
+            .name("Consulting")
+            .rate(Rate::daily(dec!(500.0)))
+            .rate_history(vec![HistoricRate::sample_other()])
+            .cadence(Cadence::Monthly)
+            .build();
+        assert!(matches!(
+            result,
+            Err(Error::InvalidGranularityForHistoricRate { .. })
+        ));
+    }
+
+    #[test]
+    fn rolling_fortnight_requires_anchor() {
+        let builder = || {
+            Sut::builder()
+                .name("Consulting")
+                .rate(Rate::daily(dec!(500.0)))
+                .cadence(Cadence::RollingFortnight)
+        };
+        assert_eq!(builder().build(), Err(Error::MissingRollingPeriodAnchor));
+        let sut = builder()
+            .rolling_period_anchor(Date::from_str("2025-06-30").unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(
+            sut.rolling_period_anchor_or_err().unwrap().to_string(),
+            "2025-06-30"
+        );
+    }
+
+    #[test]
+    fn period_containing() {
+        let date = Date::from_str("2025-06-15").unwrap();
+        let period = |cadence: Cadence| {
+            Sut::builder()
+                .name("Consulting")
+                .rate(Rate::daily(dec!(500.0)))
+                .cadence(cadence)
+                .rolling_period_anchor(Date::from_str("2025-06-30").unwrap())
+                .build()
+                .unwrap()
+                .period_containing(&date)
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(period(Cadence::Monthly), "2025-06-first-half");
+        assert_eq!(period(Cadence::Weekly), "2025-W24");
+        assert_eq!(period(Cadence::Quarterly), "2025-Q2");
+        assert_eq!(period(Cadence::Yearly), "2025");
+        assert_eq!(period(Cadence::RollingFortnight), "2025-06-02..2025-06-15");
+    }
 }
@@ -0,0 +1,126 @@
+use crate::prelude::*;
+
+/// A service invoiced on a line of its own after the service fees, at a rate
+/// of its own, e.g. on-call hours next to development days, or a second
+/// project with a purchase order of its own.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder, Getters, WithSetters)]
+pub struct ServiceLine {
+    /// Description of the service printed on the line, e.g. `"On-call"`.
+    #[builder(into)]
+    #[getset(get = "pub", set_with = "pub")]
+    name: String,
+
+    /// The rate of the service, e.g. `Hourly(UnitPrice(80.0))`.
+    #[getset(get = "pub", set_with = "pub")]
+    rate: Rate,
+
+    /// Where the quantity of the line comes from, defaults to the time
+    /// worked in the period.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    quantity: ServiceLineQuantity,
+
+    /// A purchase order of the service, e.g. `"PO-67890"`, printed after the
+    /// description, if it differs from the purchase order of the invoice.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    purchase_order: Option<PurchaseOrder>,
+
+    /// The VAT or sales tax rate of the service, e.g. `25%`, defaults to the
+    /// tax rate of the service fees.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    tax_rate: Option<Percentage>,
+
+    /// A standing discount of the service, e.g. `"10%"`, deducted on a line
+    /// of its own after the service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    discount: Option<Discount>,
+}
+
+/// Where the quantity of a [`ServiceLine`] comes from.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, IsVariant)]
+pub enum ServiceLineQuantity {
+    /// The time worked in the period or date range in the granularity of the
+    /// rate of the line, according to the work schedule of the service fees,
+    /// less the dates off, and the time off of the invoice if of the same
+    /// granularity, or the time of the timesheet when invoicing one.
+    #[default]
+    Worked,
+
+    /// The same quantity every period, e.g. `Fixed(10.0)` for ten on-call
+    /// hours, pro-rated by the share of the working days of the period that
+    /// is invoiced, e.g. for a date range or outside of the contract window.
+    Fixed(Quantity),
+}
+
+impl ServiceLine {
+    /// The description printed on the line, followed by the purchase order
+    /// if any.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(ServiceLine::sample().description(), "On-call");
+    /// assert_eq!(ServiceLine::sample_other().description(), "Project Moriarty (PO-67890)");
+    /// ```
+    pub fn description(&self) -> String {
+        match &self.purchase_order {
+            Some(purchase_order) => format!("{} ({})", self.name, purchase_order),
+            None => self.name.clone(),
+        }
+    }
+}
+
+impl HasSample for ServiceLine {
+    fn sample() -> Self {
+        Self::builder()
+            .name("On-call")
+            .rate(Rate::hourly(dec!(80)))
+            .quantity(ServiceLineQuantity::Fixed(Quantity::from(dec!(10))))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .name("Project Moriarty")
+            .rate(Rate::daily(dec!(500)))
+            .purchase_order(PurchaseOrder::sample_other())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = ServiceLine;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn quantity_defaults_to_worked() {
+        let sut: Sut = ron::from_str(r#"(name: "Dev", rate: Daily(UnitPrice(500.0)))"#).unwrap();
+        assert_eq!(*sut.quantity(), ServiceLineQuantity::Worked);
+        assert_eq!(sut.purchase_order(), &None);
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let sut = Sut::sample();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron::from_str::<Sut>(&ron).unwrap(), sut);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::prelude::*;
+
+/// The hours you work each weekday and your employment percentage, used to
+/// calculate the billable quantity when invoicing per day or hour, e.g. a
+/// 4-day week of 7.5 hours per day, or 80% part time.
+///
+/// Weekdays with zero hours are not working days.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Getters)]
+#[serde(try_from = "UncheckedWorkSchedule")]
+pub struct WorkSchedule {
+    /// Hours worked on Mondays, e.g. `8.0`.
+    #[getset(get = "pub")]
+    monday: Quantity,
+
+    /// Hours worked on Tuesdays, e.g. `8.0`.
+    #[getset(get = "pub")]
+    tuesday: Quantity,
+
+    /// Hours worked on Wednesdays, e.g. `8.0`.
+    #[getset(get = "pub")]
+    wednesday: Quantity,
+
+    /// Hours worked on Thursdays, e.g. `8.0`.
+    #[getset(get = "pub")]
+    thursday: Quantity,
+
+    /// Hours worked on Fridays, e.g. `8.0`.
+    #[getset(get = "pub")]
+    friday: Quantity,
+
+    /// Hours worked on Saturdays, typically `0.0`.
+    #[getset(get = "pub")]
+    saturday: Quantity,
+
+    /// Hours worked on Sundays, typically `0.0`.
+    #[getset(get = "pub")]
+    sunday: Quantity,
+
+    /// The share of the schedule you work, e.g. `Some(80.0)` for 80% part
+    /// time, scaling the quantity of days and hours. `None` means full time.
+    #[getset(get = "pub")]
+    employment_percentage: Option<Percentage>,
+}
+
+/// A `WorkSchedule` as read from disk, before being validated.
+#[derive(Deserialize)]
+#[serde(rename = "WorkSchedule")]
+struct UncheckedWorkSchedule {
+    monday: Quantity,
+    tuesday: Quantity,
+    wednesday: Quantity,
+    thursday: Quantity,
+    friday: Quantity,
+    saturday: Quantity,
+    sunday: Quantity,
+    employment_percentage: Option<Percentage>,
+}
+
+impl TryFrom<UncheckedWorkSchedule> for WorkSchedule {
+    type Error = crate::prelude::Error;
+    fn try_from(unchecked: UncheckedWorkSchedule) -> Result<Self> {
+        Self::builder()
+            .monday(unchecked.monday)
+            .tuesday(unchecked.tuesday)
+            .wednesday(unchecked.wednesday)
+            .thursday(unchecked.thursday)
+            .friday(unchecked.friday)
+            .saturday(unchecked.saturday)
+            .sunday(unchecked.sunday)
+            .maybe_employment_percentage(unchecked.employment_percentage)
+            .build()
+    }
+}
+
+#[bon]
+impl WorkSchedule {
+    /// Creates a new schedule, weekdays not specified default to 8 hours for
+    /// Monday to Friday and 0 hours for Saturday and Sunday.
+    ///
+    /// # Errors
+    /// Returns an error if any weekday has negative hours or more than 24
+    /// hours, or if no weekday has any working hours.
+    #[builder]
+    pub fn new(
+        #[builder(default = Quantity::EIGHT)] monday: Quantity,
+        #[builder(default = Quantity::EIGHT)] tuesday: Quantity,
+        #[builder(default = Quantity::EIGHT)] wednesday: Quantity,
+        #[builder(default = Quantity::EIGHT)] thursday: Quantity,
+        #[builder(default = Quantity::EIGHT)] friday: Quantity,
+        #[builder(default = Quantity::ZERO)] saturday: Quantity,
+        #[builder(default = Quantity::ZERO)] sunday: Quantity,
+        employment_percentage: Option<Percentage>,
+    ) -> Result<Self> {
+        Self {
+            monday,
+            tuesday,
+            wednesday,
+            thursday,
+            friday,
+            saturday,
+            sunday,
+            employment_percentage,
+        }
+        .validate()
+    }
+}
+
+impl Default for WorkSchedule {
+    /// Full time, 8 hours per day Monday to Friday.
+    fn default() -> Self {
+        Self::builder().build().expect("Default schedule is valid")
+    }
+}
+
+impl WorkSchedule {
+    /// Validates that every weekday has between 0 and 24 hours, and that at
+    /// least one weekday has working hours.
+    pub fn validate(self) -> Result<Self> {
+        let max_hours = Quantity::from(dec!(24));
+        for weekday in Self::weekdays() {
+            let hours = self.hours_on(weekday);
+            if hours < Quantity::ZERO || hours > max_hours {
+                return Err(Error::InvalidWorkSchedule {
+                    reason: format!("{hours} hours on {weekday} is not between 0 and 24"),
+                });
+            }
+        }
+        if !Self::weekdays().any(|weekday| self.is_working_day(weekday)) {
+            return Err(Error::InvalidWorkSchedule {
+                reason: "No weekday has any working hours".to_owned(),
+            });
+        }
+        Ok(self)
+    }
+
+    /// All weekdays, starting with Monday.
+    pub fn weekdays() -> impl Iterator<Item = Weekday> {
+        [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]
+        .into_iter()
+    }
+
+    /// The hours worked on `weekday`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    ///
+    /// let schedule = WorkSchedule::default();
+    /// assert_eq!(schedule.hours_on(Weekday::Mon), Quantity::EIGHT);
+    /// assert_eq!(schedule.hours_on(Weekday::Sun), Quantity::ZERO);
+    /// ```
+    pub fn hours_on(&self, weekday: Weekday) -> Quantity {
+        match weekday {
+            Weekday::Mon => self.monday,
+            Weekday::Tue => self.tuesday,
+            Weekday::Wed => self.wednesday,
+            Weekday::Thu => self.thursday,
+            Weekday::Fri => self.friday,
+            Weekday::Sat => self.saturday,
+            Weekday::Sun => self.sunday,
+        }
+    }
+
+    /// Whether `weekday` has any working hours.
+    pub fn is_working_day(&self, weekday: Weekday) -> bool {
+        self.hours_on(weekday) > Quantity::ZERO
+    }
+
+    /// The average hours of the working days, e.g. `7.5` for a 4-day week of
+    /// 7.5 hours per day, used to convert hours into days.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    ///
+    /// assert_eq!(WorkSchedule::default().hours_per_working_day(), Quantity::EIGHT);
+    /// ```
+    pub fn hours_per_working_day(&self) -> Quantity {
+        let working_days = Self::weekdays()
+            .filter(|weekday| self.is_working_day(*weekday))
+            .count();
+        let hours = Self::weekdays()
+            .map(|weekday| *self.hours_on(weekday))
+            .sum::<rust_decimal::Decimal>();
+        Quantity::from(hours / rust_decimal::Decimal::from(working_days))
+    }
+
+    /// The factor to scale the quantity of days and hours with, e.g. `0.8` for
+    /// 80% part time, and `1` for full time.
+    pub fn employment_factor(&self) -> rust_decimal::Decimal {
+        self.employment_percentage
+            .map(|percentage| percentage.as_fraction())
+            .unwrap_or(rust_decimal::Decimal::ONE)
+    }
+}
+
+impl HasSample for WorkSchedule {
+    fn sample() -> Self {
+        Self::default()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .monday(Quantity::from(dec!(7.5)))
+            .tuesday(Quantity::from(dec!(7.5)))
+            .wednesday(Quantity::from(dec!(7.5)))
+            .thursday(Quantity::from(dec!(7.5)))
+            .friday(Quantity::ZERO)
+            .employment_percentage(Percentage::sample())
+            .build()
+            .expect("Sample should be valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_ron_snapshot;
+    use test_log::test;
+
+    type Sut = WorkSchedule;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn default_is_full_time_monday_to_friday() {
+        let sut = Sut::default();
+        assert!(sut.is_working_day(Weekday::Fri));
+        assert!(!sut.is_working_day(Weekday::Sat));
+        assert_eq!(sut.employment_factor(), dec!(1));
+    }
+
+    #[test]
+    fn employment_factor_of_part_time() {
+        assert_eq!(Sut::sample_other().employment_factor(), dec!(0.8));
+    }
+
+    #[test]
+    fn throws_when_no_working_days() {
+        let result = Sut::builder()
+            .monday(Quantity::ZERO)
+            .tuesday(Quantity::ZERO)
+            .wednesday(Quantity::ZERO)
+            .thursday(Quantity::ZERO)
+            .friday(Quantity::ZERO)
+            .build();
+        assert!(matches!(result, Err(Error::InvalidWorkSchedule { .. })));
+    }
+
+    #[test]
+    fn throws_when_more_than_24_hours() {
+        let result = Sut::builder().monday(Quantity::from(dec!(25))).build();
+        assert!(matches!(result, Err(Error::InvalidWorkSchedule { .. })));
+    }
+
+    #[test]
+    fn test_serde() {
+        assert_ron_snapshot!(Sut::sample_other())
+    }
+
+    #[test]
+    fn deserialization_roundtrip() {
+        let sut = Sut::sample_other();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron::from_str::<Sut>(&ron).unwrap(), sut);
+    }
+
+    #[test]
+    fn deserialization_validates() {
+        let ron = r#"WorkSchedule(
+            monday: Quantity(25.0),
+            tuesday: Quantity(8.0),
+            wednesday: Quantity(8.0),
+            thursday: Quantity(8.0),
+            friday: Quantity(8.0),
+            saturday: Quantity(0.0),
+            sunday: Quantity(0.0),
+            employment_percentage: None,
+        )"#;
+        let result = ron::from_str::<Sut>(ron);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("not between 0 and 24")
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use derive_more::Constructor;
+
+use crate::prelude::*;
+
+/// A year and quarter, e.g. `2025-Q3`, used to model a three months period,
+/// e.g. for retainer clients billed per quarter.
+#[derive(
+    Clone,
+    Copy,
+    derive_more::Debug,
+    Display,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SerializeDisplay,
+    DeserializeFromStr,
+    Builder,
+    Getters,
+    Constructor,
+)]
+#[display("{year:04}-{quarter}")]
+#[debug("{year:04}-{quarter}")]
+pub struct YearAndQuarter {
+    /// e.g. 2025
+    #[getset(get = "pub")]
+    year: Year,
+
+    /// e.g. `Q3` for July to September
+    #[getset(get = "pub")]
+    quarter: Quarter,
+}
+
+impl From<YearAndMonth> for YearAndQuarter {
+    /// The quarter containing the month, e.g. `2025-Q2` for `2025-05`.
+    fn from(value: YearAndMonth) -> Self {
+        Self::new(*value.year(), Quarter::from(*value.month()))
+    }
+}
+
+impl YearAndQuarter {
+    /// The quarter containing today's date.
+    pub fn current() -> Self {
+        Self::from(YearAndMonth::current())
+    }
+
+    /// The quarter before the current quarter.
+    pub fn last() -> Self {
+        Self::current().one_quarter_earlier()
+    }
+
+    /// Returns the quarter before this one, e.g. `2024-Q4` for `2025-Q1`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let quarter = YearAndQuarter::from_str("2025-Q1").unwrap();
+    /// assert_eq!(quarter.one_quarter_earlier().to_string(), "2024-Q4");
+    /// ```
+    pub fn one_quarter_earlier(&self) -> Self {
+        match self.quarter {
+            Quarter::First => Self::new(Year::from(**self.year() - 1), Quarter::Fourth),
+            Quarter::Second => Self::new(self.year, Quarter::First),
+            Quarter::Third => Self::new(self.year, Quarter::Second),
+            Quarter::Fourth => Self::new(self.year, Quarter::Third),
+        }
+    }
+}
+
+impl IsPeriod for YearAndQuarter {
+    fn max_granularity(&self) -> Granularity {
+        Granularity::Quarter
+    }
+
+    fn elapsed_periods_since(&self, start: impl std::borrow::Borrow<Self>) -> Result<u16> {
+        let start = start.borrow();
+        let quarters =
+            |period: &Self| **period.year() as i32 * 4 + i16::from(period.quarter) as i32;
+        let elapsed = quarters(self) - quarters(start);
+        if elapsed < 0 {
+            return Err(Error::StartPeriodAfterEndPeriod {
+                start: start.to_string(),
+                end: self.to_string(),
+            });
+        }
+        Ok(elapsed as u16)
+    }
+
+    fn to_date_start_of_period(&self) -> Date {
+        YearAndMonth::new(self.year, *self.quarter.first_month()).to_date_start_of_period()
+    }
+
+    fn to_date_end_of_period(&self) -> Date {
+        YearAndMonth::new(self.year, *self.quarter.last_month()).to_date_end_of_period()
+    }
+
+    fn year(&self) -> &Year {
+        &self.year
+    }
+
+    fn month(&self) -> &Month {
+        self.quarter.first_month()
+    }
+
+    fn period_of_same_kind_containing(&self, date: &Date) -> Self {
+        Self::from(YearAndMonth::from(*date))
+    }
+}
+
+impl FromStr for YearAndQuarter {
+    type Err = crate::Error;
+
+    /// Parses `"YYYY-Qn"` into a `YearAndQuarter`, e.g. `"2025-Q3"`.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (year, quarter) = s.split_once('-').ok_or_else(|| Error::InvalidPeriod {
+            bad_value: s.to_owned(),
+        })?;
+        if !quarter.to_lowercase().starts_with('q') {
+            return Err(Error::InvalidPeriod {
+                bad_value: s.to_owned(),
+            });
+        }
+        Ok(Self::new(
+            Year::from_str(year)?,
+            Quarter::from_str(quarter)?,
+        ))
+    }
+}
+
+impl HasSample for YearAndQuarter {
+    fn sample() -> Self {
+        Self::new(2025.into(), Quarter::Third)
+    }
+
+    fn sample_other() -> Self {
+        Self::new(2025.into(), Quarter::Fourth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_ron_snapshot;
+    use test_log::test;
+
+    type Sut = YearAndQuarter;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn start_and_end_of_period() {
+        let sut = Sut::sample();
+        assert_eq!(sut.to_date_start_of_period().to_string(), "2025-07-01");
+        assert_eq!(sut.to_date_end_of_period().to_string(), "2025-09-30");
+        assert_eq!(*sut.month(), Month::July);
+    }
+
+    #[test]
+    fn elapsed_periods_since() {
+        assert_eq!(
+            Sut::sample_other()
+                .elapsed_periods_since(Sut::sample())
+                .unwrap(),
+            1
+        );
+        let start = Sut::from_str("2024-Q4").unwrap();
+        let end = Sut::from_str("2025-Q2").unwrap();
+        assert_eq!(end.elapsed_periods_since(start).unwrap(), 2);
+        assert!(start.elapsed_periods_since(end).is_err());
+    }
+
+    #[test]
+    fn one_quarter_earlier() {
+        assert_eq!(Sut::sample_other().one_quarter_earlier(), Sut::sample());
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for s in ["2025", "2025-3", "2025-Q0", "2025-Q5", "2025-07"] {
+            assert!(Sut::from_str(s).is_err(), "Expected error for: {s}");
+        }
+    }
+
+    #[test]
+    fn serde_sample() {
+        assert_ron_snapshot!(&Sut::sample())
+    }
+}
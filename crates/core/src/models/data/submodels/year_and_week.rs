@@ -0,0 +1,281 @@
+use crate::prelude::*;
+
+/// An ISO 8601 week, e.g. `2025-W27`, used to model a weekly period from
+/// Monday to Sunday.
+///
+/// The year is the ISO week-numbering year, which differs from the calendar
+/// year for some days around new year, e.g. Monday 2024-12-30 is in `2025-W01`.
+#[derive(
+    Clone,
+    Copy,
+    derive_more::Debug,
+    Display,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SerializeDisplay,
+    DeserializeFromStr,
+    Getters,
+)]
+#[display("{year:04}-W{week:02}")]
+#[debug("{year:04}-W{week:02}")]
+pub struct YearAndWeek {
+    /// The ISO week-numbering year, e.g. 2025
+    #[getset(get = "pub")]
+    year: Year,
+
+    /// The ISO week number, between 1 and 52 or 53 depending on the year.
+    #[getset(get = "pub")]
+    week: u8,
+
+    /// The month of the Thursday of the week, which is the month the majority
+    /// of the days of the week belong to.
+    month: Month,
+}
+
+#[bon]
+impl YearAndWeek {
+    /// Creates a new `YearAndWeek`.
+    ///
+    /// # Errors
+    /// Returns an error if the week does not exist in the year, e.g. week 53
+    /// of 2025.
+    #[builder]
+    pub fn new(year: Year, week: u8) -> Result<Self> {
+        let thursday = NaiveDate::from_isoywd_opt(*year as i32, week as u32, Weekday::Thu)
+            .ok_or_else(|| Error::InvalidPeriod {
+                bad_value: format!("{year:04}-W{week:02}"),
+            })?;
+        Ok(Self::from(thursday))
+    }
+}
+
+impl From<NaiveDate> for YearAndWeek {
+    /// The ISO week containing `date`.
+    fn from(date: NaiveDate) -> Self {
+        let iso_week = date.iso_week();
+        let thursday = NaiveDate::from_isoywd_opt(iso_week.year(), iso_week.week(), Weekday::Thu)
+            .expect("Every ISO week has a Thursday");
+        Self {
+            year: Year::from(iso_week.year() as u16),
+            week: iso_week.week() as u8,
+            month: Month::try_from(thursday.month()).expect("Chrono should return valid month"),
+        }
+    }
+}
+
+impl YearAndWeek {
+    /// The week containing today's date.
+    pub fn current() -> Self {
+        Self::from(chrono::Local::now().date_naive())
+    }
+
+    /// The week before the current week.
+    pub fn last() -> Self {
+        Self::current().one_week_earlier()
+    }
+
+    /// Returns the week before this one, e.g. `2024-W52` for `2025-W01`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let week = YearAndWeek::from_str("2025-W01").unwrap();
+    /// assert_eq!(week.one_week_earlier().to_string(), "2024-W52");
+    /// ```
+    pub fn one_week_earlier(&self) -> Self {
+        Self::from(self.monday() - chrono::Duration::weeks(1))
+    }
+
+    /// The Monday starting this week.
+    fn monday(&self) -> NaiveDate {
+        NaiveDate::from_isoywd_opt(**self.year() as i32, self.week as u32, Weekday::Mon)
+            .expect("Week is validated on construction")
+    }
+}
+
+impl IsPeriod for YearAndWeek {
+    fn max_granularity(&self) -> Granularity {
+        Granularity::Day
+    }
+
+    fn elapsed_periods_since(&self, start: impl std::borrow::Borrow<Self>) -> Result<u16> {
+        let start = start.borrow();
+        if start > self {
+            return Err(Error::StartPeriodAfterEndPeriod {
+                start: start.to_string(),
+                end: self.to_string(),
+            });
+        }
+        Ok((self.monday() - start.monday()).num_weeks() as u16)
+    }
+
+    fn to_date_start_of_period(&self) -> Date {
+        Date::from(self.monday())
+    }
+
+    fn to_date_end_of_period(&self) -> Date {
+        Date::from(self.monday() + chrono::Duration::days(6))
+    }
+
+    fn year(&self) -> &Year {
+        &self.year
+    }
+
+    fn month(&self) -> &Month {
+        &self.month
+    }
+
+    fn period_of_same_kind_containing(&self, date: &Date) -> Self {
+        Self::from(date.to_datetime().date())
+    }
+}
+
+impl FromStr for YearAndWeek {
+    type Err = crate::Error;
+
+    /// Parses `"YYYY-Www"` into a `YearAndWeek`, e.g. `"2025-W27"`.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || Error::InvalidPeriod {
+            bad_value: s.to_owned(),
+        };
+        let (year, week) = s.split_once('-').ok_or_else(invalid)?;
+        let week = week
+            .strip_prefix('W')
+            .or_else(|| week.strip_prefix('w'))
+            .ok_or_else(invalid)?;
+        let year = Year::from_str(year)?;
+        let week = week.parse::<u8>().map_err(|_| invalid())?;
+        Self::builder().year(year).week(week).build()
+    }
+}
+
+impl HasSample for YearAndWeek {
+    fn sample() -> Self {
+        Self::builder()
+            .year(2025.into())
+            .week(27)
+            .build()
+            .expect("Valid week")
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .year(2025.into())
+            .week(28)
+            .build()
+            .expect("Valid week")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_ron_snapshot;
+    use test_log::test;
+
+    type Sut = YearAndWeek;
+
+    fn week(s: &str) -> Sut {
+        Sut::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn start_and_end_of_period() {
+        let sut = Sut::sample();
+        assert_eq!(sut.to_date_start_of_period().to_string(), "2025-06-30");
+        assert_eq!(sut.to_date_end_of_period().to_string(), "2025-07-06");
+        assert_eq!(*sut.month(), Month::July);
+    }
+
+    #[test]
+    fn week_one_starts_in_previous_calendar_year() {
+        let sut = week("2025-W01");
+        assert_eq!(sut.to_date_start_of_period().to_string(), "2024-12-30");
+        assert_eq!(*sut.month(), Month::January);
+        assert_eq!(
+            Sut::from(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap()),
+            sut
+        );
+    }
+
+    #[test]
+    fn elapsed_periods_since() {
+        assert_eq!(
+            Sut::sample_other()
+                .elapsed_periods_since(Sut::sample())
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            Sut::sample().elapsed_periods_since(Sut::sample()).unwrap(),
+            0
+        );
+        // 2020 has 53 weeks
+        assert_eq!(
+            week("2021-W01")
+                .elapsed_periods_since(week("2020-W01"))
+                .unwrap(),
+            53
+        );
+        assert_eq!(
+            week("2026-W01")
+                .elapsed_periods_since(week("2025-W01"))
+                .unwrap(),
+            52
+        );
+    }
+
+    #[test]
+    fn elapsed_periods_since_throws_when_start_is_after_end() {
+        let result = Sut::sample().elapsed_periods_since(Sut::sample_other());
+        assert!(matches!(
+            result,
+            Err(Error::StartPeriodAfterEndPeriod { .. })
+        ));
+    }
+
+    #[test]
+    fn one_week_earlier_across_53_week_year() {
+        assert_eq!(week("2021-W01").one_week_earlier(), week("2020-W53"));
+    }
+
+    #[test]
+    fn from_str_valid() {
+        assert_eq!(week("2025-w27"), Sut::sample());
+        assert_eq!(week("2020-W53").to_string(), "2020-W53");
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for s in [
+            "2025", "2025-27", "2025-W00", "2025-W53", "2025-Wx", "2025-07",
+        ] {
+            assert!(Sut::from_str(s).is_err(), "Expected error for: {s}");
+        }
+    }
+
+    #[test]
+    fn serde_sample() {
+        assert_ron_snapshot!(&Sut::sample())
+    }
+
+    #[test]
+    fn deserialize_ron() {
+        let sut: Sut = ron::de::from_str(r#""2025-W28""#).unwrap();
+        assert_eq!(sut, Sut::sample_other());
+    }
+}
@@ -50,6 +50,20 @@ impl YearMonthAndFortnight {
             .build()
     }
 
+    /// Returns the first day of the fortnight, which is the 1st for the first
+    /// half or the day after the last day of the first half for the second half.
+    fn first_day_of_half(&self) -> Day {
+        match self.half {
+            MonthHalf::First => Day::try_from(1).expect("LEQ 31"),
+            MonthHalf::Second => Day::try_from(if self.month == Month::February {
+                15
+            } else {
+                16
+            })
+            .expect("LEQ 31"),
+        }
+    }
+
     /// Returns the last day of the fortnight, which is either the 14th or
     /// 15th for the first half or the last day of the month for the second half.
     fn last_day_of_half(&self) -> Day {
@@ -89,6 +103,14 @@ impl IsPeriod for YearMonthAndFortnight {
         Ok(elapsed as u16)
     }
 
+    fn to_date_start_of_period(&self) -> Date {
+        Date::builder()
+            .year(*self.year())
+            .month(*self.month())
+            .day(self.first_day_of_half())
+            .build()
+    }
+
     fn to_date_end_of_period(&self) -> Date {
         Date::builder()
             .year(*self.year())
@@ -104,6 +126,14 @@ impl IsPeriod for YearMonthAndFortnight {
     fn month(&self) -> &Month {
         &self.month
     }
+
+    fn period_of_same_kind_containing(&self, date: &Date) -> Self {
+        Self::builder()
+            .year(*date.year())
+            .month(*date.month())
+            .half(MonthHalf::from(*date))
+            .build()
+    }
 }
 
 impl FromStr for YearMonthAndFortnight {
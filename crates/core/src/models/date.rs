@@ -10,6 +10,8 @@ use crate::prelude::*;
     PartialEq,
     Eq,
     Hash,
+    PartialOrd,
+    Ord,
     SerializeDisplay,
     DeserializeFromStr,
     Builder,
@@ -103,9 +105,27 @@ impl Date {
         Self::from(advanced_date)
     }
 
+    /// The due date of an invoice dated `self` with payment terms `terms`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let date = Date::from_str("2025-05-20").unwrap();
+    /// let terms = PaymentTerms::EndOfMonth { days: 15 };
+    /// assert_eq!(date.advance(&terms).to_string(), "2025-06-15");
+    /// ```
     pub fn advance(&self, terms: &PaymentTerms) -> Self {
+        let after_days = |date: Self, days: u16| {
+            Self::from(date.to_datetime() + chrono::Duration::days(days as i64))
+        };
         match terms {
-            PaymentTerms::Net(days) => self.advance_days(days.due_in()),
+            PaymentTerms::Net(days) => after_days(*self, *days.due_in()),
+            PaymentTerms::EndOfMonth { days } => {
+                after_days(YearAndMonth::from(*self).to_date_end_of_month(), *days)
+            }
+            PaymentTerms::DueOnReceipt => *self,
+            PaymentTerms::FixedDate(date) => *date.max(self),
+            PaymentTerms::EarlyPaymentDiscount { net, .. } => after_days(*self, *net.due_in()),
         }
     }
 }
@@ -37,6 +37,7 @@ impl Decimal {
     pub const ONE: Self = Self(rust_decimal::Decimal::ONE);
     pub const TWO: Self = Self(rust_decimal::Decimal::TWO);
     pub const EIGHT: Self = Self(rust_decimal::Decimal::from_parts(8, 0, 0, false, 0));
+    pub const HUNDRED: Self = Self(rust_decimal::Decimal::ONE_HUNDRED);
 }
 
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
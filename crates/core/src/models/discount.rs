@@ -0,0 +1,151 @@
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use crate::prelude::*;
+
+/// A discount on a line item or on a whole invoice, either a percentage, e.g.
+/// `"10%"`, or a fixed amount, e.g. `"500"`, in the currency of what is
+/// discounted.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Display,
+    IsVariant,
+    DeserializeFromStr,
+    SerializeDisplay,
+)]
+pub enum Discount {
+    /// A share of the total cost, e.g. `10%`.
+    #[display("{_0}")]
+    Percentage(Percentage),
+
+    /// A fixed amount off the total cost, e.g. `500`.
+    #[display("{_0}")]
+    Amount(Cost),
+}
+
+impl Discount {
+    /// The amount to deduct from `total`, rounded to two decimals, which is
+    /// negative if `total` is, e.g. for credit notes. A fixed amount is at
+    /// most `total`, so that nothing is discounted below zero.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let total = Cost::from(dec!(1234.5));
+    /// assert_eq!(*Discount::from_str("10%").unwrap().amount_of(total), dec!(123.45));
+    /// assert_eq!(*Discount::from_str("500").unwrap().amount_of(total), dec!(500));
+    /// assert_eq!(*Discount::from_str("2000").unwrap().amount_of(total), dec!(1234.5));
+    /// ```
+    pub fn amount_of(&self, total: Cost) -> Cost {
+        match self {
+            Self::Percentage(percentage) => {
+                Cost::from((*total * percentage.as_fraction()).round_dp(2))
+            }
+            Self::Amount(amount) if total.is_sign_negative() => Cost::from((-**amount).max(*total)),
+            Self::Amount(amount) => Cost::from((**amount).min(*total)),
+        }
+    }
+}
+
+impl FromStr for Discount {
+    type Err = crate::Error;
+
+    /// Parses a percentage if ending with `%`, e.g. `"10%"`, otherwise an
+    /// amount, e.g. `"500"`.
+    ///
+    /// # Errors
+    /// Returns an error if not a valid percentage, or if the amount is not a
+    /// positive number.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: String| Error::InvalidDiscount {
+            invalid_string: s.to_owned(),
+            reason,
+        };
+        let trimmed = s.trim();
+        if trimmed.ends_with('%') {
+            return Percentage::from_str(trimmed)
+                .map(Self::Percentage)
+                .map_err(|e| invalid(e.to_string()));
+        }
+        let amount = rust_decimal::Decimal::from_str(trimmed)
+            .map_err(|e| invalid(format!("Expected a percentage or an amount, {e}")))?;
+        if amount.is_sign_negative() || amount.is_zero() {
+            return Err(invalid("Amount must be positive".to_owned()));
+        }
+        Ok(Self::Amount(Cost::from(amount)))
+    }
+}
+
+impl HasSample for Discount {
+    fn sample() -> Self {
+        Self::Percentage(Percentage::try_from(dec!(10)).expect("Valid percentage"))
+    }
+
+    fn sample_other() -> Self {
+        Self::Amount(Cost::from(dec!(500)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Discount;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        for sut in [Sut::sample(), Sut::sample_other()] {
+            assert_eq!(Sut::from_str(&sut.to_string()).unwrap(), sut);
+        }
+        assert_eq!(Sut::sample().to_string(), "10%");
+        assert_eq!(Sut::sample_other().to_string(), "500");
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for invalid in ["", "ten", "110%", "-5", "0", "-5%"] {
+            assert!(
+                Sut::from_str(invalid).is_err(),
+                "Expected error for: {invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let ron = ron::to_string(&Sut::sample()).unwrap();
+        assert_eq!(ron, r#""10%""#);
+        assert_eq!(ron::from_str::<Sut>(&ron).unwrap(), Sut::sample());
+    }
+
+    #[test]
+    fn amount_of_credit_is_negative() {
+        let total = Cost::from(dec!(-200));
+        assert_eq!(*Sut::sample().amount_of(total), dec!(-20));
+    }
+
+    #[test]
+    fn amount_of_is_at_most_total() {
+        let amount = Sut::Amount(Cost::from(dec!(500)));
+        assert_eq!(*amount.amount_of(Cost::from(dec!(200))), dec!(200));
+        assert_eq!(*amount.amount_of(Cost::from(dec!(-200))), dec!(-200));
+        assert_eq!(*amount.amount_of(Cost::from(dec!(-800))), dec!(-500));
+        assert_eq!(*amount.amount_of(Cost::from(dec!(0))), dec!(0));
+    }
+}
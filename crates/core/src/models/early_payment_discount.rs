@@ -0,0 +1,93 @@
+use crate::prelude::*;
+
+/// A cash discount offered for early payment of an invoice, e.g. `2%` off if
+/// paid within 10 days with payment terms `2/10 Net 30`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Getters)]
+pub struct EarlyPaymentDiscount {
+    /// The discount, e.g. `2%`.
+    #[getset(get = "pub")]
+    percentage: Percentage,
+
+    /// The last day to pay with the discount, e.g. `2025-06-10`.
+    #[getset(get = "pub")]
+    deadline: Date,
+
+    /// The amount to pay with the discount, i.e. the grand total less the
+    /// discount, rounded to two decimals.
+    #[getset(get = "pub")]
+    discounted_amount: Cost,
+}
+
+#[bon]
+impl EarlyPaymentDiscount {
+    /// Creates a new early payment discount of `percentage` off `total`,
+    /// payable by `deadline`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let discount = EarlyPaymentDiscount::builder()
+    ///     .percentage(Percentage::try_from(dec!(2)).unwrap())
+    ///     .deadline(Date::from_str("2025-06-10").unwrap())
+    ///     .total(Cost::from(dec!(1000)))
+    ///     .build();
+    /// assert_eq!(**discount.discounted_amount(), dec!(980));
+    /// ```
+    #[builder]
+    pub fn new(percentage: Percentage, deadline: Date, total: Cost) -> Self {
+        let discounted_amount =
+            (*total * (rust_decimal::Decimal::ONE - percentage.as_fraction())).round_dp(2);
+        Self {
+            percentage,
+            deadline,
+            discounted_amount: Cost::from(discounted_amount),
+        }
+    }
+}
+
+impl HasSample for EarlyPaymentDiscount {
+    fn sample() -> Self {
+        Self::builder()
+            .percentage(Percentage::try_from(dec!(2)).expect("Valid percentage"))
+            .deadline(Date::from_str("2025-06-10").expect("Valid date"))
+            .total(Cost::sample())
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .percentage(Percentage::sample_other())
+            .deadline(Date::sample_other())
+            .total(Cost::sample_other())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = EarlyPaymentDiscount;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn discounted_amount_is_rounded() {
+        let sut = Sut::builder()
+            .percentage(Percentage::try_from(dec!(2.5)).unwrap())
+            .deadline(Date::sample())
+            .total(Cost::from(dec!(333.33)))
+            .build();
+        assert_eq!(**sut.discounted_amount(), dec!(325.00));
+    }
+}
@@ -26,6 +26,22 @@ pub enum Error {
     #[error("Period is not YearMonthAndFortnight")]
     PeriodIsNotYearMonthAndFortnight,
 
+    /// Period is not YearAndWeek
+    #[error("Period is not YearAndWeek")]
+    PeriodIsNotYearAndWeek,
+
+    /// Period is not YearAndQuarter
+    #[error("Period is not YearAndQuarter")]
+    PeriodIsNotYearAndQuarter,
+
+    /// Period is not Year
+    #[error("Period is not Year")]
+    PeriodIsNotYear,
+
+    /// Period is not RollingFortnight
+    #[error("Period is not RollingFortnight")]
+    PeriodIsNotRollingFortnight,
+
     #[error(
         "Invalid granularity for time off: '{free_granularity}', expected: '{service_fees_granularity}', use the same time unit for time off as you specified in service fees. View it with `klirr data dump` command."
     )]
@@ -303,4 +319,261 @@ pub enum Error {
     /// Error when parsing the response from the exchange rate API.
     #[error("Failed to parse exchange rate response, because: {underlying}")]
     ParseError { underlying: String },
+
+    /// Failed to parse a percentage, e.g. when not a number or not between 0 and 100.
+    #[error("Invalid percentage: '{invalid_string}', reason: {reason}")]
+    InvalidPercentage {
+        invalid_string: String,
+        reason: String,
+    },
+
+    /// Invalid work schedule, e.g. when no weekday has any working hours.
+    #[error("Invalid work schedule, because: {reason}")]
+    InvalidWorkSchedule { reason: String },
+
+    /// Failed to parse a date off, e.g. when the range is reversed.
+    #[error("Invalid date off: '{invalid_string}', reason: {reason}")]
+    InvalidDateOff {
+        invalid_string: String,
+        reason: String,
+    },
+
+    /// Failed to parse a timesheet, e.g. when a row is missing the hours.
+    #[error("Invalid timesheet at line {line}, reason: {reason}")]
+    InvalidTimesheet { line: usize, reason: String },
+
+    /// A timesheet can only be used when invoicing per day or hour.
+    #[error("A timesheet requires the granularity to be Day or Hour, but it is {granularity}")]
+    TimesheetRequiresDayOrHourGranularity { granularity: Granularity },
+
+    /// A timesheet entry is dated outside of the target period.
+    #[error("Timesheet entry dated {date} is outside of the target period {target_period}")]
+    TimesheetEntryOutsideTargetPeriod { date: String, target_period: String },
+
+    /// A timesheet entry is dated on a recorded full day off.
+    #[error("Timesheet entry dated {date} is on the recorded date off {date_off}")]
+    TimesheetEntryOnDateOff { date: String, date_off: String },
+
+    /// Cannot invoice for a granularity coarser than the cadence, e.g. for a
+    /// month when cadence is weekly.
+    #[error("Cannot invoice for {granularity} when cadence is {cadence}")]
+    CannotInvoiceForGranularityWithCadence {
+        granularity: Granularity,
+        cadence: Cadence,
+    },
+
+    /// The period kind of expenses does not match the cadence, e.g. expensing
+    /// a month when cadence is weekly.
+    #[error("Cannot expense for period '{period}' when cadence is {cadence}")]
+    CannotExpenseForPeriodWithCadence { period: String, cadence: Cadence },
+
+    /// Two rolling fortnights are not a multiple of 14 days apart, i.e. they
+    /// are not anchored on the same date.
+    #[error("Rolling fortnights '{start}' and '{end}' are not a multiple of 14 days apart")]
+    RollingFortnightsNotAligned { start: String, end: String },
+
+    /// The service fees lack the anchor date of rolling fortnights.
+    #[error(
+        "Cadence RollingFortnight requires `rolling_period_anchor` in service fees, the first day of any 14 days period"
+    )]
+    MissingRollingPeriodAnchor,
+
+    /// A rolling fortnight does not start a multiple of 14 days from the
+    /// anchor date of the service fees.
+    #[error("Period '{period}' is not anchored on the rolling period anchor {anchor}")]
+    RollingFortnightNotAnchored { period: String, anchor: String },
+
+    /// The end date of a contract is before its start date.
+    #[error("Invalid contract window, end date {end} is before start date {start}")]
+    InvalidContractWindow { start: String, end: String },
+
+    /// The target period is entirely outside of the contract window.
+    #[error("Target period {target_period} is outside of the contract window {contract_window}")]
+    TargetPeriodOutsideContractWindow {
+        target_period: String,
+        contract_window: String,
+    },
+
+    /// The end date of a date range is before its start date.
+    #[error("Invalid date range, end date {end} is before start date {start}")]
+    InvalidDateRange { start: String, end: String },
+
+    /// Failed to parse PaymentTerms from a string, e.g. when the format is incorrect.
+    #[error("Failed to parse PaymentTerms from string: {invalid_string}")]
+    FailedToParsePaymentTerms { invalid_string: String },
+
+    /// A reminder was requested for an invoice which is not overdue at the
+    /// date of the reminder.
+    #[error("Invoice is not overdue, due date {due_date} is not before {date}")]
+    InvoiceNotOverdue { due_date: String, date: String },
+
+    /// The invoice number of a reminder does not match the number of the
+    /// invoice issued for the period.
+    #[error("Invoice number mismatch, expected {expected} for period {period}, but got {found}")]
+    InvoiceNumberMismatch {
+        expected: String,
+        found: String,
+        period: String,
+    },
+
+    /// Failed to read the ledger of issued invoices.
+    #[error("Failed to read ledger: {underlying}")]
+    FailedToReadLedger { underlying: String },
+
+    /// Failed to append an entry to the ledger of issued invoices.
+    #[error("Failed to write ledger: {underlying}")]
+    FailedToWriteLedger { underlying: String },
+
+    /// The invoice number has already been issued to another client, for
+    /// another period or kind of invoice according to the ledger.
+    #[error("Invoice number {number} for {period} has already been issued for {existing_period}")]
+    InvoiceNumberCollision {
+        number: String,
+        period: String,
+        existing_period: String,
+    },
+
+    /// No invoice with the number has been recorded in the ledger.
+    #[error("No invoice with number {number} in the ledger")]
+    NoLedgerEntryWithNumber { number: String },
+
+    /// No client profile with the name exists in the data directory.
+    #[error("No client profile named '{name}', expected at: {path}")]
+    NoClientProfileNamed { name: String, path: String },
+
+    /// Failed to build a client profile, e.g. when the input is invalid.
+    #[error("Invalid client profile, reason: {reason}")]
+    InvalidClientProfile { reason: String },
+
+    /// Failed to parse a discount, e.g. when neither a percentage nor an amount.
+    #[error("Invalid discount: '{invalid_string}', reason: {reason}")]
+    InvalidDiscount {
+        invalid_string: String,
+        reason: String,
+    },
+
+    /// A historic rate of the service fees has another granularity than the
+    /// current rate, e.g. a daily rate before an hourly rate.
+    #[error(
+        "Invalid granularity of historic rate until {until}: '{historic_granularity}', expected: '{service_fees_granularity}', the rate history of the service fees must use the same time unit as the rate."
+    )]
+    InvalidGranularityForHistoricRate {
+        until: String,
+        historic_granularity: Granularity,
+        service_fees_granularity: Granularity,
+    },
+
+    /// The milestones of a milestone contract are invalid, e.g. add up to
+    /// more than the contract total.
+    #[error("Invalid milestone contract, reason: {reason}")]
+    InvalidMilestoneContract { reason: String },
+
+    /// No milestone with the name exists in the milestone contract.
+    #[error(
+        "No milestone named '{name}' in the milestone contract, available milestones: [{available}], see `milestones.ron` in the data directory."
+    )]
+    NoMilestoneNamed { name: String, available: String },
+
+    /// The milestone has already been billed according to the ledger.
+    #[error(
+        "Milestone '{milestone}' has already been billed in invoice {number}, credit that invoice to bill it again."
+    )]
+    MilestoneAlreadyBilled { milestone: String, number: String },
+
+    /// A top-up of a retainer is not a positive amount.
+    #[error("Invalid retainer top-up: '{amount}', must be a positive amount.")]
+    InvalidRetainerTopUp { amount: String },
+
+    /// Failed to copy the receipt of an expense into the receipts folder of
+    /// the data directory, e.g. when the receipt file does not exist.
+    #[error("Failed to save receipt '{path}', underlying: {underlying}")]
+    FailedToSaveReceipt { path: String, underlying: String },
+
+    /// Failed to read the receipt of an expense from the receipts folder of
+    /// the data directory.
+    #[error("Failed to read receipt '{path}', underlying: {underlying}")]
+    FailedToReadReceipt { path: String, underlying: String },
+
+    /// An expense selector is neither a position, starting at `1`, nor a name.
+    #[error(
+        "Invalid expense '{invalid_string}', expected its position, e.g. `2`, or its name, e.g. `Coffee`"
+    )]
+    InvalidExpenseSelector { invalid_string: String },
+
+    /// No expense recorded for the period is addressed by the selector.
+    #[error(
+        "No expense {selector} recorded for period {period}, which has {count} expenses, list them with `klirr data expenses list`."
+    )]
+    NoExpenseMatching {
+        period: String,
+        selector: String,
+        count: usize,
+    },
+
+    /// The name of an expense matches several expenses recorded for the period.
+    #[error(
+        "Found {count} expenses named '{name}' recorded for period {period}, address the expense by its position instead."
+    )]
+    AmbiguousExpenseName {
+        period: String,
+        name: String,
+        count: usize,
+    },
+
+    /// A milestone is billed but no milestone contract has been set up.
+    #[error(
+        "Cannot bill milestone '{name}' without a milestone contract, see `milestones.ron` in the data directory."
+    )]
+    NoMilestoneContract { name: String },
+
+    /// An amount is added to or drawn from a retainer in another currency,
+    /// e.g. after the currency of the payment information was changed.
+    #[error(
+        "The retainer is in {retainer_currency}, cannot top it up or draw from it in {currency}."
+    )]
+    RetainerCurrencyMismatch {
+        /// The currency of the retainer, e.g. "EUR".
+        retainer_currency: Currency,
+        /// The currency of the top-up or invoice, e.g. "USD".
+        currency: Currency,
+    },
+
+    /// A date range to invoice overlaps a period recorded as off.
+    #[error(
+        "Date range {date_range} overlaps the period off {period_off}, invoice the days outside of it instead."
+    )]
+    DateRangeOverlapsPeriodOff {
+        date_range: String,
+        period_off: String,
+    },
+
+    /// A previously issued invoice prepared again from the data on disk no
+    /// longer matches the invoice recorded in the ledger, e.g. because the
+    /// rate or the exchange rates changed since.
+    #[error(
+        "Invoice {number} was issued with a total of {issued}, but prepared again it totals {total}, refusing to refer to it."
+    )]
+    IssuedInvoiceChanged {
+        /// The number of the invoice, e.g. "LUP-2025-0042".
+        number: String,
+        /// The total recorded in the ledger, e.g. "9000.00 EUR".
+        issued: String,
+        /// The total of the invoice prepared again, e.g. "9500.00 EUR".
+        total: String,
+    },
+
+    /// The output path specified has no parent directory, e.g. `/`.
+    #[error("Invalid output path '{path}', it must be a file in an existing directory.")]
+    InvalidOutputPath { path: String },
+
+    /// A credit note of none of the invoice, i.e. with a percentage of zero.
+    #[error("Nothing to credit of invoice {number}, the percentage to credit must be above 0%.")]
+    NothingToCredit { number: String },
+
+    /// The name of a client profile is not the name of a file in the
+    /// directory of client profiles, e.g. `../acme`.
+    #[error(
+        "Invalid client profile name '{name}', it must not be empty, start with '.' or contain '/' or '\\'."
+    )]
+    InvalidClientProfileName { name: String },
 }
@@ -0,0 +1,153 @@
+use crate::prelude::*;
+
+/// Addresses one of the expenses recorded for a period, either by its
+/// position in the period as listed, starting at `1`, or by its name.
+#[derive(Clone, Debug, Display, PartialEq, Eq, Hash)]
+pub enum ExpenseSelector {
+    /// The position of the expense in the period, starting at `1`, e.g. `2`
+    /// for the second expense listed.
+    #[display("#{_0}")]
+    Index(usize),
+
+    /// The name of the expense, case insensitive, e.g. `"coffee"`, which must
+    /// match exactly one expense of the period.
+    #[display("'{_0}'")]
+    Name(String),
+}
+
+impl ExpenseSelector {
+    /// The index of the expense addressed by this selector among `items`,
+    /// the expenses recorded for `period`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let items = vec![Item::sample_expense_breakfast(), Item::sample_expense_coffee()];
+    /// let period = YearAndMonth::may(2025);
+    /// assert_eq!(ExpenseSelector::Index(2).index_in(&items, &period).unwrap(), 1);
+    /// let coffee = ExpenseSelector::Name("coffee".to_owned());
+    /// assert_eq!(coffee.index_in(&items, &period).unwrap(), 1);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `Error::NoExpenseMatching` if no expense is addressed, and
+    /// `Error::AmbiguousExpenseName` if the name matches several expenses.
+    pub fn index_in(&self, items: &[Item], period: &impl IsPeriod) -> Result<usize> {
+        let period = Into::<PeriodAnno>::into(period.clone()).to_string();
+        let no_match = || Error::NoExpenseMatching {
+            period: period.clone(),
+            selector: self.to_string(),
+            count: items.len(),
+        };
+        match self {
+            Self::Index(position) => position
+                .checked_sub(1)
+                .filter(|index| *index < items.len())
+                .ok_or_else(no_match),
+            Self::Name(name) => {
+                let matching = items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| item.name().eq_ignore_ascii_case(name.trim()))
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>();
+                match matching.as_slice() {
+                    [] => Err(no_match()),
+                    [index] => Ok(*index),
+                    _ => Err(Error::AmbiguousExpenseName {
+                        period,
+                        name: name.clone(),
+                        count: matching.len(),
+                    }),
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for ExpenseSelector {
+    type Err = Error;
+
+    /// Parses a position, e.g. `"2"`, or else a name, e.g. `"Coffee"`.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(Error::InvalidExpenseSelector {
+                invalid_string: s.to_owned(),
+            });
+        }
+        match s.parse::<usize>() {
+            Ok(0) => Err(Error::InvalidExpenseSelector {
+                invalid_string: s.to_owned(),
+            }),
+            Ok(position) => Ok(Self::Index(position)),
+            Err(_) => Ok(Self::Name(s.to_owned())),
+        }
+    }
+}
+
+impl HasSample for ExpenseSelector {
+    fn sample() -> Self {
+        Self::Index(1)
+    }
+
+    fn sample_other() -> Self {
+        Self::Name("Coffee".to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = ExpenseSelector;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Sut::from_str("2").unwrap(), Sut::Index(2));
+        assert_eq!(Sut::from_str(" Coffee ").unwrap(), Sut::sample_other());
+        assert!(Sut::from_str("0").is_err());
+        assert!(Sut::from_str(" ").is_err());
+    }
+
+    #[test]
+    fn index_in() {
+        let period = YearAndMonth::may(2025);
+        let items = vec![
+            Item::sample_expense_breakfast(),
+            Item::sample_expense_coffee(),
+            Item::sample_expense_coffee().with_receipt(Some(PathBuf::from("a.jpg"))),
+        ];
+        assert_eq!(Sut::Index(1).index_in(&items, &period).unwrap(), 0);
+        assert!(matches!(
+            Sut::Index(4).index_in(&items, &period),
+            Err(Error::NoExpenseMatching { count: 3, .. })
+        ));
+        assert_eq!(
+            Sut::Name("BREAKFAST".to_owned())
+                .index_in(&items, &period)
+                .unwrap(),
+            0
+        );
+        assert!(matches!(
+            Sut::Name("Coffee".to_owned()).index_in(&items, &period),
+            Err(Error::AmbiguousExpenseName { count: 2, .. })
+        ));
+        assert!(matches!(
+            Sut::Name("Lunch".to_owned()).index_in(&items, &period),
+            Err(Error::NoExpenseMatching { .. })
+        ));
+    }
+}
@@ -0,0 +1,49 @@
+use derive_more::FromStr;
+
+use crate::prelude::*;
+
+/// An invoice number as printed on the invoice, formatted according to the
+/// [`InvoiceNumberFormat`], e.g. `"LUP-2025-0042"`, or just `"90"` with the
+/// default format.
+#[derive(
+    Clone, Debug, Display, Serialize, Deserialize, PartialEq, Eq, Hash, From, Deref, FromStr,
+)]
+#[from(String, &'static str)]
+#[serde(transparent)]
+pub struct FormattedInvoiceNumber(String);
+
+impl HasSample for FormattedInvoiceNumber {
+    fn sample() -> Self {
+        Self::from("9876")
+    }
+
+    fn sample_other() -> Self {
+        Self::from("1234")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = FormattedInvoiceNumber;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            Sut::from_str("LUP-2025-0042").unwrap(),
+            Sut::from("LUP-2025-0042")
+        );
+    }
+}
@@ -5,9 +5,10 @@ use crate::prelude::*;
 /// details from the `ProtoInvoiceInfo`.
 #[derive(Clone, Debug, Serialize, PartialEq, Eq, Hash, Deserialize, Getters, Builder)]
 pub struct InvoiceInfoFull {
-    /// The unique number of this invoice, typically a number, e.g. `"90"`
+    /// The unique number of this invoice formatted according to the number
+    /// format, e.g. `"90"` or `"LUP-2025-0042"`.
     #[getset(get = "pub")]
-    number: InvoiceNumber,
+    number: FormattedInvoiceNumber,
 
     /// When the payment is due, calculated from the invoice date and payment terms.
     #[getset(get = "pub")]
@@ -17,6 +18,16 @@ pub struct InvoiceInfoFull {
     #[getset(get = "pub")]
     due_date: Date,
 
+    /// The payment terms in the language of the invoice, e.g. `"Net 30"`.
+    #[getset(get = "pub")]
+    terms: String,
+
+    /// The last day to pay with the cash discount of the payment terms, if
+    /// any, e.g. 10 days after the invoice date for `2/10 Net 30`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    discount_deadline: Option<Date>,
+
     /// A purchase order number associated with this invoice, e.g. `"PO-12345"`
     /// Typically agreed upon between the vendor and client before the
     /// invoice is issued.
@@ -31,14 +42,66 @@ pub struct InvoiceInfoFull {
     #[builder(default)]
     #[getset(get = "pub")]
     emphasize_color_hex: HexColor,
+
+    /// Public holidays falling on weekdays in the invoiced period, which have
+    /// been excluded from the quantity of days or hours invoiced.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[getset(get = "pub")]
+    excluded_holidays: Vec<Holiday>,
+
+    /// The discount of the whole invoice, if any, deducted on lines of its
+    /// own once the line items are in the target currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    discount: Option<Discount>,
+
+    /// The name of the milestone of the milestone contract billed, if this
+    /// invoice bills a milestone, e.g. `"Kickoff"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    milestone: Option<String>,
+
+    /// The balance prepaid by the client available to pay this invoice, if
+    /// the client pays a retainer, drawn from once the line items are in the
+    /// target currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    retainer_balance: Option<Cost>,
+}
+
+impl InvoiceInfoFull {
+    /// The information of this invoice dated `invoice_date` and due on
+    /// `due_date`, e.g. as recorded in the ledger when it was issued.
+    pub fn as_issued(self, invoice_date: Date, due_date: Date) -> Self {
+        Self {
+            invoice_date,
+            due_date,
+            ..self
+        }
+    }
+
+    /// The information of a credit note numbered `number` and dated `date`
+    /// crediting this invoice, which is due on its date without any cash
+    /// discount.
+    pub fn to_credit_note(self, number: FormattedInvoiceNumber, date: Date) -> Self {
+        Self {
+            number,
+            invoice_date: date,
+            due_date: date,
+            discount_deadline: None,
+            ..self
+        }
+    }
 }
 
 impl HasSample for InvoiceInfoFull {
     fn sample() -> Self {
         Self::builder()
-            .number(InvoiceNumber::sample())
+            .number(FormattedInvoiceNumber::sample())
             .invoice_date(Date::sample())
             .due_date(Date::sample())
+            .terms(PaymentTerms::sample().localized(&L18nInvoiceInfo::english()))
             .purchase_order(PurchaseOrder::sample())
             .footer_text(FooterText::sample())
             .emphasize_color_hex(HexColor::sample())
@@ -47,9 +110,10 @@ impl HasSample for InvoiceInfoFull {
 
     fn sample_other() -> Self {
         Self::builder()
-            .number(InvoiceNumber::sample_other())
+            .number(FormattedInvoiceNumber::sample_other())
             .invoice_date(Date::sample_other())
             .due_date(Date::sample_other())
+            .terms(PaymentTerms::sample_other().localized(&L18nInvoiceInfo::english()))
             .purchase_order(PurchaseOrder::sample_other())
             .footer_text(FooterText::sample_other())
             .emphasize_color_hex(HexColor::sample_other())
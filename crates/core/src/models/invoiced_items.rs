@@ -1,12 +1,25 @@
 use crate::prelude::*;
 
-/// The items being invoiced this month, either services or expenses.
+/// The items being invoiced this month, either services or expenses, or both
+/// in a single invoice.
 #[derive(Clone, Debug, Display, Serialize, Deserialize, IsVariant, PartialEq)]
 pub enum InvoicedItems {
     #[display("Service {{ time_off: {} }} ", time_off.map(|d| *d).unwrap_or(Quantity::ZERO))]
     Service { time_off: Option<TimeOff> },
+    /// Services with the quantity being the hours worked according to a
+    /// timesheet, instead of the working days of the period.
+    #[display("Timesheet {{ hours: {} }}", timesheet.total_hours())]
+    Timesheet { timesheet: Timesheet },
     #[display("Expenses")]
     Expenses,
+    /// Services followed by the expenses of the period in a single invoice,
+    /// numbered as the services invoice of the period.
+    #[display("ServiceAndExpenses {{ time_off: {} }}", time_off.map(|d| *d).unwrap_or(Quantity::ZERO))]
+    ServiceAndExpenses { time_off: Option<TimeOff> },
+    /// A single milestone of the milestone contract, e.g. `"Kickoff"`, which
+    /// can only be billed once.
+    #[display("Milestone {{ name: {name} }}")]
+    Milestone { name: String },
 }
 impl MaybeIsExpenses for InvoicedItems {
     fn is_expenses(&self) -> bool {
@@ -49,11 +62,30 @@ mod tests {
         assert_ne!(Sut::sample(), Sut::sample_other());
     }
 
+    #[test]
+    fn display_timesheet() {
+        let sut = Sut::Timesheet {
+            timesheet: Timesheet::sample(),
+        };
+        assert_eq!(sut.to_string(), "Timesheet { hours: 15.5 }");
+    }
+
+    #[test]
+    fn display_milestone() {
+        let sut = Sut::Milestone {
+            name: "Kickoff".to_owned(),
+        };
+        assert_eq!(sut.to_string(), "Milestone { name: Kickoff }");
+    }
+
     #[test]
     fn is_expenses() {
         assert!(MaybeIsExpenses::is_expenses(&Sut::Expenses));
         assert!(!MaybeIsExpenses::is_expenses(&Sut::Service {
             time_off: None
         }));
+        assert!(!MaybeIsExpenses::is_expenses(&Sut::ServiceAndExpenses {
+            time_off: None
+        }));
     }
 }
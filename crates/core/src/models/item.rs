@@ -16,6 +16,7 @@ macro_rules! define_item_struct {
             Deserialize,
             Getters,
             Setters,
+            WithSetters,
             Builder,
         )]
         #[display(
@@ -44,6 +45,21 @@ macro_rules! define_item_struct {
             /// The date of the expense, e.g. `2025-05-31`
             #[getset(get = "pub")]
             transaction_date: Date,
+            /// The VAT or sales tax rate of the item, e.g. `25%`, if taxed.
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            #[getset(get = "pub")]
+            tax_rate: Option<Percentage>,
+            /// A discount on the total cost of the item, e.g. `10%`, listed
+            /// on a line of its own after the item.
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            #[getset(get = "pub", set_with = "pub")]
+            discount: Option<Discount>,
+            /// The receipt of the expense, the path to a receipt image or PDF
+            /// when recorded, saved as the name of its copy in the receipts
+            /// folder of the data directory, e.g. `"2025-05-31_coffee.jpg"`.
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            #[getset(get = "pub", set_with = "pub")]
+            receipt: Option<PathBuf>,
         }
     };
 }
@@ -126,6 +142,46 @@ impl Item {
         Ok(converted_rates.with_total_cost())
     }
 
+    /// Converts the item into the target currency like
+    /// [`Item::total_cost_in_target_currency`], followed by a line deducting
+    /// its discount, if any. A discount of a fixed amount is converted from
+    /// the currency of the item, and is at most the total cost of the item.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let item = Item::from_str("Consulting, 500, EUR, 10, 2025-05-31").unwrap().with_discount(Some(Discount::from_str("10%").unwrap()));
+    /// let exchange_rates = ExchangeRates::builder()
+    ///     .target_currency(Currency::EUR)
+    ///     .rates(ExchangeRatesMap::new())
+    ///     .build();
+    /// let lines = item.lines_in_target_currency(&exchange_rates).unwrap();
+    /// assert_eq!(lines.len(), 2);
+    /// assert_eq!(**lines[1].total_cost(), dec!(-500));
+    /// ```
+    pub fn lines_in_target_currency(
+        self,
+        exchange_rates: &ExchangeRates,
+    ) -> Result<Vec<ItemConvertedIntoTargetCurrency>> {
+        let source_currency = self.currency;
+        let converted = self.total_cost_in_target_currency(exchange_rates)?;
+        let Some(discount) = *converted.discount() else {
+            return Ok(vec![converted]);
+        };
+        let amount = match discount {
+            Discount::Percentage(_) => discount.amount_of(*converted.total_cost()),
+            Discount::Amount(amount) => Discount::Amount(Cost::from(
+                exchange_rates
+                    .convert(UnitPrice::from(*amount), source_currency)?
+                    .round_dp(2),
+            ))
+            .amount_of(*converted.total_cost()),
+        };
+        let line = ItemConvertedIntoTargetCurrency::discounting(&converted, amount);
+        Ok(vec![converted, line])
+    }
+
     /// Maps an `Item` into an `ItemConvertedIntoTargetCurrency` with the total cost
     /// calculated in the source currency.
     ///
@@ -145,6 +201,36 @@ impl Item {
             .build()
     }
 
+    /// Formats the expense in the format parsed by [`Item::from_str`], i.e.
+    /// `name, unit_price, currency, quantity, transaction_date`, followed by
+    /// the tax rate and the receipt, if any. The discount is not included.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let expense = "Taxi, 30, EUR, 1, 2025-05-31, , taxi.pdf";
+    /// let item = Item::from_str(expense).unwrap();
+    /// assert_eq!(item.to_expense_string(), expense);
+    /// assert_eq!(Item::from_str(&item.to_expense_string()).unwrap(), item);
+    /// ```
+    pub fn to_expense_string(&self) -> String {
+        let mut parts = vec![
+            self.name.clone(),
+            self.unit_price.to_string(),
+            self.currency.to_string(),
+            self.quantity.to_string(),
+            self.transaction_date.to_string(),
+        ];
+        if self.tax_rate.is_some() || self.receipt.is_some() {
+            parts.push(self.tax_rate.map(|t| t.to_string()).unwrap_or_default());
+        }
+        if let Some(receipt) = &self.receipt {
+            parts.push(receipt.display().to_string());
+        }
+        parts.join(", ")
+    }
+
     /// Converts the item into a new item with the unit price converted to the target currency
     /// using the provided exchange rates.
     fn with_exchange_rates(self, exchange_rates: &ExchangeRates) -> Result<Self> {
@@ -155,6 +241,9 @@ impl Item {
             .unit_price(converted_unit_price)
             .quantity(self.quantity)
             .currency(*exchange_rates.target_currency())
+            .maybe_tax_rate(self.tax_rate)
+            .maybe_discount(self.discount)
+            .maybe_receipt(self.receipt)
             .build())
     }
 }
@@ -163,13 +252,16 @@ impl FromStr for Item {
     type Err = crate::prelude::Error;
 
     /// Parses a string in the format: "name, unit_price, currency, quantity, transaction_date", or
-    /// without spaces after commas, even mixed, e.g. "Coffee, 2.5,EUR, 3.0,2025-05-31".
+    /// without spaces after commas, even mixed, e.g. "Coffee, 2.5,EUR, 3.0,2025-05-31",
+    /// optionally followed by a tax rate, e.g. "Coffee, 2.5, EUR, 3.0, 2025-05-31, 12%",
+    /// and the path to a receipt, e.g. "Coffee, 2.5, EUR, 3.0, 2025-05-31, 12%, coffee.jpg",
+    /// leaving the tax rate empty if untaxed, e.g. "Taxi, 30, EUR, 1, 2025-05-31, , taxi.pdf".
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let parts: Vec<&str> = s.split(',').map(str::trim).collect();
-        if parts.len() != 5 {
+        if !(5..=7).contains(&parts.len()) {
             return Err(Error::InvalidExpenseItem {
                 invalid_string: s.to_string(),
-                reason: "Expected 5 comma-separated values, on format: \"Coffee, 2.5, EUR, 3.0, 2025-05-31\"".to_string(),
+                reason: "Expected 5 to 7 comma-separated values, on format: \"Coffee, 2.5, EUR, 3.0, 2025-05-31\", \"Coffee, 2.5, EUR, 3.0, 2025-05-31, 12%\" or \"Coffee, 2.5, EUR, 3.0, 2025-05-31, 12%, coffee.jpg\"".to_string(),
             });
         }
 
@@ -206,12 +298,29 @@ impl FromStr for Item {
             reason: format!("Failed to parse transaction_date: {e}"),
         })?;
 
+        let tax_rate = parts
+            .get(5)
+            .filter(|tax_rate| !tax_rate.is_empty())
+            .map(|tax_rate| Percentage::from_str(tax_rate))
+            .transpose()
+            .map_err(|e| Error::InvalidExpenseItem {
+                invalid_string: s.to_string(),
+                reason: format!("Failed to parse tax_rate: {e}"),
+            })?;
+
+        let receipt = parts
+            .get(6)
+            .filter(|receipt| !receipt.is_empty())
+            .map(PathBuf::from);
+
         Ok(Item::builder()
             .name(name)
             .unit_price(unit_price)
             .currency(currency)
             .quantity(quantity)
             .transaction_date(transaction_date)
+            .maybe_tax_rate(tax_rate)
+            .maybe_receipt(receipt)
             .build())
     }
 }
@@ -249,16 +358,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_str_with_tax_rate() {
+        let sut = Sut::from_str("Coffee, 2.5, EUR, 3.0, 2025-05-31, 12%").unwrap();
+        assert_eq!(
+            sut.tax_rate(),
+            &Some(Percentage::try_from(dec!(12)).unwrap())
+        );
+        assert_eq!(
+            Sut::from_str("Coffee, 2.5, EUR, 3.0, 2025-05-31")
+                .unwrap()
+                .tax_rate(),
+            &None
+        );
+    }
+
+    #[test]
+    fn from_str_with_receipt() {
+        let sut =
+            Sut::from_str("Coffee, 2.5, EUR, 3.0, 2025-05-31, 12%, receipts/coffee.jpg").unwrap();
+        assert_eq!(
+            sut.tax_rate(),
+            &Some(Percentage::try_from(dec!(12)).unwrap())
+        );
+        assert_eq!(sut.receipt(), &Some(PathBuf::from("receipts/coffee.jpg")));
+        let untaxed = Sut::from_str("Taxi, 30, EUR, 1, 2025-05-31, , taxi.pdf").unwrap();
+        assert_eq!(untaxed.tax_rate(), &None);
+        assert_eq!(untaxed.receipt(), &Some(PathBuf::from("taxi.pdf")));
+    }
+
+    #[test]
+    fn to_expense_string_roundtrip() {
+        for expense in [
+            "Coffee, 2.5, EUR, 3.0, 2025-05-31",
+            "Coffee, 2.5, EUR, 3.0, 2025-05-31, 12%",
+            "Coffee, 2.5, EUR, 3.0, 2025-05-31, 12%, receipts/coffee.jpg",
+        ] {
+            let sut = Sut::from_str(expense).unwrap();
+            assert_eq!(sut.to_expense_string(), expense);
+        }
+    }
+
     #[test]
     fn from_str_invalid() {
         let invalid_strings = [
-            "Coffee,2.5, EUR,3.0",                          // Missing transaction_date
-            "Coffee,2.5, EUR,3.0, invalid_date",            // Invalid transaction_date
-            "Coffee,2.5, EUR,3.0, 2025-05-31, extra",       // Too many parts
-            "Coffee,invalid_price, EUR,3.0, 2025-05-31",    // Invalid unit_price
+            "Coffee,2.5, EUR,3.0",                    // Missing transaction_date
+            "Coffee,2.5, EUR,3.0, invalid_date",      // Invalid transaction_date
+            "Coffee,2.5, EUR,3.0, 2025-05-31, extra", // Invalid tax_rate
+            "Coffee,2.5, EUR,3.0, 2025-05-31, 12%, a.jpg, extra", // Too many parts
+            "Coffee,invalid_price, EUR,3.0, 2025-05-31", // Invalid unit_price
             "Coffee,2.5, invalid_currency,3.0, 2025-05-31", // Invalid currency
-            "Coffee,2.5, EUR,-3.0, 2025-05-31",             // Negative quantity
-            "Coffee,2.5, EUR,a, 2025-05-31",                // Negative quantity
+            "Coffee,2.5, EUR,-3.0, 2025-05-31",       // Negative quantity
+            "Coffee,2.5, EUR,a, 2025-05-31",          // Negative quantity
         ];
 
         for &s in &invalid_strings {
@@ -13,6 +13,74 @@ pub struct ItemConvertedIntoTargetCurrency {
     /// The total cost of the item, calculated as `unit_price * quantity`
     #[getset(get = "pub")]
     total_cost: Cost,
+
+    /// True if this is a line deducting the discount of the item before it,
+    /// or of the whole invoice, with a negative total cost.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    is_discount: bool,
+}
+
+impl ItemConvertedIntoTargetCurrency {
+    /// A line deducting `amount` for the discount of `discounted`, an item in
+    /// the target currency, with a negative unit price and total cost, e.g.
+    /// `-50` for a discount of `10%` of `500`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let line = ItemConvertedIntoTargetCurrency::discounting(&Item::sample(), Cost::from(dec!(50)));
+    /// assert!(line.is_discount());
+    /// assert_eq!(**line.total_cost(), dec!(-50));
+    /// assert_eq!(line.name(), Item::sample().name());
+    /// ```
+    pub fn discounting(discounted: &Item, amount: Cost) -> Self {
+        let in_source_currency = Item::builder()
+            .name(discounted.name().clone())
+            .unit_price(UnitPrice::from(-*amount))
+            .currency(*discounted.currency())
+            .quantity(Quantity::from(rust_decimal::Decimal::ONE))
+            .transaction_date(*discounted.transaction_date())
+            .maybe_tax_rate(*discounted.tax_rate())
+            .maybe_discount(*discounted.discount())
+            .build();
+        Self::builder()
+            .in_source_currency(in_source_currency)
+            .total_cost(Cost::from(-*amount))
+            .is_discount(true)
+            .build()
+    }
+
+    /// The reversal of `fraction` of this item in a credit note, i.e. with a
+    /// negative quantity and total cost, e.g. `-0.5` for half of one item.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let item = ItemConvertedIntoTargetCurrency::sample();
+    /// let credited = item.credited(dec!(0.5));
+    /// assert_eq!(**credited.quantity(), -**item.quantity() * dec!(0.5));
+    /// assert_eq!(**credited.total_cost(), -**item.total_cost() * dec!(0.5));
+    /// ```
+    pub fn credited(&self, fraction: rust_decimal::Decimal) -> Self {
+        let item = &self.in_source_currency;
+        let quantity = Quantity::from(-**item.quantity() * fraction);
+        let in_source_currency = Item::builder()
+            .name(item.name().clone())
+            .unit_price(*item.unit_price())
+            .currency(*item.currency())
+            .quantity(quantity)
+            .transaction_date(*item.transaction_date())
+            .maybe_tax_rate(*item.tax_rate())
+            .maybe_discount(*item.discount())
+            .build();
+        Self::builder()
+            .in_source_currency(in_source_currency)
+            .total_cost(Cost::from((-**self.total_cost() * fraction).round_dp(2)))
+            .is_discount(self.is_discount)
+            .build()
+    }
 }
 
 impl HasSample for ItemConvertedIntoTargetCurrency {
@@ -2,7 +2,7 @@ use crate::prelude::*;
 
 /// The content of the localization file, which includes
 /// client information, invoice information, vendor information,
-/// and line items.
+/// line items, tax, payment reminders, credit notes and retainers.
 #[derive(Debug, Clone, Serialize, Deserialize, Getters, Builder)]
 pub struct L18nContent {
     #[getset(get = "pub")]
@@ -17,6 +17,18 @@ pub struct L18nContent {
     #[getset(get = "pub")]
     line_items: L18nLineItems,
 
+    #[getset(get = "pub")]
+    tax: L18nTax,
+
+    #[getset(get = "pub")]
+    reminder: L18nReminder,
+
+    #[getset(get = "pub")]
+    credit_note: L18nCreditNote,
+
+    #[getset(get = "pub")]
+    retainer: L18nRetainer,
+
     #[getset(get = "pub")]
     month_names: [String; 12],
 }
@@ -28,6 +40,10 @@ impl L18nContent {
             .invoice_info(L18nInvoiceInfo::english())
             .vendor_info(L18nVendorInfo::english())
             .line_items(L18nLineItems::english())
+            .tax(L18nTax::english())
+            .reminder(L18nReminder::english())
+            .credit_note(L18nCreditNote::english())
+            .retainer(L18nRetainer::english())
             .month_names([
                 "January".to_string(),
                 "February".to_string(),
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+
+/// Localization for credit notes reversing a previously issued invoice, used
+/// in the header and in the section referencing the original invoice.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, Builder)]
+pub struct L18nCreditNote {
+    /// EN: "Credit note"
+    #[getset(get = "pub")]
+    title: String,
+
+    /// EN: "Credit note no:"
+    #[getset(get = "pub")]
+    credit_note_identifier: String,
+
+    /// EN: "Date:"
+    #[getset(get = "pub")]
+    date: String,
+
+    /// EN: "Credits invoice {number} dated {invoice_date}."
+    #[getset(get = "pub")]
+    credits_invoice: String,
+
+    /// EN: "Crediting {percentage} of the invoice amount {invoice_amount}."
+    #[getset(get = "pub")]
+    partial: String,
+
+    /// EN: "Total credited:"
+    #[getset(get = "pub")]
+    total_credited: String,
+}
+
+impl L18nCreditNote {
+    pub fn english() -> Self {
+        Self::builder()
+            .title("Credit note".to_string())
+            .credit_note_identifier("Credit note no:".to_string())
+            .date("Date:".to_string())
+            .credits_invoice("Credits invoice {number} dated {invoice_date}.".to_string())
+            .partial("Crediting {percentage} of the invoice amount {invoice_amount}.".to_string())
+            .total_credited("Total credited:".to_string())
+            .build()
+    }
+}
@@ -31,6 +31,39 @@ pub struct L18nInvoiceInfo {
     /// EN: "Terms"
     #[getset(get = "pub")]
     terms: String,
+
+    /// EN: "Net {days}", `{days}` is replaced by the number of days.
+    #[getset(get = "pub")]
+    net_terms: String,
+
+    /// EN: "End of month"
+    #[getset(get = "pub")]
+    end_of_month_terms: String,
+
+    /// EN: "End of month + {days} days", `{days}` is replaced by the number
+    /// of days.
+    #[getset(get = "pub")]
+    end_of_month_plus_days_terms: String,
+
+    /// EN: "Due on receipt"
+    #[getset(get = "pub")]
+    due_on_receipt_terms: String,
+
+    /// EN: "Due {date}", `{date}` is replaced by the due date.
+    #[getset(get = "pub")]
+    fixed_date_terms: String,
+
+    /// EN: "{percentage} {discount_days} days, Net {days}", `{percentage}` is
+    /// replaced by the discount, `{discount_days}` by the number of days
+    /// within which the discount applies and `{days}` by the net days.
+    #[getset(get = "pub")]
+    early_payment_discount_terms: String,
+
+    /// EN: "Pay {discounted_amount} by {discount_date} ({percentage} discount),
+    /// otherwise {total} by {due_date}", with placeholders replaced by the
+    /// discounted and full amounts and their deadlines.
+    #[getset(get = "pub")]
+    early_payment_discount_offer: String,
 }
 
 impl L18nInvoiceInfo {
@@ -43,6 +76,16 @@ impl L18nInvoiceInfo {
             .client_contact("For the attention of:".to_string())
             .vendor_contact("Our reference:".to_string())
             .terms("Terms:".to_string())
+            .net_terms("Net {days}".to_string())
+            .end_of_month_terms("End of month".to_string())
+            .end_of_month_plus_days_terms("End of month + {days} days".to_string())
+            .due_on_receipt_terms("Due on receipt".to_string())
+            .fixed_date_terms("Due {date}".to_string())
+            .early_payment_discount_terms("{percentage} {discount_days} days, Net {days}".to_string())
+            .early_payment_discount_offer(
+                "Pay {discounted_amount} by {discount_date} ({percentage} discount), otherwise {total} by {due_date}"
+                    .to_string(),
+            )
             .build()
     }
 }
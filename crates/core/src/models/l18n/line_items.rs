@@ -27,6 +27,25 @@ pub struct L18nLineItems {
     /// EN: "Grand Total:"
     #[getset(get = "pub")]
     grand_total: String,
+
+    /// EN: "Subtotal services:", the subtotal of the services of an invoice
+    /// for both services and expenses.
+    #[getset(get = "pub")]
+    services_subtotal: String,
+
+    /// EN: "Subtotal expenses:", the subtotal of the expenses of an invoice
+    /// for both services and expenses.
+    #[getset(get = "pub")]
+    expenses_subtotal: String,
+
+    /// EN: "Excluded public holidays:"
+    #[getset(get = "pub")]
+    excluded_holidays: String,
+
+    /// EN: "Discount {discount}", the label of a line deducting a discount,
+    /// e.g. `"Discount 10%"`, followed by the discounted item, if any.
+    #[getset(get = "pub")]
+    discount: String,
 }
 
 impl L18nLineItems {
@@ -38,6 +57,10 @@ impl L18nLineItems {
             .unit_price("Unit price".to_string())
             .total_cost("Total cost".to_string())
             .grand_total("Grand Total:".to_string())
+            .services_subtotal("Subtotal services:".to_string())
+            .expenses_subtotal("Subtotal expenses:".to_string())
+            .excluded_holidays("Excluded public holidays:".to_string())
+            .discount("Discount {discount}".to_string())
             .build()
     }
 }
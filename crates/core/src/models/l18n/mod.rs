@@ -1,16 +1,24 @@
 mod client_info;
 mod content;
+mod credit_note;
 mod invoice_info;
 mod language;
 mod line_items;
 mod localization;
+mod reminder;
+mod retainer;
 mod swedish;
+mod tax;
 mod vendor_info;
 
 pub use client_info::*;
 pub use content::*;
+pub use credit_note::*;
 pub use invoice_info::*;
 pub use language::*;
 pub use line_items::*;
 pub use localization::*;
+pub use reminder::*;
+pub use retainer::*;
+pub use tax::*;
 pub use vendor_info::*;
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+/// Localization for payment reminders of overdue invoices, used in the
+/// reminder section referencing the original invoice.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, Builder)]
+pub struct L18nReminder {
+    /// EN: "Payment reminder"
+    #[getset(get = "pub")]
+    title: String,
+
+    /// EN: "Regarding invoice {number} dated {invoice_date}, due {due_date}."
+    #[getset(get = "pub")]
+    regarding: String,
+
+    /// EN: "As of {date} we have not received your payment, ...", `{date}`
+    /// is replaced by the date of the reminder.
+    #[getset(get = "pub")]
+    unpaid: String,
+
+    /// EN: "Your payment was received on {date}, after the due date, ...",
+    /// `{date}` is replaced by the payment date.
+    #[getset(get = "pub")]
+    paid_late: String,
+
+    /// EN: "Invoice amount"
+    #[getset(get = "pub")]
+    invoice_amount: String,
+
+    /// EN: "Late payment interest {rate} per year for {days} days"
+    #[getset(get = "pub")]
+    late_interest: String,
+
+    /// EN: "Reminder fee"
+    #[getset(get = "pub")]
+    reminder_fee: String,
+
+    /// EN: "Paid {date}"
+    #[getset(get = "pub")]
+    paid: String,
+
+    /// EN: "Amount due:"
+    #[getset(get = "pub")]
+    amount_due: String,
+}
+
+impl L18nReminder {
+    pub fn english() -> Self {
+        Self::builder()
+            .title("Payment reminder".to_string())
+            .regarding("Regarding invoice {number} dated {invoice_date}, due {due_date}.".to_string())
+            .unpaid("As of {date} we have not received your payment, please pay the amount due below at your earliest convenience.".to_string())
+            .paid_late("Your payment was received on {date}, after the due date, please pay the late payment interest and fee below.".to_string())
+            .invoice_amount("Invoice amount".to_string())
+            .late_interest("Late payment interest {rate} per year for {days} days".to_string())
+            .reminder_fee("Reminder fee".to_string())
+            .paid("Paid {date}".to_string())
+            .amount_due("Amount due:".to_string())
+            .build()
+    }
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+
+/// Localization for the amount drawn from the balance prepaid by a client
+/// paying a retainer, deducted from the grand total.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, Builder)]
+pub struct L18nRetainer {
+    /// EN: "Drawn from prepaid retainer:"
+    #[getset(get = "pub")]
+    drawn: String,
+
+    /// EN: "Remaining retainer balance:"
+    #[getset(get = "pub")]
+    remaining: String,
+
+    /// EN: "Amount due:"
+    #[getset(get = "pub")]
+    amount_due: String,
+}
+
+impl L18nRetainer {
+    pub fn english() -> Self {
+        Self::builder()
+            .drawn("Drawn from prepaid retainer:".to_string())
+            .remaining("Remaining retainer balance:".to_string())
+            .amount_due("Amount due:".to_string())
+            .build()
+    }
+}
@@ -28,6 +28,18 @@ impl L18nInvoiceInfo {
             .client_contact("Er referens:".to_string())
             .vendor_contact("Vår referens:".to_string())
             .terms("Villkor".to_string())
+            .net_terms("Netto {days} dagar".to_string())
+            .end_of_month_terms("Månadens slut".to_string())
+            .end_of_month_plus_days_terms("Månadens slut + {days} dagar".to_string())
+            .due_on_receipt_terms("Betalas vid mottagande".to_string())
+            .fixed_date_terms("Förfaller {date}".to_string())
+            .early_payment_discount_terms(
+                "{percentage} {discount_days} dagar, netto {days} dagar".to_string(),
+            )
+            .early_payment_discount_offer(
+                "Betala {discounted_amount} senast {discount_date} ({percentage} rabatt), annars {total} senast {due_date}"
+                    .to_string(),
+            )
             .build()
     }
 }
@@ -54,6 +66,60 @@ impl L18nLineItems {
             .unit_price("Enhetspris".to_string())
             .total_cost("Kostnad".to_string())
             .grand_total("Totalt:".to_string())
+            .services_subtotal("Delsumma tjänster:".to_string())
+            .expenses_subtotal("Delsumma utlägg:".to_string())
+            .excluded_holidays("Exkluderade helgdagar:".to_string())
+            .discount("Rabatt {discount}".to_string())
+            .build()
+    }
+}
+
+impl L18nTax {
+    pub fn swedish() -> Self {
+        Self::builder()
+            .total_excluding_tax("Totalt exkl. moms:".to_string())
+            .tax("Moms {rate} på {taxable_amount}:".to_string())
+            .total_including_tax("Totalt inkl. moms:".to_string())
+            .reverse_charge("Omvänd skattskyldighet: köparen redovisar mervärdesskatten, artikel 196 i rådets direktiv 2006/112/EG.".to_string())
+            .build()
+    }
+}
+
+impl L18nReminder {
+    pub fn swedish() -> Self {
+        Self::builder()
+            .title("Betalningspåminnelse".to_string())
+            .regarding("Avser faktura {number} daterad {invoice_date}, med förfallodatum {due_date}.".to_string())
+            .unpaid("Per {date} har vi inte mottagit er betalning, vänligen betala nedanstående belopp snarast.".to_string())
+            .paid_late("Er betalning mottogs {date}, efter förfallodatum, vänligen betala nedanstående dröjsmålsränta och avgift.".to_string())
+            .invoice_amount("Fakturabelopp".to_string())
+            .late_interest("Dröjsmålsränta {rate} per år för {days} dagar".to_string())
+            .reminder_fee("Påminnelseavgift".to_string())
+            .paid("Betalt {date}".to_string())
+            .amount_due("Att betala:".to_string())
+            .build()
+    }
+}
+
+impl L18nCreditNote {
+    pub fn swedish() -> Self {
+        Self::builder()
+            .title("Kreditfaktura".to_string())
+            .credit_note_identifier("Kreditfakturanr:".to_string())
+            .date("Datum:".to_string())
+            .credits_invoice("Krediterar faktura {number} daterad {invoice_date}.".to_string())
+            .partial("Krediterar {percentage} av fakturabeloppet {invoice_amount}.".to_string())
+            .total_credited("Totalt krediterat:".to_string())
+            .build()
+    }
+}
+
+impl L18nRetainer {
+    pub fn swedish() -> Self {
+        Self::builder()
+            .drawn("Avräknat från förskott:".to_string())
+            .remaining("Kvarvarande förskott:".to_string())
+            .amount_due("Att betala:".to_string())
             .build()
     }
 }
@@ -65,6 +131,10 @@ impl L18nContent {
             .invoice_info(L18nInvoiceInfo::swedish())
             .vendor_info(L18nVendorInfo::swedish())
             .line_items(L18nLineItems::swedish())
+            .tax(L18nTax::swedish())
+            .reminder(L18nReminder::swedish())
+            .credit_note(L18nCreditNote::swedish())
+            .retainer(L18nRetainer::swedish())
             .month_names([
                 "Januari".to_string(),
                 "Februari".to_string(),
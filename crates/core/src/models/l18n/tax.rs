@@ -0,0 +1,34 @@
+use crate::prelude::*;
+
+/// Localization for the VAT or sales tax added to the grand total, per tax
+/// rate, and the legal wording of reverse charged invoices.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, Builder)]
+pub struct L18nTax {
+    /// EN: "Total excluding VAT:"
+    #[getset(get = "pub")]
+    total_excluding_tax: String,
+
+    /// EN: "VAT {rate} of {taxable_amount}:"
+    #[getset(get = "pub")]
+    tax: String,
+
+    /// EN: "Total including VAT:"
+    #[getset(get = "pub")]
+    total_including_tax: String,
+
+    /// EN: "Reverse charge: VAT to be accounted for by the recipient, Article
+    /// 196 of Council Directive 2006/112/EC."
+    #[getset(get = "pub")]
+    reverse_charge: String,
+}
+
+impl L18nTax {
+    pub fn english() -> Self {
+        Self::builder()
+            .total_excluding_tax("Total excluding VAT:".to_string())
+            .tax("VAT {rate} of {taxable_amount}:".to_string())
+            .total_including_tax("Total including VAT:".to_string())
+            .reverse_charge("Reverse charge: VAT to be accounted for by the recipient, Article 196 of Council Directive 2006/112/EC.".to_string())
+            .build()
+    }
+}
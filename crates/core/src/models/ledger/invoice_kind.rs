@@ -0,0 +1,110 @@
+use crate::prelude::*;
+
+/// The kind of an issued invoice, recorded in the [`Ledger`].
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, Serialize, Deserialize, IsVariant)]
+pub enum InvoiceKind {
+    /// An invoice for services, invoiced per period or from a timesheet.
+    #[display("services")]
+    Services,
+
+    /// An invoice for expenses.
+    #[display("expenses")]
+    Expenses,
+
+    /// An invoice for services followed by the expenses of the same period.
+    #[display("services and expenses")]
+    ServicesAndExpenses,
+
+    /// A credit note reversing a previously issued invoice, numbered in a
+    /// series of its own.
+    #[display("credit note")]
+    CreditNote,
+
+    /// An invoice billing a milestone of a fixed-price contract, numbered
+    /// after the last invoice in the ledger.
+    #[display("milestone")]
+    Milestone,
+}
+
+impl From<bool> for InvoiceKind {
+    /// `Expenses` if `is_expenses` is true, else `Services`.
+    fn from(is_expenses: bool) -> Self {
+        if is_expenses {
+            Self::Expenses
+        } else {
+            Self::Services
+        }
+    }
+}
+
+impl From<&LineItemsFlat> for InvoiceKind {
+    /// `ServicesAndExpenses` if the line items contain expenses in a section
+    /// of their own, else `Expenses` or `Services`.
+    fn from(line_items: &LineItemsFlat) -> Self {
+        if line_items.expenses().is_empty() {
+            Self::from(*line_items.is_expenses())
+        } else {
+            Self::ServicesAndExpenses
+        }
+    }
+}
+
+impl From<&PreparedData> for InvoiceKind {
+    /// `CreditNote` or `Milestone` if `data` is a credit note or bills a
+    /// milestone, else the kind of its line items.
+    fn from(data: &PreparedData) -> Self {
+        if data.credit_note().is_some() {
+            Self::CreditNote
+        } else if data.information().milestone().is_some() {
+            Self::Milestone
+        } else {
+            Self::from(data.line_items())
+        }
+    }
+}
+
+impl HasSample for InvoiceKind {
+    fn sample() -> Self {
+        Self::Services
+    }
+
+    fn sample_other() -> Self {
+        Self::Expenses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = InvoiceKind;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn from_is_expenses() {
+        assert_eq!(Sut::from(false), Sut::Services);
+        assert_eq!(Sut::from(true), Sut::Expenses);
+    }
+
+    #[test]
+    fn from_line_items() {
+        assert_eq!(Sut::from(&LineItemsFlat::sample()), Sut::Services);
+        assert_eq!(Sut::from(&LineItemsFlat::sample_other()), Sut::Expenses);
+        let line_items = LineItemsFlat::builder()
+            .is_expenses(false)
+            .items(vec![ItemConvertedIntoTargetCurrency::sample_other()])
+            .expenses(vec![ItemConvertedIntoTargetCurrency::sample()])
+            .build();
+        assert_eq!(Sut::from(&line_items), Sut::ServicesAndExpenses);
+    }
+}
@@ -0,0 +1,667 @@
+use crate::prelude::*;
+
+/// An append-only record of all issued invoices, in the order they were
+/// generated, e.g. to detect duplicate invoice numbers or gaps between them.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, From, Getters)]
+pub struct Ledger {
+    /// The entries of the ledger, in the order they were recorded.
+    #[getset(get = "pub")]
+    entries: Vec<LedgerEntry>,
+}
+
+impl Ledger {
+    /// All entries with the invoice number `number`, more than one if the
+    /// invoice has been generated again with changes.
+    pub fn entries_with_number(&self, number: &FormattedInvoiceNumber) -> Vec<&LedgerEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.number() == number)
+            .collect()
+    }
+
+    /// The most recently recorded invoice, not credit note, numbered `number`.
+    pub fn invoice_with_number(&self, number: &FormattedInvoiceNumber) -> Option<&LedgerEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.number() == number && !entry.kind().is_credit_note())
+    }
+
+    /// The unformatted number of a credit note of the invoice `credited_invoice`
+    /// dated `date` totalling `total`: the number of an already recorded
+    /// identical credit note, i.e. of that invoice on that date with the same
+    /// total, or else the number following the last credit note of the same
+    /// fiscal year, according to `number_reset`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let ledger = Ledger::default();
+    /// let number = ledger.credit_note_sequence_number(
+    ///     &FormattedInvoiceNumber::sample(),
+    ///     &Date::sample(),
+    ///     &Cost::from(dec!(-100)),
+    ///     &InvoiceNumberReset::Never,
+    /// );
+    /// assert_eq!(number, InvoiceNumber::from(1));
+    /// ```
+    pub fn credit_note_sequence_number(
+        &self,
+        credited_invoice: &FormattedInvoiceNumber,
+        date: &Date,
+        total: &Cost,
+        number_reset: &InvoiceNumberReset,
+    ) -> InvoiceNumber {
+        let credit_notes = self
+            .entries
+            .iter()
+            .filter(|entry| entry.kind().is_credit_note())
+            .collect::<Vec<_>>();
+        if let Some(existing) = credit_notes.iter().find(|entry| {
+            entry.credited_invoice().as_ref() == Some(credited_invoice)
+                && entry.invoice_date() == date
+                && entry.total() == total
+        }) {
+            return existing.sequence_number().clone();
+        }
+        let fiscal_year = number_reset.fiscal_year(date);
+        let last = credit_notes
+            .iter()
+            .filter(|entry| {
+                number_reset.is_never()
+                    || number_reset.fiscal_year(entry.invoice_date()) == fiscal_year
+            })
+            .map(|entry| **entry.sequence_number())
+            .max()
+            .unwrap_or(0);
+        InvoiceNumber::from(last + 1)
+    }
+
+    /// The unformatted number of an invoice to `client` for `period`, in the
+    /// series of invoice numbers shared by all clients: the number of an
+    /// already recorded invoice of the same client, period, date range and
+    /// kind, or else
+    /// the number following the last invoice of the same fiscal year as
+    /// `invoice_date`, according to `number_reset`. `None` if no invoice has
+    /// been recorded yet, i.e. the series starts from the offset.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let entry = LedgerEntry::sample();
+    /// let ledger = Ledger::from(vec![entry.clone()]);
+    /// let number = |client: &str| {
+    ///     ledger.shared_sequence_number(
+    ///         client,
+    ///         entry.period(),
+    ///         &None,
+    ///         false,
+    ///         entry.invoice_date(),
+    ///         &InvoiceNumberReset::Never,
+    ///     )
+    /// };
+    /// assert_eq!(number(entry.client()), Some(entry.sequence_number().clone()));
+    /// assert_eq!(
+    ///     number("Other Ltd"),
+    ///     Some(InvoiceNumber::from(**entry.sequence_number() + 1))
+    /// );
+    /// assert_eq!(
+    ///     Ledger::default().shared_sequence_number(
+    ///         "Other Ltd",
+    ///         entry.period(),
+    ///         &None,
+    ///         false,
+    ///         entry.invoice_date(),
+    ///         &InvoiceNumberReset::Never,
+    ///     ),
+    ///     None
+    /// );
+    /// ```
+    pub fn shared_sequence_number(
+        &self,
+        client: &str,
+        period: &PeriodAnno,
+        date_range: &Option<DateRange>,
+        is_expenses: bool,
+        invoice_date: &Date,
+        number_reset: &InvoiceNumberReset,
+    ) -> Option<InvoiceNumber> {
+        let invoices = self
+            .entries
+            .iter()
+            .filter(|entry| !entry.kind().is_credit_note())
+            .collect::<Vec<_>>();
+        if let Some(existing) = invoices.iter().rev().find(|entry| {
+            entry.client() == client
+                && entry.period() == period
+                && entry.date_range() == date_range
+                && !entry.kind().is_milestone()
+                && entry.kind().is_expenses() == is_expenses
+        }) {
+            return Some(existing.sequence_number().clone());
+        }
+        self.next_sequence_number(invoice_date, number_reset)
+    }
+
+    /// The unformatted number following the last invoice, neither credit
+    /// note nor billing a milestone, of the same fiscal year as
+    /// `invoice_date`, according to `number_reset`. `None` if no invoice has
+    /// been recorded yet.
+    pub fn next_sequence_number(
+        &self,
+        invoice_date: &Date,
+        number_reset: &InvoiceNumberReset,
+    ) -> Option<InvoiceNumber> {
+        let invoices = self
+            .entries
+            .iter()
+            .filter(|entry| !entry.kind().is_credit_note() && !entry.kind().is_milestone())
+            .collect::<Vec<_>>();
+        if invoices.is_empty() {
+            return None;
+        }
+        let fiscal_year = number_reset.fiscal_year(invoice_date);
+        let last = invoices
+            .iter()
+            .filter(|entry| {
+                number_reset.is_never()
+                    || number_reset.fiscal_year(entry.invoice_date()) == fiscal_year
+            })
+            .map(|entry| **entry.sequence_number())
+            .max()
+            .unwrap_or(0);
+        Some(InvoiceNumber::from(last + 1))
+    }
+
+    /// The invoice billing the milestone named `milestone` to `client`,
+    /// unless it has been credited in full, i.e. the totals of its credit
+    /// notes cancel its total, after which the milestone can be billed again.
+    pub fn invoice_of_milestone(&self, client: &str, milestone: &str) -> Option<&LedgerEntry> {
+        self.entries.iter().rev().find(|entry| {
+            entry.client() == client
+                && entry.milestone().as_deref() == Some(milestone)
+                && !entry.kind().is_credit_note()
+                && **entry.total() + self.credited_total_of(entry.number()) > dec!(0)
+        })
+    }
+
+    /// The sum of the totals of the credit notes crediting the invoice with
+    /// `number`, which is negative, or zero if it has not been credited.
+    fn credited_total_of(&self, number: &FormattedInvoiceNumber) -> rust_decimal::Decimal {
+        self.entries
+            .iter()
+            .filter(|credit_note| credit_note.credited_invoice().as_ref() == Some(number))
+            .map(|credit_note| **credit_note.total())
+            .sum()
+    }
+
+    /// The unformatted number of the invoice billing the milestone named
+    /// `milestone` to `client`, in a series of its own: the number of the
+    /// invoice already billing it, if any, else the number following the last
+    /// invoice billing a milestone of the same fiscal year as `invoice_date`,
+    /// according to `number_reset`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let number = Ledger::sample().milestone_sequence_number(
+    ///     "Holmes Ltd",
+    ///     "Kickoff",
+    ///     &Date::sample(),
+    ///     &InvoiceNumberReset::Never,
+    /// );
+    /// assert_eq!(number, InvoiceNumber::from(1));
+    /// ```
+    pub fn milestone_sequence_number(
+        &self,
+        client: &str,
+        milestone: &str,
+        invoice_date: &Date,
+        number_reset: &InvoiceNumberReset,
+    ) -> InvoiceNumber {
+        if let Some(existing) = self.invoice_of_milestone(client, milestone) {
+            return existing.sequence_number().clone();
+        }
+        let fiscal_year = number_reset.fiscal_year(invoice_date);
+        let last = self
+            .entries
+            .iter()
+            .filter(|entry| entry.kind().is_milestone())
+            .filter(|entry| {
+                number_reset.is_never()
+                    || number_reset.fiscal_year(entry.invoice_date()) == fiscal_year
+            })
+            .map(|entry| **entry.sequence_number())
+            .max()
+            .unwrap_or(0);
+        InvoiceNumber::from(last + 1)
+    }
+
+    /// Whether an identical invoice, see [`LedgerEntry::is_same_invoice_as`],
+    /// has already been recorded.
+    pub fn contains_same_invoice_as(&self, entry: &LedgerEntry) -> bool {
+        self.entries
+            .iter()
+            .any(|existing| existing.is_same_invoice_as(entry))
+    }
+
+    /// Checks that an invoice numbered `number` to `client` for `period` of
+    /// `kind` does not collide with an entry of the same number to another
+    /// client, for another period, date range or of another kind, e.g. an
+    /// invoice for a custom date range and the regular invoice of the period
+    /// containing its last day.
+    ///
+    /// # Errors
+    /// Returns `Error::InvoiceNumberCollision` if the number has already been
+    /// used for a different invoice.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let entry = LedgerEntry::sample();
+    /// let ledger = Ledger::from(vec![entry.clone()]);
+    /// let validate = |client: &str, kind: InvoiceKind, date_range: Option<DateRange>| {
+    ///     ledger.validate_number(entry.number(), client, entry.period(), &date_range, kind)
+    /// };
+    /// assert!(validate(entry.client(), *entry.kind(), None).is_ok());
+    /// assert!(validate(entry.client(), InvoiceKind::Expenses, None).is_err());
+    /// assert!(validate(entry.client(), *entry.kind(), Some(DateRange::sample())).is_err());
+    /// assert!(validate("Other Ltd", *entry.kind(), None).is_err());
+    /// ```
+    pub fn validate_number(
+        &self,
+        number: &FormattedInvoiceNumber,
+        client: &str,
+        period: &PeriodAnno,
+        date_range: &Option<DateRange>,
+        kind: InvoiceKind,
+    ) -> Result<()> {
+        let describe = |client: &str,
+                        period: &PeriodAnno,
+                        date_range: &Option<DateRange>,
+                        kind: &InvoiceKind| match date_range {
+            Some(date_range) => format!("{} {} ({}) to {}", period, date_range, kind, client),
+            None => format!("{} ({}) to {}", period, kind, client),
+        };
+        match self
+            .entries_with_number(number)
+            .into_iter()
+            .find(|existing| {
+                existing.client() != client
+                    || existing.period() != period
+                    || existing.date_range() != date_range
+                    || *existing.kind() != kind
+            }) {
+            Some(existing) => Err(Error::InvoiceNumberCollision {
+                number: number.to_string(),
+                period: describe(client, period, date_range, &kind),
+                existing_period: describe(
+                    existing.client(),
+                    existing.period(),
+                    existing.date_range(),
+                    existing.kind(),
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Pairs of consecutive invoice numbers, ordered by invoice date, with
+    /// unissued numbers between them, e.g. `("41", "44")` if `42` and `43`
+    /// were never issued. A restart of the numbering, e.g. at the start of a
+    /// fiscal year, is not a gap. Credit notes and invoices billing
+    /// milestones are numbered in series of their own, checked separately
+    /// from other invoices.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let entry = |number: u16, date: &str| {
+    ///     LedgerEntry::builder()
+    ///         .number(FormattedInvoiceNumber::from(number.to_string()))
+    ///         .sequence_number(InvoiceNumber::from(number))
+    ///         .period(PeriodAnno::sample())
+    ///         .kind(InvoiceKind::Services)
+    ///         .client("Holmes Ltd".to_owned())
+    ///         .invoice_date(Date::from_str(date).unwrap())
+    ///         .due_date(Date::from_str(date).unwrap())
+    ///         .issued_on(Date::from_str(date).unwrap())
+    ///         .total(Cost::from(dec!(100)))
+    ///         .currency(Currency::EUR)
+    ///         .output_path(PathBuf::from("invoice.pdf"))
+    ///         .sha256(PdfSha256::sample())
+    ///         .build()
+    /// };
+    /// let ledger = Ledger::from(vec![
+    ///     entry(41, "2025-03-31"),
+    ///     entry(44, "2025-06-30"),
+    ///     entry(1, "2026-01-31"),
+    /// ]);
+    /// assert_eq!(ledger.gaps(), vec![("41".into(), "44".into())]);
+    /// ```
+    pub fn gaps(&self) -> Vec<(FormattedInvoiceNumber, FormattedInvoiceNumber)> {
+        let series = |kind: &InvoiceKind| (kind.is_credit_note(), kind.is_milestone());
+        [(false, false), (true, false), (false, true)]
+            .into_iter()
+            .flat_map(|of_series| {
+                let mut entries = self
+                    .entries
+                    .iter()
+                    .filter(|entry| series(entry.kind()) == of_series)
+                    .collect::<Vec<_>>();
+                entries.sort_by_key(|entry| (*entry.invoice_date(), **entry.sequence_number()));
+                entries.dedup_by_key(|entry| entry.number());
+                entries
+                    .windows(2)
+                    .filter(|pair| **pair[1].sequence_number() > **pair[0].sequence_number() + 1)
+                    .map(|pair| (pair[0].number().clone(), pair[1].number().clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+impl HasSample for Ledger {
+    fn sample() -> Self {
+        Self::from(vec![LedgerEntry::sample()])
+    }
+
+    fn sample_other() -> Self {
+        Self::from(vec![LedgerEntry::sample(), LedgerEntry::sample_other()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Ledger;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn validate_number_of_unused_number_is_ok() {
+        let sut = Sut::sample();
+        let result = sut.validate_number(
+            &FormattedInvoiceNumber::from("1"),
+            "Holmes Ltd",
+            &PeriodAnno::sample_other(),
+            &None,
+            InvoiceKind::Expenses,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn validate_number_used_for_other_period_is_err() {
+        let sut = Sut::sample();
+        let entry = LedgerEntry::sample();
+        let result = sut.validate_number(
+            entry.number(),
+            entry.client(),
+            &PeriodAnno::sample_other(),
+            &None,
+            *entry.kind(),
+        );
+        assert!(matches!(result, Err(Error::InvoiceNumberCollision { .. })));
+    }
+
+    #[test]
+    fn validate_number_used_for_other_client_is_err() {
+        let sut = Sut::sample();
+        let entry = LedgerEntry::sample();
+        let result = sut.validate_number(
+            entry.number(),
+            "Other Ltd",
+            entry.period(),
+            &None,
+            *entry.kind(),
+        );
+        assert!(matches!(result, Err(Error::InvoiceNumberCollision { .. })));
+    }
+
+    #[test]
+    fn validate_number_used_for_other_date_range_is_err() {
+        let entry = LedgerEntry::sample().with_date_range(Some(DateRange::sample()));
+        let sut = Sut::from(vec![entry.clone()]);
+        let validate = |date_range: Option<DateRange>| {
+            sut.validate_number(
+                entry.number(),
+                entry.client(),
+                entry.period(),
+                &date_range,
+                *entry.kind(),
+            )
+        };
+        assert!(validate(Some(DateRange::sample())).is_ok());
+        assert!(matches!(
+            validate(None),
+            Err(Error::InvoiceNumberCollision { .. })
+        ));
+        assert!(matches!(
+            validate(Some(DateRange::sample_other())),
+            Err(Error::InvoiceNumberCollision { .. })
+        ));
+    }
+
+    #[test]
+    fn entries_with_number() {
+        let sut = Sut::sample_other();
+        let entry = LedgerEntry::sample_other();
+        assert_eq!(sut.entries_with_number(entry.number()), vec![&entry]);
+        assert!(
+            sut.entries_with_number(&FormattedInvoiceNumber::from("1"))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn contains_same_invoice_as() {
+        let sut = Sut::sample();
+        assert!(sut.contains_same_invoice_as(&LedgerEntry::sample()));
+        assert!(!sut.contains_same_invoice_as(&LedgerEntry::sample_other()));
+    }
+
+    fn credit_note(number: u16, credited_invoice: &str, date: &str) -> LedgerEntry {
+        credit_note_of_total(number, credited_invoice, date, dec!(-100))
+    }
+
+    fn credit_note_of_total(
+        number: u16,
+        credited_invoice: &str,
+        date: &str,
+        total: rust_decimal::Decimal,
+    ) -> LedgerEntry {
+        LedgerEntry::builder()
+            .number(FormattedInvoiceNumber::from(format!("CN-{}", number)))
+            .sequence_number(InvoiceNumber::from(number))
+            .period(PeriodAnno::sample())
+            .kind(InvoiceKind::CreditNote)
+            .credited_invoice(FormattedInvoiceNumber::from(credited_invoice.to_owned()))
+            .client("Holmes Ltd".to_owned())
+            .invoice_date(Date::from_str(date).unwrap())
+            .due_date(Date::from_str(date).unwrap())
+            .issued_on(Date::from_str(date).unwrap())
+            .total(Cost::from(total))
+            .currency(Currency::EUR)
+            .output_path(PathBuf::from("credit_note.pdf"))
+            .sha256(PdfSha256::sample())
+            .build()
+    }
+
+    fn milestone_invoice(number: u16, milestone: &str) -> LedgerEntry {
+        LedgerEntry::builder()
+            .number(FormattedInvoiceNumber::from(number.to_string()))
+            .sequence_number(InvoiceNumber::from(number))
+            .period(PeriodAnno::sample())
+            .kind(InvoiceKind::Milestone)
+            .milestone(milestone.to_owned())
+            .client("Holmes Ltd".to_owned())
+            .invoice_date(Date::from_str("2025-05-20").unwrap())
+            .due_date(Date::from_str("2025-06-19").unwrap())
+            .issued_on(Date::from_str("2025-05-20").unwrap())
+            .total(Cost::from(dec!(15000)))
+            .currency(Currency::EUR)
+            .output_path(PathBuf::from("milestone.pdf"))
+            .sha256(PdfSha256::sample())
+            .build()
+    }
+
+    #[test]
+    fn invoice_of_milestone_unless_credited() {
+        let kickoff = milestone_invoice(41, "Kickoff");
+        let sut = Sut::from(vec![kickoff.clone()]);
+        assert_eq!(
+            sut.invoice_of_milestone("Holmes Ltd", "Kickoff"),
+            Some(&kickoff)
+        );
+        assert_eq!(sut.invoice_of_milestone("Holmes Ltd", "Delivery"), None);
+        assert_eq!(sut.invoice_of_milestone("Other Ltd", "Kickoff"), None);
+
+        // Partly credited, e.g. a price reduction, is still billed
+        let partly = credit_note(1, "41", "2025-05-25");
+        let sut = Sut::from(vec![kickoff.clone(), partly.clone()]);
+        assert_eq!(
+            sut.invoice_of_milestone("Holmes Ltd", "Kickoff"),
+            Some(&kickoff)
+        );
+
+        let rest = credit_note_of_total(2, "41", "2025-05-26", dec!(-14900));
+        let sut = Sut::from(vec![kickoff, partly, rest]);
+        assert_eq!(sut.invoice_of_milestone("Holmes Ltd", "Kickoff"), None);
+    }
+
+    #[test]
+    fn milestone_sequence_number() {
+        let sut = Sut::from(vec![milestone_invoice(41, "Kickoff")]);
+        let number = |milestone: &str| {
+            sut.milestone_sequence_number(
+                "Holmes Ltd",
+                milestone,
+                &Date::from_str("2025-06-20").unwrap(),
+                &InvoiceNumberReset::Never,
+            )
+        };
+        assert_eq!(number("Kickoff"), InvoiceNumber::from(41));
+        assert_eq!(number("Delivery"), InvoiceNumber::from(42));
+    }
+
+    #[test]
+    fn milestones_are_numbered_apart_from_other_invoices() {
+        let sut = Sut::from(vec![LedgerEntry::sample(), milestone_invoice(1, "Kickoff")]);
+        let number = sut.milestone_sequence_number(
+            "Holmes Ltd",
+            "Delivery",
+            &Date::from_str("2025-06-20").unwrap(),
+            &InvoiceNumberReset::Never,
+        );
+        assert_eq!(number, InvoiceNumber::from(2));
+        assert_eq!(
+            sut.next_sequence_number(&Date::sample(), &InvoiceNumberReset::Never),
+            Some(InvoiceNumber::from(
+                **LedgerEntry::sample().sequence_number() + 1
+            ))
+        );
+        assert!(sut.gaps().is_empty());
+    }
+
+    #[test]
+    fn invoice_with_number_ignores_credit_notes() {
+        let entry = LedgerEntry::sample();
+        let sut = Sut::from(vec![
+            entry.clone(),
+            credit_note(1, &entry.number().to_string(), "2025-06-15"),
+        ]);
+        assert_eq!(sut.invoice_with_number(entry.number()), Some(&entry));
+        assert_eq!(
+            sut.invoice_with_number(&FormattedInvoiceNumber::from("CN-1")),
+            None
+        );
+    }
+
+    #[test]
+    fn credit_note_sequence_number_follows_last_credit_note() {
+        let sut = Sut::from(vec![
+            LedgerEntry::sample(),
+            credit_note(1, "40", "2025-03-15"),
+            credit_note(2, "41", "2025-04-15"),
+        ]);
+        let number = sut.credit_note_sequence_number(
+            &FormattedInvoiceNumber::from("42"),
+            &Date::from_str("2025-05-15").unwrap(),
+            &Cost::from(dec!(-100)),
+            &InvoiceNumberReset::Never,
+        );
+        assert_eq!(number, InvoiceNumber::from(3));
+    }
+
+    #[test]
+    fn credit_note_sequence_number_reuses_number_of_same_credit_note() {
+        let sut = Sut::from(vec![
+            credit_note(1, "40", "2025-03-15"),
+            credit_note(2, "41", "2025-04-15"),
+        ]);
+        let number = |total: Cost| {
+            sut.credit_note_sequence_number(
+                &FormattedInvoiceNumber::from("40"),
+                &Date::from_str("2025-03-15").unwrap(),
+                &total,
+                &InvoiceNumberReset::Never,
+            )
+        };
+        assert_eq!(number(Cost::from(dec!(-100))), InvoiceNumber::from(1));
+
+        // Another credit note of the same invoice on the same date, e.g. of
+        // another share of it, is numbered on its own
+        assert_eq!(number(Cost::from(dec!(-50))), InvoiceNumber::from(3));
+    }
+
+    #[test]
+    fn credit_note_sequence_number_restarts_every_fiscal_year() {
+        let sut = Sut::from(vec![
+            credit_note(1, "40", "2025-03-15"),
+            credit_note(2, "41", "2025-04-15"),
+        ]);
+        let number = sut.credit_note_sequence_number(
+            &FormattedInvoiceNumber::from("42"),
+            &Date::from_str("2025-07-15").unwrap(),
+            &Cost::from(dec!(-100)),
+            &InvoiceNumberReset::Yearly {
+                fiscal_year_start: Month::July,
+            },
+        );
+        assert_eq!(number, InvoiceNumber::from(1));
+    }
+
+    #[test]
+    fn gaps_of_credit_notes_are_separate_from_invoices() {
+        let sut = Sut::from(vec![
+            LedgerEntry::sample(),
+            credit_note(1, "40", "2025-03-15"),
+            credit_note(3, "41", "2025-04-15"),
+        ]);
+        assert_eq!(
+            sut.gaps(),
+            vec![(
+                FormattedInvoiceNumber::from("CN-1"),
+                FormattedInvoiceNumber::from("CN-3")
+            )]
+        );
+    }
+
+    #[test]
+    fn gaps_of_reissued_invoice_is_empty() {
+        let sut = Sut::from(vec![LedgerEntry::sample(), LedgerEntry::sample()]);
+        assert!(sut.gaps().is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+use crate::prelude::*;
+
+/// A record of an issued invoice in the [`Ledger`], e.g. its number, period,
+/// client, total and the SHA-256 digest of its PDF.
+#[derive(
+    Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Builder, Getters, WithSetters,
+)]
+pub struct LedgerEntry {
+    /// The number of the invoice as printed on it, e.g. `"LUP-2025-0042"`.
+    #[getset(get = "pub")]
+    number: FormattedInvoiceNumber,
+
+    /// The unformatted number of the invoice, e.g. `42`, used to detect gaps.
+    #[getset(get = "pub")]
+    sequence_number: InvoiceNumber,
+
+    /// The period invoiced for, e.g. `2025-05`.
+    #[getset(get = "pub")]
+    period: PeriodAnno,
+
+    /// The custom range of dates invoiced for instead of the whole period, if
+    /// any, e.g. `2025-05-10..2025-05-20`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    date_range: Option<DateRange>,
+
+    /// Whether the invoice is for services, expenses or both, or is a credit note.
+    #[getset(get = "pub")]
+    kind: InvoiceKind,
+
+    /// The number of the invoice credited, if this is a credit note.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    credited_invoice: Option<FormattedInvoiceNumber>,
+
+    /// The name of the milestone billed, if this invoice bills a milestone of
+    /// a fixed-price contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    milestone: Option<String>,
+
+    /// The name of the company invoiced, e.g. `"Holmes Ltd"`.
+    #[getset(get = "pub")]
+    client: String,
+
+    /// The date of the invoice, e.g. `2025-05-31`.
+    #[getset(get = "pub")]
+    invoice_date: Date,
+
+    /// The due date of the invoice, e.g. `2025-06-30`.
+    #[getset(get = "pub")]
+    due_date: Date,
+
+    /// The date the invoice was generated, e.g. `2025-06-02`.
+    #[getset(get = "pub")]
+    issued_on: Date,
+
+    /// The grand total of the invoice in `currency`, e.g. `9000`.
+    #[getset(get = "pub")]
+    total: Cost,
+
+    /// The currency of the invoice, e.g. `EUR`.
+    #[getset(get = "pub")]
+    currency: Currency,
+
+    /// Where the PDF was saved.
+    #[getset(get = "pub")]
+    output_path: PathBuf,
+
+    /// The SHA-256 digest of the PDF.
+    #[getset(get = "pub")]
+    sha256: PdfSha256,
+}
+
+impl LedgerEntry {
+    /// A record of the invoice in `named_pdf` for `period`, generated on
+    /// `issued_on`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let named_pdf = NamedPdf::sample();
+    /// let entry = LedgerEntry::issued(
+    ///     &named_pdf,
+    ///     PeriodAnno::sample(),
+    ///     InvoiceNumber::sample(),
+    ///     Date::sample(),
+    /// );
+    /// assert_eq!(entry.number(), named_pdf.prepared_data().information().number());
+    /// assert_eq!(*entry.sha256(), PdfSha256::from(named_pdf.pdf()));
+    /// ```
+    pub fn issued(
+        named_pdf: &NamedPdf,
+        period: PeriodAnno,
+        sequence_number: InvoiceNumber,
+        issued_on: Date,
+    ) -> Self {
+        let data = named_pdf.prepared_data();
+        Self::builder()
+            .number(data.information().number().clone())
+            .sequence_number(sequence_number)
+            .period(period)
+            .kind(InvoiceKind::from(data))
+            .maybe_credited_invoice(
+                data.credit_note()
+                    .as_ref()
+                    .map(|credit_note| credit_note.invoice_number().clone()),
+            )
+            .maybe_milestone(data.information().milestone().clone())
+            .client(data.client().company_name().clone())
+            .invoice_date(*data.information().invoice_date())
+            .due_date(*data.information().due_date())
+            .issued_on(issued_on)
+            .total(data.total_including_tax())
+            .currency(*data.payment_info().currency())
+            .output_path(named_pdf.saved_at().clone())
+            .sha256(PdfSha256::from(named_pdf.pdf()))
+            .build()
+    }
+
+    /// Whether `other` records the same invoice, i.e. has the same number,
+    /// period, date range and kind, and an identical PDF.
+    pub fn is_same_invoice_as(&self, other: &Self) -> bool {
+        self.number == other.number
+            && self.period == other.period
+            && self.date_range == other.date_range
+            && self.kind == other.kind
+            && self.sha256 == other.sha256
+    }
+}
+
+impl std::fmt::Display for LedgerEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}{} {} {} {} {}, due {}",
+            self.number,
+            self.invoice_date,
+            self.period,
+            self.date_range
+                .map(|date_range| format!(" ({})", date_range))
+                .unwrap_or_default(),
+            self.kind,
+            self.client,
+            self.total,
+            self.currency,
+            self.due_date
+        )?;
+        if let Some(credited_invoice) = &self.credited_invoice {
+            write!(f, ", credits {}", credited_invoice)?;
+        }
+        if let Some(milestone) = &self.milestone {
+            write!(f, ", milestone {}", milestone)?;
+        }
+        Ok(())
+    }
+}
+
+impl HasSample for LedgerEntry {
+    fn sample() -> Self {
+        Self::issued(
+            &NamedPdf::sample(),
+            PeriodAnno::sample(),
+            InvoiceNumber::sample(),
+            Date::sample(),
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::issued(
+            &NamedPdf::sample_other(),
+            PeriodAnno::sample_other(),
+            InvoiceNumber::sample_other(),
+            Date::sample_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = LedgerEntry;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn is_same_invoice_as_ignores_issued_on() {
+        let sut = Sut::sample();
+        let reissued = Sut::issued(
+            &NamedPdf::sample(),
+            PeriodAnno::sample(),
+            InvoiceNumber::sample(),
+            Date::sample_other(),
+        );
+        assert!(sut.is_same_invoice_as(&reissued));
+    }
+
+    #[test]
+    fn is_not_same_invoice_if_pdf_differs() {
+        let sut = Sut::sample();
+        let named_pdf = NamedPdf::builder()
+            .prepared_data(PreparedData::sample())
+            .pdf(Pdf::sample_other())
+            .saved_at(PathBuf::from("/tmp/sample_invoice.pdf"))
+            .name("sample_invoice.pdf".to_owned())
+            .build();
+        let altered = Sut::issued(
+            &named_pdf,
+            PeriodAnno::sample(),
+            InvoiceNumber::sample(),
+            Date::sample(),
+        );
+        assert!(!sut.is_same_invoice_as(&altered));
+    }
+
+    #[test]
+    fn issued_credit_note() {
+        let data = PreparedData::sample().to_credit_note(
+            FormattedInvoiceNumber::from("CN-1"),
+            Date::sample(),
+            Percentage::HUNDRED,
+        );
+        let named_pdf = NamedPdf::builder()
+            .prepared_data(data)
+            .pdf(Pdf::sample())
+            .saved_at(PathBuf::from("/tmp/sample_credit_note.pdf"))
+            .name("sample_credit_note.pdf".to_owned())
+            .build();
+        let sut = Sut::issued(
+            &named_pdf,
+            PeriodAnno::sample(),
+            InvoiceNumber::from(1),
+            Date::sample(),
+        );
+        assert_eq!(*sut.kind(), InvoiceKind::CreditNote);
+        assert_eq!(
+            *sut.credited_invoice(),
+            Some(FormattedInvoiceNumber::sample())
+        );
+        assert!(sut.to_string().ends_with(", credits 9876"));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Sut::sample_other();
+        let json = serde_json::to_string(&sut).unwrap();
+        assert_eq!(serde_json::from_str::<Sut>(&json).unwrap(), sut);
+    }
+}
@@ -0,0 +1,10 @@
+mod invoice_kind;
+#[allow(clippy::module_inception)]
+mod ledger;
+mod ledger_entry;
+mod pdf_sha256;
+
+pub use invoice_kind::*;
+pub use ledger::*;
+pub use ledger_entry::*;
+pub use pdf_sha256::*;
@@ -0,0 +1,74 @@
+use serde_with::serde_as;
+use sha2::{Digest, Sha256};
+
+use crate::prelude::*;
+
+/// The SHA-256 digest of a PDF, e.g. of an issued invoice recorded in the
+/// [`Ledger`], to be able to verify that a PDF has not been altered.
+#[serde_as]
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    derive_more::Display,
+    derive_more::Debug,
+)]
+#[display("{}", hex::encode(self.0))]
+#[debug("{}", hex::encode(self.0))]
+#[serde(transparent)]
+pub struct PdfSha256(#[serde_as(as = "serde_with::hex::Hex")] [u8; 32]);
+
+impl From<&Pdf> for PdfSha256 {
+    /// Hashes the bytes of `pdf` with SHA-256.
+    fn from(pdf: &Pdf) -> Self {
+        Self(Sha256::digest(pdf.as_ref()).into())
+    }
+}
+
+impl HasSample for PdfSha256 {
+    fn sample() -> Self {
+        Self::from(&Pdf::sample())
+    }
+
+    fn sample_other() -> Self {
+        Self::from(&Pdf::sample_other())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = PdfSha256;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn display_is_hex_of_digest() {
+        assert_eq!(
+            Sut::from(&Pdf::from(b"abc".to_vec())).to_string(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Sut::sample();
+        let json = serde_json::to_string(&sut).unwrap();
+        assert_eq!(json, format!("\"{}\"", sut));
+        assert_eq!(serde_json::from_str::<Sut>(&json).unwrap(), sut);
+    }
+}
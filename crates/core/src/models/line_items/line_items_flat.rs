@@ -6,9 +6,24 @@ pub struct LineItemsFlat {
     #[getset(get = "pub")]
     is_expenses: bool,
 
-    /// Either a single item (Service) or one or more expenses
+    /// Either one or more services or one or more expenses
     #[getset(get = "pub")]
     items: Vec<ItemConvertedIntoTargetCurrency>,
+
+    /// Expenses invoiced together with the services in `items`, listed in a
+    /// section of their own, empty unless invoicing services and expenses in
+    /// a single invoice.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[getset(get = "pub")]
+    expenses: Vec<ItemConvertedIntoTargetCurrency>,
+
+    /// Lines deducting a discount of the whole invoice, one per tax rate of
+    /// the discounted items, listed after all other items.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[getset(get = "pub")]
+    discounts: Vec<ItemConvertedIntoTargetCurrency>,
 }
 
 impl MaybeIsExpenses for LineItemsFlat {
@@ -17,19 +32,103 @@ impl MaybeIsExpenses for LineItemsFlat {
     }
 }
 
+impl LineItemsFlat {
+    /// All lines of the invoice, the items followed by any expenses in a
+    /// section of their own and the discounts of the whole invoice.
+    pub fn all_items(&self) -> impl Iterator<Item = &ItemConvertedIntoTargetCurrency> {
+        self.items
+            .iter()
+            .chain(self.expenses.iter())
+            .chain(self.discounts.iter())
+    }
+
+    /// The sum of the total cost of all items, including any expenses in a
+    /// section of their own, less any discounts, in the target currency.
+    pub fn grand_total(&self) -> Cost {
+        Cost::from(
+            self.all_items()
+                .map(|item| **item.total_cost())
+                .sum::<rust_decimal::Decimal>(),
+        )
+    }
+
+    /// Adds lines deducting `discount` of the whole invoice, dated
+    /// `invoice_date`, one per tax rate of the items proportionally to their
+    /// total cost, so that the tax is calculated on the discounted total.
+    pub fn with_invoice_discount(self, discount: &Discount, invoice_date: Date) -> Self {
+        let Some(currency) = self.all_items().next().map(|item| *item.currency()) else {
+            return self;
+        };
+        let mut totals = IndexMap::<Option<Percentage>, rust_decimal::Decimal>::new();
+        for item in self.all_items() {
+            *totals.entry(*item.tax_rate()).or_default() += **item.total_cost();
+        }
+        let grand_total = self.grand_total();
+        let amount = discount.amount_of(grand_total);
+        let mut remaining = *amount;
+        let count = totals.len();
+        let discounts = totals
+            .into_iter()
+            .enumerate()
+            .map(|(index, (tax_rate, total))| {
+                let share = if index + 1 == count || grand_total.is_zero() {
+                    remaining
+                } else {
+                    (*amount * total / *grand_total).round_dp(2)
+                };
+                remaining -= share;
+                let discounted = Item::builder()
+                    .name(String::new())
+                    .unit_price(UnitPrice::from(total))
+                    .currency(currency)
+                    .quantity(Quantity::from(rust_decimal::Decimal::ONE))
+                    .transaction_date(invoice_date)
+                    .maybe_tax_rate(tax_rate)
+                    .discount(*discount)
+                    .build();
+                ItemConvertedIntoTargetCurrency::discounting(&discounted, Cost::from(share))
+            })
+            .collect();
+        Self { discounts, ..self }
+    }
+
+    /// The reversal of `percentage` of all items in a credit note, with
+    /// negative quantities and total costs.
+    pub fn credited(&self, percentage: &Percentage) -> Self {
+        let fraction = percentage.as_fraction();
+        let credited = |items: &[ItemConvertedIntoTargetCurrency]| {
+            items
+                .iter()
+                .map(|item| item.credited(fraction))
+                .collect::<Vec<_>>()
+        };
+        Self::builder()
+            .is_expenses(self.is_expenses)
+            .items(credited(&self.items))
+            .expenses(credited(&self.expenses))
+            .discounts(credited(&self.discounts))
+            .build()
+    }
+}
+
 impl TryFrom<(LineItemsPricedInSourceCurrency, ExchangeRates)> for LineItemsFlat {
     type Error = crate::prelude::Error;
 
     /// Converts the line items priced in source currency into a flat list of items
-    /// priced in the target currency, using the provided exchange rates.
+    /// priced in the target currency, using the provided exchange rates, each
+    /// discounted item followed by a line deducting its discount.
     fn try_from(
         (line_items, exchange_rates): (LineItemsPricedInSourceCurrency, ExchangeRates),
     ) -> Result<Self> {
         match line_items {
-            LineItemsPricedInSourceCurrency::Service(item) => {
-                let service = item.total_cost_in_target_currency(&exchange_rates)?;
+            LineItemsPricedInSourceCurrency::Service(services) => {
+                let services = services
+                    .into_iter()
+                    .map(|service| service.lines_in_target_currency(&exchange_rates))
+                    .collect::<Result<Vec<_>>>()?
+                    .concat();
                 let flat = LineItemsFlat::builder()
-                    .items(vec![service])
+                    .items(services)
                     .is_expenses(false)
                     .build();
                 Ok(flat)
@@ -37,18 +136,61 @@ impl TryFrom<(LineItemsPricedInSourceCurrency, ExchangeRates)> for LineItemsFlat
             LineItemsPricedInSourceCurrency::Expenses(expenses) => {
                 let expenses = expenses
                     .into_iter()
-                    .map(|expense| expense.total_cost_in_target_currency(&exchange_rates))
-                    .collect::<Result<Vec<_>>>()?;
+                    .map(|expense| expense.lines_in_target_currency(&exchange_rates))
+                    .collect::<Result<Vec<_>>>()?
+                    .concat();
                 let flat = LineItemsFlat::builder()
                     .items(expenses)
                     .is_expenses(true)
                     .build();
                 Ok(flat)
             }
+            LineItemsPricedInSourceCurrency::ServiceAndExpenses { services, expenses } => {
+                let services = services
+                    .into_iter()
+                    .map(|service| service.lines_in_target_currency(&exchange_rates))
+                    .collect::<Result<Vec<_>>>()?
+                    .concat();
+                let expenses = expenses
+                    .into_iter()
+                    .map(|expense| expense.lines_in_target_currency(&exchange_rates))
+                    .collect::<Result<Vec<_>>>()?
+                    .concat();
+                let flat = LineItemsFlat::builder()
+                    .items(services)
+                    .expenses(expenses)
+                    .is_expenses(false)
+                    .build();
+                Ok(flat)
+            }
         }
     }
 }
 
+impl LineItemsFlat {
+    /// A sample with a single item taxed at `25%`.
+    pub fn sample_taxed() -> Self {
+        let item = Item::sample();
+        let taxed = Item::builder()
+            .name(item.name().clone())
+            .unit_price(*item.unit_price())
+            .currency(*item.currency())
+            .quantity(*item.quantity())
+            .transaction_date(*item.transaction_date())
+            .tax_rate(Percentage::try_from(dec!(25)).expect("Valid percentage"))
+            .build();
+        Self::builder()
+            .is_expenses(false)
+            .items(vec![
+                ItemConvertedIntoTargetCurrency::builder()
+                    .in_source_currency(taxed)
+                    .total_cost(Cost::sample())
+                    .build(),
+            ])
+            .build()
+    }
+}
+
 impl HasSample for LineItemsFlat {
     fn sample() -> Self {
         Self::builder()
@@ -101,6 +243,156 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_grand_total() {
+        let sut = Sut::builder()
+            .is_expenses(true)
+            .items(vec![
+                ItemConvertedIntoTargetCurrency::sample(),
+                ItemConvertedIntoTargetCurrency::sample_other(),
+            ])
+            .build();
+        assert_eq!(sut.grand_total(), Cost::from(dec!(850)));
+    }
+
+    #[test]
+    fn test_credited_negates_grand_total() {
+        let sut = Sut::builder()
+            .is_expenses(true)
+            .items(vec![
+                ItemConvertedIntoTargetCurrency::sample(),
+                ItemConvertedIntoTargetCurrency::sample_other(),
+            ])
+            .build();
+        assert_eq!(
+            sut.credited(&Percentage::HUNDRED).grand_total(),
+            Cost::from(dec!(-850))
+        );
+        assert_eq!(
+            sut.credited(&Percentage::try_from(dec!(10)).unwrap())
+                .grand_total(),
+            Cost::from(dec!(-85))
+        );
+    }
+
+    #[test]
+    fn test_service_and_expenses_conversion() {
+        let line_items = LineItemsPricedInSourceCurrency::ServiceAndExpenses {
+            services: vec![Item::sample_consulting_service()],
+            expenses: vec![Item::sample_expense_coffee()],
+        };
+        let exchange_rates = ExchangeRates::builder()
+            .rates(ExchangeRatesMap::from_iter([(
+                Currency::GBP,
+                UnitPrice::from(dec!(10.0)),
+            )]))
+            .target_currency(Currency::EUR)
+            .build();
+        let sut = Sut::try_from((line_items, exchange_rates)).unwrap();
+        assert!(!MaybeIsExpenses::is_expenses(&sut));
+        assert_eq!(sut.items().len(), 1);
+        assert_eq!(sut.expenses().len(), 1);
+        let subtotals = **sut.items()[0].total_cost() + **sut.expenses()[0].total_cost();
+        assert_eq!(*sut.grand_total(), subtotals);
+    }
+
+    #[test]
+    fn test_item_discounts_follow_items() {
+        let item = |s: &str, discount: &str| {
+            Item::from_str(s)
+                .unwrap()
+                .with_discount(Some(Discount::from_str(discount).unwrap()))
+        };
+        let line_items = LineItemsPricedInSourceCurrency::ServiceAndExpenses {
+            services: vec![item("Consulting, 500, EUR, 10, 2025-05-31", "10%")],
+            expenses: vec![item("Coffee, 4, GBP, 2, 2025-05-31", "1")],
+        };
+        let exchange_rates = ExchangeRates::builder()
+            .rates(ExchangeRatesMap::from_iter([(
+                Currency::GBP,
+                UnitPrice::from(dec!(10.0)),
+            )]))
+            .target_currency(Currency::EUR)
+            .build();
+        let sut = Sut::try_from((line_items, exchange_rates)).unwrap();
+        let totals = |items: &[ItemConvertedIntoTargetCurrency]| {
+            items
+                .iter()
+                .map(|item| (**item.total_cost(), *item.is_discount()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            totals(sut.items()),
+            vec![(dec!(5000), false), (dec!(-500), true)]
+        );
+        assert_eq!(
+            totals(sut.expenses()),
+            vec![(dec!(80), false), (dec!(-10), true)]
+        );
+        assert_eq!(*sut.grand_total(), dec!(4570));
+    }
+
+    #[test]
+    fn test_invoice_discount_per_tax_rate() {
+        let item = |s: &str| Item::from_str(s).unwrap().with_total_cost();
+        let sut = Sut::builder()
+            .is_expenses(false)
+            .items(vec![
+                item("Consulting, 300, EUR, 1, 2025-05-31, 25%"),
+                item("Books, 100, EUR, 1, 2025-05-31, 6%"),
+            ])
+            .build();
+        let date = Date::from_str("2025-05-31").unwrap();
+
+        let discounted = sut
+            .clone()
+            .with_invoice_discount(&Discount::from_str("10%").unwrap(), date);
+        let discounts = discounted
+            .discounts()
+            .iter()
+            .map(|line| (**line.total_cost(), *line.tax_rate()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            discounts,
+            vec![
+                (dec!(-30), Some(Percentage::try_from(dec!(25)).unwrap())),
+                (dec!(-10), Some(Percentage::try_from(dec!(6)).unwrap())),
+            ]
+        );
+        assert_eq!(*discounted.grand_total(), dec!(360));
+
+        let discounted = sut.with_invoice_discount(&Discount::from_str("100").unwrap(), date);
+        assert_eq!(*discounted.grand_total(), dec!(300));
+        assert_eq!(
+            *discounted.credited(&Percentage::HUNDRED).grand_total(),
+            dec!(-300)
+        );
+    }
+
+    #[test]
+    fn test_discount_of_fixed_amount_is_at_most_total() {
+        let line_items = LineItemsPricedInSourceCurrency::Service(vec![
+            Item::from_str("Consulting, 50, EUR, 2, 2025-05-31")
+                .unwrap()
+                .with_discount(Some(Discount::from_str("20").unwrap())),
+            Item::from_str("Books, 10, EUR, 1, 2025-05-31")
+                .unwrap()
+                .with_discount(Some(Discount::from_str("30").unwrap())),
+        ]);
+        let exchange_rates = ExchangeRates::builder()
+            .rates(ExchangeRatesMap::new())
+            .target_currency(Currency::EUR)
+            .build();
+        let sut = Sut::try_from((line_items, exchange_rates)).unwrap();
+        assert_eq!(*sut.grand_total(), dec!(80));
+
+        let discounted = sut.with_invoice_discount(
+            &Discount::from_str("500").unwrap(),
+            Date::from_str("2025-05-31").unwrap(),
+        );
+        assert_eq!(*discounted.grand_total(), dec!(0));
+    }
+
     #[test]
     fn test_is_expenses() {
         assert!(!MaybeIsExpenses::is_expenses(&Sut::sample()));
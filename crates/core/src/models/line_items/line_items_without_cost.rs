@@ -12,20 +12,39 @@ impl MaybeIsExpenses for LineItemsPricedInSourceCurrency {
     }
 }
 
-/// Services or expenses included in this invoice to be paid by the client.
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, From, TryUnwrap, IsVariant)]
-#[from(Vec<Item>, Item)]
+/// Services or expenses, or both, included in this invoice to be paid by the
+/// client.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, TryUnwrap, IsVariant)]
 pub enum LineItemsPricedInSourceCurrency {
-    /// Service sold by the vendor to the client, e.g. `"Agreed Consulting Fees"`
-    Service(Item),
+    /// Services sold by the vendor to the client, the service fees, e.g.
+    /// `"Agreed Consulting Fees"`, followed by any service lines
+    Service(Vec<Item>),
     /// Expense incurred by the vendor, travel expenses for a conference/summit/
     /// retreat
     Expenses(Vec<Item>),
+    /// Services sold by the vendor followed by the expenses incurred in the
+    /// same period, invoiced together.
+    #[try_unwrap(ignore)]
+    ServiceAndExpenses {
+        services: Vec<Item>,
+        expenses: Vec<Item>,
+    },
+}
+
+impl LineItemsPricedInSourceCurrency {
+    /// The expenses among the line items, which might be priced in another
+    /// currency than the invoice, empty for services only.
+    pub fn expenses(&self) -> &[Item] {
+        match self {
+            Self::Service(_) => &[],
+            Self::Expenses(expenses) | Self::ServiceAndExpenses { expenses, .. } => expenses,
+        }
+    }
 }
 
 impl HasSample for LineItemsPricedInSourceCurrency {
     fn sample() -> Self {
-        Self::Service(Item::sample())
+        Self::Service(vec![Item::sample()])
     }
 
     fn sample_other() -> Self {
@@ -51,6 +70,18 @@ mod tests {
         assert_ne!(Sut::sample(), Sut::sample_other());
     }
 
+    #[test]
+    fn expenses() {
+        assert!(Sut::sample().expenses().is_empty());
+        assert_eq!(Sut::sample_other().expenses(), &[Item::sample_other()]);
+        let sut = Sut::ServiceAndExpenses {
+            services: vec![Item::sample_consulting_service()],
+            expenses: vec![Item::sample_expense_coffee()],
+        };
+        assert_eq!(sut.expenses(), &[Item::sample_expense_coffee()]);
+        assert!(!MaybeIsExpenses::is_expenses(&sut));
+    }
+
     #[test]
     fn is_expenses() {
         assert!(!MaybeIsExpenses::is_expenses(
@@ -1,13 +1,18 @@
 mod cost;
+mod credit_note;
 mod data;
 mod date;
 mod day;
 mod decimal;
 mod deserialize_contents_of_ron;
+mod discount;
+mod early_payment_discount;
 mod error;
 mod exchange_rates;
+mod expense_selector;
 mod font_identifier;
 mod font_weight;
+mod formatted_invoice_number;
 mod invoice_info_full;
 mod invoice_number;
 mod invoiced_items;
@@ -15,27 +20,41 @@ mod item;
 mod item_converted_into_target_currency;
 mod l18n;
 mod layout;
+mod ledger;
 mod line_items;
 mod month;
 mod named_pdf;
 mod output_path;
 mod pdf;
+mod percentage;
 mod quantity;
+mod receipt;
+mod reminder;
+mod retainer_drawdown;
+mod tax;
+mod timesheet;
 mod unit_price;
+mod valid_credit_note_input;
 mod valid_input;
+mod valid_reminder_input;
 mod year;
 mod year_and_month;
 
 pub use cost::*;
+pub use credit_note::*;
 pub use data::*;
 pub use date::*;
 pub use day::*;
 pub use decimal::*;
 pub use deserialize_contents_of_ron::*;
+pub use discount::*;
+pub use early_payment_discount::*;
 pub use error::*;
 pub use exchange_rates::*;
+pub use expense_selector::*;
 pub use font_identifier::*;
 pub use font_weight::*;
+pub use formatted_invoice_number::*;
 pub use invoice_info_full::*;
 pub use invoice_number::*;
 pub use invoiced_items::*;
@@ -43,13 +62,22 @@ pub use item::*;
 pub use item_converted_into_target_currency::*;
 pub use l18n::*;
 pub use layout::*;
+pub use ledger::*;
 pub use line_items::*;
 pub use month::*;
 pub use named_pdf::*;
 pub use output_path::*;
 pub use pdf::*;
+pub use percentage::*;
 pub use quantity::*;
+pub use receipt::*;
+pub use reminder::*;
+pub use retainer_drawdown::*;
+pub use tax::*;
+pub use timesheet::*;
 pub use unit_price::*;
+pub use valid_credit_note_input::*;
 pub use valid_input::*;
+pub use valid_reminder_input::*;
 pub use year::*;
 pub use year_and_month::*;
@@ -2,7 +2,7 @@ use crate::prelude::*;
 
 /// The outcome of generating a PDF with the produced PDF, its name, save location,
 /// and the prepared data used to generate it.
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Builder, Getters)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Builder, Getters, WithSetters)]
 pub struct NamedPdf {
     /// The prepared data used to generate the PDF, e.g. invoice data.
     #[getset(get = "pub")]
@@ -19,6 +19,12 @@ pub struct NamedPdf {
     /// The name of the PDF file, e.g. "invoice_123.pdf"
     #[getset(get = "pub")]
     name: String,
+
+    /// The receipts of the expenses invoiced, attached to the email of the
+    /// PDF.
+    #[builder(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    receipts: Vec<Receipt>,
 }
 
 impl HasSample for NamedPdf {
@@ -0,0 +1,164 @@
+use crate::prelude::*;
+
+/// A percentage between `0` and `100`, e.g. `80` for an employment of 80%.
+#[derive(
+    Clone, Copy, Display, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Deref,
+)]
+#[display("{_0}%")]
+#[deref(forward)]
+#[serde(try_from = "UncheckedPercentage")]
+pub struct Percentage(Decimal);
+
+/// A percentage as stored in the data files, validated when deserializing.
+#[derive(Deserialize)]
+#[serde(rename = "Percentage")]
+struct UncheckedPercentage(Decimal);
+
+impl TryFrom<UncheckedPercentage> for Percentage {
+    type Error = crate::Error;
+
+    fn try_from(unchecked: UncheckedPercentage) -> Result<Self, Self::Error> {
+        Self::try_from(*unchecked.0)
+    }
+}
+
+impl TryFrom<rust_decimal::Decimal> for Percentage {
+    type Error = crate::Error;
+
+    /// Checks that `value` is between `0` and `100`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    ///
+    /// assert!(Percentage::try_from(dec!(7.5)).is_ok());
+    /// assert!(Percentage::try_from(dec!(-1)).is_err());
+    /// assert!(Percentage::try_from(dec!(100.1)).is_err());
+    /// ```
+    fn try_from(value: rust_decimal::Decimal) -> Result<Self, Self::Error> {
+        if value.is_sign_negative() || value > rust_decimal::Decimal::ONE_HUNDRED {
+            return Err(Error::InvalidPercentage {
+                invalid_string: value.to_string(),
+                reason: "Must be between 0 and 100".to_owned(),
+            });
+        }
+        Ok(Self(Decimal::from(value)))
+    }
+}
+
+impl Percentage {
+    pub const ZERO: Self = Self(Decimal::ZERO);
+    pub const HUNDRED: Self = Self(Decimal::HUNDRED);
+
+    /// Returns this percentage as a fraction, e.g. `0.8` for `80%`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    ///
+    /// let percentage = Percentage::from_str("80%").unwrap();
+    /// assert_eq!(percentage.as_fraction(), dec!(0.8));
+    /// ```
+    pub fn as_fraction(&self) -> rust_decimal::Decimal {
+        **self / rust_decimal::Decimal::ONE_HUNDRED
+    }
+}
+
+impl FromStr for Percentage {
+    type Err = crate::Error;
+
+    /// Parses a percentage with or without a trailing `%`, e.g. `"80"`, `"7.5%"`
+    /// or `"25 %"`.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a number, or if the number is
+    /// not between `0` and `100`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| Error::InvalidPercentage {
+            invalid_string: s.to_owned(),
+            reason: reason.to_owned(),
+        };
+        let value = s.trim().trim_end_matches('%').trim();
+        let value =
+            rust_decimal::Decimal::from_str(value).map_err(|e| invalid(&format!("{:?}", e)))?;
+        Self::try_from(value).map_err(|_| invalid("Must be between 0 and 100"))
+    }
+}
+
+impl HasSample for Percentage {
+    fn sample() -> Self {
+        Self(Decimal::from(dec!(80)))
+    }
+
+    fn sample_other() -> Self {
+        Self(Decimal::from(dec!(2.5)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Percentage;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Sut::sample_other().to_string(), "2.5%");
+    }
+
+    #[test]
+    fn from_str_valid() {
+        for (s, expected) in [
+            ("80", dec!(80)),
+            ("80%", dec!(80)),
+            (" 7.5 % ", dec!(7.5)),
+            ("0", dec!(0)),
+            ("100", dec!(100)),
+        ] {
+            assert_eq!(*Sut::from_str(s).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for s in ["", "abc", "-1", "100.1", "%"] {
+            assert!(Sut::from_str(s).is_err(), "Expected error for: {s}");
+        }
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let sut = Sut::sample();
+        let ron = ron::to_string(&sut).unwrap();
+        let deserialized: Sut = ron::from_str(&ron).unwrap();
+        assert_eq!(deserialized, sut);
+    }
+
+    #[test]
+    fn deserialize_ron() {
+        let sut: Sut = ron::from_str("Percentage(7.5)").unwrap();
+        assert_eq!(*sut, dec!(7.5));
+    }
+
+    #[test]
+    fn deserialize_out_of_range_is_err() {
+        for ron in ["Percentage(-1.0)", "Percentage(100.5)"] {
+            assert!(
+                ron::from_str::<Sut>(ron).is_err(),
+                "Expected error for: {ron}"
+            );
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use derive_more::FromStr;
 
 /// The quantity of items, e.g. the number of days of consulting service.
 #[derive(
@@ -12,6 +13,7 @@ use crate::prelude::*;
     Serialize,
     Deserialize,
     From,
+    FromStr,
     Deref,
     PartialOrd,
     derive_more::Add,
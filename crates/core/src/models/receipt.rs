@@ -0,0 +1,82 @@
+use crate::prelude::*;
+
+/// A receipt of an expense, an image or PDF read from the receipts folder of
+/// the data directory, attached to the email of the invoice of the expense.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Builder, Getters)]
+pub struct Receipt {
+    /// The name of the receipt file, e.g. `"2025-05-31_coffee.jpg"`.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    name: String,
+
+    /// The contents of the receipt file.
+    #[getset(get = "pub")]
+    content: Vec<u8>,
+}
+
+impl Receipt {
+    /// The MIME type of the receipt according to the extension of its name,
+    /// `application/octet-stream` if unknown.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(Receipt::sample().content_type(), "image/jpeg");
+    /// assert_eq!(Receipt::sample_other().content_type(), "application/pdf");
+    /// ```
+    pub fn content_type(&self) -> &'static str {
+        let extension = Path::new(&self.name)
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        match extension.as_deref() {
+            Some("pdf") => "application/pdf",
+            Some("jpg" | "jpeg") => "image/jpeg",
+            Some("png") => "image/png",
+            Some("gif") => "image/gif",
+            Some("webp") => "image/webp",
+            Some("heic") => "image/heic",
+            _ => "application/octet-stream",
+        }
+    }
+}
+
+impl HasSample for Receipt {
+    fn sample() -> Self {
+        Self::builder()
+            .name("2025-05-31_coffee.jpg")
+            .content(vec![0xff, 0xd8, 0xff, 0xe0])
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .name("2025-05-20_breakfast.PDF")
+            .content(vec![0x25, 0x50, 0x44, 0x46])
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Receipt;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn content_type_of_unknown_extension() {
+        let sut = Sut::builder().name("receipt").content(vec![]).build();
+        assert_eq!(sut.content_type(), "application/octet-stream");
+    }
+}
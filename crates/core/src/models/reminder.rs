@@ -0,0 +1,175 @@
+use crate::prelude::*;
+
+/// A payment reminder of an overdue invoice, charging late payment interest
+/// from the due date and a fixed reminder fee.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Getters)]
+pub struct Reminder {
+    /// The date until which late payment interest is charged, i.e. the date
+    /// the invoice was paid, or the date of the reminder if still unpaid.
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// Whether the invoice has been paid, after the due date, in which case
+    /// only the interest and the fee are due.
+    #[getset(get = "pub")]
+    is_paid: bool,
+
+    /// The number of days from the due date to `date`.
+    #[getset(get = "pub")]
+    days_overdue: u32,
+
+    /// The late payment interest rate per year, e.g. `10%`.
+    #[getset(get = "pub")]
+    annual_interest_rate: Percentage,
+
+    /// The grand total of the original invoice.
+    #[getset(get = "pub")]
+    invoice_amount: Cost,
+
+    /// The late payment interest on the invoice amount.
+    #[getset(get = "pub")]
+    late_interest: Cost,
+
+    /// The fixed reminder fee.
+    #[getset(get = "pub")]
+    reminder_fee: Cost,
+
+    /// The total amount due, i.e. the interest and the fee, plus the invoice
+    /// amount if not yet paid.
+    #[getset(get = "pub")]
+    amount_due: Cost,
+}
+
+#[bon]
+impl Reminder {
+    /// Creates a reminder of an invoice of `invoice_amount` due on `due_date`,
+    /// charging interest and fee according to `late_payment` until `date`.
+    ///
+    /// # Errors
+    /// Returns an error if the invoice is not overdue at `date`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let reminder = Reminder::builder()
+    ///     .late_payment(&LatePayment::sample_other())
+    ///     .invoice_amount(Cost::from(dec!(1000)))
+    ///     .due_date(Date::from_str("2025-06-30").unwrap())
+    ///     .date(Date::from_str("2025-09-11").unwrap())
+    ///     .is_paid(false)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(*reminder.days_overdue(), 73);
+    /// // 10% per year for 73 days plus a fee of 60
+    /// assert_eq!(**reminder.amount_due(), dec!(1080));
+    /// ```
+    #[builder]
+    pub fn new(
+        late_payment: &LatePayment,
+        invoice_amount: Cost,
+        due_date: Date,
+        date: Date,
+        is_paid: bool,
+    ) -> Result<Self> {
+        let days_overdue = (date.to_datetime() - due_date.to_datetime()).num_days();
+        if days_overdue <= 0 {
+            return Err(Error::InvoiceNotOverdue {
+                due_date: due_date.to_string(),
+                date: date.to_string(),
+            });
+        }
+        let days_overdue = days_overdue as u32;
+        let annual_interest_rate = late_payment.annual_interest_rate()?;
+        let late_interest = late_payment.interest(&invoice_amount, days_overdue)?;
+        let reminder_fee = *late_payment.reminder_fee();
+        let unpaid = if is_paid {
+            rust_decimal::Decimal::ZERO
+        } else {
+            *invoice_amount
+        };
+        let amount_due = Cost::from(unpaid + *late_interest + *reminder_fee);
+        Ok(Self {
+            date,
+            is_paid,
+            days_overdue,
+            annual_interest_rate,
+            invoice_amount,
+            late_interest,
+            reminder_fee,
+            amount_due,
+        })
+    }
+}
+
+impl HasSample for Reminder {
+    fn sample() -> Self {
+        Self::builder()
+            .late_payment(&LatePayment::sample_other())
+            .invoice_amount(Cost::sample())
+            .due_date(Date::from_str("2025-06-30").expect("Valid date"))
+            .date(Date::from_str("2025-07-31").expect("Valid date"))
+            .is_paid(false)
+            .build()
+            .expect("Overdue")
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .late_payment(&LatePayment::sample())
+            .invoice_amount(Cost::sample_other())
+            .due_date(Date::sample_other())
+            .date(Date::from_str("2025-01-15").expect("Valid date"))
+            .is_paid(true)
+            .build()
+            .expect("Overdue")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Reminder;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn unpaid_includes_invoice_amount() {
+        let sut = Sut::sample();
+        assert_eq!(*sut.days_overdue(), 31);
+        assert_eq!(**sut.late_interest(), dec!(2.97));
+        assert_eq!(**sut.amount_due(), dec!(412.97));
+    }
+
+    #[test]
+    fn paid_late_excludes_invoice_amount() {
+        let sut = Sut::sample_other();
+        assert_eq!(*sut.days_overdue(), 31);
+        // 8% per year of 500 for 31 days
+        assert_eq!(**sut.late_interest(), dec!(3.40));
+        assert_eq!(**sut.amount_due(), dec!(3.40));
+    }
+
+    #[test]
+    fn throws_when_not_overdue() {
+        let due_date = Date::sample();
+        let result = Sut::builder()
+            .late_payment(&LatePayment::sample())
+            .invoice_amount(Cost::sample())
+            .due_date(due_date)
+            .date(due_date)
+            .is_paid(false)
+            .build();
+        assert!(matches!(result, Err(Error::InvoiceNotOverdue { .. })));
+    }
+}
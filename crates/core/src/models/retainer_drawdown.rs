@@ -0,0 +1,102 @@
+use crate::prelude::*;
+
+/// The amount drawn from the balance prepaid by a client to pay an invoice,
+/// deducted from the grand total on a line of its own, and what remains of
+/// the balance.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Getters)]
+pub struct RetainerDrawdown {
+    /// The amount drawn from the balance, at most the grand total.
+    #[getset(get = "pub")]
+    drawn: Cost,
+
+    /// The balance remaining after this invoice.
+    #[getset(get = "pub")]
+    remaining: Cost,
+
+    /// The amount left to pay, i.e. the grand total less the amount drawn.
+    #[getset(get = "pub")]
+    amount_due: Cost,
+}
+
+#[bon]
+impl RetainerDrawdown {
+    /// Creates a new drawdown of `balance` paying as much as possible of
+    /// `total`, nothing if either is not positive.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let drawdown = RetainerDrawdown::builder()
+    ///     .balance(Cost::from(dec!(1000)))
+    ///     .total(Cost::from(dec!(1500)))
+    ///     .build();
+    /// assert_eq!(**drawdown.drawn(), dec!(1000));
+    /// assert_eq!(**drawdown.remaining(), dec!(0));
+    /// assert_eq!(**drawdown.amount_due(), dec!(500));
+    /// ```
+    #[builder]
+    pub fn new(balance: Cost, total: Cost) -> Self {
+        let drawn = (*balance).min(*total).max(rust_decimal::Decimal::ZERO);
+        Self {
+            drawn: Cost::from(drawn),
+            remaining: Cost::from(*balance - drawn),
+            amount_due: Cost::from(*total - drawn),
+        }
+    }
+}
+
+impl HasSample for RetainerDrawdown {
+    fn sample() -> Self {
+        Self::builder()
+            .balance(Cost::from(dec!(10000)))
+            .total(Cost::sample())
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .balance(Cost::from(dec!(100)))
+            .total(Cost::sample_other())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = RetainerDrawdown;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn balance_exceeding_total_pays_all() {
+        let sut = Sut::builder()
+            .balance(Cost::from(dec!(5000)))
+            .total(Cost::from(dec!(1250)))
+            .build();
+        assert_eq!(*sut.drawn(), Cost::from(dec!(1250)));
+        assert_eq!(*sut.remaining(), Cost::from(dec!(3750)));
+        assert_eq!(*sut.amount_due(), Cost::from(dec!(0)));
+    }
+
+    #[test]
+    fn empty_balance_draws_nothing() {
+        let sut = Sut::builder()
+            .balance(Cost::from(dec!(0)))
+            .total(Cost::from(dec!(1250)))
+            .build();
+        assert_eq!(*sut.drawn(), Cost::from(dec!(0)));
+        assert_eq!(*sut.amount_due(), Cost::from(dec!(1250)));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::prelude::*;
+
+/// The VAT or sales tax of an invoice, with a subtotal per tax rate, added to
+/// the grand total of the line items.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Getters)]
+pub struct Tax {
+    /// The taxed amount and tax per tax rate, in descending order of rate.
+    #[getset(get = "pub")]
+    subtotals: Vec<TaxSubtotal>,
+
+    /// The sum of the tax of all subtotals.
+    #[getset(get = "pub")]
+    total_tax: Cost,
+
+    /// The grand total of the line items including tax.
+    #[getset(get = "pub")]
+    total_including_tax: Cost,
+
+    /// True if the client accounts for the VAT, i.e. when the vendor and
+    /// client are registered for VAT in different EU member states, in which
+    /// case the tax is zero.
+    #[getset(get = "pub")]
+    is_reverse_charge: bool,
+}
+
+/// The taxed amount and tax of the line items of a single tax rate.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Getters)]
+pub struct TaxSubtotal {
+    /// The tax rate, e.g. `25%`.
+    #[getset(get = "pub")]
+    rate: Percentage,
+
+    /// The sum of the total cost of the line items of this rate.
+    #[getset(get = "pub")]
+    taxable_amount: Cost,
+
+    /// The tax on the taxable amount, rounded to two decimals, zero if
+    /// reverse charged.
+    #[getset(get = "pub")]
+    tax: Cost,
+}
+
+impl Tax {
+    /// The tax of `line_items`, none if no line item has a tax rate. Unless
+    /// `is_reverse_charge` the tax of each rate is the taxable amount times
+    /// the rate, rounded to two decimals.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let line_items = LineItemsFlat::sample_taxed();
+    /// let tax = Tax::new(&line_items, false).unwrap();
+    /// assert_eq!(**tax.total_tax(), dec!(87.5));
+    /// assert_eq!(**tax.total_including_tax(), dec!(437.5));
+    /// let reverse_charged = Tax::new(&line_items, true).unwrap();
+    /// assert_eq!(**reverse_charged.total_tax(), dec!(0));
+    /// assert!(Tax::new(&LineItemsFlat::sample(), false).is_none());
+    /// ```
+    pub fn new(line_items: &LineItemsFlat, is_reverse_charge: bool) -> Option<Self> {
+        let mut taxable_amounts = IndexMap::<Percentage, rust_decimal::Decimal>::new();
+        for item in line_items.all_items() {
+            if let Some(rate) = item.tax_rate() {
+                *taxable_amounts.entry(*rate).or_default() += **item.total_cost();
+            }
+        }
+        if taxable_amounts.is_empty() {
+            return None;
+        }
+        taxable_amounts.sort_by(|lhs, _, rhs, _| rhs.cmp(lhs));
+
+        let subtotals = taxable_amounts
+            .into_iter()
+            .map(|(rate, taxable_amount)| {
+                let tax = if is_reverse_charge {
+                    rust_decimal::Decimal::ZERO
+                } else {
+                    (taxable_amount * rate.as_fraction()).round_dp(2)
+                };
+                TaxSubtotal {
+                    rate,
+                    taxable_amount: Cost::from(taxable_amount),
+                    tax: Cost::from(tax),
+                }
+            })
+            .collect::<Vec<_>>();
+        let total_tax = subtotals
+            .iter()
+            .map(|subtotal| *subtotal.tax)
+            .sum::<rust_decimal::Decimal>();
+        Some(Self {
+            subtotals,
+            total_tax: Cost::from(total_tax),
+            total_including_tax: Cost::from(*line_items.grand_total() + total_tax),
+            is_reverse_charge,
+        })
+    }
+}
+
+impl HasSample for Tax {
+    fn sample() -> Self {
+        Self::new(&LineItemsFlat::sample_taxed(), false).expect("Sample is taxed")
+    }
+
+    fn sample_other() -> Self {
+        Self::new(&LineItemsFlat::sample_taxed(), true).expect("Sample is taxed")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Tax;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn subtotal_per_rate_in_descending_order() {
+        let item = |total_cost: rust_decimal::Decimal, tax_rate: Option<rust_decimal::Decimal>| {
+            let item = Item::builder()
+                .name("Service".to_owned())
+                .unit_price(UnitPrice::from(total_cost))
+                .currency(Currency::SEK)
+                .quantity(Quantity::from(dec!(1)))
+                .transaction_date(Date::sample())
+                .maybe_tax_rate(tax_rate.map(|rate| Percentage::try_from(rate).unwrap()))
+                .build();
+            ItemConvertedIntoTargetCurrency::builder()
+                .in_source_currency(item)
+                .total_cost(Cost::from(total_cost))
+                .build()
+        };
+        let line_items = LineItemsFlat::builder()
+            .is_expenses(false)
+            .items(vec![
+                item(dec!(1000), Some(dec!(6))),
+                item(dec!(333.33), Some(dec!(25))),
+                item(dec!(500), Some(dec!(6))),
+                item(dec!(100), None),
+            ])
+            .build();
+        let sut = Sut::new(&line_items, false).unwrap();
+        let rates = sut
+            .subtotals()
+            .iter()
+            .map(|subtotal| {
+                (
+                    **subtotal.rate(),
+                    **subtotal.taxable_amount(),
+                    **subtotal.tax(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rates,
+            vec![
+                (dec!(25), dec!(333.33), dec!(83.33)),
+                (dec!(6), dec!(1500), dec!(90)),
+            ]
+        );
+        assert_eq!(**sut.total_tax(), dec!(173.33));
+        assert_eq!(**sut.total_including_tax(), dec!(2106.66));
+    }
+
+    #[test]
+    fn reverse_charge_zeroes_tax() {
+        let sut = Sut::sample_other();
+        assert!(sut.is_reverse_charge());
+        assert!(
+            sut.subtotals()
+                .iter()
+                .all(|subtotal| **subtotal.tax() == dec!(0))
+        );
+        assert_eq!(
+            sut.total_including_tax(),
+            &LineItemsFlat::sample_taxed().grand_total()
+        );
+    }
+}
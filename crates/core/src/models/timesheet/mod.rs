@@ -0,0 +1,8 @@
+#[allow(clippy::module_inception)]
+mod timesheet;
+mod timesheet_entry;
+mod timesheet_format;
+
+pub use timesheet::*;
+pub use timesheet_entry::*;
+pub use timesheet_format::*;
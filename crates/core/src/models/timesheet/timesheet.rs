@@ -0,0 +1,309 @@
+use crate::prelude::*;
+
+/// Hours worked on dates, imported from a CSV file, used as the billable
+/// quantity instead of the working days of the target period.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Getters)]
+pub struct Timesheet {
+    /// The entries of the timesheet, in the order of the file.
+    #[getset(get = "pub")]
+    entries: Vec<TimesheetEntry>,
+}
+
+/// Parses a date in the format `YYYY-MM-DD` or `MM/DD/YYYY`.
+fn parse_date(s: &str) -> Result<Date> {
+    match s.split('/').collect::<Vec<_>>().as_slice() {
+        [month, day, year] => Date::from_str(&format!("{year}-{month:0>2}-{day:0>2}")),
+        _ => Date::from_str(s),
+    }
+}
+
+/// Parses hours as a decimal number, e.g. `7.5`, or as a duration of
+/// `HH:MM:SS` or `HH:MM`, e.g. `07:30:00`.
+fn parse_hours(s: &str) -> Result<Quantity> {
+    let invalid = || Error::InvalidTimesheet {
+        line: 0,
+        reason: format!("Invalid hours: '{s}'"),
+    };
+    let decimal = |s: &str| rust_decimal::Decimal::from_str(s).map_err(|_| invalid());
+    let hours = match s.split(':').collect::<Vec<_>>().as_slice() {
+        [hours] => decimal(hours)?,
+        [hours, minutes] => decimal(hours)? + decimal(minutes)? / dec!(60),
+        [hours, minutes, seconds] => {
+            decimal(hours)? + decimal(minutes)? / dec!(60) + decimal(seconds)? / dec!(3600)
+        }
+        _ => return Err(invalid()),
+    };
+    if hours.is_sign_negative() {
+        return Err(invalid());
+    }
+    Ok(Quantity::from(hours.round_dp(4)))
+}
+
+impl Timesheet {
+    /// Creates a new timesheet from entries.
+    pub fn new(entries: impl IntoIterator<Item = TimesheetEntry>) -> Self {
+        Self {
+            entries: entries.into_iter().collect(),
+        }
+    }
+
+    /// Reads and parses the timesheet CSV file at `path`, detecting the format
+    /// from the header if `format` is `None`.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not a valid timesheet.
+    pub fn read(path: impl AsRef<Path>, format: Option<TimesheetFormat>) -> Result<Self> {
+        let path = path.as_ref();
+        let csv = fs::read_to_string(path).map_err(|e| Error::FileNotFound {
+            path: path.display().to_string(),
+            underlying: e.to_string(),
+        })?;
+        Self::parse(&csv, format)
+    }
+
+    /// Parses a timesheet CSV, detecting the format from the header if
+    /// `format` is `None`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    ///
+    /// let csv = "date,hours,description\n2025-05-02,7.5,Code review\n2025-05-05,8,";
+    /// let timesheet = Timesheet::parse(csv, None).unwrap();
+    /// assert_eq!(timesheet.entries().len(), 2);
+    /// assert_eq!(timesheet.total_hours(), Quantity::from(dec!(15.5)));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if a row is missing a date or hours, if a date or hours
+    /// cannot be parsed, if a header lacks the columns of the format, or if
+    /// there are no entries.
+    pub fn parse(csv: &str, format: Option<TimesheetFormat>) -> Result<Self> {
+        let rows = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(csv.as_bytes())
+            .into_records()
+            .map(|row| {
+                row.map_err(|e| Error::InvalidTimesheet {
+                    line: e.position().map(|p| p.line()).unwrap_or_default() as usize,
+                    reason: e.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let Some(first_row) = rows.first() else {
+            return Err(Error::InvalidTimesheet {
+                line: 0,
+                reason: "The timesheet is empty".to_owned(),
+            });
+        };
+        let format = format.unwrap_or_else(|| TimesheetFormat::detect(first_row));
+
+        let (date_index, hours_index, description_index, rows) =
+            match (format.date_columns(), format.hours_columns()) {
+                (Some(date_columns), Some(hours_columns)) => {
+                    let header = first_row
+                        .iter()
+                        .map(|column| column.to_lowercase())
+                        .collect::<Vec<_>>();
+                    let index_of = |names: &[&str]| {
+                        names
+                            .iter()
+                            .find_map(|name| header.iter().position(|column| column == name))
+                            .ok_or_else(|| Error::InvalidTimesheet {
+                                line: 1,
+                                reason: format!("Missing column '{}' for {format}", names[0]),
+                            })
+                    };
+                    let description_index = index_of(&["description"]).ok();
+                    (
+                        index_of(date_columns)?,
+                        index_of(hours_columns)?,
+                        description_index,
+                        &rows[1..],
+                    )
+                }
+                _ => {
+                    let first_cell = first_row.get(0).unwrap_or_default();
+                    let has_header = parse_date(first_cell).is_err();
+                    (
+                        0,
+                        1,
+                        Some(2),
+                        if has_header { &rows[1..] } else { &rows[..] },
+                    )
+                }
+            };
+
+        let entries = rows
+            .iter()
+            .map(|row| {
+                let line = row.position().map(|p| p.line()).unwrap_or_default() as usize;
+                let invalid = |reason: String| Error::InvalidTimesheet { line, reason };
+                let cell = |index: usize, name: &str| {
+                    row.get(index)
+                        .filter(|cell| !cell.is_empty())
+                        .ok_or_else(|| invalid(format!("Missing {name}")))
+                };
+                let date =
+                    parse_date(cell(date_index, "date")?).map_err(|e| invalid(e.to_string()))?;
+                let hours = parse_hours(cell(hours_index, "hours")?).map_err(|e| match e {
+                    Error::InvalidTimesheet { reason, .. } => invalid(reason),
+                    e => e,
+                })?;
+                let description = description_index
+                    .and_then(|index| row.get(index))
+                    .filter(|description| !description.is_empty())
+                    .map(str::to_owned);
+                Ok(TimesheetEntry::builder()
+                    .date(date)
+                    .hours(hours)
+                    .maybe_description(description)
+                    .build())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if entries.is_empty() {
+            return Err(Error::InvalidTimesheet {
+                line: 0,
+                reason: "The timesheet has no entries".to_owned(),
+            });
+        }
+        Ok(Self::new(entries))
+    }
+
+    /// The entries of the timesheet within `date_range`, e.g. the dates of
+    /// one of several rates in effect during the invoiced period.
+    pub fn within(&self, date_range: &DateRange) -> Self {
+        Self::new(
+            self.entries
+                .iter()
+                .filter(|entry| date_range.contains(entry.date()))
+                .cloned(),
+        )
+    }
+
+    /// The sum of the hours of all entries.
+    pub fn total_hours(&self) -> Quantity {
+        self.entries
+            .iter()
+            .fold(Quantity::ZERO, |acc, entry| acc + *entry.hours())
+    }
+}
+
+impl HasSample for Timesheet {
+    fn sample() -> Self {
+        Self::new([TimesheetEntry::sample(), TimesheetEntry::sample_other()])
+    }
+
+    fn sample_other() -> Self {
+        Self::new([TimesheetEntry::sample_other()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Timesheet;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn parse_plain_without_header() {
+        let csv = "2025-05-02, 7.5,\"Code review, part 1\"\n\n2025-05-05,8\n";
+        let sut = Sut::parse(csv, None).unwrap();
+        assert_eq!(
+            sut,
+            Sut::new([
+                TimesheetEntry::builder()
+                    .date(Date::from_str("2025-05-02").unwrap())
+                    .hours(Quantity::from(dec!(7.5)))
+                    .description("Code review, part 1")
+                    .build(),
+                TimesheetEntry::sample_other(),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_toggl() {
+        let csv = indoc::indoc! {r#"
+            User,Email,Client,Project,Task,Description,Billable,Start date,Start time,End date,End time,Duration,Tags,Amount ()
+            Jane,jane@example.com,Acme,Website,,Code review,Yes,2025-05-02,09:00:00,2025-05-02,16:30:00,07:30:00,,
+            Jane,jane@example.com,Acme,Website,,"Meeting, weekly",Yes,2025-05-05,09:00:00,2025-05-05,09:20:00,00:20:00,,
+        "#};
+        let sut = Sut::parse(csv, None).unwrap();
+        assert_eq!(sut.entries().len(), 2);
+        assert_eq!(
+            sut.entries()[1].description().as_deref(),
+            Some("Meeting, weekly")
+        );
+        assert_eq!(sut.total_hours(), Quantity::from(dec!(7.8333)));
+    }
+
+    #[test]
+    fn parse_clockify() {
+        let csv = indoc::indoc! {r#"
+            "Project","Client","Description","Task","User","Group","Email","Tags","Billable","Start Date","Start Time","End Date","End Time","Duration (h)","Duration (decimal)"
+            "Website","Acme","Code review","","Jane","","jane@example.com","","Yes","05/02/2025","09:00:00 AM","05/02/2025","04:30:00 PM","07:30:00","7.50"
+        "#};
+        let sut = Sut::parse(csv, None).unwrap();
+        assert_eq!(sut, Sut::new([TimesheetEntry::sample()]));
+    }
+
+    #[test]
+    fn parse_throws_for_missing_column() {
+        let csv = "Description,Start date,Duration\nCode review,2025-05-02,07:30:00";
+        let result = Sut::parse(csv, Some(TimesheetFormat::Clockify));
+        assert!(matches!(
+            result,
+            Err(Error::InvalidTimesheet { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn parse_throws_for_invalid_hours() {
+        let csv = "2025-05-02,7.5\n2025-05-05,-1";
+        let result = Sut::parse(csv, None);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidTimesheet { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn parse_throws_for_invalid_date() {
+        let csv = "date,hours\n2025-13-02,7.5";
+        let result = Sut::parse(csv, None);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidTimesheet { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn parse_throws_when_empty() {
+        assert!(matches!(
+            Sut::parse("date,hours,description\n", None),
+            Err(Error::InvalidTimesheet { .. })
+        ));
+    }
+
+    #[test]
+    fn read_throws_for_missing_file() {
+        let result = Sut::read("/nonexistent/timesheet.csv", None);
+        assert!(matches!(result, Err(Error::FileNotFound { .. })));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::prelude::*;
+
+/// A single entry of a timesheet, the hours worked on a date, e.g.
+/// `2025-05-02, 7.5, "Code review"`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Builder, Getters)]
+pub struct TimesheetEntry {
+    /// The date the hours were worked, e.g. `2025-05-02`.
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// The number of hours worked, e.g. `7.5`.
+    #[getset(get = "pub")]
+    hours: Quantity,
+
+    /// An optional description of the work, e.g. `"Code review"`.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    description: Option<String>,
+}
+
+impl HasSample for TimesheetEntry {
+    fn sample() -> Self {
+        Self::builder()
+            .date(Date::from_str("2025-05-02").expect("Valid date"))
+            .hours(Quantity::from(dec!(7.5)))
+            .description("Code review")
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .date(Date::from_str("2025-05-05").expect("Valid date"))
+            .hours(Quantity::EIGHT)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = TimesheetEntry;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::prelude::*;
+use derive_more::FromStr;
+
+/// The format of a timesheet CSV file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Default, FromStr, EnumIter)]
+pub enum TimesheetFormat {
+    /// Rows of `date,hours,description`, e.g. `2025-05-02,7.5,Code review`,
+    /// with an optional header row and an optional description.
+    #[default]
+    Plain,
+
+    /// The detailed CSV export of Toggl Track, using the `Start date`,
+    /// `Duration` (`HH:MM:SS`) and `Description` columns.
+    Toggl,
+
+    /// The detailed CSV export of Clockify, using the `Start Date`,
+    /// `Duration (decimal)` or `Duration (h)` and `Description` columns.
+    Clockify,
+}
+
+impl TimesheetFormat {
+    /// Detects the format from the first row of a timesheet, falling back to
+    /// [`TimesheetFormat::Plain`] if it is not a known header.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    ///
+    /// let header = ["Project", "Description", "Start Date", "Duration (decimal)"];
+    /// assert_eq!(TimesheetFormat::detect(header), TimesheetFormat::Clockify);
+    /// assert_eq!(TimesheetFormat::detect(["2025-05-02", "7.5"]), TimesheetFormat::Plain);
+    /// ```
+    pub fn detect<'a>(first_row: impl IntoIterator<Item = &'a str>) -> Self {
+        let columns = first_row
+            .into_iter()
+            .map(|column| column.trim().to_lowercase())
+            .collect::<Vec<_>>();
+        let has = |name: &str| columns.iter().any(|column| column == name);
+        if has("duration (decimal)") || has("duration (h)") {
+            Self::Clockify
+        } else if has("start date") && has("duration") {
+            Self::Toggl
+        } else {
+            Self::Plain
+        }
+    }
+
+    /// The names of the date column in the header, lowercased, `None` for
+    /// formats without a header.
+    pub(crate) fn date_columns(&self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::Plain => None,
+            Self::Toggl | Self::Clockify => Some(&["start date"]),
+        }
+    }
+
+    /// The names of the hours column in the header in order of preference,
+    /// lowercased, `None` for formats without a header.
+    pub(crate) fn hours_columns(&self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::Plain => None,
+            Self::Toggl => Some(&["duration"]),
+            Self::Clockify => Some(&["duration (decimal)", "duration (h)"]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = TimesheetFormat;
+
+    #[test]
+    fn detect_toggl() {
+        let header = [
+            "User",
+            "Email",
+            "Client",
+            "Project",
+            "Task",
+            "Description",
+            "Billable",
+            "Start date",
+            "Start time",
+            "End date",
+            "End time",
+            "Duration",
+        ];
+        assert_eq!(Sut::detect(header), Sut::Toggl);
+    }
+
+    #[test]
+    fn detect_plain_header() {
+        assert_eq!(Sut::detect(["date", "hours", "description"]), Sut::Plain);
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Sut::from_str("toggl").unwrap(), Sut::Toggl);
+        assert_eq!(Sut::from_str("Clockify").unwrap(), Sut::Clockify);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::prelude::*;
+
+/// Input for a credit note reversing a previously issued invoice which has
+/// been validated and is ready for processing.
+#[derive(Debug, Clone, Display, Builder, Getters, WithSetters)]
+#[display(
+    "Invoice: {}, number: {}, date: {}, percentage: {}",
+    invoice,
+    number,
+    date,
+    percentage
+)]
+pub struct ValidCreditNoteInput {
+    /// The input of the original invoice, which is generated again to get the
+    /// invoiced line items, e.g. its period, items and language.
+    #[getset(get = "pub", set_with = "pub")]
+    invoice: ValidInput,
+
+    /// The number of the original invoice, which must match the number of the
+    /// invoice generated for the period.
+    #[getset(get = "pub")]
+    number: FormattedInvoiceNumber,
+
+    /// The date of the credit note.
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// The share of the invoice to credit, `100%` for a full reversal.
+    #[builder(default = Percentage::HUNDRED)]
+    #[getset(get = "pub")]
+    percentage: Percentage,
+}
+
+impl HasSample for ValidCreditNoteInput {
+    fn sample() -> Self {
+        Self::builder()
+            .invoice(ValidInput::sample())
+            .number(FormattedInvoiceNumber::sample())
+            .date(Date::from_str("2025-07-31").expect("Valid date"))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .invoice(ValidInput::sample_other())
+            .number(FormattedInvoiceNumber::sample_other())
+            .date(Date::from_str("2025-01-15").expect("Valid date"))
+            .percentage(Percentage::try_from(dec!(50)).expect("Valid percentage"))
+            .build()
+    }
+}
@@ -4,7 +4,7 @@ use crate::prelude::*;
 /// Controls which language to use, the month for which to generate the invoice,
 /// the items to be invoiced, the layout of the invoice, and an optional output path
 /// for the generated PDF file.
-#[derive(Debug, Clone, Display, Builder, Getters)]
+#[derive(Debug, Clone, Display, Builder, Getters, WithSetters)]
 #[display("Layout: {}, Period: {}, out: {:?}, items: {}, language: {}", layout, period, maybe_output_path.as_ref().map(|d|d.display()), items, language)]
 pub struct ValidInput {
     /// The language to use for the invoice, used on labels, headers etc.
@@ -16,13 +16,47 @@ pub struct ValidInput {
     /// The period for which to generate the invoice, this affects the invoice
     /// number as well as the invoice date and due date.
     ///
-    /// Note: We use the period type with the highest granularity, so that we
-    /// always can convert it to a kind of period of more coarse granularity.
-    /// For example, if the period is `YearMonthAndFortnight`, we can always
-    /// convert it to `YearAndMonth` later in the flow if that matches the invoice
-    /// cadence.
+    /// Note: For monthly and bi-weekly cadence we use the period type with the
+    /// highest granularity, so that we always can convert it to a kind of period
+    /// of more coarse granularity. For example, if the period is
+    /// `YearMonthAndFortnight`, we can always convert it to `YearAndMonth` later
+    /// in the flow if that matches the invoice cadence. For weekly cadence the
+    /// period is a `YearAndWeek`.
+    #[builder(into)]
     #[getset(get = "pub")]
-    period: YearMonthAndFortnight,
+    period: PeriodAnno,
+
+    /// An optional custom range of dates to invoice for instead of the whole
+    /// `period`, which then is the period containing the last day of the
+    /// range, used for the invoice number.
+    #[getset(get = "pub")]
+    date_range: Option<DateRange>,
+
+    /// An optional override of the invoice date, which otherwise is the last
+    /// day of the period or date range.
+    #[getset(get = "pub")]
+    invoice_date: Option<Date>,
+
+    /// An optional override of the unformatted invoice number, which
+    /// otherwise is calculated from the offset, e.g. the next number of the
+    /// series shared by client profiles.
+    #[getset(get = "pub", set_with = "pub")]
+    invoice_number: Option<InvoiceNumber>,
+
+    /// The name of the client profile to invoice, e.g. `"acme"`, instead of
+    /// the default client, see [`ClientProfile`].
+    #[getset(get = "pub")]
+    client: Option<String>,
+
+    /// A discount of the whole invoice, e.g. `"10%"`, replacing the standing
+    /// discount of the invoice information, if any.
+    #[getset(get = "pub", set_with = "pub")]
+    discount: Option<Discount>,
+
+    /// A discount of the service fees, e.g. `"500"` to waive part of a
+    /// period, replacing the standing discount of the service fees, if any.
+    #[getset(get = "pub", set_with = "pub")]
+    service_discount: Option<Discount>,
 
     /// The items to be invoiced, either services or expenses.
     #[builder(default)]
@@ -45,6 +79,27 @@ pub struct ValidInput {
     email: Option<DecryptedEmailSettings>,
 }
 
+impl ValidInput {
+    /// The date of the invoice for `target_period`: the chosen invoice date if
+    /// any, else the last day of the date range if any, else the last day of
+    /// `target_period`.
+    ///
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let input = ValidInput::builder().period(YearAndMonth::may(2025)).build();
+    /// assert_eq!(
+    ///     input.invoice_date_for(&YearAndMonth::may(2025)),
+    ///     Date::from_str("2025-05-31").unwrap()
+    /// );
+    /// ```
+    pub fn invoice_date_for(&self, target_period: &impl IsPeriod) -> Date {
+        self.invoice_date
+            .or(self.date_range.map(|date_range| *date_range.end()))
+            .unwrap_or_else(|| target_period.to_date_end_of_period())
+    }
+}
+
 impl HasSample for ValidInput {
     fn sample() -> Self {
         Self::builder()
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+
+/// Input for a payment reminder of a previously issued invoice which has been
+/// validated and is ready for processing.
+#[derive(Debug, Clone, Display, Builder, Getters, WithSetters)]
+#[display(
+    "Invoice: {}, number: {}, date: {}, paid: {}",
+    invoice,
+    number,
+    date,
+    is_paid
+)]
+pub struct ValidReminderInput {
+    /// The input of the original invoice, which is generated again to get the
+    /// invoiced amount and due date, e.g. its period, items and language.
+    #[getset(get = "pub", set_with = "pub")]
+    invoice: ValidInput,
+
+    /// The number of the original invoice, which must match the number of the
+    /// invoice generated for the period.
+    #[getset(get = "pub")]
+    number: FormattedInvoiceNumber,
+
+    /// The date until which late payment interest is charged, i.e. the date
+    /// the invoice was paid, or today if unpaid.
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// Whether the invoice was paid, late, on `date`.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    is_paid: bool,
+}
+
+impl HasSample for ValidReminderInput {
+    fn sample() -> Self {
+        Self::builder()
+            .invoice(ValidInput::sample())
+            .number(FormattedInvoiceNumber::sample())
+            .date(Date::from_str("2025-07-31").expect("Valid date"))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .invoice(ValidInput::sample_other())
+            .number(FormattedInvoiceNumber::sample_other())
+            .date(Date::from_str("2025-01-15").expect("Valid date"))
+            .is_paid(true)
+            .build()
+    }
+}